@@ -0,0 +1,497 @@
+"""Cargo manifest and workspace parsing for Rust project analysis.
+
+Reads ``Cargo.toml`` files directly (no cargo invocation) and resolves the
+pieces the analyzer cares about: workspace members (including glob members and
+``exclude``), ``[workspace.package]`` / ``[workspace.dependencies]``
+inheritance, and every dependency table - ``[dependencies]``,
+``[dev-dependencies]``, ``[build-dependencies]`` and their ``[target.*]``
+variants. ``Cargo.lock`` is parsed into the resolved crate graph with pinned
+versions and sources.
+"""
+
+from __future__ import annotations
+
+import re
+
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+import toml
+
+from claude_builder.core.models import DependencyInfo
+
+
+CARGO_MANIFEST = "Cargo.toml"
+CARGO_LOCK = "Cargo.lock"
+CRATES_IO_INDEXES = (
+    "registry+https://github.com/rust-lang/crates.io-index",
+    "sparse+https://index.crates.io/",
+)
+
+# Crate-level inner attributes such as ``#![no_std]`` (at line start only)
+CRATE_ATTRIBUTE_PATTERN = re.compile(r"^\s*#!\[\s*(no_std|no_main)\s*\]", re.MULTILINE)
+
+# Manifest table name -> dependency kind
+DEPENDENCY_TABLES: dict[str, str] = {
+    "dependencies": "normal",
+    "dev-dependencies": "dev",
+    "build-dependencies": "build",
+}
+
+
+@dataclass
+class CargoDependency:
+    """A single dependency declaration from a Cargo manifest."""
+
+    name: str  # Real crate name (honours ``package = "..."`` renames)
+    alias: str  # Key used in the manifest
+    kind: str = "normal"  # normal, dev or build
+    version: str | None = None
+    features: list[str] = field(default_factory=list)
+    default_features: bool = True
+    optional: bool = False
+    target: str | None = None  # cfg/triple for [target.*] tables
+    workspace: bool = False  # Inherited via ``dep.workspace = true``
+    path: str | None = None
+    git: str | None = None
+
+
+def _parse_dependency_spec(spec: Any) -> dict[str, Any]:
+    """Normalise a dependency value (string or inline table) into a dict."""
+    if isinstance(spec, str):
+        return {"version": spec}
+    if isinstance(spec, dict):
+        return dict(spec)
+    return {}
+
+
+def _build_dependency(
+    alias: str,
+    spec: dict[str, Any],
+    *,
+    kind: str,
+    target: str | None,
+    workspace_dependencies: dict[str, Any],
+) -> CargoDependency:
+    """Create a dependency, merging ``[workspace.dependencies]`` when inherited."""
+    inherited = bool(spec.get("workspace"))
+    merged = dict(spec)
+    if inherited:
+        base = _parse_dependency_spec(workspace_dependencies.get(alias))
+        merged = {**base, **{k: v for k, v in spec.items() if k != "workspace"}}
+        # Features are additive on top of the workspace declaration
+        merged["features"] = list(base.get("features", [])) + [
+            f for f in spec.get("features", []) if f not in base.get("features", [])
+        ]
+
+    version = merged.get("version")
+    default_features = merged.get("default-features", merged.get("default_features"))
+    return CargoDependency(
+        name=str(merged.get("package") or alias),
+        alias=alias,
+        kind=kind,
+        version=version if isinstance(version, str) else None,
+        features=[str(f) for f in merged.get("features", []) or []],
+        default_features=default_features is not False,
+        optional=bool(merged.get("optional", False)),
+        target=target,
+        workspace=inherited,
+        path=merged.get("path"),
+        git=merged.get("git"),
+    )
+
+
+@dataclass
+class CargoManifest:
+    """A parsed ``Cargo.toml`` file."""
+
+    path: Path
+    data: dict[str, Any] = field(default_factory=dict)
+
+    @classmethod
+    def load(cls, manifest_path: Path) -> CargoManifest | None:
+        """Load a manifest, returning None when missing or unparseable."""
+        if not manifest_path.is_file():
+            return None
+        try:
+            with manifest_path.open(encoding="utf-8") as f:
+                data = toml.load(f)
+        except (OSError, toml.TomlDecodeError, ValueError, UnicodeDecodeError):
+            return None
+        return cls(path=manifest_path, data=data)
+
+    @property
+    def directory(self) -> Path:
+        """Directory containing the manifest."""
+        return self.path.parent
+
+    @property
+    def package(self) -> dict[str, Any]:
+        """The ``[package]`` table (empty for virtual manifests)."""
+        package = self.data.get("package", {})
+        return package if isinstance(package, dict) else {}
+
+    @property
+    def workspace(self) -> dict[str, Any]:
+        """The ``[workspace]`` table (empty when not a workspace root)."""
+        workspace = self.data.get("workspace", {})
+        return workspace if isinstance(workspace, dict) else {}
+
+    @property
+    def name(self) -> str:
+        """Package name, falling back to the directory name."""
+        return str(self.package.get("name") or self.directory.name)
+
+    def package_field(
+        self, key: str, workspace_package: dict[str, Any] | None = None
+    ) -> str | None:
+        """Read a ``[package]`` field, resolving ``key.workspace = true``."""
+        value = self.package.get(key)
+        if isinstance(value, dict) and value.get("workspace"):
+            value = (workspace_package or {}).get(key)
+        return str(value) if value is not None and not isinstance(value, dict) else None
+
+    def dependencies(
+        self, workspace_dependencies: dict[str, Any] | None = None
+    ) -> list[CargoDependency]:
+        """Return dependencies from every dependency table in the manifest."""
+        workspace_dependencies = workspace_dependencies or {}
+        found: list[CargoDependency] = []
+
+        def collect(tables: dict[str, Any], target: str | None) -> None:
+            for table_name, kind in DEPENDENCY_TABLES.items():
+                table = tables.get(table_name, {})
+                if not isinstance(table, dict):
+                    continue
+                for alias, raw in table.items():
+                    found.append(
+                        _build_dependency(
+                            alias,
+                            _parse_dependency_spec(raw),
+                            kind=kind,
+                            target=target,
+                            workspace_dependencies=workspace_dependencies,
+                        )
+                    )
+
+        collect(self.data, None)
+        targets = self.data.get("target", {})
+        if isinstance(targets, dict):
+            for target, tables in targets.items():
+                if isinstance(tables, dict):
+                    collect(tables, target)
+        return found
+
+    @property
+    def lib(self) -> dict[str, Any]:
+        """The ``[lib]`` table (empty when absent)."""
+        lib = self.data.get("lib", {})
+        return lib if isinstance(lib, dict) else {}
+
+    def crate_types(self) -> list[str]:
+        """``[lib] crate-type`` values such as ``cdylib`` (empty if undeclared)."""
+        types = self.lib.get("crate-type", self.lib.get("crate_type", []))
+        return [str(t) for t in types] if isinstance(types, list) else []
+
+    def crate_roots(self) -> list[Path]:
+        """Existing crate root files (library and binary entry points)."""
+        src = self.directory / "src"
+        candidates = [src / "lib.rs", src / "main.rs"]
+        if isinstance(self.lib.get("path"), str):
+            candidates.insert(0, self.directory / self.lib["path"])
+        bins = self.data.get("bin", [])
+        for target in bins if isinstance(bins, list) else []:
+            if isinstance(target, dict) and isinstance(target.get("path"), str):
+                candidates.append(self.directory / target["path"])
+        return [path for path in dict.fromkeys(candidates) if path.is_file()]
+
+    def crate_attributes(self) -> set[str]:
+        """Unconditional crate-level attributes, e.g. ``no_std`` or ``no_main``.
+
+        ``#![cfg_attr(..., no_std)]`` is deliberately ignored: such crates still
+        build with ``std`` by default.
+        """
+        attributes: set[str] = set()
+        for root in self.crate_roots():
+            try:
+                content = root.read_text(encoding="utf-8", errors="ignore")
+            except OSError:
+                continue
+            attributes.update(CRATE_ATTRIBUTE_PATTERN.findall(content))
+        return attributes
+
+    def crate_targets(self) -> list[str]:
+        """Return the crate target kinds built by this package."""
+        src = self.directory / "src"
+
+        targets: list[str] = []
+        if self.lib.get("proc-macro") or self.lib.get("proc_macro"):
+            targets.append("proc-macro")
+        elif "lib" in self.data or (src / "lib.rs").exists():
+            targets.extend(self.crate_types() or ["lib"])
+
+        if (
+            self.data.get("bin")
+            or (src / "main.rs").exists()
+            or (src / "bin").is_dir()
+        ):
+            targets.append("bin")
+        return targets
+
+    def crate_kind(self) -> str:
+        """Summarise the package as ``proc-macro``, ``bin``, ``cdylib`` or ``lib``."""
+        targets = self.crate_targets()
+        for kind in ("proc-macro", "bin", "cdylib", "staticlib"):
+            if kind in targets:
+                return kind
+        return "lib"
+
+
+@dataclass
+class CargoWorkspace:
+    """A Cargo workspace (or single package) rooted at a project directory."""
+
+    root: CargoManifest
+    members: list[CargoManifest] = field(default_factory=list)
+
+    @property
+    def is_workspace(self) -> bool:
+        """Whether the root manifest declares a ``[workspace]``."""
+        return "workspace" in self.root.data
+
+    @property
+    def is_virtual(self) -> bool:
+        """Whether the root is a virtual manifest (workspace without a package)."""
+        return self.is_workspace and not self.root.package
+
+    @property
+    def workspace_package(self) -> dict[str, Any]:
+        """Inheritable ``[workspace.package]`` fields."""
+        package = self.root.workspace.get("package", {})
+        return package if isinstance(package, dict) else {}
+
+    @property
+    def workspace_dependencies(self) -> dict[str, Any]:
+        """Inheritable ``[workspace.dependencies]`` declarations."""
+        deps = self.root.workspace.get("dependencies", {})
+        return deps if isinstance(deps, dict) else {}
+
+    def edition(self, manifest: CargoManifest) -> str | None:
+        """Resolved edition for a member manifest."""
+        return manifest.package_field("edition", self.workspace_package)
+
+    def version(self, manifest: CargoManifest) -> str | None:
+        """Resolved version for a member manifest."""
+        return manifest.package_field("version", self.workspace_package)
+
+    def rust_version(self, manifest: CargoManifest) -> str | None:
+        """Resolved minimum supported Rust version (``rust-version``)."""
+        return manifest.package_field("rust-version", self.workspace_package)
+
+    def dependencies(self, manifest: CargoManifest) -> list[CargoDependency]:
+        """Dependencies of a member with workspace inheritance applied."""
+        return manifest.dependencies(self.workspace_dependencies)
+
+    def all_dependencies(self) -> list[CargoDependency]:
+        """Dependencies declared by every member of the workspace."""
+        deps: list[CargoDependency] = []
+        for member in self.members:
+            deps.extend(self.dependencies(member))
+        return deps
+
+    def relative_path(self, manifest: CargoManifest) -> str:
+        """Member directory relative to the workspace root (``.`` for root)."""
+        try:
+            relative = manifest.directory.relative_to(self.root.directory)
+        except ValueError:
+            return str(manifest.directory)
+        return relative.as_posix() or "."
+
+
+def _expand_member_patterns(root: Path, patterns: list[str]) -> list[Path]:
+    """Expand ``members``/``exclude`` entries (which may be globs) to dirs."""
+    directories: list[Path] = []
+    for pattern in patterns:
+        if not isinstance(pattern, str) or not pattern:
+            continue
+        if any(ch in pattern for ch in "*?["):
+            matches = sorted(p for p in root.glob(pattern) if p.is_dir())
+        else:
+            matches = [root / pattern]
+        for match in matches:
+            resolved = match.resolve()
+            if resolved not in directories:
+                directories.append(resolved)
+    return directories
+
+
+def load_cargo_workspace(project_path: Path) -> CargoWorkspace | None:
+    """Load the Cargo workspace rooted at ``project_path``.
+
+    A plain package is treated as a one-member workspace, mirroring cargo's own
+    behaviour. Returns None when there is no readable root ``Cargo.toml``.
+    """
+    root = CargoManifest.load(project_path.resolve() / CARGO_MANIFEST)
+    if root is None:
+        return None
+
+    workspace = CargoWorkspace(root=root)
+    if root.package:
+        workspace.members.append(root)
+
+    if not workspace.is_workspace:
+        return workspace
+
+    members = root.workspace.get("members", [])
+    excluded = set(
+        _expand_member_patterns(root.directory, root.workspace.get("exclude", []))
+    )
+    seen = {root.directory.resolve()}
+    for directory in _expand_member_patterns(root.directory, list(members)):
+        if directory in excluded or directory in seen:
+            continue
+        seen.add(directory)
+        manifest = CargoManifest.load(directory / CARGO_MANIFEST)
+        if manifest is not None:
+            workspace.members.append(manifest)
+
+    return workspace
+
+
+@dataclass
+class LockedPackage:
+    """A ``[[package]]`` entry from ``Cargo.lock``."""
+
+    name: str
+    version: str
+    source: str | None = None  # Raw source string; None for path/workspace crates
+    dependencies: list[str] = field(default_factory=list)  # Raw references
+
+    @property
+    def source_kind(self) -> str:
+        """Classify the source as ``crates.io``, ``registry``, ``git`` or ``path``."""
+        if self.source is None:
+            return "path"
+        if self.source in CRATES_IO_INDEXES:
+            return "crates.io"
+        if self.source.startswith("git+"):
+            return "git"
+        return "registry"
+
+
+def load_cargo_lock(lock_path: Path) -> list[LockedPackage] | None:
+    """Parse ``Cargo.lock`` (any lockfile version), or None when unavailable."""
+    if not lock_path.is_file():
+        return None
+    try:
+        with lock_path.open(encoding="utf-8") as f:
+            data = toml.load(f)
+    except (OSError, toml.TomlDecodeError, ValueError, UnicodeDecodeError):
+        return None
+
+    packages: list[LockedPackage] = []
+    for entry in data.get("package", []):
+        if not isinstance(entry, dict) or "name" not in entry:
+            continue
+        packages.append(
+            LockedPackage(
+                name=str(entry["name"]),
+                version=str(entry.get("version", "")),
+                source=entry.get("source"),
+                dependencies=[str(d) for d in entry.get("dependencies", [])],
+            )
+        )
+    return packages
+
+
+def _resolve_lock_reference(
+    reference: str, by_name: dict[str, list[LockedPackage]]
+) -> LockedPackage | None:
+    """Resolve ``name``, ``name version`` or ``name version (source)`` refs."""
+    parts = reference.split(" ", 2)
+    candidates = by_name.get(parts[0], [])
+    if len(parts) > 1:
+        candidates = [p for p in candidates if p.version == parts[1]] or candidates
+    return candidates[0] if candidates else None
+
+
+def build_dependency_graph(
+    project_path: Path, workspace: CargoWorkspace | None = None
+) -> list[DependencyInfo]:
+    """Build the resolved crate graph from ``Cargo.lock``.
+
+    Workspace members themselves are excluded; every other locked crate is
+    returned with its pinned version, source kind, outgoing edges and whether
+    a workspace member depends on it directly. Direct dependencies carry the
+    dependency type and features declared in the member manifests.
+    """
+    packages = load_cargo_lock(project_path / CARGO_LOCK)
+    if not packages:
+        return []
+    if workspace is None:
+        workspace = load_cargo_workspace(project_path)
+
+    by_name: dict[str, list[LockedPackage]] = {}
+    for package in packages:
+        by_name.setdefault(package.name, []).append(package)
+
+    if workspace is not None and workspace.members:
+        member_names = {member.name for member in workspace.members}
+    else:
+        member_names = {p.name for p in packages if p.source is None}
+
+    # Manifest declarations of direct dependencies: kinds and enabled features
+    declared_kinds: dict[str, set[str]] = {}
+    declared_features: dict[str, list[str]] = {}
+    for dep in workspace.all_dependencies() if workspace else []:
+        declared_kinds.setdefault(dep.name, set()).add(dep.kind)
+        features = declared_features.setdefault(dep.name, [])
+        features.extend(f for f in dep.features if f not in features)
+
+    def key(package: LockedPackage) -> tuple[str, str]:
+        return (package.name, package.version)
+
+    direct: set[tuple[str, str]] = set()
+    for package in packages:
+        if package.name not in member_names or package.source is not None:
+            continue
+        for reference in package.dependencies:
+            resolved = _resolve_lock_reference(reference, by_name)
+            if resolved is not None and resolved.name not in member_names:
+                direct.add(key(resolved))
+
+    graph: list[DependencyInfo] = []
+    for package in packages:
+        if package.name in member_names and package.source is None:
+            continue
+        edges: dict[str, str] = {}
+        for reference in package.dependencies:
+            resolved = _resolve_lock_reference(reference, by_name)
+            if resolved is not None:
+                edges[resolved.name] = resolved.version
+
+        is_direct = key(package) in direct
+        kinds = declared_kinds.get(package.name, set()) if is_direct else set()
+        if not kinds or "normal" in kinds:
+            dependency_type = "runtime"
+        elif "build" in kinds:
+            dependency_type = "build"
+        else:
+            dependency_type = "development"
+
+        graph.append(
+            DependencyInfo(
+                name=package.name,
+                version=package.version,
+                dependency_type=dependency_type,
+                source=package.source_kind,
+                package_managers=["cargo"],
+                dependencies=edges,
+                direct=is_direct,
+                features=declared_features.get(package.name, []) if is_direct else [],
+            )
+        )
+
+    graph.sort(key=lambda dep: (not dep.direct, dep.name, dep.version or ""))
+    return graph
@@ -0,0 +1,513 @@
+"""Code metrics for the deep complexity assessment.
+
+With ``deep_analysis`` enabled the analyzer measures source code instead of
+counting files: lines of code, an estimate of cyclomatic complexity (one per
+function plus one per branch keyword, ``case`` or boolean operator), the public
+API surface and fan-in/fan-out between the project's own modules. Comments and
+string literals are blanked out before matching, and imports are resolved
+against the project's files, so third-party packages never count as coupling.
+
+A module is a source file, except in Go where it is the package directory.
+Generated and minified files are skipped. The numbers are read from the text
+with regular expressions rather than a parser; they are meant for thresholds
+and rankings, not for exact reports.
+
+Per-file measurements are kept in the analysis cache's file index, so an
+incremental run only measures the files that changed.
+"""
+
+from __future__ import annotations
+
+import posixpath
+import re
+
+from collections import defaultdict
+from dataclasses import dataclass
+from functools import partial
+from pathlib import Path
+from typing import Any, Iterable, Mapping
+
+from claude_builder.analysis.domain_terms import (
+    FUNCTION_DECLARATION,
+    IMPORT_BLOCK_LINE,
+    QUOTED_MODULE,
+)
+from claude_builder.analysis.rust_source import strip_comments_and_strings
+from claude_builder.core.analysis_cache import FileIndex, file_fact
+from claude_builder.core.models import ComplexityMetrics, LanguageMetrics, ModuleMetrics
+
+
+MAX_SOURCE_FILES = 5000
+MAX_FILE_SIZE = 512 * 1024
+MAX_HOTSPOTS = 5
+# Modules below these are not worth a warning in the generated guidance
+MIN_HOTSPOT_COMPLEXITY = 10
+MIN_HOTSPOT_FAN_IN = 3
+# Guidance on branching and API stability starts at these averages and sizes
+BRANCHY_FUNCTION_PATHS = 5
+LARGE_PUBLIC_API = 100
+# Markers of generated code (protoc, go generate, bundlers, ...)
+GENERATED_MARKERS = ("@generated", "code generated", "do not edit", "autogenerated")
+
+C_STYLE_CODE = re.compile(
+    r"/\*.*?\*/|//[^\n]*|`(?:\\.|[^`\\])*`|\"(?:\\.|[^\"\\\n])*\"|'(?:\\.|[^'\\\n])*'",
+    re.DOTALL,
+)
+PYTHON_CODE = re.compile(
+    r"(?:[rRbBuUfF]{0,2})(?:\"\"\".*?\"\"\"|'''.*?'''"
+    r"|\"(?:\\.|[^\"\\\n])*\"|'(?:\\.|[^'\\\n])*')|#[^\n]*",
+    re.DOTALL,
+)
+
+C_STYLE_BRANCHES = re.compile(r"\b(?:if|for|while|case|catch)\b|&&|\|\|")
+NOT_A_METHOD = r"(?!(?:if|for|while|switch|catch|return|new|else|synchronized)\b)"
+
+
+@dataclass(frozen=True)
+class LanguageSyntax:
+    """What counts as a function, a branch, a public item and an import."""
+
+    functions: re.Pattern[str]
+    branches: re.Pattern[str] = C_STYLE_BRANCHES
+    public_items: re.Pattern[str] | None = None
+    imports: tuple[re.Pattern[str], ...] = ()
+    separator: str = "/"  # Between the parts of an imported module path
+    python_comments: bool = False
+    package_modules: bool = False  # Go: all files of a directory form one module
+    nested_modules: bool = False  # Rust: modules declared in foo.rs live in foo/
+
+
+SYNTAX: dict[str, LanguageSyntax] = {
+    "python": LanguageSyntax(
+        functions=re.compile(r"^[ \t]*(?:async[ \t]+)?def[ \t]+\w", re.MULTILINE),
+        branches=re.compile(r"\b(?:if|elif|for|while|except|case|and|or)\b"),
+        public_items=re.compile(
+            r"^(?:(?:async[ \t]+)?def|class)[ \t]+(?!_)\w", re.MULTILINE
+        ),
+        imports=(
+            re.compile(
+                r"^[ \t]*from[ \t]+(\.+[\w.]*|[\w.]+)[ \t]+import[ \t]+\(?[ \t]*"
+                r"([\w, \t]*)",
+                re.MULTILINE,
+            ),
+            re.compile(r"^[ \t]*import[ \t]+([\w.]+)", re.MULTILINE),
+        ),
+        separator=".",
+        python_comments=True,
+    ),
+    "rust": LanguageSyntax(
+        functions=re.compile(r"\bfn[ \t]+\w"),
+        branches=re.compile(r"\b(?:if|for|while)\b|&&|\|\||=>"),
+        public_items=re.compile(
+            r"^[ \t]*pub[ \t]+(?:(?:async|const|unsafe|extern)[ \t]+)*"
+            r"(?:fn|struct|enum|trait|type|const|static|union|mod)\b",
+            re.MULTILINE,
+        ),
+        imports=(
+            re.compile(
+                r"^[ \t]*(?:pub(?:\([^)]*\))?[ \t]+)?use[ \t]+([\w:]+)(?:\{([^}]*)\})?",
+                re.MULTILINE,
+            ),
+            re.compile(
+                r"^[ \t]*(?:pub(?:\([^)]*\))?[ \t]+)?mod[ \t]+(\w+)[ \t]*;",
+                re.MULTILINE,
+            ),
+        ),
+        separator="::",
+        nested_modules=True,
+    ),
+    "go": LanguageSyntax(
+        functions=re.compile(r"^func\b", re.MULTILINE),
+        branches=re.compile(r"\b(?:if|for|case)\b|&&|\|\|"),
+        public_items=re.compile(
+            r"^(?:func[ \t]+(?:\([^)]*\)[ \t]*)?|type[ \t]+|var[ \t]+|const[ \t]+)"
+            r"[A-Z]",
+            re.MULTILINE,
+        ),
+        imports=(
+            re.compile(r'^[ \t]*import[ \t]+(?:\w+[ \t]+)?"([^"]+)"', re.MULTILINE),
+            IMPORT_BLOCK_LINE,
+        ),
+        package_modules=True,
+    ),
+    "java": LanguageSyntax(
+        functions=re.compile(
+            r"^[ \t]*(?:(?:public|protected|private|static|final|abstract|"
+            r"synchronized|native|default)[ \t]+)*(?:<[^>]+>[ \t]+)?"
+            rf"(?:{NOT_A_METHOD}[\w<>\[\],.?]+[ \t]+)?{NOT_A_METHOD}\w+[ \t]*"
+            r"\([^;{)]*\)[ \t]*(?:throws[ \t]+[\w., \t]+)?\{",
+            re.MULTILINE,
+        ),
+        public_items=re.compile(r"^[ \t]*public[ \t]", re.MULTILINE),
+        imports=(
+            re.compile(r"^[ \t]*import[ \t]+(?:static[ \t]+)?([\w.]+)", re.MULTILINE),
+        ),
+        separator=".",
+    ),
+    "kotlin": LanguageSyntax(
+        functions=re.compile(r"\bfun[ \t]+"),
+        public_items=re.compile(
+            r"^[ \t]*(?:(?:public|open|abstract|sealed|data|enum|inline|suspend|"
+            r"override|operator|infix)[ \t]+)*(?:fun|class|interface|object)[ \t]",
+            re.MULTILINE,
+        ),
+        imports=(re.compile(r"^[ \t]*import[ \t]+([\w.]+)", re.MULTILINE),),
+        separator=".",
+    ),
+    "javascript": LanguageSyntax(
+        functions=re.compile(
+            r"\bfunction\b|=>|^[ \t]*(?:(?:async|static|get|set)[ \t]+)*"
+            rf"{NOT_A_METHOD}\w+[ \t]*\([^)]*\)[ \t]*\{{",
+            re.MULTILINE,
+        ),
+        public_items=re.compile(r"^[ \t]*export\b", re.MULTILINE),
+        imports=(QUOTED_MODULE,),
+    ),
+}
+SYNTAX["typescript"] = SYNTAX["javascript"]
+# Languages without their own entry: C-style comments and declarations
+GENERIC_SYNTAX = LanguageSyntax(functions=FUNCTION_DECLARATION)
+# Index file names that stand for their directory (``import pkg``, ``mod pkg``)
+PACKAGE_FILES = {"__init__", "mod", "index", "lib", "main"}
+
+
+@dataclass
+class _Module:
+    metrics: ModuleMetrics
+    syntax: LanguageSyntax
+    references: list[str]
+    directory: str  # Base of relative references
+
+
+def is_generated(path: Path, text: str) -> bool:
+    """Minified bundles and files marked as generated."""
+    if ".min." in path.name:
+        return True
+    head = text[:500].lower()
+    return any(marker in head for marker in GENERATED_MARKERS)
+
+
+def strip_code(text: str, language: str) -> str:
+    """Blank out comments and string literals, keeping line breaks."""
+    if language == "rust":
+        return strip_comments_and_strings(text)
+    syntax = SYNTAX.get(language, GENERIC_SYNTAX)
+    pattern = PYTHON_CODE if syntax.python_comments else C_STYLE_CODE
+
+    def blank(match: re.Match[str]) -> str:
+        token = match.group(0)
+        kept = "" if token[0] in "#/" else '""'
+        return kept + "\n" * token.count("\n")
+
+    return pattern.sub(blank, text)
+
+
+def count_loc(code: str) -> int:
+    """Non-blank lines of code that has been through ``strip_code``."""
+    return sum(1 for line in code.splitlines() if line.strip())
+
+
+def estimate_complexity(code: str, syntax: LanguageSyntax) -> tuple[int, int]:
+    """``(functions, cyclomatic complexity)`` of stripped code."""
+    functions = len(syntax.functions.findall(code))
+    branches = len(syntax.branches.findall(code))
+    return functions, max(functions, 1 if code.strip() else 0) + branches
+
+
+def import_references(text: str, syntax: LanguageSyntax) -> list[str]:
+    """Imported module paths as written, e.g. ``crate::parser`` or ``./api``."""
+    references: list[str] = []
+    for pattern in syntax.imports:
+        for match in pattern.finditer(text):
+            module = match.group(1)
+            names = match.group(2) if pattern.groups > 1 else None
+            if not names:
+                references.append(module)
+                continue
+            # ``from pkg import a, b`` and ``use crate::{a, b}`` name submodules
+            glue = "" if module.endswith((syntax.separator, ".")) else syntax.separator
+            for name in names.replace("\n", " ").split(","):
+                name = name.strip().split(" ")[0].strip("{}")
+                if name and name not in ("*", "self"):
+                    references.append(f"{module}{glue}{name}")
+    return references
+
+
+def _split_path(path: str) -> list[str]:
+    return [part for part in path.split("/") if part and part != "."]
+
+
+def _reference_parts(
+    reference: str, syntax: LanguageSyntax, directory: str
+) -> tuple[list[str], bool]:
+    """Path parts of an import and whether they are relative to ``directory``."""
+    if syntax.separator == "::":
+        parts = [part for part in reference.split("::") if part]
+        if parts and parts[0] in ("self", "super"):
+            base = directory if parts[0] == "self" else posixpath.dirname(directory)
+            return [*_split_path(base), *parts[1:]], True
+        if len(parts) == 1:
+            # ``mod child;`` declares a module below the current one
+            return [*_split_path(directory), parts[0]], True
+        return [part for part in parts if part != "crate"], False
+    if syntax.separator == "." and reference.startswith("."):
+        dots = len(reference) - len(reference.lstrip("."))
+        base = posixpath.join(directory, *[".."] * (dots - 1))
+        parts = [part for part in reference[dots:].split(".") if part]
+        return [*_split_path(posixpath.normpath(base)), *parts], True
+    if reference.startswith("."):
+        resolved = posixpath.normpath(posixpath.join(directory, reference))
+        return _split_path(resolved), True
+    if syntax.package_modules:
+        return _split_path(reference), False
+    reference = re.sub(r"^[@~]/", "", reference)
+    return [part for part in re.split(r"[./]", reference) if part], False
+
+
+class _ModuleIndex:
+    """Project modules by path and by every trailing part of their path."""
+
+    def __init__(self, keys: Iterable[str]) -> None:
+        self.exact: dict[tuple[str, ...], str] = {}
+        self.suffixes: dict[tuple[str, ...], set[str]] = defaultdict(set)
+        for key in keys:
+            parts = tuple(_split_path(key))
+            names = [parts]
+            if len(parts) > 1 and parts[-1] in PACKAGE_FILES:
+                names.append(parts[:-1])
+            for name in names:
+                self.exact.setdefault(name, key)
+                for start in range(len(name)):
+                    self.suffixes[name[start:]].add(key)
+
+    def resolve(
+        self, parts: list[str], relative: bool, syntax: LanguageSyntax
+    ) -> str | None:
+        """The project module an import refers to, if exactly one matches."""
+        if relative:
+            # Imported names may follow the module: ``from .models import User``
+            for end in range(len(parts), 0, -1):
+                if tuple(parts[:end]) in self.exact:
+                    return self.exact[tuple(parts[:end])]
+            return None
+        if syntax.package_modules:
+            # Go import paths start with the module path, e.g. example.com/app
+            candidates = [tuple(parts[start:]) for start in range(len(parts))]
+        else:
+            candidates = [tuple(parts[:end]) for end in range(len(parts), 0, -1)]
+        for name in candidates:
+            matches = self.suffixes.get(name)
+            if matches:
+                return next(iter(matches)) if len(matches) == 1 else None
+        return None
+
+
+def _measure_file(path: Path, language: str) -> list[Any] | None:
+    """``[loc, functions, complexity, public_api, references]`` of a file."""
+    try:
+        if path.stat().st_size > MAX_FILE_SIZE:
+            return None
+        text = path.read_text(encoding="utf-8", errors="ignore")
+    except OSError:
+        return None
+    if is_generated(path, text):
+        return None
+
+    syntax = SYNTAX.get(language, GENERIC_SYNTAX)
+    code = strip_code(text, language)
+    functions, complexity = estimate_complexity(code, syntax)
+    public_api = len(syntax.public_items.findall(code)) if syntax.public_items else 0
+    return [
+        count_loc(code),
+        functions,
+        complexity,
+        public_api,
+        import_references(text, syntax),
+    ]
+
+
+def _scan_module(
+    project_path: Path,
+    path: Path,
+    language: str,
+    file_index: FileIndex | None = None,
+) -> tuple[str, ModuleMetrics, list[str]] | None:
+    measured = file_fact(
+        file_index,
+        path,
+        f"code_metrics:{language}",
+        partial(_measure_file, language=language),
+    )
+    if measured is None:
+        return None
+    loc, functions, complexity, public_api, references = measured
+    relative = path.relative_to(project_path).as_posix()
+    metrics = ModuleMetrics(
+        path=relative,
+        language=language,
+        loc=loc,
+        functions=functions,
+        cyclomatic_complexity=complexity,
+        public_api=public_api,
+    )
+    return relative, metrics, list(references)
+
+
+def _module_directory(relative: str, syntax: LanguageSyntax) -> str:
+    """Directory that relative imports of a file start from."""
+    directory, name = posixpath.split(relative)
+    stem = posixpath.splitext(name)[0]
+    if syntax.nested_modules and stem not in PACKAGE_FILES:
+        return posixpath.join(directory, stem)
+    return directory
+
+
+def _collect_modules(
+    project_path: Path,
+    sources: Mapping[str, Iterable[Path]],
+    file_index: FileIndex | None = None,
+) -> dict[str, _Module]:
+    modules: dict[str, _Module] = {}
+    scanned = 0
+    for language, paths in sources.items():
+        syntax = SYNTAX.get(language, GENERIC_SYNTAX)
+        for path in sorted(paths):
+            if scanned >= MAX_SOURCE_FILES:
+                return modules
+            result = _scan_module(project_path, path, language, file_index)
+            if result is None:
+                continue
+            scanned += 1
+            relative, metrics, references = result
+            directory = _module_directory(relative, syntax)
+            if not syntax.package_modules:
+                key = posixpath.splitext(relative)[0]
+                modules[key] = _Module(metrics, syntax, references, directory)
+                continue
+            key = directory or "."
+            package = modules.get(key)
+            if package is None:
+                metrics.path = key
+                modules[key] = _Module(metrics, syntax, references, directory)
+            else:
+                _merge(package.metrics, metrics)
+                package.references.extend(references)
+    return modules
+
+
+def _merge(target: ModuleMetrics, other: ModuleMetrics) -> None:
+    target.loc += other.loc
+    target.functions += other.functions
+    target.cyclomatic_complexity += other.cyclomatic_complexity
+    target.public_api += other.public_api
+
+
+def measure_code(
+    project_path: Path,
+    sources: Mapping[str, Iterable[Path]],
+    file_index: FileIndex | None = None,
+) -> ComplexityMetrics:
+    """Measure the source files of each language (``language -> files``).
+
+    With a ``file_index`` the measurements of unchanged files are reused.
+    """
+    modules = _collect_modules(project_path, sources, file_index)
+    index = _ModuleIndex(modules)
+
+    edges: set[tuple[str, str]] = set()
+    for key, module in modules.items():
+        for reference in module.references:
+            parts, relative = _reference_parts(
+                reference, module.syntax, module.directory
+            )
+            target = index.resolve(parts, relative, module.syntax)
+            if target is not None and target != key:
+                edges.add((key, target))
+    for source, target in edges:
+        modules[source].metrics.fan_out += 1
+        modules[target].metrics.fan_in += 1
+
+    metrics = ComplexityMetrics(dependencies=len(edges))
+    for module in modules.values():
+        item = module.metrics
+        language = metrics.languages.setdefault(
+            item.language, LanguageMetrics(item.language)
+        )
+        language.modules += 1
+        language.loc += item.loc
+        language.functions += item.functions
+        language.cyclomatic_complexity += item.cyclomatic_complexity
+        language.public_api += item.public_api
+
+    measured = [module.metrics for module in modules.values()]
+    metrics.most_complex = sorted(
+        (
+            item
+            for item in measured
+            if item.cyclomatic_complexity >= MIN_HOTSPOT_COMPLEXITY
+        ),
+        key=lambda item: (-item.cyclomatic_complexity, item.path),
+    )[:MAX_HOTSPOTS]
+    metrics.most_depended_on = sorted(
+        (item for item in measured if item.fan_in >= MIN_HOTSPOT_FAN_IN),
+        key=lambda item: (-item.fan_in, item.path),
+    )[:MAX_HOTSPOTS]
+    return metrics
+
+
+def _plural(count: int, noun: str) -> str:
+    return f"{count} {noun}{'' if count == 1 else 's'}"
+
+
+def summarize_code_metrics(metrics: ComplexityMetrics, complexity_level: str) -> str:
+    """Markdown table of the metrics plus guidance derived from them."""
+    lines = [
+        f"Measured {metrics.loc:,} lines of code in {metrics.modules} modules "
+        f"({metrics.dependencies} internal imports); assessed as "
+        f"**{complexity_level}**.",
+        "",
+        "| Language | Modules | LOC | Functions | Complexity / function "
+        "| Public API |",
+        "|---|---|---|---|---|---|",
+    ]
+    for language in sorted(metrics.languages.values(), key=lambda item: -item.loc):
+        lines.append(
+            f"| {language.language} | {language.modules} | {language.loc:,} "
+            f"| {language.functions} | {language.complexity_per_function:.1f} "
+            f"| {language.public_api} |"
+        )
+    lines.append("")
+
+    if metrics.most_complex:
+        lines.append(
+            "- **Complexity hotspots**: add tests around these before changing "
+            "them and refactor in small steps:"
+        )
+        lines.extend(
+            f"  - `{item.path}`: complexity {item.cyclomatic_complexity} over "
+            f"{_plural(item.functions, 'function')}"
+            for item in metrics.most_complex
+        )
+    if metrics.most_depended_on:
+        lines.append(
+            "- **Most depended-on modules**: changes ripple to their importers; "
+            "keep their interfaces stable:"
+        )
+        lines.extend(
+            f"  - `{item.path}`: imported by {_plural(item.fan_in, 'module')}"
+            for item in metrics.most_depended_on
+        )
+    per_function = metrics.complexity_per_function
+    if per_function >= BRANCHY_FUNCTION_PATHS:
+        lines.append(
+            f"- Functions average {per_function:.1f} paths; put new logic in small "
+            "functions instead of adding branches to existing ones."
+        )
+    if metrics.public_api >= LARGE_PUBLIC_API:
+        lines.append(
+            f"- {metrics.public_api} public items form the API surface; treat "
+            "changes to them as breaking and document them."
+        )
+    if metrics.factors:
+        lines.append(f"- **Complexity factors**: {', '.join(metrics.factors)}")
+    return "\n".join(lines).rstrip()
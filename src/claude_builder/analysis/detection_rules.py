@@ -0,0 +1,581 @@
+"""Declarative custom detection rules.
+
+Rules add or override detections for languages, frameworks, domains, tools and
+project types. They are read from ``analysis.custom_detection_rules`` in the
+project config (``claude-builder.json`` / ``claude-builder.toml``), the global
+config (``~/.config/claude-builder/config.toml``, ...) and from YAML, TOML or
+JSON files listed in ``analysis.custom_rule_files``. Project rules replace
+global rules with the same name, and inline rules replace rule-file rules.
+
+Schema (TOML; YAML and JSON use the same keys)::
+
+    [analysis.custom_detection_rules.acme-rpc]
+    target = "framework"   # language | framework | domain | tool | project_type
+    value = "acme-rpc"     # reported name, defaults to the rule name
+    threshold = 2          # minimum summed weight, defaults to any match
+    override = false       # replace the detected primary value
+    conditions = [
+        { file_exists = "acme.yaml", weight = 2 },
+        { glob = "rpc/**/*.acme" },
+        { content = "import acme_rpc", files = "*.py" },
+        { dependency = "acme-rpc" },
+    ]
+
+A rule file holds the same tables under a top-level ``rules`` key::
+
+    rules:
+      acme-rpc:
+        target: framework
+        conditions:
+          - file_exists: acme.yaml
+
+Conditions (each weighs ``weight``, default 1):
+
+* ``file_exists`` - a file or directory at this project-relative path.
+* ``glob`` - any analyzed file matches. Patterns without ``/`` match file
+  names at any depth; others match the project-relative path.
+* ``content`` - a regular expression found in an analyzed file, optionally
+  limited to files matching the ``files`` glob.
+* ``dependency`` - a dependency declared in any supported manifest
+  (``pyproject.toml``, ``package.json``, ``Cargo.toml``, ``go.mod``, ...).
+
+``tool`` rules also name the ``category`` they are reported under, one of the
+``DevelopmentEnvironment`` tool lists (``ci_cd_systems``, ``observability``,
+...). ``project_type`` values are ``ProjectType`` values such as ``"cli_tool"``.
+Without ``override`` a rule fills the primary value only when nothing was
+detected and otherwise adds a secondary value.
+"""
+
+from __future__ import annotations
+
+import fnmatch
+import json
+import re
+
+from dataclasses import dataclass, field, fields
+from pathlib import Path
+from typing import Any, Iterable, Mapping
+
+import toml
+import yaml
+
+from claude_builder.analysis.cargo import load_cargo_workspace
+from claude_builder.analysis.gomod import load_go_workspace
+from claude_builder.analysis.jvm import load_jvm_build
+from claude_builder.core.analysis_cache import FileIndex, file_fact
+from claude_builder.core.models import (
+    DetectionEvidence,
+    DetectionRuleMatch,
+    DevelopmentEnvironment,
+    ProjectAnalysis,
+    ProjectType,
+    ToolMetadata,
+)
+from claude_builder.utils.exceptions import ConfigError
+from claude_builder.utils.project_files import ProjectFiles
+
+
+RULE_TARGETS = ("language", "framework", "domain", "tool", "project_type")
+CONDITION_KINDS = ("file_exists", "glob", "content", "dependency")
+RULE_KEYS = {
+    "target",
+    "value",
+    "category",
+    "threshold",
+    "override",
+    "conditions",
+    "description",
+    "source",
+}
+TOOL_CATEGORIES = tuple(
+    f.name
+    for f in fields(DevelopmentEnvironment)
+    if f.name not in ("package_managers", "tool_details")
+)
+DEFAULT_RULE_SOURCE = "analyzer configuration"
+# Tool confidence buckets, as used by the MLOps detector
+HIGH_CONFIDENCE = 75.0
+MEDIUM_CONFIDENCE = 40.0
+PACKAGE_JSON_TABLES = (
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+)
+REQUIREMENT_NAME = re.compile(r"^\s*([A-Za-z0-9][A-Za-z0-9._-]*)")
+
+
+@dataclass
+class RuleCondition:
+    """One weighted condition of a detection rule."""
+
+    kind: str  # One of CONDITION_KINDS
+    pattern: str
+    weight: float = 1.0
+    files: str | None = None  # ``content`` only: glob of files to search
+
+
+@dataclass
+class DetectionRule:
+    """A parsed custom detection rule."""
+
+    name: str
+    target: str
+    value: str
+    conditions: list[RuleCondition] = field(default_factory=list)
+    threshold: float | None = None
+    override: bool = False
+    category: str | None = None
+    source: str = DEFAULT_RULE_SOURCE
+
+    @property
+    def total_weight(self) -> float:
+        return sum(condition.weight for condition in self.conditions)
+
+
+def _rule_error(name: str, problem: str) -> ConfigError:
+    return ConfigError(f"Invalid custom detection rule '{name}': {problem}")
+
+
+def _is_number(value: Any) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
+
+
+def _parse_condition(name: str, data: Any) -> RuleCondition:
+    if not isinstance(data, Mapping):
+        raise _rule_error(name, "conditions must be tables")
+    kinds = [kind for kind in CONDITION_KINDS if kind in data]
+    if len(kinds) != 1:
+        expected = ", ".join(CONDITION_KINDS)
+        raise _rule_error(name, f"each condition needs exactly one of {expected}")
+    kind = kinds[0]
+    unknown = set(data) - {kind, "weight", "files"}
+    if unknown or ("files" in data and kind != "content"):
+        extra = ", ".join(sorted(unknown or {"files"}))
+        raise _rule_error(name, f"unsupported condition keys: {extra}")
+
+    pattern = data[kind]
+    if not isinstance(pattern, str) or not pattern:
+        raise _rule_error(name, f"{kind} must be a non-empty string")
+    if kind == "content":
+        try:
+            re.compile(pattern)
+        except re.error as e:
+            raise _rule_error(name, f"invalid content regex {pattern!r}: {e}") from e
+    weight = data.get("weight", 1.0)
+    if not _is_number(weight) or weight <= 0:
+        raise _rule_error(name, "weight must be a positive number")
+    files = data.get("files")
+    if files is not None and not isinstance(files, str):
+        raise _rule_error(name, "files must be a glob string")
+    return RuleCondition(kind=kind, pattern=pattern, weight=float(weight), files=files)
+
+
+def parse_detection_rule(name: str, data: Any) -> DetectionRule:
+    """Validate one rule table; raises ``ConfigError`` naming the problem."""
+    if not isinstance(data, Mapping):
+        raise _rule_error(name, "expected a table of rule settings")
+    unknown = sorted(set(data) - RULE_KEYS)
+    if unknown:
+        raise _rule_error(name, f"unsupported keys: {', '.join(unknown)}")
+
+    target = data.get("target")
+    if target not in RULE_TARGETS:
+        raise _rule_error(name, f"target must be one of {', '.join(RULE_TARGETS)}")
+    value = data.get("value", name)
+    if not isinstance(value, str) or not value:
+        raise _rule_error(name, "value must be a non-empty string")
+    if target == "project_type" and value not in {t.value for t in ProjectType}:
+        raise _rule_error(name, f"unknown project type {value!r}")
+
+    category = data.get("category")
+    if target == "tool" and category not in TOOL_CATEGORIES:
+        expected = ", ".join(TOOL_CATEGORIES)
+        raise _rule_error(name, f"category must be one of {expected}")
+    if target != "tool" and category is not None:
+        raise _rule_error(name, "category only applies to tool rules")
+
+    conditions = data.get("conditions")
+    if not isinstance(conditions, list) or not conditions:
+        raise _rule_error(name, "conditions must be a non-empty list")
+
+    threshold = data.get("threshold")
+    if threshold is not None and not _is_number(threshold):
+        raise _rule_error(name, "threshold must be a number")
+
+    return DetectionRule(
+        name=name,
+        target=target,
+        value=value,
+        conditions=[_parse_condition(name, item) for item in conditions],
+        threshold=None if threshold is None else float(threshold),
+        override=bool(data.get("override", False)),
+        category=category,
+        source=str(data.get("source") or DEFAULT_RULE_SOURCE),
+    )
+
+
+def parse_detection_rules(rules: Mapping[str, Any] | None) -> list[DetectionRule]:
+    """Parse ``analysis.custom_detection_rules`` in definition order."""
+    if not rules:
+        return []
+    if not isinstance(rules, Mapping):
+        msg = "custom_detection_rules must map rule names to rule tables"
+        raise ConfigError(msg)
+    return [parse_detection_rule(str(name), data) for name, data in rules.items()]
+
+
+def describe_source(path: Path, project_path: Path | None = None) -> str:
+    """Short label for where a rule was defined (project-relative or ``~/``)."""
+    path = path.expanduser()
+    if project_path is not None:
+        try:
+            return path.resolve().relative_to(project_path.resolve()).as_posix()
+        except ValueError:
+            pass
+    home = Path.home()
+    try:
+        return f"~/{path.relative_to(home).as_posix()}"
+    except ValueError:
+        return str(path)
+
+
+def load_rule_file(path: Path) -> dict[str, Any]:
+    """Read the ``rules`` table of a YAML, TOML or JSON rule file."""
+    try:
+        text = path.read_text(encoding="utf-8")
+        suffix = path.suffix.lower()
+        if suffix in (".yaml", ".yml"):
+            data = yaml.safe_load(text)
+        elif suffix == ".toml":
+            data = toml.loads(text)
+        else:
+            data = json.loads(text)
+    except (OSError, ValueError, yaml.YAMLError, toml.TomlDecodeError) as e:
+        msg = f"Failed to read detection rule file {path}: {e}"
+        raise ConfigError(msg) from e
+    rules = data.get("rules") if isinstance(data, dict) else None
+    if not isinstance(rules, dict):
+        msg = f"Detection rule file {path} has no 'rules' table"
+        raise ConfigError(msg)
+    return rules
+
+
+def collect_rule_definitions(
+    analysis_section: Any, config_path: Path, project_path: Path | None = None
+) -> dict[str, dict[str, Any]]:
+    """Rules defined by one config file, each stamped with its ``source``.
+
+    Rule files listed in ``custom_rule_files`` are resolved relative to the
+    config file; inline rules replace rule-file rules with the same name.
+    """
+    if not isinstance(analysis_section, Mapping):
+        return {}
+    collected: dict[str, dict[str, Any]] = {}
+    for entry in analysis_section.get("custom_rule_files") or []:
+        rule_path = (config_path.parent / Path(str(entry)).expanduser()).resolve()
+        source = describe_source(rule_path, project_path)
+        for name, data in load_rule_file(rule_path).items():
+            collected[str(name)] = _with_source(data, source)
+
+    source = describe_source(config_path, project_path)
+    inline = analysis_section.get("custom_detection_rules") or {}
+    if isinstance(inline, Mapping):
+        for name, data in inline.items():
+            collected[str(name)] = _with_source(data, source)
+    return collected
+
+
+def _with_source(data: Any, source: str) -> Any:
+    if isinstance(data, Mapping) and not data.get("source"):
+        return {**data, "source": source}
+    return data
+
+
+def normalize_dependency(name: str) -> str:
+    """Case- and separator-insensitive form (``Flask_Login`` == ``flask-login``)."""
+    return re.sub(r"[-_.]+", "-", name).lower()
+
+
+def _python_dependencies(project_path: Path) -> set[str]:
+    names: set[str] = set()
+    for requirements in sorted(project_path.glob("requirements*.txt")):
+        try:
+            lines = requirements.read_text(encoding="utf-8").splitlines()
+        except (OSError, UnicodeDecodeError):
+            continue
+        for line in lines:
+            match = REQUIREMENT_NAME.match(line.split("#", 1)[0])
+            if match:
+                names.add(match.group(1))
+
+    try:
+        pyproject = toml.loads((project_path / "pyproject.toml").read_text("utf-8"))
+    except (OSError, UnicodeDecodeError, toml.TomlDecodeError):
+        return names
+    project = pyproject.get("project", {})
+    specs = list(project.get("dependencies", []))
+    for extra in project.get("optional-dependencies", {}).values():
+        specs.extend(extra)
+    for spec in specs:
+        match = REQUIREMENT_NAME.match(str(spec))
+        if match:
+            names.add(match.group(1))
+
+    poetry = pyproject.get("tool", {}).get("poetry", {})
+    tables = [poetry.get("dependencies", {}), poetry.get("dev-dependencies", {})]
+    tables.extend(
+        group.get("dependencies", {}) for group in poetry.get("group", {}).values()
+    )
+    for table in tables:
+        names.update(name for name in table if name != "python")
+    return names
+
+
+def _node_dependencies(project_path: Path) -> set[str]:
+    try:
+        package = json.loads((project_path / "package.json").read_text("utf-8"))
+    except (OSError, UnicodeDecodeError, ValueError):
+        return set()
+    if not isinstance(package, dict):
+        return set()
+    names: set[str] = set()
+    for table in PACKAGE_JSON_TABLES:
+        if isinstance(package.get(table), dict):
+            names.update(package[table])
+    return names
+
+
+def manifest_dependencies(project_path: Path) -> set[str]:
+    """Dependency names from Python, Node, Cargo, Go and Maven/Gradle manifests.
+
+    JVM dependencies are reported both as ``group:artifact`` and ``artifact``.
+    """
+    names = _python_dependencies(project_path) | _node_dependencies(project_path)
+
+    cargo = load_cargo_workspace(project_path)
+    if cargo is not None:
+        names.update(dep.name for dep in cargo.all_dependencies())
+
+    go = load_go_workspace(project_path)
+    if go is not None:
+        names.update(req.path for req in go.all_requirements())
+
+    jvm = load_jvm_build(project_path)
+    if jvm is not None:
+        for dep in jvm.all_dependencies():
+            names.update((dep.coordinate, dep.artifact))
+    return names
+
+
+def _glob_matches(relative: str, pattern: str) -> bool:
+    if "/" not in pattern:
+        return fnmatch.fnmatchcase(relative.rsplit("/", 1)[-1], pattern)
+    pattern = pattern.lstrip("/")
+    if fnmatch.fnmatchcase(relative, pattern):
+        return True
+    # ``dir/**/x`` also matches ``dir/x``
+    return "/**/" in pattern and fnmatch.fnmatchcase(
+        relative, pattern.replace("/**/", "/")
+    )
+
+
+class RuleEvaluator:
+    """Evaluates rules against one project, parsing manifests at most once.
+
+    Content matches are kept per file in the ``file_index``, so a cached
+    analysis only searches the files that changed.
+    """
+
+    def __init__(
+        self,
+        project_path: Path,
+        files: ProjectFiles,
+        file_index: FileIndex | None = None,
+    ):
+        self.project_path = project_path
+        self.files = files
+        self.file_index = file_index
+        self._dependencies: set[str] | None = None
+
+    @property
+    def dependencies(self) -> set[str]:
+        """Normalized dependency names declared in the project's manifests."""
+        if self._dependencies is None:
+            self._dependencies = {
+                normalize_dependency(name)
+                for name in manifest_dependencies(self.project_path)
+            }
+        return self._dependencies
+
+    def evaluate(self, rules: Iterable[DetectionRule]) -> list[DetectionRuleMatch]:
+        matches = []
+        for rule in rules:
+            score = 0.0
+            evidence = []
+            for condition in rule.conditions:
+                found = self._check(condition)
+                if found is not None:
+                    score += condition.weight
+                    evidence.append(found)
+            threshold = rule.threshold
+            if score <= 0 or (threshold is not None and score < threshold):
+                continue
+            matches.append(
+                DetectionRuleMatch(
+                    rule=rule.name,
+                    target=rule.target,
+                    value=rule.value,
+                    source=rule.source,
+                    score=score,
+                    confidence=round(100.0 * score / rule.total_weight, 1),
+                    evidence=evidence,
+                )
+            )
+        return matches
+
+    def _check(self, condition: RuleCondition) -> str | None:
+        """Evidence for a satisfied condition, or None."""
+        if condition.kind == "file_exists":
+            path = self.project_path / condition.pattern
+            return f"{condition.pattern} exists" if path.exists() else None
+        if condition.kind == "dependency":
+            if normalize_dependency(condition.pattern) in self.dependencies:
+                return f"depends on {condition.pattern}"
+            return None
+
+        glob = condition.pattern if condition.kind == "glob" else condition.files
+        candidates = [
+            path
+            for path in self.files.files
+            if glob is None or _glob_matches(self.files.relative(path), glob)
+        ]
+        if condition.kind == "glob":
+            return self.files.relative(candidates[0]) if candidates else None
+
+        regex = re.compile(condition.pattern, re.MULTILINE)
+
+        def matches(path: Path) -> bool:
+            content = self.files.read_text(path)
+            return content is not None and regex.search(content) is not None
+
+        for path in candidates:
+            if file_fact(self.file_index, path, f"rule:{condition.pattern}", matches):
+                return f"{self.files.relative(path)} matches {condition.pattern!r}"
+        return None
+
+
+def evaluate_detection_rules(
+    rules: Iterable[DetectionRule],
+    project_path: Path,
+    files: ProjectFiles,
+    file_index: FileIndex | None = None,
+) -> list[DetectionRuleMatch]:
+    """Matches of every rule whose conditions reach its threshold."""
+    return RuleEvaluator(project_path, files, file_index).evaluate(rules)
+
+
+def apply_rule_matches(
+    analysis: ProjectAnalysis,
+    rules: Iterable[DetectionRule],
+    matches: Iterable[DetectionRuleMatch],
+) -> None:
+    """Merge rule matches into the analysis, recording each match's effect."""
+    by_name = {rule.name: rule for rule in rules}
+    for match in matches:
+        rule = by_name[match.rule]
+        if rule.target == "language":
+            match.effect = _apply_primary(analysis.language_info, match, rule.override)
+        elif rule.target == "framework":
+            match.effect = _apply_primary(analysis.framework_info, match, rule.override)
+        elif rule.target == "domain":
+            match.effect = _apply_domain(analysis, match, rule.override)
+        elif rule.target == "tool":
+            match.effect = _apply_tool(analysis, match, str(rule.category))
+        else:
+            match.effect = _apply_project_type(analysis, match, rule.override)
+        analysis.rule_matches.append(match)
+
+        evidence = {
+            "language": analysis.language_info.evidence,
+            "framework": analysis.framework_info.evidence,
+            "domain": analysis.domain_info.evidence,
+            "project_type": analysis.project_type_evidence,
+        }.get(rule.target)
+        if evidence is not None:
+            evidence.append(
+                DetectionEvidence(
+                    match.value,
+                    f"custom rule `{match.rule}` in {match.source} ({match.effect})",
+                    match.score,
+                )
+            )
+
+
+def _apply_primary(info: Any, match: DetectionRuleMatch, override: bool) -> str:
+    """Shared by ``LanguageInfo`` and ``FrameworkInfo`` (primary + secondary)."""
+    if info.primary == match.value:
+        info.confidence = max(info.confidence, match.confidence)
+        return "confirmed primary"
+    if override or not info.primary:
+        if info.primary and info.primary not in info.secondary:
+            info.secondary.insert(0, info.primary)
+        if match.value in info.secondary:
+            info.secondary.remove(match.value)
+        info.primary = match.value
+        info.confidence = match.confidence
+        return "set as primary"
+    if match.value not in info.secondary:
+        info.secondary.append(match.value)
+    return "added as secondary"
+
+
+def _apply_domain(
+    analysis: ProjectAnalysis, match: DetectionRuleMatch, override: bool
+) -> str:
+    domain_info = analysis.domain_info
+    if domain_info.domain == match.value:
+        domain_info.confidence = max(domain_info.confidence, match.confidence)
+        return "confirmed domain"
+    if override or not domain_info.domain:
+        domain_info.domain = match.value
+        domain_info.confidence = match.confidence
+        domain_info.indicators.extend(match.evidence)
+        return "set as domain"
+    if match.value not in domain_info.specialized_patterns:
+        domain_info.specialized_patterns.append(match.value)
+    return "added as specialized pattern"
+
+
+def _apply_tool(
+    analysis: ProjectAnalysis, match: DetectionRuleMatch, category: str
+) -> str:
+    tools: list[str] = getattr(analysis.dev_environment, category)
+    if match.value not in tools:
+        tools.append(match.value)
+    if match.confidence >= HIGH_CONFIDENCE:
+        bucket = "high"
+    elif match.confidence >= MEDIUM_CONFIDENCE:
+        bucket = "medium"
+    else:
+        bucket = "low"
+    analysis.dev_environment.tool_details[match.value] = ToolMetadata(
+        name=match.value,
+        slug=match.value,
+        category=category,
+        confidence=bucket,
+        score=match.score,
+    )
+    return f"added to {category}"
+
+
+def _apply_project_type(
+    analysis: ProjectAnalysis, match: DetectionRuleMatch, override: bool
+) -> str:
+    project_type = ProjectType(match.value)
+    if analysis.project_type == project_type:
+        return "confirmed project type"
+    if override or analysis.project_type == ProjectType.UNKNOWN:
+        analysis.project_type = project_type
+        return "set as project type"
+    return f"kept detected {analysis.project_type.value} (no override)"
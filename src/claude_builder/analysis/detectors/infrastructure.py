@@ -15,6 +15,7 @@ from claude_builder.analysis.tool_recommendations import (
 )
 from claude_builder.core.models import ToolMetadata
 from claude_builder.utils.file_patterns import FilePatterns
+from claude_builder.utils.project_files import ProjectFiles, walk_project
 
 
 # Categorization helpers
@@ -25,10 +26,18 @@ SECRETS_MANAGEMENT_TOOLS = {"vault", "sops"}
 class InfrastructureDetector:
     """Detects infrastructure, observability, and security tools for a project."""
 
-    def __init__(self, project_path: Path) -> None:
+    def __init__(self, project_path: Path, files: ProjectFiles | None = None) -> None:
         self.project_path = Path(project_path)
+        # Shared project walk; built on first use when not supplied
+        self._files = files
         self._raw: dict[str, dict[str, float]] = {}
 
+    @property
+    def files(self) -> ProjectFiles:
+        if self._files is None:
+            self._files = walk_project(self.project_path)
+        return self._files
+
     def _classify_confidence(self, scores: dict[str, float]) -> dict[str, str]:
         """Map raw scores to confidence buckets.
 
@@ -52,7 +61,7 @@ class InfrastructureDetector:
 
     def detect(self) -> dict[str, list[str]]:
         """Return categorized lists only (for analyzer integration)."""
-        self._raw = FilePatterns.detect_all_devops_tools(self.project_path)
+        self._raw = FilePatterns.detect_all_devops_tools(self.project_path, self.files)
 
         infra_scores = self._raw.get("infrastructure", {})
         obsv_scores = self._raw.get("observability", {})
@@ -115,7 +124,7 @@ class InfrastructureDetector:
         for category in ("infrastructure", "observability", "security"):
             for tool, score in self._raw.get(category, {}).items():
                 files = FilePatterns.collect_tool_examples(
-                    self.project_path, category, tool
+                    self.project_path, category, tool, files=self.files
                 )
                 recommendations = get_recommendations(tool)
                 entry = ToolMetadata(
@@ -13,13 +13,16 @@ from claude_builder.analysis.tool_recommendations import (
     get_recommendations,
 )
 from claude_builder.core.models import ToolMetadata
+from claude_builder.utils.project_files import ProjectFiles, walk_project
 
 
 class MLOpsDetector(BaseDetector):
     """Detector for MLOps tools and data pipeline frameworks."""
 
-    def __init__(self) -> None:
+    def __init__(self, files: Optional[ProjectFiles] = None) -> None:
         super().__init__()
+        # Shared project walk; root-level globs are answered from it
+        self._files = files
         # Internal patterns for MLOps detection
         self._patterns = {
             # Data Version Control
@@ -90,6 +93,11 @@ class MLOpsDetector(BaseDetector):
             },
         }
 
+    def _project_files(self, project_path: Path) -> ProjectFiles:
+        if self._files is None or self._files.root != project_path:
+            self._files = walk_project(project_path)
+        return self._files
+
     def _calculate_confidence(self, project_path: Path, tool: str) -> int:
         """Calculate confidence score for a specific MLOps tool."""
         confidence = 0
@@ -107,7 +115,7 @@ class MLOpsDetector(BaseDetector):
 
         # Weight: globs (+4)
         for glob_pattern in patterns.get("globs", []):
-            if list(project_path.glob(glob_pattern)):
+            if self._project_files(project_path).glob(glob_pattern):
                 confidence += 4
 
         return confidence
@@ -149,11 +157,10 @@ class MLOpsDetector(BaseDetector):
                     return matches[:limit]
 
         for glob_pattern in patterns.get("globs", []):
-            for match in root.glob(glob_pattern):
-                if match.exists():
-                    _record(match)
-                    if len(matches) >= limit:
-                        return matches[:limit]
+            for match in self._project_files(project_path).glob(glob_pattern):
+                _record(match)
+                if len(matches) >= limit:
+                    return matches[:limit]
 
         return matches[:limit]
 
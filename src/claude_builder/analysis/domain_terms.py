@@ -0,0 +1,86 @@
+"""Identifier tokens used as domain signals.
+
+Domain detection looks at what a project names things, not at its prose:
+declared type and function names, imported modules, route paths and the
+directory and file names of the project. Each name is split into lowercase
+words on ``snake_case``, ``camelCase``, ``kebab-case`` and path boundaries, so
+``OrderService``, ``order_items`` and ``/api/orders/{id}`` all yield
+``order`` (plural forms are folded onto the keyword when matching).
+
+The patterns are deliberately language-agnostic (``class``/``struct``/
+``interface``, ``def``/``fn``/``func``/``function``, ``import``/``use``/
+``require``, ...) so Rust, TypeScript, Go, JVM and Python sources are read the
+same way.
+"""
+
+from __future__ import annotations
+
+import re
+
+from pathlib import PurePath
+from typing import Iterable
+
+
+TYPE_DECLARATION = re.compile(
+    r"\b(?:class|struct|enum|interface|trait|type|record|object|protocol|"
+    r"message|service)\s+([A-Za-z_]\w*)"
+)
+# Go methods put the receiver before the name: ``func (s *Store) Checkout``
+FUNCTION_DECLARATION = re.compile(
+    r"\b(?:def|fn|func|function|fun)\s+(?:\([^)]*\)\s*)?([A-Za-z_]\w*)"
+)
+MODULE_REFERENCE = re.compile(
+    r"\b(?:import|from|use|mod|package|namespace)\s+([A-Za-z_@][\w.:/@-]*)"
+)
+QUOTED_MODULE = re.compile(r"""(?:\bfrom|\brequire\(|\bimport\(?)\s*["']([^"']+)["']""")
+# Lines holding only a quoted path, as in Go ``import ( ... )`` blocks
+IMPORT_BLOCK_LINE = re.compile(r'^\s*(?:\w+\s+)?"([\w.@/-]+)"\s*$', re.MULTILINE)
+ROUTE_PATH = re.compile(r"""["'`](/[\w/{}:<>.*-]+)["'`]""")
+
+NAME_PATTERNS = (
+    TYPE_DECLARATION,
+    FUNCTION_DECLARATION,
+    MODULE_REFERENCE,
+    QUOTED_MODULE,
+    IMPORT_BLOCK_LINE,
+    ROUTE_PATH,
+)
+
+_WORD_BOUNDARY = re.compile(r"[^A-Za-z0-9]+")
+_CAMEL_CASE = re.compile(r"[A-Z]+(?=[A-Z][a-z])|[A-Z]?[a-z]+|[A-Z]+|[0-9]+")
+
+
+def split_identifier(name: str) -> list[str]:
+    """Lowercase words of a name: ``HTTPOrderItems`` -> http, order, items."""
+    words: list[str] = []
+    for part in _WORD_BOUNDARY.split(name):
+        words.extend(word.lower() for word in _CAMEL_CASE.findall(part))
+    return [word for word in words if len(word) > 1 and not word.isdigit()]
+
+
+def source_tokens(text: str) -> list[str]:
+    """Words of the names declared, imported or routed in a source file."""
+    tokens: list[str] = []
+    for pattern in NAME_PATTERNS:
+        for name in pattern.findall(text):
+            tokens.extend(split_identifier(name))
+    return tokens
+
+
+def path_tokens(path: PurePath | str) -> list[str]:
+    """Words of the directory and file names of a path, without the extension."""
+    path = PurePath(path)
+    parts = [*path.parent.parts, path.stem]
+    return [word for part in parts for word in split_identifier(part)]
+
+
+def keyword_variants(keywords: Iterable[str]) -> dict[str, str]:
+    """Token -> keyword lookup that also folds simple plurals onto the keyword."""
+    variants: dict[str, str] = {}
+    for keyword in keywords:
+        forms = [keyword, f"{keyword}s", f"{keyword}es"]
+        if keyword.endswith("y"):
+            forms.append(f"{keyword[:-1]}ies")
+        for form in forms:
+            variants.setdefault(form, keyword)
+    return variants
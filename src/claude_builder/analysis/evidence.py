@@ -0,0 +1,74 @@
+"""Scores that remember why they were given.
+
+Detectors score candidates (languages, frameworks, domains) by adding weights
+for every signal they find. ``EvidenceScores`` behaves like
+``defaultdict(float)``, so language plugins that only do ``scores[name] += n``
+keep working, while ``add_score`` also records a ``DetectionEvidence`` entry
+such as "Cargo.toml dependency `axum` +10". Points added without a recorded
+signal are attributed to their source with ``attribute_rest``, so the evidence
+of a candidate always adds up to its score.
+"""
+
+from __future__ import annotations
+
+from collections import defaultdict
+from typing import Iterable, MutableMapping
+
+from claude_builder.core.models import DetectionEvidence
+
+
+class EvidenceScores(defaultdict):  # type: ignore[type-arg]
+    """Candidate scores plus the signals that produced them."""
+
+    def __init__(self) -> None:
+        super().__init__(float)
+        self.evidence: list[DetectionEvidence] = []
+
+    def add(self, candidate: str, weight: float, signal: str) -> None:
+        self[candidate] += weight
+        self.evidence.append(DetectionEvidence(candidate, signal, weight))
+
+    def explained(self, candidate: str) -> float:
+        """Points of a candidate that have a recorded signal."""
+        return sum(e.weight for e in self.evidence if e.candidate == candidate)
+
+    def attribute_rest(self, signal: str) -> None:
+        """Record unexplained points (e.g. from third-party plugins) as ``signal``."""
+        for candidate, score in list(self.items()):
+            rest = score - self.explained(candidate)
+            if rest > 1e-9:
+                self.evidence.append(DetectionEvidence(candidate, signal, rest))
+
+    def update_from(self, other: EvidenceScores) -> None:
+        """Add another set of scores and their evidence."""
+        for candidate, score in other.items():
+            self[candidate] += score
+        self.evidence.extend(other.evidence)
+
+
+def add_score(
+    scores: MutableMapping[str, float], candidate: str, weight: float, signal: str
+) -> None:
+    """Add ``weight`` to a candidate, recording ``signal`` when scores keep evidence."""
+    if isinstance(scores, EvidenceScores):
+        scores.add(candidate, weight, signal)
+    else:
+        scores[candidate] = scores.get(candidate, 0.0) + weight
+
+
+def evidence_for(
+    evidence: Iterable[DetectionEvidence], candidate: str | None
+) -> list[DetectionEvidence]:
+    """Evidence supporting one candidate, strongest signal first."""
+    return sorted(
+        (e for e in evidence if e.candidate == candidate),
+        key=lambda e: -e.weight,
+    )
+
+
+def ranked_candidates(evidence: Iterable[DetectionEvidence]) -> list[tuple[str, float]]:
+    """Candidates with their total evidence weight, highest first."""
+    totals: dict[str, float] = defaultdict(float)
+    for item in evidence:
+        totals[item.candidate] += item.weight
+    return sorted(totals.items(), key=lambda item: (-item[1], item[0]))
@@ -0,0 +1,111 @@
+"""Catalogue of Go frameworks and ecosystem modules.
+
+Entries match module paths exactly after dropping the major-version suffix, so
+``github.com/labstack/echo/v4`` counts as echo while a fork under a different
+path does not. Requirements marked ``// indirect`` are only pulled in by other
+modules and count for less.
+"""
+
+from __future__ import annotations
+
+from collections import defaultdict
+from dataclasses import dataclass
+from typing import Iterable
+
+from claude_builder.analysis.gomod import GoRequirement
+
+
+# Indirect requirements are not imported by the project itself
+INDIRECT_REQUIREMENT_WEIGHT = 0.3
+
+
+@dataclass(frozen=True)
+class GoFrameworkSignature:
+    """Module paths (without ``/vN``) that identify a Go framework."""
+
+    category: str
+    modules: dict[str, float]
+    web_framework: bool = False
+
+
+GO_FRAMEWORKS: dict[str, GoFrameworkSignature] = {
+    # Web servers and routers
+    "gin": GoFrameworkSignature(
+        category="web", modules={"github.com/gin-gonic/gin": 10.0}, web_framework=True
+    ),
+    "echo": GoFrameworkSignature(
+        category="web",
+        modules={"github.com/labstack/echo": 10.0},
+        web_framework=True,
+    ),
+    "fiber": GoFrameworkSignature(
+        category="web", modules={"github.com/gofiber/fiber": 10.0}, web_framework=True
+    ),
+    "chi": GoFrameworkSignature(
+        category="web", modules={"github.com/go-chi/chi": 8.0}, web_framework=True
+    ),
+    "gorilla": GoFrameworkSignature(
+        category="web", modules={"github.com/gorilla/mux": 8.0}, web_framework=True
+    ),
+    # RPC
+    "grpc": GoFrameworkSignature(
+        category="grpc",
+        modules={
+            "google.golang.org/grpc": 8.0,
+            "google.golang.org/protobuf": 2.0,
+            "github.com/grpc-ecosystem/grpc-gateway": 2.0,
+        },
+    ),
+    # CLI
+    "cobra": GoFrameworkSignature(
+        category="cli",
+        modules={"github.com/spf13/cobra": 6.0, "github.com/spf13/viper": 1.0},
+    ),
+    "urfave_cli": GoFrameworkSignature(
+        category="cli", modules={"github.com/urfave/cli": 6.0}
+    ),
+    # Databases
+    "gorm": GoFrameworkSignature(category="database", modules={"gorm.io/gorm": 6.0}),
+    "ent": GoFrameworkSignature(category="database", modules={"entgo.io/ent": 6.0}),
+    "sqlx": GoFrameworkSignature(
+        category="database", modules={"github.com/jmoiron/sqlx": 4.0}
+    ),
+    # Kubernetes controllers and operators
+    "controller_runtime": GoFrameworkSignature(
+        category="kubernetes",
+        modules={
+            "sigs.k8s.io/controller-runtime": 8.0,
+            "k8s.io/client-go": 3.0,
+        },
+    ),
+}
+
+
+def go_framework_matches(
+    requirements: Iterable[GoRequirement],
+) -> dict[tuple[str, str], float]:
+    """Score of every (framework, module) pair found in ``require`` entries.
+
+    Each module counts once per framework, using its strongest declaration when
+    several workspace members require it.
+    """
+    best: dict[tuple[str, str], float] = {}
+    for requirement in requirements:
+        base_path = requirement.base_path
+        for framework, signature in GO_FRAMEWORKS.items():
+            score = signature.modules.get(base_path)
+            if score is None:
+                continue
+            if requirement.indirect:
+                score *= INDIRECT_REQUIREMENT_WEIGHT
+            key = (framework, base_path)
+            best[key] = max(best.get(key, 0.0), score)
+    return best
+
+
+def score_go_frameworks(requirements: Iterable[GoRequirement]) -> dict[str, float]:
+    """Score catalogue frameworks from ``require`` entries (exact path matches)."""
+    scores: dict[str, float] = defaultdict(float)
+    for (framework, _module), score in go_framework_matches(requirements).items():
+        scores[framework] += score
+    return dict(scores)
@@ -0,0 +1,300 @@
+"""go.mod and go.work parsing for Go project analysis.
+
+Reads the module files directly (no ``go`` invocation): the module path, the
+``go`` and ``toolchain`` directives, ``require`` blocks (including
+``// indirect`` markers) and ``replace`` directives. A ``go.work`` file at the
+project root turns the project into a workspace whose ``use`` directories are
+loaded as member modules.
+
+Requirements become the project's dependency graph; ``go.mod`` already lists
+the versions selected by minimal version selection, so no ``go.sum`` parsing is
+needed.
+"""
+
+from __future__ import annotations
+
+import re
+
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from claude_builder.core.models import DependencyInfo, GoWorkspaceInfo
+
+
+GO_MOD = "go.mod"
+GO_WORK = "go.work"
+
+# Major-version suffix of a module path, e.g. ``/v4`` in ``.../echo/v4``
+MAJOR_VERSION_SUFFIX = re.compile(r"/v\d+$")
+
+
+@dataclass
+class GoRequirement:
+    """A single ``require`` entry."""
+
+    path: str
+    version: str
+    indirect: bool = False
+
+    @property
+    def base_path(self) -> str:
+        """Module path without its major-version suffix."""
+        return MAJOR_VERSION_SUFFIX.sub("", self.path)
+
+
+@dataclass
+class GoReplace:
+    """A ``replace old [version] => new [version]`` directive."""
+
+    old_path: str
+    new_path: str
+    old_version: str | None = None
+    new_version: str | None = None
+
+    def describe(self) -> str:
+        old = " ".join(filter(None, [self.old_path, self.old_version]))
+        new = " ".join(filter(None, [self.new_path, self.new_version]))
+        return f"{old} => {new}"
+
+    @property
+    def is_local(self) -> bool:
+        """Whether the replacement points at a directory on disk."""
+        return self.new_path.startswith(("./", "../", "/")) or self.new_path in (
+            ".",
+            "..",
+        )
+
+
+@dataclass
+class GoModule:
+    """Contents of a single ``go.mod`` file."""
+
+    directory: Path
+    module: str | None = None
+    go_version: str | None = None
+    toolchain: str | None = None
+    requires: list[GoRequirement] = field(default_factory=list)
+    replaces: list[GoReplace] = field(default_factory=list)
+
+    @classmethod
+    def load(cls, go_mod_path: Path) -> GoModule | None:
+        try:
+            content = go_mod_path.read_text(encoding="utf-8")
+        except (OSError, UnicodeDecodeError):
+            return None
+        return parse_go_mod(content, go_mod_path.parent)
+
+    @property
+    def direct_requires(self) -> list[GoRequirement]:
+        return [req for req in self.requires if not req.indirect]
+
+
+@dataclass
+class GoWorkspace:
+    """A single module or a ``go.work`` workspace and its member modules."""
+
+    root: Path
+    modules: list[GoModule] = field(default_factory=list)
+    is_workspace: bool = False
+    go_version: str | None = None  # From go.work; members keep their own
+    toolchain: str | None = None
+    replaces: list[GoReplace] = field(default_factory=list)  # go.work only
+
+    def all_requirements(self) -> list[GoRequirement]:
+        """Requirements of every member module, in member order."""
+        return [req for module in self.modules for req in module.requires]
+
+    def relative_path(self, module: GoModule) -> str:
+        try:
+            relative = module.directory.relative_to(self.root)
+        except ValueError:
+            return str(module.directory)
+        return relative.as_posix() or "."
+
+
+def _strip_comment(line: str) -> tuple[str, str]:
+    """Split a line into code and the text of its ``//`` comment."""
+    code, _, comment = line.partition("//")
+    return code.strip(), comment.strip()
+
+
+def _unquote(token: str) -> str:
+    if len(token) >= 2 and token[0] == token[-1] and token[0] in "\"`":
+        return token[1:-1]
+    return token
+
+
+def _iter_directives(content: str) -> list[tuple[str, list[str], str]]:
+    """Flatten ``verb ( ... )`` blocks into ``(verb, args, comment)`` entries."""
+    entries: list[tuple[str, list[str], str]] = []
+    block: str | None = None
+    for raw_line in content.splitlines():
+        code, comment = _strip_comment(raw_line)
+        if not code:
+            continue
+        if block is not None:
+            if code == ")":
+                block = None
+            else:
+                entries.append((block, [_unquote(t) for t in code.split()], comment))
+            continue
+        verb, *remainder = code.split(None, 1)
+        rest = remainder[0].strip() if remainder else ""
+        if rest == "(":
+            block = verb
+        elif rest:
+            entries.append((verb, [_unquote(t) for t in rest.split()], comment))
+    return entries
+
+
+def _parse_replace(args: list[str]) -> GoReplace | None:
+    if "=>" not in args:
+        return None
+    arrow = args.index("=>")
+    old, new = args[:arrow], args[arrow + 1 :]
+    if not old or not new:
+        return None
+    return GoReplace(
+        old_path=old[0],
+        old_version=old[1] if len(old) > 1 else None,
+        new_path=new[0],
+        new_version=new[1] if len(new) > 1 else None,
+    )
+
+
+def parse_go_mod(content: str, directory: Path) -> GoModule:
+    """Parse ``go.mod`` text; unknown directives are ignored."""
+    module = GoModule(directory=directory)
+    for verb, args, comment in _iter_directives(content):
+        if verb == "module" and args:
+            module.module = args[0]
+        elif verb == "go" and args:
+            module.go_version = args[0]
+        elif verb == "toolchain" and args:
+            module.toolchain = args[0]
+        elif verb == "require" and len(args) >= 2:
+            module.requires.append(
+                GoRequirement(
+                    path=args[0],
+                    version=args[1],
+                    indirect=comment.startswith("indirect"),
+                )
+            )
+        elif verb == "replace":
+            replace = _parse_replace(args)
+            if replace is not None:
+                module.replaces.append(replace)
+    return module
+
+
+def load_go_workspace(project_path: Path) -> GoWorkspace | None:
+    """Load ``go.work`` (preferred) or ``go.mod`` from the project root."""
+    go_work = project_path / GO_WORK
+    if go_work.is_file():
+        try:
+            content = go_work.read_text(encoding="utf-8")
+        except (OSError, UnicodeDecodeError):
+            content = ""
+        workspace = GoWorkspace(root=project_path, is_workspace=True)
+        for verb, args, _comment in _iter_directives(content):
+            if verb == "go" and args:
+                workspace.go_version = args[0]
+            elif verb == "toolchain" and args:
+                workspace.toolchain = args[0]
+            elif verb == "use" and args:
+                module = GoModule.load(project_path / args[0] / GO_MOD)
+                if module is not None:
+                    workspace.modules.append(module)
+            elif verb == "replace":
+                replace = _parse_replace(args)
+                if replace is not None:
+                    workspace.replaces.append(replace)
+        return workspace
+
+    go_mod = project_path / GO_MOD
+    if go_mod.is_file():
+        module = GoModule.load(go_mod)
+        if module is None:
+            return None
+        return GoWorkspace(
+            root=project_path,
+            modules=[module],
+            go_version=module.go_version,
+            toolchain=module.toolchain,
+        )
+    return None
+
+
+def build_go_dependency_graph(workspace: GoWorkspace) -> list[DependencyInfo]:
+    """Requirements of every member module as dependency entries.
+
+    Modules that are themselves workspace members are skipped, and replaced
+    modules report where the code actually comes from.
+    """
+    member_paths = {module.module for module in workspace.modules if module.module}
+    replaces = {
+        replace.old_path: replace
+        for module in workspace.modules
+        for replace in module.replaces
+    }
+    # go.work replacements override those of the member modules
+    replaces.update({replace.old_path: replace for replace in workspace.replaces})
+
+    graph: dict[str, DependencyInfo] = {}
+    for requirement in workspace.all_requirements():
+        if requirement.path in member_paths:
+            continue
+        replace = replaces.get(requirement.path)
+        if replace is None:
+            source = "proxy"
+        elif replace.is_local:
+            source = "path"
+        else:
+            source = "replace"
+        existing = graph.get(requirement.path)
+        if existing is not None:
+            # A module required directly anywhere is a direct dependency
+            existing.direct = existing.direct or not requirement.indirect
+            continue
+        graph[requirement.path] = DependencyInfo(
+            name=requirement.path,
+            version=requirement.version,
+            source=source,
+            package_managers=["go"],
+            direct=not requirement.indirect,
+        )
+
+    return sorted(graph.values(), key=lambda dep: (not dep.direct, dep.name))
+
+
+def summarize_go_workspace(info: GoWorkspaceInfo, max_requires: int = 12) -> str:
+    """Markdown bullet list describing the project's Go modules."""
+    lines = []
+    for module in info.modules:
+        header = f"- **Module** `{module.module}`"
+        if module.path != ".":
+            header += f" in `{module.path}/`"
+        if module.go_version:
+            header += f" (go {module.go_version})"
+        lines.append(header)
+        if module.requires:
+            shown = ", ".join(f"`{req}`" for req in module.requires[:max_requires])
+            more = len(module.requires) - max_requires
+            suffix = f" (+{more} more)" if more > 0 else ""
+            lines.append(f"  - Requires: {shown}{suffix}")
+        if module.indirect_requires:
+            lines.append(
+                f"  - {module.indirect_requires} indirect requirements "
+                "(managed by `go mod tidy`)"
+            )
+        lines.extend(f"  - Replace: `{replace}`" for replace in module.replaces)
+
+    if info.is_workspace:
+        lines.append(
+            "- **Workspace**: `go.work` ties the modules together; run "
+            "`go work sync` after changing requirements"
+        )
+        lines.extend(f"  - Replace: `{replace}`" for replace in info.replaces)
+    if info.toolchain:
+        lines.append(f"- **Toolchain**: `{info.toolchain}`")
+    return "\n".join(lines)
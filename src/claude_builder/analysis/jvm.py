@@ -0,0 +1,636 @@
+"""Maven and Gradle build parsing for JVM (Java/Kotlin) project analysis.
+
+Reads build files directly (no ``mvn``/``gradle`` invocation):
+
+- Maven: ``pom.xml`` via ``defusedxml`` - coordinates, ``<parent>``,
+  ``<properties>`` (``${...}`` placeholders are resolved), dependencies,
+  ``<dependencyManagement>`` imports (BOMs), build plugins and ``<modules>``
+  for multi-module reactors.
+- Gradle: Groovy and Kotlin DSL ``plugins { }`` / ``apply plugin`` blocks,
+  dependency declarations in string, map and ``platform(...)`` notation,
+  ``libs.*`` references resolved through ``gradle/libs.versions.toml``, and
+  ``include`` statements in ``settings.gradle(.kts)``.
+
+Gradle scripts are code, so extraction is pattern-based and best-effort.
+"""
+
+from __future__ import annotations
+
+import re
+
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+from xml.etree.ElementTree import Element
+
+import toml
+
+from defusedxml import DefusedXmlException, ElementTree
+
+
+POM_XML = "pom.xml"
+GRADLE_BUILD_FILES = ("build.gradle.kts", "build.gradle")
+GRADLE_SETTINGS_FILES = ("settings.gradle.kts", "settings.gradle")
+GRADLE_VERSION_CATALOG = Path("gradle") / "libs.versions.toml"
+
+# Maven scopes and Gradle configurations that only affect tests
+TEST_CONFIGURATIONS = {
+    "test",
+    "testImplementation",
+    "testRuntimeOnly",
+    "testCompileOnly",
+    "androidTestImplementation",
+}
+
+GRADLE_CONFIGURATIONS = (
+    "implementation",
+    "api",
+    "compileOnly",
+    "runtimeOnly",
+    "annotationProcessor",
+    "kapt",
+    "ksp",
+    "developmentOnly",
+    "testImplementation",
+    "testRuntimeOnly",
+    "testCompileOnly",
+    "testAnnotationProcessor",
+    "androidTestImplementation",
+    "compile",
+    "testCompile",
+)
+
+_CONFIG_ALTERNATION = "|".join(GRADLE_CONFIGURATIONS)
+# implementation 'g:a:v' / implementation("g:a:v") / api(platform("g:a:v"))
+GRADLE_STRING_DEPENDENCY = re.compile(
+    rf"(?:^|[{{;])\s*({_CONFIG_ALTERNATION})\s*\(?"
+    r"\s*(?:(?:enforcedPlatform|platform)\s*\(\s*)?"
+    r"['\"]([^'\":\s]+):([^'\":\s]+)(?::([^'\"@\s]+))?[^'\"]*['\"]",
+    re.MULTILINE,
+)
+# implementation group: 'g', name: 'a', version: 'v'
+GRADLE_MAP_DEPENDENCY = re.compile(
+    rf"(?:^|[{{;])\s*({_CONFIG_ALTERNATION})\s*\(?"
+    r"\s*group\s*[:=]\s*['\"]([^'\"]+)['\"]\s*,"
+    r"\s*name\s*[:=]\s*['\"]([^'\"]+)['\"]"
+    r"(?:\s*,\s*version\s*[:=]\s*['\"]([^'\"]+)['\"])?",
+    re.MULTILINE,
+)
+# implementation(libs.spring.boot.starter.web)
+GRADLE_CATALOG_DEPENDENCY = re.compile(
+    rf"(?:^|[{{;])\s*({_CONFIG_ALTERNATION})\s*\(?"
+    r"\s*(?:(?:enforcedPlatform|platform)\s*\(\s*)?"
+    r"libs\.([A-Za-z0-9_.]+)",
+    re.MULTILINE,
+)
+# id 'org.springframework.boot' version '3.2.0' / id("io.quarkus")
+GRADLE_PLUGIN_ID = re.compile(
+    r"(?:^|[{;])\s*id\s*\(?\s*['\"]([^'\"]+)['\"]\s*\)?"
+    r"(?:\s*version\s*\(?\s*['\"]([^'\"]+)['\"])?",
+    re.MULTILINE,
+)
+# kotlin("jvm") version "1.9.22"
+GRADLE_KOTLIN_PLUGIN = re.compile(
+    r"^\s*kotlin\s*\(\s*['\"]([^'\"]+)['\"]\s*\)"
+    r"(?:\s*version\s*['\"]([^'\"]+)['\"])?",
+    re.MULTILINE,
+)
+# alias(libs.plugins.spring.boot)
+GRADLE_PLUGIN_ALIAS = re.compile(
+    r"^\s*alias\s*\(\s*libs\.plugins\.([A-Za-z0-9_.]+)", re.MULTILINE
+)
+GRADLE_APPLY_PLUGIN = re.compile(r"apply\s+plugin\s*:\s*['\"]([^'\"]+)['\"]")
+GRADLE_ROOT_PROJECT_NAME = re.compile(r"rootProject\.name\s*=\s*['\"]([^'\"]+)['\"]")
+GRADLE_INCLUDE = re.compile(r"^\s*include\s*\(?([^\n)]*)", re.MULTILINE)
+QUOTED = re.compile(r"['\"]([^'\"]+)['\"]")
+PROPERTY_REFERENCE = re.compile(r"\$\{([^}]+)\}")
+# String literals are matched (and kept) first so "https://..." survives
+GRADLE_COMMENT_OR_STRING = re.compile(
+    r"(\"\"\".*?\"\"\"|\"(?:\\.|[^\"\\\n])*\"|'(?:\\.|[^'\\\n])*')"
+    r"|/\*.*?\*/|//[^\n]*",
+    re.DOTALL,
+)
+
+# Java release declarations in Gradle scripts
+GRADLE_JAVA_RELEASE_PATTERNS = (
+    re.compile(r"JavaLanguageVersion\.of\(\s*(\d+)\s*\)"),
+    re.compile(r"jvmToolchain\(\s*(\d+)\s*\)"),
+    re.compile(r"JavaVersion\.VERSION_(\d+(?:_\d+)?)"),
+    re.compile(r"sourceCompatibility\s*=\s*['\"]?(\d+(?:\.\d+)?)"),
+)
+MAVEN_JAVA_RELEASE_PROPERTIES = (
+    "maven.compiler.release",
+    "java.version",
+    "maven.compiler.source",
+)
+
+TEST_FRAMEWORK_LABELS = {"junit5": "JUnit 5 (Jupiter)", "junit4": "JUnit 4"}
+
+
+@dataclass
+class JvmDependency:
+    """A dependency declared in a Maven POM or Gradle build script."""
+
+    group: str
+    artifact: str
+    version: str | None = None
+    scope: str = "compile"  # Maven scope or Gradle configuration
+
+    @property
+    def coordinate(self) -> str:
+        return f"{self.group}:{self.artifact}"
+
+    @property
+    def is_test(self) -> bool:
+        return self.scope in TEST_CONFIGURATIONS
+
+
+@dataclass
+class JvmPlugin:
+    """A build plugin: Maven ``group:artifact`` or Gradle plugin id."""
+
+    id: str
+    version: str | None = None
+
+
+@dataclass
+class JvmModule:
+    """One Maven module or Gradle (sub)project."""
+
+    directory: Path
+    name: str
+    build_file: str
+    dependencies: list[JvmDependency] = field(default_factory=list)
+    plugins: list[JvmPlugin] = field(default_factory=list)
+    parent: JvmDependency | None = None  # Maven <parent>
+    java_release: str | None = None
+
+    @property
+    def language(self) -> str:
+        """``kotlin`` when the module builds Kotlin sources, else ``java``."""
+        kotlin_plugin = any(
+            plugin.id.startswith(("org.jetbrains.kotlin", "kotlin-"))
+            or plugin.id == "org.jetbrains.kotlin:kotlin-maven-plugin"
+            for plugin in self.plugins
+        )
+        if kotlin_plugin or (self.directory / "src" / "main" / "kotlin").is_dir():
+            return "kotlin"
+        return "java"
+
+    def plugin_version(self, plugin_id: str) -> str | None:
+        for plugin in self.plugins:
+            if plugin.id == plugin_id and plugin.version:
+                return plugin.version
+        return None
+
+
+@dataclass
+class JvmBuild:
+    """A Maven reactor or Gradle build with its modules (root first)."""
+
+    root: Path
+    build_tool: str  # maven or gradle
+    modules: list[JvmModule] = field(default_factory=list)
+    dsl: str | None = None  # Gradle only: groovy or kotlin
+
+    @property
+    def is_multi_module(self) -> bool:
+        return len(self.modules) > 1
+
+    def all_dependencies(self) -> list[JvmDependency]:
+        return [dep for module in self.modules for dep in module.dependencies]
+
+    def all_plugins(self) -> list[JvmPlugin]:
+        return [plugin for module in self.modules for plugin in module.plugins]
+
+    @property
+    def java_release(self) -> str | None:
+        """Lowest Java release any module targets."""
+        releases = [m.java_release for m in self.modules if m.java_release]
+        if not releases:
+            return None
+        return min(releases, key=_release_key)
+
+    def relative_path(self, module: JvmModule) -> str:
+        try:
+            relative = module.directory.relative_to(self.root)
+        except ValueError:
+            return str(module.directory)
+        return relative.as_posix() or "."
+
+
+def _release_key(release: str) -> tuple[int, ...]:
+    # "1.8" and "8" are the same release
+    parts = [int(part) for part in re.findall(r"\d+", release)]
+    if parts[:1] == [1] and len(parts) > 1:
+        parts = parts[1:]
+    return tuple(parts)
+
+
+# Maven ---------------------------------------------------------------------
+
+
+def _local(tag: Any) -> str:
+    """Tag name without the ``{namespace}`` prefix."""
+    return tag.rsplit("}", 1)[-1] if isinstance(tag, str) else ""
+
+
+def _child(element: Element | None, name: str) -> Element | None:
+    if element is None:
+        return None
+    for child in element:
+        if _local(child.tag) == name:
+            return child
+    return None
+
+
+def _children(element: Element | None, name: str) -> list[Element]:
+    if element is None:
+        return []
+    return [child for child in element if _local(child.tag) == name]
+
+
+def _text(element: Element | None, name: str) -> str | None:
+    child = _child(element, name)
+    if child is None or child.text is None:
+        return None
+    return child.text.strip() or None
+
+
+def _resolve(value: str | None, properties: dict[str, str]) -> str | None:
+    """Substitute ``${property}`` references (unresolvable ones are kept)."""
+    if value is None:
+        return None
+    for _ in range(5):  # Properties may reference other properties
+        resolved = PROPERTY_REFERENCE.sub(
+            lambda m: properties.get(m.group(1), m.group(0)), value
+        )
+        if resolved == value:
+            break
+        value = resolved
+    return value
+
+
+def _maven_dependencies(
+    container: Element | None, properties: dict[str, str]
+) -> list[JvmDependency]:
+    dependencies = []
+    for dep in _children(_child(container, "dependencies"), "dependency"):
+        group = _resolve(_text(dep, "groupId"), properties)
+        artifact = _resolve(_text(dep, "artifactId"), properties)
+        if not group or not artifact:
+            continue
+        dependencies.append(
+            JvmDependency(
+                group=group,
+                artifact=artifact,
+                version=_resolve(_text(dep, "version"), properties),
+                scope=_text(dep, "scope") or "compile",
+            )
+        )
+    return dependencies
+
+
+def load_maven_module(
+    pom_path: Path, inherited: dict[str, str] | None = None
+) -> tuple[JvmModule, list[str], dict[str, str]] | None:
+    """Parse one POM; returns the module, its ``<modules>`` and properties."""
+    try:
+        root = ElementTree.parse(str(pom_path)).getroot()
+    except (OSError, ElementTree.ParseError, DefusedXmlException):
+        # DefusedXmlException: entity expansion or external references
+        return None
+
+    properties = dict(inherited or {})
+    properties_element = _child(root, "properties")
+    for prop in properties_element if properties_element is not None else []:
+        if prop.text is not None:
+            properties[_local(prop.tag)] = prop.text.strip()
+
+    parent = None
+    parent_element = _child(root, "parent")
+    if parent_element is not None:
+        group = _text(parent_element, "groupId")
+        artifact = _text(parent_element, "artifactId")
+        if group and artifact:
+            parent = JvmDependency(
+                group=group,
+                artifact=artifact,
+                version=_text(parent_element, "version"),
+                scope="parent",
+            )
+            if parent.version:
+                properties.setdefault("project.parent.version", parent.version)
+
+    version = _text(root, "version") or (parent.version if parent else None)
+    if version:
+        properties["project.version"] = version
+
+    dependencies = _maven_dependencies(root, properties)
+    # BOM imports in <dependencyManagement> pin framework versions
+    dependencies.extend(
+        dep
+        for dep in _maven_dependencies(
+            _child(root, "dependencyManagement"), properties
+        )
+        if dep.scope == "import"
+    )
+
+    plugins = []
+    build = _child(root, "build")
+    for container in (build, _child(build, "pluginManagement")):
+        for plugin in _children(_child(container, "plugins"), "plugin"):
+            artifact = _resolve(_text(plugin, "artifactId"), properties)
+            if not artifact:
+                continue
+            group = _resolve(_text(plugin, "groupId"), properties) or (
+                "org.apache.maven.plugins"
+            )
+            plugins.append(
+                JvmPlugin(
+                    id=f"{group}:{artifact}",
+                    version=_resolve(_text(plugin, "version"), properties),
+                )
+            )
+
+    java_release = next(
+        (
+            _resolve(properties[name], properties)
+            for name in MAVEN_JAVA_RELEASE_PROPERTIES
+            if properties.get(name)
+        ),
+        None,
+    )
+
+    module = JvmModule(
+        directory=pom_path.parent,
+        name=_text(root, "artifactId") or pom_path.parent.name,
+        build_file=POM_XML,
+        dependencies=dependencies,
+        plugins=plugins,
+        parent=parent,
+        java_release=java_release,
+    )
+    modules = [
+        child.text.strip()
+        for child in _children(_child(root, "modules"), "module")
+        if child.text and child.text.strip()
+    ]
+    return module, modules, properties
+
+
+def load_maven_build(project_path: Path) -> JvmBuild | None:
+    """Load the reactor rooted at ``pom.xml``, following ``<modules>``."""
+    build = JvmBuild(root=project_path, build_tool="maven")
+    pending: list[tuple[Path, dict[str, str]]] = [(project_path / POM_XML, {})]
+    seen: set[Path] = set()
+    while pending:
+        pom_path, inherited = pending.pop(0)
+        resolved = pom_path.resolve()
+        if resolved in seen or not pom_path.is_file():
+            continue
+        seen.add(resolved)
+        loaded = load_maven_module(pom_path, inherited)
+        if loaded is None:
+            continue
+        module, children, properties = loaded
+        build.modules.append(module)
+        for child in children:
+            child_path = pom_path.parent / child
+            if child_path.suffix != ".xml":
+                child_path = child_path / POM_XML
+            pending.append((child_path, properties))
+    return build if build.modules else None
+
+
+# Gradle --------------------------------------------------------------------
+
+
+def _read(path: Path) -> str | None:
+    try:
+        return path.read_text(encoding="utf-8")
+    except (OSError, UnicodeDecodeError):
+        return None
+
+
+def _strip_gradle_comments(content: str) -> str:
+    return GRADLE_COMMENT_OR_STRING.sub(lambda match: match.group(1) or "", content)
+
+
+def _catalog_key(alias: str) -> str:
+    """Gradle accessor form of a catalogue alias (``-``/``_`` become ``.``)."""
+    return re.sub(r"[-_]", ".", alias)
+
+
+@dataclass
+class VersionCatalog:
+    """Libraries and plugins from ``gradle/libs.versions.toml``."""
+
+    libraries: dict[str, JvmDependency] = field(default_factory=dict)
+    plugins: dict[str, JvmPlugin] = field(default_factory=dict)
+
+    @classmethod
+    def load(cls, path: Path) -> VersionCatalog:
+        catalog = cls()
+        content = _read(path) if path.is_file() else None
+        if not content:
+            return catalog
+        try:
+            data = toml.loads(content)
+        except (toml.TomlDecodeError, TypeError, ValueError):
+            return catalog
+
+        versions = data.get("versions", {})
+
+        def version_of(spec: dict[str, Any]) -> str | None:
+            version = spec.get("version")
+            if isinstance(version, dict):
+                ref = version.get("ref")
+                return str(versions.get(ref)) if ref in versions else None
+            if "version.ref" in spec:
+                return versions.get(spec["version.ref"])
+            return str(version) if version else None
+
+        for alias, spec in data.get("libraries", {}).items():
+            group = artifact = version = None
+            if isinstance(spec, str):
+                parts = spec.split(":")
+                if len(parts) >= 2:
+                    group, artifact = parts[0], parts[1]
+                    version = parts[2] if len(parts) > 2 else None
+            elif isinstance(spec, dict):
+                if "module" in spec and ":" in spec["module"]:
+                    group, artifact = spec["module"].split(":", 1)
+                else:
+                    group, artifact = spec.get("group"), spec.get("name")
+                version = version_of(spec)
+            if group and artifact:
+                catalog.libraries[_catalog_key(alias)] = JvmDependency(
+                    group=group, artifact=artifact, version=version
+                )
+
+        for alias, spec in data.get("plugins", {}).items():
+            if isinstance(spec, str):
+                plugin_id, _, version = spec.partition(":")
+                catalog.plugins[_catalog_key(alias)] = JvmPlugin(
+                    id=plugin_id, version=version or None
+                )
+            elif isinstance(spec, dict) and spec.get("id"):
+                catalog.plugins[_catalog_key(alias)] = JvmPlugin(
+                    id=spec["id"], version=version_of(spec)
+                )
+        return catalog
+
+
+def parse_gradle_build(
+    content: str, directory: Path, catalog: VersionCatalog | None = None
+) -> JvmModule:
+    """Extract plugins and dependencies from a Groovy or Kotlin DSL script."""
+    catalog = catalog or VersionCatalog()
+    content = _strip_gradle_comments(content)
+
+    plugins = [
+        JvmPlugin(id=match.group(1), version=match.group(2))
+        for match in GRADLE_PLUGIN_ID.finditer(content)
+    ]
+    plugins.extend(
+        JvmPlugin(id=f"org.jetbrains.kotlin.{match.group(1)}", version=match.group(2))
+        for match in GRADLE_KOTLIN_PLUGIN.finditer(content)
+    )
+    for match in GRADLE_PLUGIN_ALIAS.finditer(content):
+        plugin = catalog.plugins.get(match.group(1))
+        if plugin is not None:
+            plugins.append(plugin)
+    plugins.extend(
+        JvmPlugin(id=match.group(1))
+        for match in GRADLE_APPLY_PLUGIN.finditer(content)
+    )
+
+    dependencies = [
+        JvmDependency(
+            group=match.group(2),
+            artifact=match.group(3),
+            version=match.group(4),
+            scope=match.group(1),
+        )
+        for pattern in (GRADLE_STRING_DEPENDENCY, GRADLE_MAP_DEPENDENCY)
+        for match in pattern.finditer(content)
+    ]
+    for match in GRADLE_CATALOG_DEPENDENCY.finditer(content):
+        library = catalog.libraries.get(match.group(2))
+        if library is not None:
+            dependencies.append(
+                JvmDependency(
+                    group=library.group,
+                    artifact=library.artifact,
+                    version=library.version,
+                    scope=match.group(1),
+                )
+            )
+
+    java_release = None
+    for pattern in GRADLE_JAVA_RELEASE_PATTERNS:
+        match = pattern.search(content)
+        if match:
+            java_release = match.group(1).replace("_", ".")
+            break
+
+    return JvmModule(
+        directory=directory,
+        name=directory.name,
+        build_file="",
+        dependencies=dependencies,
+        plugins=plugins,
+        java_release=java_release,
+    )
+
+
+def _gradle_includes(settings: str) -> list[str]:
+    """Project paths from ``include`` statements (``:a:b`` -> ``a/b``)."""
+    paths = []
+    for match in GRADLE_INCLUDE.finditer(_strip_gradle_comments(settings)):
+        for name in QUOTED.findall(match.group(1)):
+            path = name.strip(":").replace(":", "/")
+            if path and path not in paths:
+                paths.append(path)
+    return paths
+
+
+def load_gradle_build(project_path: Path) -> JvmBuild | None:
+    """Load the root Gradle project and every included subproject."""
+    settings_file = next(
+        (
+            project_path / name
+            for name in GRADLE_SETTINGS_FILES
+            if (project_path / name).is_file()
+        ),
+        None,
+    )
+    directories = [project_path]
+    if settings_file is not None:
+        settings = _read(settings_file) or ""
+        directories.extend(project_path / path for path in _gradle_includes(settings))
+
+    catalog = VersionCatalog.load(project_path / GRADLE_VERSION_CATALOG)
+    build = JvmBuild(root=project_path, build_tool="gradle")
+    for directory in directories:
+        build_file = next(
+            (directory / n for n in GRADLE_BUILD_FILES if (directory / n).is_file()),
+            None,
+        )
+        if build_file is None:
+            continue
+        module = parse_gradle_build(_read(build_file) or "", directory, catalog)
+        module.build_file = build_file.name
+        if directory == project_path and settings_file is not None:
+            name = GRADLE_ROOT_PROJECT_NAME.search(_read(settings_file) or "")
+            if name:
+                module.name = name.group(1)
+        build.modules.append(module)
+        if build.dsl is None:
+            build.dsl = "kotlin" if build_file.suffix == ".kts" else "groovy"
+
+    if not build.modules and settings_file is None:
+        return None
+    if build.dsl is None and settings_file is not None:
+        build.dsl = "kotlin" if settings_file.suffix == ".kts" else "groovy"
+    return build
+
+
+def load_jvm_build(project_path: Path) -> JvmBuild | None:
+    """Load a Maven reactor (preferred) or a Gradle build from the root."""
+    if (project_path / POM_XML).is_file():
+        build = load_maven_build(project_path)
+        if build is not None:
+            return build
+    if any(
+        (project_path / name).is_file()
+        for name in GRADLE_BUILD_FILES + GRADLE_SETTINGS_FILES
+    ):
+        return load_gradle_build(project_path)
+    return None
+
+
+def summarize_jvm_details(details: dict[str, Any]) -> str:
+    """Markdown bullets describing the build from ``FrameworkInfo.details``."""
+    build_tool = details.get("build_tool")
+    if not build_tool:
+        return ""
+
+    tool = build_tool.title()
+    if details.get("build_dsl"):
+        tool += f" ({details['build_dsl'].title()} DSL)"
+    lines = [f"- **Build tool**: {tool}"]
+    if details.get("java_release"):
+        lines.append(f"- **Java release**: {details['java_release']}")
+    modules = [module for module in details.get("modules") or [] if module != "."]
+    if modules:
+        listed = ", ".join(f"`{module}`" for module in modules)
+        lines.append(f"- **Modules** ({len(modules)}): {listed}")
+    test_frameworks = details.get("test_frameworks") or []
+    if test_frameworks:
+        labels = [TEST_FRAMEWORK_LABELS.get(t, t) for t in test_frameworks]
+        lines.append(f"- **Tests**: {', '.join(labels)}")
+    for framework, version in sorted((details.get("framework_versions") or {}).items()):
+        lines.append(f"- **{framework}**: {version}")
+    return "\n".join(lines)
@@ -0,0 +1,185 @@
+"""Catalogue of JVM frameworks and JUnit generation detection.
+
+Entries match Maven group ids exactly or as a dotted prefix, so
+``io.micronaut.data`` counts as Micronaut while ``io.micronautics`` does not;
+the most specific prefix wins. Build plugins (Gradle plugin ids and Maven
+``group:artifact``) and Maven ``<parent>`` POMs count too, and test-scoped
+dependencies count for less.
+"""
+
+from __future__ import annotations
+
+from collections import defaultdict
+from dataclasses import dataclass, field
+from typing import Iterable
+
+from claude_builder.analysis.jvm import JvmBuild, JvmDependency
+
+
+# Test-only dependencies (e.g. quarkus-junit5) do not make a project use the
+# framework at runtime
+TEST_SCOPE_WEIGHT = 0.3
+
+
+@dataclass(frozen=True)
+class JvmFrameworkSignature:
+    """Group ids and build plugins that identify a JVM framework."""
+
+    category: str
+    groups: dict[str, float]
+    plugins: dict[str, float] = field(default_factory=dict)
+    web_framework: bool = False
+
+
+JVM_FRAMEWORKS: dict[str, JvmFrameworkSignature] = {
+    "springboot": JvmFrameworkSignature(
+        category="web",
+        groups={"org.springframework.boot": 10.0},
+        plugins={
+            "org.springframework.boot": 6.0,
+            "org.springframework.boot:spring-boot-maven-plugin": 6.0,
+        },
+        web_framework=True,
+    ),
+    "spring": JvmFrameworkSignature(
+        category="web",
+        groups={"org.springframework": 6.0},
+        web_framework=True,
+    ),
+    "quarkus": JvmFrameworkSignature(
+        category="web",
+        groups={"io.quarkus": 10.0},
+        plugins={
+            "io.quarkus": 6.0,
+            "io.quarkus:quarkus-maven-plugin": 6.0,
+            "io.quarkus.platform:quarkus-maven-plugin": 6.0,
+        },
+        web_framework=True,
+    ),
+    "micronaut": JvmFrameworkSignature(
+        category="web",
+        groups={"io.micronaut": 10.0},
+        plugins={
+            "io.micronaut.application": 6.0,
+            "io.micronaut.library": 4.0,
+            "io.micronaut.maven:micronaut-maven-plugin": 6.0,
+        },
+        web_framework=True,
+    ),
+    "ktor": JvmFrameworkSignature(
+        category="web",
+        groups={"io.ktor": 10.0},
+        plugins={"io.ktor.plugin": 6.0},
+        web_framework=True,
+    ),
+}
+
+# Artifacts that bring in a JUnit generation through a framework test starter
+JUNIT5_ARTIFACTS = {
+    "spring-boot-starter-test",
+    "quarkus-junit5",
+    "micronaut-test-junit5",
+    "kotest-runner-junit5",
+}
+JUNIT5_GROUPS = {"org.junit.jupiter", "org.junit.platform"}
+JUNIT4_GROUPS = {"org.junit.vintage"}
+
+
+def _matches_group(group: str, prefix: str) -> bool:
+    return group == prefix or group.startswith(prefix + ".")
+
+
+def _match_framework(group: str) -> tuple[str, str, float] | None:
+    """Framework, prefix and score of the most specific matching group.
+
+    ``org.springframework.boot`` counts as Spring Boot only, not also Spring.
+    """
+    matches = [
+        (framework, prefix, score)
+        for framework, signature in JVM_FRAMEWORKS.items()
+        for prefix, score in signature.groups.items()
+        if _matches_group(group, prefix)
+    ]
+    return max(matches, key=lambda match: len(match[1]), default=None)
+
+
+def _framework_dependencies(build: JvmBuild) -> list[JvmDependency]:
+    """Declared dependencies plus Maven parents (e.g. spring-boot-starter-parent)."""
+    dependencies = build.all_dependencies()
+    dependencies.extend(m.parent for m in build.modules if m.parent is not None)
+    return dependencies
+
+
+def jvm_framework_matches(build: JvmBuild) -> dict[tuple[str, str], float]:
+    """Score of every (framework, signal) pair from dependencies and plugins.
+
+    The signal names the build file entry, e.g. "gradle dependency
+    `io.ktor`". Each group or plugin counts once per framework, using its
+    strongest declaration across modules.
+    """
+    best: dict[tuple[str, str], float] = {}
+    for dependency in _framework_dependencies(build):
+        match = _match_framework(dependency.group)
+        if match is None:
+            continue
+        framework, prefix, score = match
+        if dependency.is_test:
+            score *= TEST_SCOPE_WEIGHT
+        key = (framework, f"{build.build_tool} dependency `{prefix}`")
+        best[key] = max(best.get(key, 0.0), score)
+
+    for plugin in build.all_plugins():
+        for framework, signature in JVM_FRAMEWORKS.items():
+            score = signature.plugins.get(plugin.id)
+            if score is not None:
+                key = (framework, f"{build.build_tool} plugin `{plugin.id}`")
+                best[key] = max(best.get(key, 0.0), score)
+    return best
+
+
+def score_jvm_frameworks(build: JvmBuild) -> dict[str, float]:
+    """Score catalogue frameworks from dependencies and build plugins."""
+    scores: dict[str, float] = defaultdict(float)
+    for (framework, _signal), score in jvm_framework_matches(build).items():
+        scores[framework] += score
+    return dict(scores)
+
+
+def jvm_framework_versions(build: JvmBuild) -> dict[str, str]:
+    """Framework -> version from plugins, parents, BOMs or dependencies."""
+    versions: dict[str, str] = {}
+
+    def record(framework: str, version: str | None) -> None:
+        if version and "${" not in version:
+            versions.setdefault(framework, version)
+
+    # Plugins, parents and BOMs pin the platform version, so check them first
+    for plugin in build.all_plugins():
+        for framework, signature in JVM_FRAMEWORKS.items():
+            if plugin.id in signature.plugins:
+                record(framework, plugin.version)
+    dependencies = _framework_dependencies(build)
+    dependencies.sort(key=lambda dep: dep.scope not in {"parent", "import"})
+    for dependency in dependencies:
+        match = _match_framework(dependency.group)
+        if match is not None:
+            record(match[0], dependency.version)
+    return versions
+
+
+def detect_junit(dependencies: Iterable[JvmDependency]) -> list[str]:
+    """``junit5`` and/or ``junit4`` (both while a suite is being migrated)."""
+    junit5 = junit4 = False
+    for dependency in dependencies:
+        if (
+            dependency.group in JUNIT5_GROUPS
+            or dependency.artifact in JUNIT5_ARTIFACTS
+            or dependency.coordinate == "org.junit:junit-bom"
+        ):
+            junit5 = True
+        elif (
+            dependency.coordinate == "junit:junit"
+            or dependency.group in JUNIT4_GROUPS
+        ):
+            junit4 = True
+    return [name for name, found in (("junit5", junit5), ("junit4", junit4)) if found]
@@ -0,0 +1,442 @@
+"""Built-in language plugins.
+
+Each plugin carries the extension lists, manifest parsing, framework catalogue,
+agent mappings and template directory for one language. Third-party plugins
+registered through entry points are added on top of (or replace) these; see
+:mod:`claude_builder.core.language_plugins`.
+"""
+
+from __future__ import annotations
+
+import json
+
+from pathlib import Path
+from typing import Any
+
+import toml
+
+from claude_builder.analysis.cargo import load_cargo_workspace
+from claude_builder.analysis.evidence import add_score
+from claude_builder.analysis.go_frameworks import GO_FRAMEWORKS, go_framework_matches
+from claude_builder.analysis.gomod import load_go_workspace
+from claude_builder.analysis.jvm import load_jvm_build
+from claude_builder.analysis.jvm_frameworks import (
+    JVM_FRAMEWORKS,
+    detect_junit,
+    jvm_framework_matches,
+    jvm_framework_versions,
+)
+from claude_builder.analysis.rust_frameworks import (
+    MATURIN_BONUS,
+    RUST_FRAMEWORKS,
+    rust_framework_matches,
+    uses_maturin,
+)
+from claude_builder.analysis.toolchains import detect_language_versions
+from claude_builder.core.language_plugins import LanguagePlugin
+
+
+LANGUAGE_TEMPLATES_DIR = Path(__file__).parent.parent / "templates" / "languages"
+
+
+class BuiltinLanguagePlugin(LanguagePlugin):
+    """Plugin whose templates and versions come from the packaged defaults."""
+
+    def __init__(self, **overrides: object) -> None:
+        super().__init__(**overrides)
+        if self.template_dir is None:
+            candidate = LANGUAGE_TEMPLATES_DIR / self.name
+            if candidate.is_dir():
+                self.template_dir = candidate
+
+    def detect_versions(self, project_path: Path) -> dict[str, str]:
+        return detect_language_versions(project_path, [self.name])
+
+
+class PythonPlugin(BuiltinLanguagePlugin):
+    name = "python"
+    extensions = (".py", ".pyx", ".pyi")
+    source_extensions = (".py",)
+    manifest_files = ("pyproject.toml", "setup.py", "requirements.txt", "Pipfile")
+    frameworks = {
+        "django": "web",
+        "flask": "web",
+        "fastapi": "web",
+        "starlette": "web",
+        "cli_tool": "cli",
+        "click": "cli",
+        "typer": "cli",
+    }
+    web_frameworks = ("django", "flask", "fastapi", "starlette")
+    agent_mappings = {
+        "primary": ["python-pro"],
+        "web_frameworks": {
+            "django": ["backend-developer", "database-architect"],
+            "flask": ["backend-developer", "api-designer"],
+            "fastapi": [
+                "backend-developer",
+                "api-designer",
+                "performance-benchmarker",
+            ],
+            "data_science": ["data-scientist", "ml-engineer"],
+        },
+        "testing": ["test-writer-fixer"],
+        "deployment": ["devops-automator"],
+    }
+
+    # (package, framework, score) for requirement and dependency name matches
+    DEPENDENCY_SIGNALS = (
+        ("django", "django", 8),
+        ("flask", "flask", 8),
+        ("fastapi", "fastapi", 8),
+        ("starlette", "starlette", 6),
+        ("click", "cli_tool", 4),
+        ("typer", "cli_tool", 4),
+    )
+    # Weaker substring matches when pyproject.toml cannot be parsed
+    PYPROJECT_TEXT_SIGNALS = (
+        ("django", "django", 5),
+        ("flask", "flask", 5),
+        ("fastapi", "fastapi", 5),
+        ("click", "cli_tool", 3),
+    )
+
+    def score_frameworks(
+        self, project_path: Path, scores: dict[str, float]
+    ) -> list[str]:
+        """Check requirements.txt and pyproject.toml for frameworks."""
+        req_file = project_path / "requirements.txt"
+        if req_file.exists():
+            try:
+                content = req_file.read_text().lower()
+                for package, framework, score in (
+                    *self.DEPENDENCY_SIGNALS,
+                    ("argparse", "cli_tool", 2),
+                ):
+                    if package in content:
+                        add_score(
+                            scores,
+                            framework,
+                            score,
+                            f"requirements.txt mentions `{package}`",
+                        )
+            except (OSError, UnicodeDecodeError):
+                pass
+
+        pyproject_file = project_path / "pyproject.toml"
+        if pyproject_file.exists():
+            try:
+                with pyproject_file.open() as f:
+                    pyproject_data = toml.load(f)
+
+                deps = {}
+                if (
+                    "project" in pyproject_data
+                    and "dependencies" in pyproject_data["project"]
+                ):
+                    for dep in pyproject_data["project"]["dependencies"]:
+                        dep_name = (
+                            dep.split("[")[0]
+                            .split(">=")[0]
+                            .split("==")[0]
+                            .split("~=")[0]
+                            .strip()
+                        )
+                        deps[dep_name.lower()] = True
+
+                for package, framework, score in self.DEPENDENCY_SIGNALS:
+                    if package in deps:
+                        add_score(
+                            scores,
+                            framework,
+                            score,
+                            f"pyproject.toml dependency `{package}`",
+                        )
+
+                # Console scripts mean the package ships a command
+                if (
+                    "project" in pyproject_data
+                    and "scripts" in pyproject_data["project"]
+                ):
+                    add_score(
+                        scores, "cli_tool", 6, "pyproject.toml [project.scripts]"
+                    )
+
+            except (OSError, toml.TomlDecodeError, ValueError):
+                # Fallback to text search
+                try:
+                    content = pyproject_file.read_text().lower()
+                    for package, framework, score in self.PYPROJECT_TEXT_SIGNALS:
+                        if package in content:
+                            add_score(
+                                scores,
+                                framework,
+                                score,
+                                f"pyproject.toml mentions `{package}`",
+                            )
+                except (OSError, UnicodeDecodeError):
+                    pass
+        return []
+
+
+class RustPlugin(BuiltinLanguagePlugin):
+    name = "rust"
+    extensions = (".rs",)
+    manifest_files = ("Cargo.toml", "Cargo.lock")
+    frameworks = {name: sig.category for name, sig in RUST_FRAMEWORKS.items()}
+    web_frameworks = tuple(
+        name for name, sig in RUST_FRAMEWORKS.items() if sig.web_framework
+    )
+    infrastructure_frameworks = tuple(
+        name for name, sig in RUST_FRAMEWORKS.items() if sig.infrastructure
+    )
+    agent_mappings = {
+        "primary": ["rust-engineer"],
+        "project_types": {
+            "cli_tool": ["cli-developer", "performance-benchmarker"],
+            "web_service": ["backend-developer", "api-designer"],
+            "systems": ["performance-benchmarker", "security-engineer"],
+            "library": ["documentation-engineer", "test-writer-fixer"],
+        },
+    }
+
+    def score_frameworks(
+        self, project_path: Path, scores: dict[str, float]
+    ) -> list[str]:
+        """Score every workspace member's crates against the catalogue."""
+        workspace = load_cargo_workspace(project_path)
+        if workspace is None:
+            return []
+
+        matches = rust_framework_matches(workspace.all_dependencies())
+        for (framework, crate), score in matches.items():
+            add_score(scores, framework, score, f"Cargo.toml dependency `{crate}`")
+
+        # PyO3 extension modules are usually built and published with maturin
+        if "pyo3" in scores and uses_maturin(project_path):
+            add_score(
+                scores, "pyo3", MATURIN_BONUS, "pyproject.toml builds with maturin"
+            )
+        return []
+
+
+class NodePlugin(BuiltinLanguagePlugin):
+    """Shared package.json handling for JavaScript and TypeScript."""
+
+    frameworks = {
+        "express": "web",
+        "nextjs": "web",
+        "nuxt": "web",
+        "react": "frontend",
+        "vue": "frontend",
+        "angular": "frontend",
+        "svelte": "frontend",
+    }
+    web_frameworks = (
+        "react",
+        "vue",
+        "angular",
+        "express",
+        "nextjs",
+        "nuxt",
+        "svelte",
+    )
+    # (substring of a dependency name, framework)
+    PACKAGE_KEYWORDS = (
+        ("react", "react"),
+        ("vue", "vue"),
+        ("angular", "angular"),
+        ("express", "express"),
+        ("next", "nextjs"),
+        ("nuxt", "nuxt"),
+        ("svelte", "svelte"),
+    )
+
+    def score_frameworks(
+        self, project_path: Path, scores: dict[str, float]
+    ) -> list[str]:
+        """Check package.json dependencies and devDependencies."""
+        package_file = project_path / "package.json"
+        deps_list: list[str] = []
+        if package_file.exists():
+            try:
+                with package_file.open() as f:
+                    package_data = json.load(f)
+
+                dependencies = {}
+                dependencies.update(package_data.get("dependencies", {}))
+                dependencies.update(package_data.get("devDependencies", {}))
+
+                for dep in dependencies:
+                    deps_list.append(dep)
+                    # First matching keyword wins: "next" before "nuxt", ...
+                    framework = next(
+                        (fw for keyword, fw in self.PACKAGE_KEYWORDS if keyword in dep),
+                        None,
+                    )
+                    if framework is not None:
+                        add_score(
+                            scores, framework, 5, f"package.json dependency `{dep}`"
+                        )
+            except (OSError, json.JSONDecodeError):
+                pass
+        return deps_list
+
+
+class JavaScriptPlugin(NodePlugin):
+    name = "javascript"
+    extensions = (".js", ".mjs", ".cjs")
+    source_extensions = (".js", ".jsx")
+    manifest_files = ("package.json", "package-lock.json")
+    agent_mappings = {
+        "primary": ["javascript-pro"],
+        "frontend": {
+            "react": ["frontend-developer", "react-specialist", "ui-designer"],
+            "vue": ["frontend-developer", "vue-expert", "ui-designer"],
+            "angular": [
+                "frontend-developer",
+                "angular-architect",
+                "typescript-pro",
+            ],
+        },
+        "backend": {
+            "node": ["backend-developer", "api-designer"],
+            "express": ["backend-developer", "api-designer"],
+            "nestjs": ["backend-developer", "typescript-pro", "api-designer"],
+        },
+    }
+
+
+class TypeScriptPlugin(NodePlugin):
+    name = "typescript"
+    extensions = (".ts", ".tsx")
+    manifest_files = ("package.json", "tsconfig.json")
+
+
+class JvmLanguagePlugin(BuiltinLanguagePlugin):
+    """Shared Maven/Gradle handling for Java and Kotlin."""
+
+    frameworks = {name: sig.category for name, sig in JVM_FRAMEWORKS.items()}
+    web_frameworks = tuple(
+        name for name, sig in JVM_FRAMEWORKS.items() if sig.web_framework
+    )
+
+    def score_frameworks(
+        self, project_path: Path, scores: dict[str, float]
+    ) -> list[str]:
+        """Score pom.xml / Gradle dependencies and plugins of every module."""
+        build = load_jvm_build(project_path)
+        if build is None:
+            return []
+
+        for (framework, signal), score in jvm_framework_matches(build).items():
+            add_score(scores, framework, score, signal)
+        return sorted({dep.coordinate for dep in build.all_dependencies()})
+
+    def framework_details(self, project_path: Path) -> dict[str, Any]:
+        build = load_jvm_build(project_path)
+        if build is None:
+            return {}
+
+        details: dict[str, Any] = {
+            "build_tool": build.build_tool,
+            "modules": [build.relative_path(module) for module in build.modules],
+            "test_frameworks": detect_junit(build.all_dependencies()),
+            "framework_versions": jvm_framework_versions(build),
+        }
+        if build.dsl:
+            details["build_dsl"] = build.dsl
+        if build.java_release:
+            details["java_release"] = build.java_release
+        return details
+
+
+class JavaPlugin(JvmLanguagePlugin):
+    name = "java"
+    extensions = (".java",)
+    manifest_files = (
+        "pom.xml",
+        "build.gradle",
+        "build.gradle.kts",
+        "settings.gradle",
+        "settings.gradle.kts",
+    )
+    agent_mappings = {
+        "primary": ["java-pro"],
+        "project_types": {
+            "api_service": ["backend-developer", "api-designer"],
+            "library": ["documentation-engineer", "test-writer-fixer"],
+        },
+    }
+
+
+class KotlinPlugin(JvmLanguagePlugin):
+    name = "kotlin"
+    extensions = (".kt", ".kts")
+    source_extensions = (".kt",)
+    manifest_files = ("build.gradle.kts", "settings.gradle.kts")
+    agent_mappings = {
+        "primary": ["kotlin-specialist"],
+        "project_types": {
+            "api_service": ["backend-developer", "api-designer"],
+            "library": ["documentation-engineer", "test-writer-fixer"],
+        },
+    }
+
+
+class GoPlugin(BuiltinLanguagePlugin):
+    name = "go"
+    extensions = (".go",)
+    manifest_files = ("go.mod", "go.sum", "go.work")
+    frameworks = {name: sig.category for name, sig in GO_FRAMEWORKS.items()}
+    web_frameworks = tuple(
+        name for name, sig in GO_FRAMEWORKS.items() if sig.web_framework
+    )
+    agent_mappings = {
+        "primary": ["golang-pro"],
+        "project_types": {
+            "cli_tool": ["cli-developer", "test-writer-fixer"],
+            "api_service": ["backend-developer", "api-designer"],
+            "library": ["documentation-engineer", "test-writer-fixer"],
+        },
+    }
+
+    def score_frameworks(
+        self, project_path: Path, scores: dict[str, float]
+    ) -> list[str]:
+        """Score go.mod requirements of every module against the catalogue."""
+        workspace = load_go_workspace(project_path)
+        if workspace is None:
+            return []
+
+        requirements = workspace.all_requirements()
+        for (framework, module), score in go_framework_matches(requirements).items():
+            add_score(scores, framework, score, f"go.mod requires `{module}`")
+        return sorted({req.path for req in requirements if not req.indirect})
+
+
+BUILTIN_LANGUAGE_PLUGINS: list[LanguagePlugin] = [
+    PythonPlugin(),
+    RustPlugin(),
+    JavaScriptPlugin(),
+    TypeScriptPlugin(),
+    JavaPlugin(),
+    KotlinPlugin(),
+    GoPlugin(),
+    BuiltinLanguagePlugin(name="c", extensions=(".c", ".h")),
+    BuiltinLanguagePlugin(
+        name="cpp",
+        extensions=(".cpp", ".cxx", ".cc", ".hpp", ".hxx"),
+        source_extensions=(".cpp", ".hpp"),
+    ),
+    BuiltinLanguagePlugin(
+        name="csharp", extensions=(".cs",), manifest_files=("*.csproj", "*.sln")
+    ),
+    BuiltinLanguagePlugin(name="php", extensions=(".php",)),
+    BuiltinLanguagePlugin(name="ruby", extensions=(".rb",)),
+    BuiltinLanguagePlugin(name="scala", extensions=(".scala",)),
+    BuiltinLanguagePlugin(name="swift", extensions=(".swift",), source_extensions=()),
+    # Counted for language statistics only, never as source code
+    BuiltinLanguagePlugin(name="css", extensions=(".css",), source_extensions=()),
+    BuiltinLanguagePlugin(name="markdown", extensions=(".md",), source_extensions=()),
+]
@@ -0,0 +1,382 @@
+"""Monorepo detection: which workspace tools are in use and where the packages are.
+
+Supported layouts:
+
+- JavaScript: pnpm (``pnpm-workspace.yaml``), yarn/npm (``workspaces`` in
+  ``package.json``), Nx (``nx.json`` plus ``project.json`` files) and
+  Turborepo (``turbo.json``).
+- Rust: Cargo workspace members.
+- Python: uv workspaces (``[tool.uv.workspace]``) and Poetry path
+  dependencies declared with ``develop = true``.
+- Bazel: the outermost directories holding a ``BUILD`` file below a
+  ``WORKSPACE``/``MODULE.bazel`` root.
+- Go: modules listed in ``go.work``.
+
+Packages are directories strictly below the root; a package found by several
+tools (e.g. pnpm and Nx) is reported once with every tool that lists it.
+"""
+
+from __future__ import annotations
+
+import json
+
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Iterable
+
+import toml
+import yaml
+
+from claude_builder.analysis.cargo import load_cargo_workspace
+from claude_builder.analysis.gomod import load_go_workspace
+from claude_builder.core.models import MonorepoInfo
+
+
+# A layout needs at least this many packages to count as a monorepo
+MIN_MONOREPO_PACKAGES = 2
+
+BAZEL_ROOT_FILES = ("WORKSPACE", "WORKSPACE.bazel", "MODULE.bazel")
+BAZEL_BUILD_FILES = ("BUILD", "BUILD.bazel")
+BAZEL_MAX_DEPTH = 4
+NX_MAX_DEPTH = 4
+
+# Directories never searched for packages
+SKIPPED_DIRECTORIES = {
+    "node_modules",
+    ".git",
+    "target",
+    "dist",
+    "build",
+    ".venv",
+    "venv",
+    "__pycache__",
+}
+
+
+@dataclass
+class MonorepoPackage:
+    """A package directory and the workspace tools that list it."""
+
+    directory: Path
+    name: str
+    tools: list[str] = field(default_factory=list)
+
+
+@dataclass
+class MonorepoLayout:
+    """Workspace tools found at a root and the packages they declare."""
+
+    root: Path
+    tools: list[str] = field(default_factory=list)
+    packages: list[MonorepoPackage] = field(default_factory=list)
+
+    @property
+    def is_monorepo(self) -> bool:
+        return len(self.packages) >= MIN_MONOREPO_PACKAGES
+
+    def relative_path(self, package: MonorepoPackage) -> str:
+        try:
+            return package.directory.relative_to(self.root).as_posix()
+        except ValueError:
+            return str(package.directory)
+
+    def add(self, tool: str, directories: Iterable[Path]) -> None:
+        """Record ``tool`` and the package directories it declares."""
+        if tool not in self.tools:
+            self.tools.append(tool)
+        known = {package.directory: package for package in self.packages}
+        for directory in directories:
+            directory = directory.resolve()
+            if directory == self.root or not directory.is_dir():
+                continue
+            try:
+                directory.relative_to(self.root)
+            except ValueError:
+                continue  # Outside the repository (e.g. ``../shared``)
+            package = known.get(directory)
+            if package is None:
+                package = MonorepoPackage(directory, package_name(directory))
+                known[directory] = package
+                self.packages.append(package)
+            if tool not in package.tools:
+                package.tools.append(tool)
+
+
+def _read_json(path: Path) -> dict[str, Any]:
+    try:
+        data = json.loads(path.read_text(encoding="utf-8"))
+    except (OSError, UnicodeDecodeError, ValueError):
+        return {}
+    return data if isinstance(data, dict) else {}
+
+
+def _read_toml(path: Path) -> dict[str, Any]:
+    try:
+        return toml.loads(path.read_text(encoding="utf-8"))
+    except (OSError, UnicodeDecodeError, toml.TomlDecodeError, TypeError):
+        return {}
+
+
+def _read_yaml(path: Path) -> dict[str, Any]:
+    try:
+        data = yaml.safe_load(path.read_text(encoding="utf-8"))
+    except (OSError, UnicodeDecodeError, yaml.YAMLError):
+        return {}
+    return data if isinstance(data, dict) else {}
+
+
+def package_name(directory: Path) -> str:
+    """Name from the package's own manifest, falling back to the directory."""
+    package_json = _read_json(directory / "package.json")
+    if isinstance(package_json.get("name"), str):
+        return package_json["name"]
+
+    cargo = _read_toml(directory / "Cargo.toml").get("package", {})
+    if isinstance(cargo, dict) and isinstance(cargo.get("name"), str):
+        return cargo["name"]
+
+    pyproject = _read_toml(directory / "pyproject.toml")
+    for table in (pyproject.get("project"), pyproject.get("tool", {}).get("poetry")):
+        if isinstance(table, dict) and isinstance(table.get("name"), str):
+            return table["name"]
+
+    go_workspace = load_go_workspace(directory)
+    if go_workspace is not None and go_workspace.modules:
+        module = go_workspace.modules[0]
+        if module.directory.resolve() == directory.resolve() and module.module:
+            return module.module
+    return directory.name
+
+
+def expand_workspace_globs(
+    root: Path, patterns: Iterable[Any], marker: str | None = None
+) -> list[Path]:
+    """Directories matched by workspace globs; ``!pattern`` entries exclude.
+
+    With ``marker`` set, only directories containing that file are kept.
+    """
+    included: list[Path] = []
+    excluded: set[Path] = set()
+    for pattern in patterns:
+        if not isinstance(pattern, str) or not pattern.strip():
+            continue
+        pattern = pattern.strip().rstrip("/")
+        target = excluded if pattern.startswith("!") else None
+        pattern = pattern.lstrip("!")
+        if pattern.startswith("./"):
+            pattern = pattern[2:]
+        if any(ch in pattern for ch in "*?["):
+            matches = sorted(p for p in root.glob(pattern) if p.is_dir())
+        else:
+            matches = [root / pattern]
+        for match in matches:
+            try:
+                relative_parts = match.relative_to(root).parts
+            except ValueError:
+                continue  # ``../`` patterns point outside the repository
+            if any(part in SKIPPED_DIRECTORIES for part in relative_parts):
+                continue
+            resolved = match.resolve()
+            if target is not None:
+                target.add(resolved)
+            elif resolved not in included:
+                included.append(resolved)
+    return [
+        directory
+        for directory in included
+        if directory not in excluded
+        and directory.is_dir()
+        and (marker is None or (directory / marker).is_file())
+    ]
+
+
+def _walk_directories(root: Path, max_depth: int) -> Iterable[tuple[Path, int]]:
+    """Breadth-first directories below ``root`` (skipping build/vendor dirs)."""
+    pending = [(root, 0)]
+    while pending:
+        directory, depth = pending.pop(0)
+        if depth >= max_depth:
+            continue
+        try:
+            children = sorted(p for p in directory.iterdir() if p.is_dir())
+        except OSError:
+            continue
+        for child in children:
+            name = child.name
+            if name in SKIPPED_DIRECTORIES or name.startswith((".", "bazel-")):
+                continue
+            yield child, depth + 1
+            pending.append((child, depth + 1))
+
+
+def _javascript_packages(root: Path, layout: MonorepoLayout) -> None:
+    package_json = _read_json(root / "package.json")
+    pnpm = _read_yaml(root / "pnpm-workspace.yaml")
+    if (root / "pnpm-workspace.yaml").is_file():
+        patterns = pnpm.get("packages") or []
+        layout.add("pnpm", expand_workspace_globs(root, patterns, "package.json"))
+
+    workspaces = package_json.get("workspaces")
+    if isinstance(workspaces, dict):
+        workspaces = workspaces.get("packages")
+    if isinstance(workspaces, list) and workspaces:
+        tool = "yarn" if (root / "yarn.lock").is_file() else "npm"
+        layout.add(tool, expand_workspace_globs(root, workspaces, "package.json"))
+
+    declared = [package.directory for package in layout.packages]
+    if (root / "nx.json").is_file():
+        projects = [
+            directory
+            for directory, _depth in _walk_directories(root, NX_MAX_DEPTH)
+            if (directory / "project.json").is_file()
+        ]
+        layout.add("nx", declared + projects)
+    if (root / "turbo.json").is_file():
+        layout.add("turborepo", declared)
+
+
+def _cargo_packages(root: Path, layout: MonorepoLayout) -> None:
+    workspace = load_cargo_workspace(root)
+    if workspace is not None and workspace.is_workspace:
+        layout.add("cargo", [member.directory for member in workspace.members])
+
+
+def _python_packages(root: Path, layout: MonorepoLayout) -> None:
+    pyproject = _read_toml(root / "pyproject.toml")
+    tool = pyproject.get("tool", {})
+
+    uv_workspace = tool.get("uv", {}).get("workspace")
+    if isinstance(uv_workspace, dict):
+        members = expand_workspace_globs(
+            root, uv_workspace.get("members", []), "pyproject.toml"
+        )
+        excluded = set(
+            expand_workspace_globs(root, uv_workspace.get("exclude", []))
+        )
+        layout.add("uv", [d for d in members if d not in excluded])
+
+    poetry = tool.get("poetry", {})
+    if isinstance(poetry, dict) and poetry:
+        tables = [poetry.get("dependencies", {}), poetry.get("dev-dependencies", {})]
+        tables.extend(
+            group.get("dependencies", {})
+            for group in (poetry.get("group") or {}).values()
+            if isinstance(group, dict)
+        )
+        paths = [
+            root / spec["path"]
+            for table in tables
+            if isinstance(table, dict)
+            for spec in table.values()
+            if isinstance(spec, dict) and spec.get("path") and spec.get("develop")
+        ]
+        if paths:
+            layout.add("poetry", paths)
+
+
+def _bazel_packages(root: Path, layout: MonorepoLayout) -> None:
+    if not any((root / name).is_file() for name in BAZEL_ROOT_FILES):
+        return
+    packages: list[Path] = []
+    for directory, _depth in _walk_directories(root, BAZEL_MAX_DEPTH):
+        if any(parent in packages for parent in directory.parents):
+            continue  # Nested Bazel packages belong to the outer one here
+        if any((directory / name).is_file() for name in BAZEL_BUILD_FILES):
+            packages.append(directory)
+    layout.add("bazel", packages)
+
+
+def _go_packages(root: Path, layout: MonorepoLayout) -> None:
+    workspace = load_go_workspace(root)
+    if workspace is not None and workspace.is_workspace:
+        layout.add("go", [module.directory for module in workspace.modules])
+
+
+def detect_monorepo(project_path: Path) -> MonorepoLayout | None:
+    """Workspace layout at ``project_path``, or None unless it is a monorepo."""
+    root = project_path.resolve()
+    layout = MonorepoLayout(root=root)
+    for detect in (
+        _javascript_packages,
+        _cargo_packages,
+        _python_packages,
+        _bazel_packages,
+        _go_packages,
+    ):
+        detect(root, layout)
+
+    layout.packages.sort(key=layout.relative_path)
+    return layout if layout.is_monorepo else None
+
+
+def package_commands(layout: MonorepoLayout, package: MonorepoPackage) -> list[str]:
+    """Commands scoped to one package, run from the repository root."""
+    path = layout.relative_path(package)
+    name = package.name
+    commands: list[str] = []
+    for tool in package.tools:
+        if tool == "pnpm":
+            commands.extend(
+                f"pnpm --filter {name} {script}"
+                for script in _package_scripts(package.directory)
+            )
+        elif tool in ("yarn", "npm"):
+            prefix = (
+                f"yarn workspace {name}"
+                if tool == "yarn"
+                else f"npm run --workspace {name}"
+            )
+            commands.extend(
+                f"{prefix} {script}" for script in _package_scripts(package.directory)
+            )
+        elif tool == "nx":
+            commands.extend(f"npx nx {target} {name}" for target in ("build", "test"))
+        elif tool == "turborepo":
+            commands.append(f"npx turbo run build test --filter={name}")
+        elif tool == "cargo":
+            commands.extend([f"cargo build -p {name}", f"cargo test -p {name}"])
+        elif tool == "uv":
+            commands.append(f"uv run --package {name} pytest {path}")
+        elif tool == "poetry":
+            commands.append(f"cd {path} && poetry run pytest")
+        elif tool == "bazel":
+            commands.extend([f"bazel build //{path}/...", f"bazel test //{path}/..."])
+        elif tool == "go":
+            commands.extend([f"go build ./{path}/...", f"go test ./{path}/..."])
+
+    # Keep the first occurrence of each command
+    return list(dict.fromkeys(commands))
+
+
+def _package_scripts(directory: Path) -> list[str]:
+    """``package.json`` scripts worth listing (build, test, lint, dev)."""
+    scripts = _read_json(directory / "package.json").get("scripts")
+    if not isinstance(scripts, dict):
+        return []
+    preferred = ("build", "test", "lint", "dev")
+    return [script for script in preferred if script in scripts]
+
+
+def summarize_monorepo(info: MonorepoInfo) -> str:
+    """Markdown list of the packages with their language and framework."""
+    if not info.packages:
+        return ""
+
+    tools = ", ".join(info.tools)
+    lines = [
+        f"This monorepo ({tools}) contains {len(info.packages)} packages, each "
+        "with its own CLAUDE.md:",
+        "",
+    ]
+    for package in info.packages:
+        details = []
+        analysis = package.analysis
+        if analysis is not None:
+            details.extend(
+                value for value in (analysis.language, analysis.framework) if value
+            )
+            if analysis.project_type.value != "unknown":
+                details.append(analysis.project_type.value.replace("_", " "))
+        suffix = f": {', '.join(details)}" if details else ""
+        lines.append(f"- **{package.name}** (`{package.path}`){suffix}")
+    return "\n".join(lines)
@@ -0,0 +1,58 @@
+"""Nested ``<package>/CLAUDE.md`` files for the packages of a monorepo.
+
+Both the document generator and the template manager's Claude target emit these
+files, so the rendering lives here rather than in either of them.
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from claude_builder.core.models import MonorepoInfo, ProjectAnalysis
+from claude_builder.core.template_engine import render_template_string
+
+
+PACKAGE_TEMPLATE = (
+    Path(__file__).parent.parent / "templates" / "base" / "package_instructions.md"
+)
+
+
+def _load_package_template() -> str:
+    try:
+        return PACKAGE_TEMPLATE.read_text(encoding="utf-8")
+    except (OSError, UnicodeDecodeError):
+        return ""
+
+
+def generate_package_docs(analysis: ProjectAnalysis) -> dict[str, str]:
+    """``<package>/CLAUDE.md`` for every analyzed monorepo package."""
+    monorepo = getattr(analysis, "monorepo", None)
+    if not isinstance(monorepo, MonorepoInfo):
+        return {}
+
+    template = _load_package_template()
+    files: dict[str, str] = {}
+    for package in monorepo.packages:
+        package_analysis = package.analysis
+        if package_analysis is None:
+            continue
+        variables = {
+            "package_name": package.name,
+            "package_path": package.path,
+            "repository_name": analysis.project_path.name,
+            "language": str(package_analysis.language or "Unknown"),
+            "framework": str(package_analysis.framework or "None"),
+            "project_type": package_analysis.project_type.value.replace(
+                "_", " "
+            ).title(),
+            "workspace_tools": ", ".join(package.tools),
+            "package_commands": "\n".join(package.commands)
+            or "# No package-scoped commands detected",
+        }
+        files[f"{package.path}/CLAUDE.md"] = render_template_string(
+            template, variables
+        )
+        # Nested workspaces (e.g. a Cargo workspace inside a pnpm repo)
+        for name, content in generate_package_docs(package_analysis).items():
+            files[f"{package.path}/{name}"] = content
+    return files
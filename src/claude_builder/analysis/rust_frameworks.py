@@ -0,0 +1,237 @@
+"""Catalogue of Rust frameworks and ecosystem crates.
+
+Each entry maps exact crate names (never substrings - ``warp`` must not match
+``warpgrid``) to a score, mirroring the weighting used by
+``FilePatterns.FRAMEWORK_PATTERNS``. Enabled Cargo features add further
+evidence, so ``tokio`` with ``full`` outweighs a bare ``tokio`` pulled in for a
+single utility.
+
+General-purpose crates (async runtimes, serde, tracing) are flagged as
+``infrastructure``: they are reported as secondary frameworks but never become
+the primary framework, which stays unset when no application framework is
+present.
+"""
+
+from __future__ import annotations
+
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Iterable
+
+from claude_builder.analysis.cargo import CargoDependency
+
+
+# Optional dependencies are gated behind a feature flag, so they count for less
+OPTIONAL_DEPENDENCY_WEIGHT = 0.5
+
+
+@dataclass(frozen=True)
+class RustFrameworkSignature:
+    """Crates and features that identify a Rust framework."""
+
+    category: str
+    crates: dict[str, float]
+    features: dict[str, float] = field(default_factory=dict)  # "crate/feature"
+    web_framework: bool = False
+    infrastructure: bool = False
+
+
+RUST_FRAMEWORKS: dict[str, RustFrameworkSignature] = {
+    # Web servers
+    "axum": RustFrameworkSignature(
+        category="web", crates={"axum": 10.0}, web_framework=True
+    ),
+    "actix": RustFrameworkSignature(
+        category="web",
+        crates={"actix-web": 10.0, "actix-rt": 2.0},
+        web_framework=True,
+    ),
+    "warp": RustFrameworkSignature(
+        category="web", crates={"warp": 10.0}, web_framework=True
+    ),
+    "rocket": RustFrameworkSignature(
+        category="web", crates={"rocket": 10.0}, web_framework=True
+    ),
+    # Async runtimes
+    "tokio": RustFrameworkSignature(
+        category="async_runtime",
+        crates={"tokio": 6.0},
+        features={
+            "tokio/full": 2.0,
+            "tokio/rt-multi-thread": 1.0,
+            "tokio/macros": 1.0,
+        },
+        infrastructure=True,
+    ),
+    "async-std": RustFrameworkSignature(
+        category="async_runtime",
+        crates={"async-std": 6.0},
+        features={"async-std/attributes": 1.0},
+        infrastructure=True,
+    ),
+    # gRPC
+    "tonic": RustFrameworkSignature(
+        category="grpc",
+        crates={
+            "tonic": 9.0,
+            "tonic-build": 2.0,
+            "prost": 2.0,
+            "prost-build": 1.0,
+        },
+    ),
+    # Databases
+    "sqlx": RustFrameworkSignature(
+        category="database",
+        crates={"sqlx": 8.0},
+        features={
+            "sqlx/postgres": 1.0,
+            "sqlx/mysql": 1.0,
+            "sqlx/sqlite": 1.0,
+            "sqlx/macros": 1.0,
+        },
+    ),
+    "diesel": RustFrameworkSignature(
+        category="database",
+        crates={"diesel": 8.0, "diesel_migrations": 2.0, "diesel-async": 1.0},
+    ),
+    "sea-orm": RustFrameworkSignature(
+        category="database",
+        crates={"sea-orm": 8.0, "sea-orm-migration": 2.0},
+    ),
+    # Games, desktop and frontend
+    "bevy": RustFrameworkSignature(category="game", crates={"bevy": 10.0}),
+    "tauri": RustFrameworkSignature(
+        category="desktop", crates={"tauri": 10.0, "tauri-build": 2.0}
+    ),
+    "leptos": RustFrameworkSignature(
+        category="frontend",
+        crates={"leptos": 10.0, "leptos_router": 1.0},
+        features={"leptos/ssr": 1.0, "leptos/csr": 1.0, "leptos/hydrate": 1.0},
+    ),
+    "yew": RustFrameworkSignature(category="frontend", crates={"yew": 10.0}),
+    "dioxus": RustFrameworkSignature(
+        category="frontend",
+        crates={"dioxus": 10.0},
+        features={"dioxus/web": 1.0, "dioxus/desktop": 1.0},
+    ),
+    # Command-line applications
+    "clap": RustFrameworkSignature(
+        category="cli", crates={"clap": 5.0}, features={"clap/derive": 1.0}
+    ),
+    # Infrastructure crates used by every kind of project
+    "serde": RustFrameworkSignature(
+        category="serialization",
+        crates={"serde": 2.0, "serde_json": 1.0},
+        features={"serde/derive": 1.0},
+        infrastructure=True,
+    ),
+    "tracing": RustFrameworkSignature(
+        category="observability",
+        crates={"tracing": 3.0, "tracing-subscriber": 1.0},
+        infrastructure=True,
+    ),
+    # Embedded / no_std
+    "embassy": RustFrameworkSignature(
+        category="embedded",
+        crates={
+            "embassy-executor": 9.0,
+            "embassy-time": 2.0,
+            "embassy-sync": 1.0,
+            "embassy-stm32": 2.0,
+            "embassy-nrf": 2.0,
+            "embassy-rp": 2.0,
+        },
+    ),
+    "embedded": RustFrameworkSignature(
+        category="embedded",
+        crates={
+            "cortex-m": 5.0,
+            "cortex-m-rt": 3.0,
+            "embedded-hal": 4.0,
+            "riscv-rt": 3.0,
+            "defmt": 2.0,
+            "panic-probe": 1.0,
+        },
+    ),
+    # WebAssembly and Python bindings
+    "wasm-bindgen": RustFrameworkSignature(
+        category="wasm",
+        crates={
+            "wasm-bindgen": 7.0,
+            "wasm-bindgen-futures": 1.0,
+            "web-sys": 2.0,
+            "js-sys": 1.0,
+        },
+    ),
+    "pyo3": RustFrameworkSignature(
+        category="python_bindings",
+        crates={"pyo3": 9.0, "numpy": 1.0},
+        features={"pyo3/extension-module": 3.0},
+    ),
+}
+
+# Bonus applied to pyo3 when the package is built with maturin
+MATURIN_BONUS = 3.0
+
+
+def crates_in_category(category: str) -> set[str]:
+    """All catalogue crate names belonging to a category (e.g. ``embedded``)."""
+    return {
+        crate
+        for signature in RUST_FRAMEWORKS.values()
+        if signature.category == category
+        for crate in signature.crates
+    }
+
+
+def uses_maturin(project_path: Path) -> bool:
+    """Whether ``pyproject.toml`` builds the crate with maturin."""
+    pyproject = project_path / "pyproject.toml"
+    if not pyproject.is_file():
+        return False
+    try:
+        return "maturin" in pyproject.read_text(encoding="utf-8")
+    except (OSError, UnicodeDecodeError):
+        return False
+
+
+def rust_framework_matches(
+    dependencies: Iterable[CargoDependency],
+) -> dict[tuple[str, str], float]:
+    """Score of every (framework, crate) pair found in Cargo dependencies.
+
+    Each crate counts once per framework, using the strongest declaration when
+    the same crate appears in several members or dependency tables.
+    """
+    best: dict[tuple[str, str], float] = {}
+    for dep in dependencies:
+        for framework, signature in RUST_FRAMEWORKS.items():
+            base = signature.crates.get(dep.name)
+            if base is None:
+                continue
+            score = base + sum(
+                signature.features.get(f"{dep.name}/{feature}", 0.0)
+                for feature in dep.features
+            )
+            if dep.optional:
+                score *= OPTIONAL_DEPENDENCY_WEIGHT
+            key = (framework, dep.name)
+            best[key] = max(best.get(key, 0.0), score)
+    return best
+
+
+def score_rust_frameworks(
+    dependencies: Iterable[CargoDependency],
+) -> dict[str, float]:
+    """Score catalogue frameworks from Cargo dependencies (exact name matches)."""
+    scores: dict[str, float] = defaultdict(float)
+    for (framework, _crate), score in rust_framework_matches(dependencies).items():
+        scores[framework] += score
+    return dict(scores)
+
+
+def get_rust_framework_category(framework: str) -> str | None:
+    """Category for a catalogue framework, or None when unknown."""
+    signature = RUST_FRAMEWORKS.get(framework)
+    return signature.category if signature else None
@@ -0,0 +1,374 @@
+"""Lightweight Rust source scanner.
+
+Extracts structural facts for generated documentation without rustc or cargo:
+the module tree (following ``mod`` declarations through ``foo.rs`` and
+``foo/mod.rs`` layouts, including ``#[path]`` overrides), public items, async
+runtime entry points such as ``#[tokio::main]``, test locations and ``unsafe``
+block counts.
+
+Comments and string literals are blanked out before matching so that code in
+docs or strings is not reported. Macro-generated items are invisible here; the
+results describe what a reader of the source would see.
+"""
+
+from __future__ import annotations
+
+import re
+
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from claude_builder.analysis.cargo import (
+    CargoManifest,
+    CargoWorkspace,
+    load_cargo_workspace,
+)
+from claude_builder.core.models import RustModuleInfo, RustSourceInfo
+
+
+MAX_SOURCE_FILES = 2000
+MAX_FILE_SIZE = 512 * 1024
+
+IDENT = r"(?:r#)?([A-Za-z_][A-Za-z0-9_]*)"
+MOD_DECLARATION = re.compile(
+    rf"^[ \t]*(pub(?:\([^)]*\))?[ \t]+)?mod[ \t]+{IDENT}[ \t]*([;{{])", re.MULTILINE
+)
+PATH_ATTRIBUTE = re.compile(
+    rf'#\[\s*path\s*=\s*"([^"]+)"\s*\]\s*(?:pub(?:\([^)]*\))?\s+)?mod\s+{IDENT}\s*;'
+)
+PUBLIC_ITEM = re.compile(
+    r"^[ \t]*pub[ \t]+"
+    r'(?:(?:async|const|unsafe|extern(?:[ \t]+"[^"]*")?)[ \t]+)*'
+    rf"(fn|struct|enum|trait|type|const|static|union)[ \t]+{IDENT}",
+    re.MULTILINE,
+)
+EXPORTED_MACRO = re.compile(rf"#\[\s*macro_export\s*\]\s*macro_rules!\s*{IDENT}")
+ASYNC_MAIN = re.compile(
+    r"#\[\s*((?:tokio|async_std|actix_web|actix_rt|embassy_executor)::main)\b"
+)
+TEST_ATTRIBUTE = re.compile(r"#\[\s*(?:[a-z_]+::)?test\s*[\](]")
+CFG_TEST = re.compile(r"#\[\s*cfg\s*\(\s*test\s*\)\s*\]")
+UNSAFE_BLOCK = re.compile(r"\bunsafe\s*\{")
+RAW_STRING_START = re.compile(r'b?r(#*)"')
+CHAR_LITERAL = re.compile(
+    r"'(?:\\(?:x[0-9a-fA-F]{2}|u\{[0-9a-fA-F]+\}|.)|[^\\'\n])'"
+)
+
+
+def strip_comments_and_strings(source: str) -> str:
+    """Blank out comments and string/char literals, keeping line breaks."""
+    out: list[str] = []
+    i, n = 0, len(source)
+    while i < n:
+        char = source[i]
+        if source.startswith("//", i):
+            end = source.find("\n", i)
+            i = n if end == -1 else end
+            continue
+        if source.startswith("/*", i):
+            depth, j = 1, i + 2
+            while j < n and depth:
+                if source.startswith("/*", j):
+                    depth, j = depth + 1, j + 2
+                elif source.startswith("*/", j):
+                    depth, j = depth - 1, j + 2
+                else:
+                    j += 1
+            out.append("\n" * source.count("\n", i, j))
+            i = j
+            continue
+        raw = RAW_STRING_START.match(source, i)
+        if raw and (i == 0 or not (source[i - 1].isalnum() or source[i - 1] == "_")):
+            end = source.find('"' + raw.group(1), raw.end())
+            j = n if end == -1 else end + 1 + len(raw.group(1))
+            out.append('""' + "\n" * source.count("\n", i, j))
+            i = j
+            continue
+        if char == '"':
+            j = i + 1
+            while j < n and source[j] != '"':
+                j += 2 if source[j] == "\\" else 1
+            j = min(j + 1, n)
+            out.append('""' + "\n" * source.count("\n", i, j))
+            i = j
+            continue
+        if char == "'":
+            literal = CHAR_LITERAL.match(source, i)
+            if literal:
+                out.append("' '")
+                i = literal.end()
+                continue
+        out.append(char)
+        i += 1
+    return "".join(out)
+
+
+def _line_number(text: str, offset: int) -> int:
+    return text.count("\n", 0, offset) + 1
+
+
+@dataclass
+class _ScannedFile:
+    """Per-file facts gathered while walking the module tree."""
+
+    code: str
+    declarations: list[tuple[str, bool, bool]] = field(default_factory=list)
+    path_overrides: dict[str, str] = field(default_factory=dict)
+
+
+class RustSourceScanner:
+    """Walk crate roots and their ``mod`` declarations, collecting facts."""
+
+    def __init__(self, project_root: Path) -> None:
+        self.project_root = project_root
+        self.info = RustSourceInfo()
+        self._visited: set[Path] = set()
+
+    def relative(self, path: Path) -> str:
+        try:
+            return path.relative_to(self.project_root).as_posix()
+        except ValueError:
+            return path.as_posix()
+
+    def _read(self, path: Path) -> _ScannedFile | None:
+        try:
+            if path.stat().st_size > MAX_FILE_SIZE:
+                return None
+            source = path.read_text(encoding="utf-8", errors="ignore")
+        except OSError:
+            return None
+
+        scanned = _ScannedFile(code=strip_comments_and_strings(source))
+        for match in PATH_ATTRIBUTE.finditer(source):
+            scanned.path_overrides[match.group(2)] = match.group(1)
+        for match in MOD_DECLARATION.finditer(scanned.code):
+            is_public = (match.group(1) or "").strip() == "pub"
+            scanned.declarations.append(
+                (match.group(2), is_public, match.group(3) == "{")
+            )
+        return scanned
+
+    def _record_file_facts(self, path: Path, code: str) -> list[str]:
+        relative = self.relative(path)
+
+        for match in ASYNC_MAIN.finditer(code):
+            line = _line_number(code, match.start())
+            self.info.async_entrypoints.append(
+                f"{relative}:{line} (#[{match.group(1)}])"
+            )
+
+        self.info.test_count += len(TEST_ATTRIBUTE.findall(code))
+        if CFG_TEST.search(code):
+            self.info.unit_test_files.append(relative)
+
+        unsafe_blocks = len(UNSAFE_BLOCK.findall(code))
+        if unsafe_blocks:
+            self.info.unsafe_blocks[relative] = unsafe_blocks
+
+        items = [f"{kind} {name}" for kind, name in PUBLIC_ITEM.findall(code)]
+        items.extend(f"macro {name}!" for name in EXPORTED_MACRO.findall(code))
+        return items
+
+    def scan_module(
+        self,
+        path: Path,
+        module_path: str,
+        *,
+        layout: str,
+        public: bool,
+        is_mod_root: bool,
+    ) -> None:
+        """Scan one module file and recurse into its declared submodules."""
+        path = path.resolve()
+        if path in self._visited or len(self._visited) >= MAX_SOURCE_FILES:
+            return
+        self._visited.add(path)
+
+        scanned = self._read(path)
+        if scanned is None:
+            return
+
+        self.info.modules.append(
+            RustModuleInfo(
+                path=module_path,
+                file=self.relative(path),
+                layout=layout,
+                public=public,
+                public_items=self._record_file_facts(path, scanned.code),
+            )
+        )
+
+        # Children of lib.rs/main.rs/mod.rs live beside them; foo.rs uses foo/
+        child_dir = path.parent if is_mod_root else path.parent / path.stem
+        for name, is_public, inline in scanned.declarations:
+            child_path = f"{module_path}::{name}"
+            if inline:
+                self.info.modules.append(
+                    RustModuleInfo(
+                        path=child_path, file=None, layout="inline", public=is_public
+                    )
+                )
+                continue
+
+            override = scanned.path_overrides.get(name)
+            if override:
+                candidates = [(path.parent / override, "file", False)]
+            else:
+                candidates = [
+                    (child_dir / f"{name}.rs", "file", False),
+                    (child_dir / name / "mod.rs", "mod.rs", True),
+                ]
+            for candidate, child_layout, child_is_root in candidates:
+                if candidate.is_file():
+                    self.scan_module(
+                        candidate,
+                        child_path,
+                        layout=child_layout,
+                        public=is_public,
+                        is_mod_root=child_is_root,
+                    )
+                    break
+
+    def scan_crate(self, manifest: CargoManifest) -> None:
+        """Scan every target root of a Cargo package."""
+        crate = manifest.name.replace("-", "_")
+        directory = manifest.directory
+
+        roots: list[tuple[Path, str]] = [
+            (root, crate) for root in manifest.crate_roots()
+        ]
+        bin_dir = directory / "src" / "bin"
+        if bin_dir.is_dir():
+            for binary in sorted(bin_dir.glob("*.rs")):
+                roots.append((binary, binary.stem.replace("-", "_")))
+            for binary in sorted(bin_dir.glob("*/main.rs")):
+                roots.append((binary, binary.parent.name.replace("-", "_")))
+
+        for root, name in roots:
+            self.info.crate_roots.append(self.relative(root))
+            self.scan_module(root, name, layout="root", public=True, is_mod_root=True)
+
+        extra_targets = (
+            ("tests", self.info.integration_tests),
+            ("benches", self.info.benches),
+            ("examples", self.info.examples),
+        )
+        for dirname, bucket in extra_targets:
+            target_dir = directory / dirname
+            if not target_dir.is_dir():
+                continue
+            for target in sorted(target_dir.glob("*.rs")):
+                bucket.append(self.relative(target))
+                scanned = self._read(target)
+                if scanned is not None:
+                    self._record_file_facts(target, scanned.code)
+
+
+def scan_rust_sources(
+    project_path: Path, workspace: CargoWorkspace | None = None
+) -> RustSourceInfo | None:
+    """Scan every crate of a Cargo project (or workspace) for source facts."""
+    if workspace is None:
+        workspace = load_cargo_workspace(project_path)
+    if workspace is None:
+        return None
+
+    scanner = RustSourceScanner(workspace.root.directory)
+    for manifest in workspace.members:
+        scanner.scan_crate(manifest)
+    return scanner.info
+
+
+def _tree_lines(
+    tree: dict[str, dict],
+    annotations: dict[str, str],
+    parent: str = "",
+    prefix: str = "",
+) -> list[str]:
+    lines: list[str] = []
+    # Files first, then directories, each alphabetically
+    entries = sorted(tree.items(), key=lambda item: (bool(item[1]), item[0]))
+    for index, (name, children) in enumerate(entries):
+        path = f"{parent}{name}"
+        last = index == len(entries) - 1
+        connector = "└── " if last else "├── "
+        line = f"{prefix}{connector}{name}{'/' if children else ''}"
+        if path in annotations:
+            line += f"  # {annotations[path]}"
+        lines.append(line)
+        if children:
+            child_prefix = prefix + ("    " if last else "│   ")
+            lines.extend(_tree_lines(children, annotations, f"{path}/", child_prefix))
+    return lines
+
+
+def render_module_tree(info: RustSourceInfo, project_name: str) -> str:
+    """Render scanned files as a directory tree annotated with module paths."""
+    annotations = {
+        module.file: module.path for module in info.modules if module.file
+    }
+    files = [*annotations, *info.integration_tests, *info.benches, *info.examples]
+    files.append("Cargo.toml")
+
+    tree: dict[str, dict] = {}
+    for file in dict.fromkeys(files):
+        node = tree
+        for part in file.split("/"):
+            node = node.setdefault(part, {})
+
+    return "\n".join([f"{project_name}/", *_tree_lines(tree, annotations)])
+
+
+def _plural(count: int, noun: str) -> str:
+    return f"{count} {noun}{'' if count == 1 else 's'}"
+
+
+def summarize_rust_source(info: RustSourceInfo, max_modules: int = 12) -> str:
+    """Markdown bullet list of the extracted facts."""
+    file_modules = [m for m in info.modules if m.layout in ("file", "mod.rs")]
+    mod_rs = sum(1 for m in file_modules if m.layout == "mod.rs")
+    inline = sum(1 for m in info.modules if m.layout == "inline")
+    lines = [
+        f"- **Modules**: {_plural(len(file_modules), 'file module')} "
+        f"({mod_rs} `mod.rs`-style, {len(file_modules) - mod_rs} `foo.rs`-style) "
+        f"and {_plural(inline, 'inline module')} under "
+        f"{_plural(len(info.crate_roots), 'crate root')}"
+    ]
+
+    public = [m for m in info.modules if m.public_items]
+    if public:
+        lines.append("- **Public API**:")
+        for module in public[:max_modules]:
+            items = ", ".join(f"`{item}`" for item in module.public_items[:6])
+            more = len(module.public_items) - 6
+            suffix = f" (+{more} more)" if more > 0 else ""
+            lines.append(f"  - `{module.path}`: {items}{suffix}")
+        if len(public) > max_modules:
+            lines.append(f"  - ...and {len(public) - max_modules} more modules")
+
+    if info.async_entrypoints:
+        entries = ", ".join(f"`{entry}`" for entry in info.async_entrypoints)
+        lines.append(f"- **Async entry points**: {entries}")
+
+    tests = f"- **Tests**: {_plural(info.test_count, 'test function')}"
+    if info.unit_test_files:
+        tests += "; unit tests in " + ", ".join(
+            f"`{file}`" for file in info.unit_test_files
+        )
+    if info.integration_tests:
+        tests += "; integration tests in " + ", ".join(
+            f"`{file}`" for file in info.integration_tests
+        )
+    lines.append(tests)
+
+    if info.unsafe_blocks:
+        per_file = ", ".join(
+            f"`{file}` ({count})" for file, count in sorted(info.unsafe_blocks.items())
+        )
+        lines.append(
+            f"- **Unsafe**: {_plural(info.total_unsafe_blocks, '`unsafe` block')} "
+            f"in {per_file}"
+        )
+    else:
+        lines.append("- **Unsafe**: no `unsafe` blocks")
+    return "\n".join(lines)
@@ -0,0 +1,274 @@
+"""Language toolchain and version detection.
+
+Reads the files projects use to pin or constrain their language version so
+generated instructions can tell agents which language features are available:
+
+- Rust: ``rust-toolchain.toml`` / ``rust-toolchain``, ``package.rust-version``
+  (MSRV) and ``package.edition`` from Cargo.toml (workspace-inherited fields
+  included).
+- Python: ``.python-version``, ``project.requires-python`` and the
+  ``python`` constraint under ``[tool.poetry.dependencies]``.
+- JavaScript/TypeScript: ``.nvmrc``, ``.node-version`` and ``engines.node``.
+- Go: the ``go`` and ``toolchain`` directives of ``go.work`` or ``go.mod``.
+- Java/Kotlin: ``.java-version`` / ``.sdkmanrc``, the Java release targeted by
+  Maven or Gradle, and the Kotlin plugin or stdlib version.
+
+Only values that were actually found are returned; callers decide on fallbacks.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+
+from pathlib import Path
+from typing import Any, Iterable
+
+import toml
+
+from claude_builder.analysis.cargo import load_cargo_workspace
+from claude_builder.analysis.gomod import load_go_workspace
+from claude_builder.analysis.jvm import load_jvm_build
+
+
+RUST_TOOLCHAIN_FILES = ("rust-toolchain.toml", "rust-toolchain")
+NODE_VERSION_FILES = (".nvmrc", ".node-version")
+NODE_LANGUAGES = ("javascript", "typescript")
+JVM_LANGUAGES = ("java", "kotlin")
+
+
+def _read_text(path: Path) -> str | None:
+    try:
+        return path.read_text(encoding="utf-8")
+    except (OSError, UnicodeDecodeError):
+        return None
+
+
+def _first_line(content: str) -> str | None:
+    for line in content.splitlines():
+        line = line.strip()
+        if line and not line.startswith("#"):
+            return line
+    return None
+
+
+def _load_toml(path: Path) -> dict[str, Any]:
+    content = _read_text(path)
+    if content is None:
+        return {}
+    try:
+        data = toml.loads(content)
+    except (toml.TomlDecodeError, TypeError, ValueError):
+        return {}
+    return data if isinstance(data, dict) else {}
+
+
+def _version_key(version: str) -> tuple[int, ...]:
+    """Numeric sort key for versions such as ``1.70`` or ``2018``."""
+    return tuple(int(part) for part in re.findall(r"\d+", version))
+
+
+def _rust_toolchain(project_path: Path) -> str | None:
+    for name in RUST_TOOLCHAIN_FILES:
+        path = project_path / name
+        content = _read_text(path) if path.is_file() else None
+        if not content:
+            continue
+        # The legacy ``rust-toolchain`` file may be TOML or a bare channel name
+        try:
+            data = toml.loads(content)
+        except (toml.TomlDecodeError, TypeError, ValueError):
+            data = {}
+        toolchain = data.get("toolchain") if isinstance(data, dict) else None
+        if isinstance(toolchain, dict) and toolchain.get("channel"):
+            return str(toolchain["channel"])
+        if not data:
+            channel = _first_line(content)
+            if channel:
+                return channel
+    return None
+
+
+def detect_rust_versions(project_path: Path) -> dict[str, str]:
+    """Toolchain channel, MSRV and edition for a Cargo project.
+
+    Workspace members may disagree on MSRV or edition; the oldest value is
+    reported because it is the one every crate in the workspace can rely on.
+    """
+    versions: dict[str, str] = {}
+
+    toolchain = _rust_toolchain(project_path)
+    if toolchain:
+        versions["rust_toolchain"] = toolchain
+
+    workspace = load_cargo_workspace(project_path)
+    if workspace is not None:
+        msrvs = [v for m in workspace.members if (v := workspace.rust_version(m))]
+        editions = [v for m in workspace.members if (v := workspace.edition(m))]
+        msrv = workspace.workspace_package.get("rust-version")
+        if isinstance(msrv, str) and msrv:
+            msrvs.append(msrv)
+        if msrvs:
+            versions["rust_msrv"] = min(msrvs, key=_version_key)
+        if editions:
+            versions["rust_edition"] = min(editions, key=_version_key)
+
+    rust = versions.get("rust_toolchain") or versions.get("rust_msrv")
+    if rust:
+        versions["rust"] = rust
+    return versions
+
+
+def detect_python_versions(project_path: Path) -> dict[str, str]:
+    """Pinned interpreter and supported version range for a Python project."""
+    versions: dict[str, str] = {}
+
+    pinned_file = project_path / ".python-version"
+    content = _read_text(pinned_file) if pinned_file.is_file() else None
+    pinned = _first_line(content) if content else None
+    if pinned:
+        versions["python_pinned"] = pinned
+
+    pyproject = _load_toml(project_path / "pyproject.toml")
+    project = pyproject.get("project", {})
+    requires = project.get("requires-python") if isinstance(project, dict) else None
+    if not requires:
+        poetry = pyproject.get("tool", {}).get("poetry", {})
+        poetry_deps = poetry.get("dependencies") if isinstance(poetry, dict) else None
+        requires = poetry_deps.get("python") if isinstance(poetry_deps, dict) else None
+    if isinstance(requires, str) and requires:
+        versions["python_requires"] = requires
+
+    python = versions.get("python_pinned") or versions.get("python_requires")
+    if python:
+        versions["python"] = python
+    return versions
+
+
+def detect_node_versions(project_path: Path) -> dict[str, str]:
+    """Pinned Node.js version and ``engines.node`` range for a JS project."""
+    versions: dict[str, str] = {}
+
+    for name in NODE_VERSION_FILES:
+        path = project_path / name
+        content = _read_text(path) if path.is_file() else None
+        pinned = _first_line(content) if content else None
+        if pinned:
+            versions["node_pinned"] = pinned.lstrip("v")
+            break
+
+    package_json = project_path / "package.json"
+    content = _read_text(package_json) if package_json.is_file() else None
+    if content:
+        try:
+            data = json.loads(content)
+        except json.JSONDecodeError:
+            data = {}
+        engines = data.get("engines", {}) if isinstance(data, dict) else {}
+        engine = engines.get("node") if isinstance(engines, dict) else None
+        if isinstance(engine, str) and engine:
+            versions["node_engine"] = engine
+
+    node = versions.get("node_pinned") or versions.get("node_engine")
+    if node:
+        versions["node"] = node
+    return versions
+
+
+def detect_go_versions(project_path: Path) -> dict[str, str]:
+    """``go`` directive and ``toolchain`` pin from go.work or go.mod.
+
+    A workspace without its own ``go`` directive reports the oldest member's.
+    """
+    versions: dict[str, str] = {}
+    workspace = load_go_workspace(project_path)
+    if workspace is None:
+        return versions
+
+    go_version = workspace.go_version
+    if not go_version:
+        member_versions = [m.go_version for m in workspace.modules if m.go_version]
+        if member_versions:
+            go_version = min(member_versions, key=_version_key)
+    if go_version:
+        versions["go_directive"] = go_version
+    if workspace.toolchain:
+        # ``toolchain go1.22.3`` names a release; keep just the version
+        versions["go_toolchain"] = re.sub(r"^go", "", workspace.toolchain)
+
+    go = versions.get("go_toolchain") or versions.get("go_directive")
+    if go:
+        versions["go"] = go
+    return versions
+
+
+def detect_jvm_versions(project_path: Path) -> dict[str, str]:
+    """Pinned JDK, Java release from the build and the Kotlin version."""
+    versions: dict[str, str] = {}
+
+    pinned = _read_text(project_path / ".java-version")
+    if pinned and pinned.strip():
+        versions["java_pinned"] = pinned.strip().splitlines()[0]
+    else:
+        sdkmanrc = _read_text(project_path / ".sdkmanrc") or ""
+        match = re.search(r"(?m)^\s*java\s*=\s*(\S+)", sdkmanrc)
+        if match:
+            versions["java_pinned"] = match.group(1)
+
+    build = load_jvm_build(project_path)
+    if build is not None:
+        if build.java_release:
+            versions["java_release"] = build.java_release
+        kotlin = next(
+            (
+                plugin.version
+                for plugin in build.all_plugins()
+                if plugin.version and "kotlin" in plugin.id
+            ),
+            None,
+        ) or next(
+            (
+                dep.version
+                for dep in build.all_dependencies()
+                if dep.version and dep.artifact.startswith("kotlin-stdlib")
+            ),
+            None,
+        )
+        if kotlin and "${" not in kotlin:
+            versions["kotlin"] = kotlin
+
+    java = versions.get("java_pinned") or versions.get("java_release")
+    if java:
+        versions["java"] = java
+    return versions
+
+
+def detect_language_versions(
+    project_path: Path, languages: Iterable[str]
+) -> dict[str, str]:
+    """Collect toolchain/version details for the given detected languages.
+
+    Keys are the language name itself (the most specific version available)
+    plus detail keys such as ``rust_msrv``, ``rust_edition``,
+    ``python_requires`` or ``node_engine``.
+    """
+    detected = set(languages)
+    node_languages = detected & set(NODE_LANGUAGES)
+    versions: dict[str, str] = {}
+
+    if "rust" in detected:
+        versions.update(detect_rust_versions(project_path))
+    if "python" in detected:
+        versions.update(detect_python_versions(project_path))
+    if "go" in detected:
+        versions.update(detect_go_versions(project_path))
+    if detected & set(JVM_LANGUAGES):
+        versions.update(detect_jvm_versions(project_path))
+    if node_languages:
+        node_versions = detect_node_versions(project_path)
+        versions.update(node_versions)
+        if "node" in node_versions:
+            for language in node_languages:
+                versions[language] = node_versions["node"]
+
+    return versions
@@ -5,6 +5,7 @@ from __future__ import annotations
 import json
 import sys
 
+from dataclasses import asdict
 from pathlib import Path
 from typing import Any
 
@@ -18,7 +19,17 @@ from rich.console import Console
 from rich.panel import Panel
 from rich.table import Table
 
+from claude_builder.analysis.evidence import evidence_for, ranked_candidates
 from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.config import ConfigManager
+from claude_builder.core.models import (
+    CargoWorkspaceInfo,
+    ComplexityMetrics,
+    GitHistoryInfo,
+    GoWorkspaceInfo,
+    MonorepoInfo,
+    RustSourceInfo,
+)
 
 from .error_handling import handle_exception
 from .next_steps import build_presenter
@@ -27,6 +38,9 @@ from .ux import UXConfig, build_ux_config
 
 FAILED_TO_ANALYZE_PROJECT = "Failed to analyze project"
 PYYAML_NOT_AVAILABLE = "PyYAML not available"
+# --explain: alternatives shown next to each decision, and signals per candidate
+EXPLAINED_CANDIDATES = 3
+EXPLAINED_SIGNALS = 8
 
 console = Console()
 
@@ -45,6 +59,15 @@ def analyze() -> None:
 
         # Interactive analysis (scaffold)
         claude-builder analyze project ./app --interactive
+
+        # Ignore the analysis cache for one run
+        claude-builder analyze project ./app --no-cache
+
+        # Show the signals behind each detection decision
+        claude-builder analyze project ./app --explain
+
+        # Assess complexity from measured code (LOC, branching, coupling)
+        claude-builder analyze project ./app --deep
     """
 
 
@@ -78,11 +101,31 @@ def analyze() -> None:
     help="Enable interactive mode (prompts coming soon). Requires TTY.",
 )
 @click.option("--verbose", "-v", count=True, help="Verbose output")
+@click.option(
+    "--explain",
+    is_flag=True,
+    help="Show the evidence behind the language, framework, domain and type",
+)
+@click.option(
+    "--deep",
+    is_flag=True,
+    help="Measure LOC, complexity, coupling and public API to assess complexity",
+)
 @click.option(
     "--no-suggestions",
     is_flag=True,
     help="Disable post-analysis suggestions",
 )
+@click.option(
+    "--no-cache",
+    is_flag=True,
+    help="Analyze from scratch without reading or writing .claude-builder/cache",
+)
+@click.option(
+    "--rebuild-cache",
+    is_flag=True,
+    help="Discard the analysis cache and rebuild it from a full scan",
+)
 @click.pass_context
 def project(ctx: click.Context, project_path: str, **options: Any) -> None:
     """Analyze a project directory."""
@@ -112,9 +155,25 @@ def project(ctx: click.Context, project_path: str, **options: Any) -> None:
         if verbose > 0:
             console.print(f"[cyan]Analyzing project at: {path}[/cyan]")
 
+        # Project and global config supply ignore patterns, detection rules, ...
+        analysis_config = dict(ConfigManager().load_config(path).analysis.__dict__)
+        analysis_config["cache_enabled"] = analysis_config.get(
+            "cache_enabled", True
+        ) and not options.get("no_cache", False)
+        analysis_config["rebuild_cache"] = options.get("rebuild_cache", False)
+        if options.get("deep", False):
+            analysis_config["deep_analysis"] = True
+
         # Create analyzer and run analysis
-        analyzer = ProjectAnalyzer()
+        analyzer = ProjectAnalyzer(analysis_config)
         analysis = analyzer.analyze(path)
+        if verbose > 0 and analysis.from_cache:
+            console.print("[dim]No files changed; using the cached analysis[/dim]")
+        elif verbose > 0 and analysis.rescanned_files is not None:
+            console.print(
+                "[dim]Updated the cached analysis for "
+                f"{len(analysis.rescanned_files)} changed files[/dim]"
+            )
 
         # Apply confidence threshold
         if analysis.analysis_confidence < confidence_threshold:
@@ -150,6 +209,7 @@ def project(ctx: click.Context, project_path: str, **options: Any) -> None:
                 analysis,
                 include_suggestions=include_suggestions,
                 verbose=verbose,
+                explain=options.get("explain", False),
             )
 
         # Save to file if requested
@@ -179,20 +239,26 @@ def _display_analysis_table(
     *,
     include_suggestions: bool = False,
     verbose: int = 0,
+    explain: bool = False,
 ) -> None:
     """Display analysis in table format."""
     _show_analysis_summary(analysis)
     _show_language_table(analysis)
     _show_framework_table(analysis, show_verbose=verbose > 0)
     _show_characteristics_table(analysis)
+    _show_code_metrics_table(analysis)
+    _show_git_history_table(analysis)
 
     if verbose > 0:
         _show_filesystem_table(analysis)
+        _show_rule_matches_table(analysis)
 
     _show_dev_environment_table(analysis, show_verbose=verbose > 0)
     _show_infrastructure_table(analysis)
     _show_mlops_table(analysis)
     _show_domain_table(analysis)
+    if explain:
+        _show_explanation(analysis)
     _show_warnings_and_suggestions(analysis, include_suggestions=include_suggestions)
 
 
@@ -256,6 +322,17 @@ def _show_framework_table(analysis: Any, *, show_verbose: bool) -> None:
     if analysis.framework_info.version:
         fw_table.add_row("Version", analysis.framework_info.version, "-")
 
+    details = getattr(analysis.framework_info, "details", None)
+    if not isinstance(details, dict):
+        details = {}
+    if details.get("build_tool"):
+        modules = len(details.get("modules") or [])
+        fw_table.add_row(
+            "Build Tool", f"{details['build_tool']} ({modules} modules)", "-"
+        )
+    if details.get("test_frameworks"):
+        fw_table.add_row("Test Frameworks", ", ".join(details["test_frameworks"]), "-")
+
     console.print(fw_table)
 
 
@@ -277,6 +354,89 @@ def _show_characteristics_table(analysis: Any) -> None:
     console.print(char_table)
 
 
+def _show_code_metrics_table(analysis: Any) -> None:
+    """Show the measured code behind a deep complexity assessment."""
+    metrics = getattr(analysis, "complexity_metrics", None)
+    if not isinstance(metrics, ComplexityMetrics):
+        return
+
+    metrics_table = Table(title="Code Metrics")
+    metrics_table.add_column("Language", style="cyan")
+    metrics_table.add_column("Modules", style="green")
+    metrics_table.add_column("LOC", style="green")
+    metrics_table.add_column("Functions", style="green")
+    metrics_table.add_column("Complexity / Function", style="yellow")
+    metrics_table.add_column("Public API", style="green")
+
+    for language in sorted(metrics.languages.values(), key=lambda item: -item.loc):
+        metrics_table.add_row(
+            language.language,
+            str(language.modules),
+            f"{language.loc:,}",
+            str(language.functions),
+            f"{language.complexity_per_function:.1f}",
+            str(language.public_api),
+        )
+    console.print(metrics_table)
+
+    for label, modules, describe in (
+        (
+            "Most complex",
+            metrics.most_complex,
+            lambda module: f"complexity {module.cyclomatic_complexity}",
+        ),
+        (
+            "Most depended on",
+            metrics.most_depended_on,
+            lambda module: f"fan-in {module.fan_in}",
+        ),
+    ):
+        if modules:
+            listed = ", ".join(f"{m.path} ({describe(m)})" for m in modules)
+            console.print(f"[cyan]{label}:[/cyan] {listed}")
+    if metrics.factors:
+        console.print(f"[cyan]Complexity factors:[/cyan] {', '.join(metrics.factors)}")
+
+
+def _show_git_history_table(analysis: Any) -> None:
+    """Show conventions and risk areas mined from the git history."""
+    history = getattr(analysis, "git_history", None)
+    if not isinstance(history, GitHistoryInfo) or not history.commits_analyzed:
+        return
+
+    history_table = Table(title="Git History")
+    history_table.add_column("Aspect", style="cyan")
+    history_table.add_column("Value", style="green")
+
+    history_table.add_row(
+        "Commits analyzed",
+        f"{history.commits_analyzed} ({history.first_commit_date} to "
+        f"{history.last_commit_date})",
+    )
+    history_table.add_row("Contributors", str(history.contributors))
+    convention = history.commit_convention
+    if convention is not None:
+        share = f" ({convention.share:.0%})" if convention.name != "free_form" else ""
+        history_table.add_row("Commit convention", f"{convention.name}{share}")
+    history_table.add_row("Branching strategy", history.branching_strategy)
+    if history.releases:
+        cadence = f"{len(history.releases.tags)} tags"
+        if history.releases.median_days is not None:
+            cadence += f", every {history.releases.median_days:g} days (median)"
+        history_table.add_row("Releases", cadence)
+    if history.hotspots:
+        history_table.add_row(
+            "Hotspots",
+            ", ".join(f"{e.path} ({e.commits})" for e in history.hotspots[:5]),
+        )
+    if history.co_changes:
+        history_table.add_row(
+            "Change together",
+            ", ".join(" + ".join(pair.files) for pair in history.co_changes[:3]),
+        )
+    console.print(history_table)
+
+
 def _show_filesystem_table(analysis: Any) -> None:
     """Show file system analysis table."""
     fs_table = Table(title="File System Analysis")
@@ -294,6 +454,31 @@ def _show_filesystem_table(analysis: Any) -> None:
     console.print(fs_table)
 
 
+def _show_rule_matches_table(analysis: Any) -> None:
+    """Show which custom detection rules matched and where they were defined."""
+    matches = getattr(analysis, "rule_matches", None) or []
+    if not matches:
+        return
+
+    table = Table(title="Custom Detection Rules")
+    table.add_column("Rule", style="cyan")
+    table.add_column("Detected", style="green")
+    table.add_column("Effect", style="yellow")
+    table.add_column("Source", style="magenta")
+    table.add_column("Evidence")
+
+    for match in matches:
+        table.add_row(
+            match.rule,
+            f"{match.target}: {match.value} ({match.confidence:.0f}%)",
+            match.effect,
+            match.source,
+            "; ".join(match.evidence),
+        )
+
+    console.print(table)
+
+
 def _show_dev_environment_table(analysis: Any, *, show_verbose: bool) -> None:
     """Show development environment table if needed."""
     if not (analysis.dev_environment.package_managers or show_verbose):
@@ -432,6 +617,57 @@ def _show_domain_table(analysis: Any) -> None:
     console.print(domain_table)
 
 
+def _show_explanation(analysis: Any) -> None:
+    """Show the signals behind each detection decision and its alternatives."""
+    decisions = [
+        ("Language", analysis.language_info.primary, analysis.language_info.evidence),
+        (
+            "Framework",
+            analysis.framework_info.primary,
+            analysis.framework_info.evidence,
+        ),
+        ("Domain", analysis.domain_info.domain, analysis.domain_info.evidence),
+        (
+            "Project Type",
+            analysis.project_type.value,
+            getattr(analysis, "project_type_evidence", []),
+        ),
+    ]
+
+    for label, chosen, evidence in decisions:
+        table = Table(title=f"Why {label}: {chosen or 'none'}")
+        table.add_column("Candidate", style="cyan")
+        table.add_column("Signal", style="green")
+        table.add_column("Weight", style="yellow", justify="right")
+
+        ranked = ranked_candidates(evidence)
+        # The chosen value first, even when an override outranked the scores
+        ranked.sort(key=lambda item: item[0] != chosen)
+        for candidate, total in ranked[:EXPLAINED_CANDIDATES]:
+            signals = evidence_for(evidence, candidate)
+            name = candidate if candidate == chosen else f"{candidate} (not chosen)"
+            if total:
+                name += f", total {_format_weight(total)}"
+            for index, item in enumerate(signals[:EXPLAINED_SIGNALS]):
+                table.add_row(
+                    name if index == 0 else "",
+                    item.signal,
+                    f"+{_format_weight(item.weight)}" if item.weight else "-",
+                )
+            if len(signals) > EXPLAINED_SIGNALS:
+                more = len(signals) - EXPLAINED_SIGNALS
+                table.add_row("", f"... {more} more signals", "")
+        if not ranked:
+            table.add_row("-", "No signals recorded", "-")
+
+        console.print(table)
+
+
+def _format_weight(weight: float) -> str:
+    """One decimal place, or two significant digits for weights below 0.1."""
+    return f"{round(weight, 1):g}" if weight >= 0.1 else f"{weight:.2g}"
+
+
 def _show_warnings_and_suggestions(analysis: Any, *, include_suggestions: bool) -> None:
     """Show warnings and suggestions if present."""
     if analysis.warnings:
@@ -479,6 +715,7 @@ def _analysis_to_dict(
             "confidence": analysis.language_info.confidence,
             "file_counts": analysis.language_info.file_counts,
             "total_lines": analysis.language_info.total_lines,
+            "evidence": _evidence_to_list(analysis.language_info),
         },
         "framework_info": {
             "primary": analysis.framework_info.primary,
@@ -486,14 +723,20 @@ def _analysis_to_dict(
             "confidence": analysis.framework_info.confidence,
             "version": analysis.framework_info.version,
             "config_files": analysis.framework_info.config_files,
+            "details": analysis.framework_info.details,
+            "evidence": _evidence_to_list(analysis.framework_info),
         },
         "domain_info": {
             "domain": analysis.domain_info.domain,
             "confidence": analysis.domain_info.confidence,
             "indicators": analysis.domain_info.indicators,
             "specialized_patterns": analysis.domain_info.specialized_patterns,
+            "evidence": _evidence_to_list(analysis.domain_info),
         },
         "project_type": analysis.project_type.value,
+        "project_type_evidence": [
+            asdict(item) for item in getattr(analysis, "project_type_evidence", [])
+        ],
         "complexity_level": analysis.complexity_level.value,
         "architecture_pattern": analysis.architecture_pattern.value,
         "development_environment": {
@@ -533,12 +776,68 @@ def _analysis_to_dict(
         "warnings": analysis.warnings,
     }
 
+    cargo_workspace = getattr(analysis, "cargo_workspace", None)
+    if isinstance(cargo_workspace, CargoWorkspaceInfo):
+        data["cargo_workspace"] = asdict(cargo_workspace)
+
+    rust_source = getattr(analysis, "rust_source", None)
+    if isinstance(rust_source, RustSourceInfo):
+        data["rust_source"] = asdict(rust_source)
+
+    go_workspace = getattr(analysis, "go_workspace", None)
+    if isinstance(go_workspace, GoWorkspaceInfo):
+        data["go_workspace"] = asdict(go_workspace)
+
+    complexity_metrics = getattr(analysis, "complexity_metrics", None)
+    if isinstance(complexity_metrics, ComplexityMetrics):
+        data["complexity_metrics"] = asdict(complexity_metrics)
+
+    git_history = getattr(analysis, "git_history", None)
+    if isinstance(git_history, GitHistoryInfo):
+        data["git_history"] = asdict(git_history)
+
+    monorepo = getattr(analysis, "monorepo", None)
+    if isinstance(monorepo, MonorepoInfo):
+        data["monorepo"] = {
+            "tools": monorepo.tools,
+            "packages": [
+                {
+                    "name": package.name,
+                    "path": package.path,
+                    "tools": package.tools,
+                    "commands": package.commands,
+                    "analysis": (
+                        _analysis_to_dict(package.analysis)
+                        if package.analysis is not None
+                        else None
+                    ),
+                }
+                for package in monorepo.packages
+            ],
+        }
+
+    rule_matches = getattr(analysis, "rule_matches", None)
+    if isinstance(rule_matches, list) and rule_matches:
+        data["rule_matches"] = [asdict(match) for match in rule_matches]
+
+    dependency_graphs = getattr(analysis, "dependency_graphs", None)
+    if isinstance(dependency_graphs, dict) and dependency_graphs:
+        data["dependency_graphs"] = {
+            manager: [dep.dict() for dep in graph]
+            for manager, graph in dependency_graphs.items()
+        }
+
     if include_suggestions:
         data["suggestions"] = analysis.suggestions
 
     return data
 
 
+def _evidence_to_list(info: Any) -> list[dict[str, Any]]:
+    """Evidence of a language, framework or domain result as plain dicts."""
+    return [asdict(item) for item in getattr(info, "evidence", None) or []]
+
+
 def _save_analysis_to_file(
     analysis: Any, output_path: Path, *, include_suggestions: bool = False
 ) -> None:
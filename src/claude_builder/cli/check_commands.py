@@ -0,0 +1,149 @@
+"""Drift check CLI command for Claude Builder."""
+
+from __future__ import annotations
+
+import json
+
+from pathlib import Path
+from typing import Any
+
+import click
+
+from rich.console import Console
+
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.config import ConfigManager
+from claude_builder.core.drift import (
+    CURRENT,
+    MISSING,
+    ORPHANED,
+    DriftReport,
+    detect_drift,
+)
+from claude_builder.core.models import OutputTarget
+from claude_builder.core.output_renderers import default_agents_dir
+from claude_builder.core.template_manager import TemplateManager
+
+from .error_handling import handle_exception
+from .ux import build_ux_config
+
+
+console = Console()
+
+
+class CheckExitCodes:
+    """Exit codes for ``claude-builder check``."""
+
+    CURRENT = 0
+    DRIFT = 1
+
+
+@click.command()
+@click.argument(
+    "project_path",
+    type=click.Path(exists=True, file_okay=False, dir_okay=True),
+    default=".",
+)
+@click.option(
+    "--output-dir",
+    type=click.Path(file_okay=False),
+    help="Directory the files were generated into (default: PROJECT_PATH)",
+)
+@click.option(
+    "--agents-dir",
+    type=click.Path(),
+    help="Directory for generated specialist files (default depends on --target)",
+)
+@click.option(
+    "--target",
+    type=click.Choice([target.value for target in OutputTarget], case_sensitive=False),
+    default=OutputTarget.CLAUDE.value,
+    show_default=True,
+    help="Output target profile the files were generated for",
+)
+@click.option("--json", "output_json", is_flag=True, help="Output the report as JSON")
+@click.option("--verbose", "-v", count=True, help="Verbose output")
+def check(project_path: str, output_json: bool, **kwargs: Any) -> None:
+    """Check whether generated instruction files are still up to date.
+
+    Re-runs the analysis and generation in memory and compares the result with
+    the files on disk, section by section. Exits with status 1 when any file is
+    missing, a section would change on regeneration, or an agent file from an
+    earlier generation is no longer produced, so it can run in CI. Sections
+    you edited by hand are not reported.
+
+    \b
+    Examples:
+        claude-builder check
+        claude-builder check ./my-project --target codex --json
+    """
+    path = Path(project_path).resolve()
+    output_dir = Path(kwargs["output_dir"]).resolve() if kwargs["output_dir"] else path
+    target = OutputTarget(kwargs["target"].lower())
+
+    try:
+        if kwargs["verbose"] > 0 and not output_json:
+            console.print(f"[cyan]Analyzing project:[/cyan] {path}")
+        config = ConfigManager().load_config(project_path=path)
+        analysis = ProjectAnalyzer(
+            config={**config.analysis.__dict__, "cache_read_only": True}
+        ).analyze(path)
+        agents_dir = kwargs["agents_dir"] or default_agents_dir(target)
+        rendered_output = TemplateManager().generate_target_artifacts(
+            analysis,
+            target=target,
+            agents_dir=agents_dir,
+        )
+        report = detect_drift(
+            output_dir, rendered_output.artifacts, agents_dir=agents_dir
+        )
+    except Exception as e:
+        ux_config = build_ux_config(
+            quiet=output_json,
+            verbose=kwargs["verbose"],
+            no_suggestions=True,
+            plain_output=not console.is_terminal,
+        )
+        exit_code = handle_exception(e, config=ux_config, console=console)
+        raise click.exceptions.Exit(exit_code) from e
+
+    if output_json:
+        # Use click.echo to avoid Rich markup processing of JSON content
+        click.echo(json.dumps(report.to_dict(), indent=2))
+    else:
+        _print_report(report, verbose=kwargs["verbose"])
+
+    if report.has_drift:
+        raise click.exceptions.Exit(CheckExitCodes.DRIFT)
+
+
+def _print_report(report: DriftReport, *, verbose: int = 0) -> None:
+    """Print a readable summary of stale files and sections."""
+    if verbose > 0:
+        for checked in report.files:
+            if checked.status == CURRENT:
+                console.print(f"[green]✓ {checked.path}[/green]")
+
+    if not report.has_drift:
+        console.print(
+            f"[green]✓ All {len(report.files)} generated files are up to date[/green]"
+        )
+        return
+
+    for stale in report.stale_files:
+        if stale.status == MISSING:
+            console.print(f"[red]✗ {stale.path}: missing[/red]")
+            continue
+        if stale.status == ORPHANED:
+            console.print(
+                f"[yellow]✗ {stale.path}: no longer generated, delete it[/yellow]"
+            )
+            continue
+        console.print(f"[yellow]✗ {stale.path}: stale[/yellow]")
+        for section in stale.sections:
+            console.print(f"    {section.section} ({section.change})")
+
+    console.print(
+        f"\n[red]{len(report.stale_files)} of {len(report.files)} generated files "
+        "are out of date.[/red] Run claude-builder to regenerate them."
+    )
@@ -0,0 +1,164 @@
+"""Unified diff previews of generated files against what is on disk."""
+
+from __future__ import annotations
+
+import difflib
+
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Iterable
+
+from rich.console import Console
+from rich.prompt import Prompt
+from rich.text import Text
+
+from claude_builder.core.managed_regions import ManagedWrite, prepare_managed_file
+
+
+ADDED = "added"
+CHANGED = "changed"
+UNCHANGED = "unchanged"
+
+# Answers to the per-file review prompt, as in ``git add -p``
+ACCEPT = "y"
+REJECT = "n"
+ACCEPT_ALL = "a"
+QUIT = "q"
+
+
+@dataclass
+class FileChange:
+    """What writing one generated file would do to the file on disk."""
+
+    path: str
+    status: str
+    diff: list[str]
+
+
+def plan_changes(
+    output_dir: Path, files: Iterable[tuple[str, str]]
+) -> list[FileChange]:
+    """Diff each ``(path, content)`` pair against ``output_dir``.
+
+    The new side is what would actually be written, so managed region markers
+    and merged hand edits show up in the diff.
+    """
+    changes = []
+    for path, content in files:
+        merge = prepare_managed_file(output_dir, path, content)
+        target = output_dir / path
+        existing = target.read_text(encoding="utf-8") if target.exists() else None
+        if existing is None:
+            status = ADDED
+        elif existing == merge.content:
+            status = UNCHANGED
+        else:
+            status = CHANGED
+        diff = list(
+            difflib.unified_diff(
+                (existing or "").splitlines(keepends=True),
+                merge.content.splitlines(keepends=True),
+                fromfile="/dev/null" if existing is None else f"a/{path}",
+                tofile=f"b/{path}",
+            )
+        )
+        changes.append(FileChange(path, status, diff))
+    return changes
+
+
+def summarize_changes(changes: list[FileChange]) -> str:
+    """``"1 added, 2 changed, 3 unchanged"``."""
+    return ", ".join(
+        f"{sum(c.status == status for c in changes)} {status}"
+        for status in (ADDED, CHANGED, UNCHANGED)
+    )
+
+
+def _style(line: str) -> str:
+    if line.startswith(("+++", "---")):
+        return "bold"
+    if line.startswith("@@"):
+        return "cyan"
+    if line.startswith("+"):
+        return "green"
+    if line.startswith("-"):
+        return "red"
+    return ""
+
+
+def print_diff(change: FileChange, console: Console) -> None:
+    """Print one file's diff, colored like ``git diff``."""
+    text = Text()
+    for line in change.diff:
+        if not line.endswith("\n"):
+            line += "\n\\ No newline at end of file\n"
+        text.append(line, style=_style(line))
+    # A Text object keeps markup-like content such as ``[project]`` literal
+    console.print(text, end="")
+
+
+def display_diff_preview(changes: list[FileChange], console: Console) -> None:
+    """Print the diff of every added or changed file and the counts."""
+    for change in changes:
+        if change.status != UNCHANGED:
+            print_diff(change, console)
+    console.print(f"[bold]Files:[/bold] {summarize_changes(changes)}")
+
+
+def review_changes(changes: list[FileChange], console: Console) -> list[FileChange]:
+    """Ask file by file which added or changed files to write.
+
+    ``y`` writes the file, ``n`` skips it, ``a`` writes it and every
+    remaining file, ``q`` skips it and every remaining file. Unchanged files
+    are always kept so their baselines stay current.
+    """
+    accepted = []
+    answer = ""
+    for change in changes:
+        if change.status == UNCHANGED or answer == ACCEPT_ALL:
+            accepted.append(change)
+            continue
+        if answer == QUIT:
+            continue
+        print_diff(change, console)
+        answer = Prompt.ask(
+            f"Write {change.path} ({change.status})?",
+            choices=[ACCEPT, REJECT, ACCEPT_ALL, QUIT],
+            default=ACCEPT,
+            console=console,
+        )
+        if answer in (ACCEPT, ACCEPT_ALL):
+            accepted.append(change)
+    skipped = len(changes) - len(accepted)
+    console.print(f"[bold]Writing[/bold] {len(accepted)} files, skipped {skipped}")
+    return accepted
+
+
+def preview_files(
+    output_dir: Path,
+    files: Iterable[tuple[str, str]],
+    console: Console,
+    *,
+    interactive: bool = False,
+) -> set[str]:
+    """Show what writing ``files`` would change and return the paths to write.
+
+    Without ``interactive`` every diff is printed and every path returned.
+    """
+    changes = plan_changes(output_dir, files)
+    if interactive:
+        return {change.path for change in review_changes(changes, console)}
+    display_diff_preview(changes, console)
+    return {change.path for change in changes}
+
+
+def report_write(written: ManagedWrite, console: Console) -> None:
+    """Tell the user which hand edits a write kept and how to undo it.
+
+    Raises :class:`GenerationError` afterwards when conflicts were written.
+    """
+    for note in written.notes:
+        console.print(f"[yellow]{note}[/yellow]")
+    if written.undo_command:
+        console.print(f"[dim]Undo with: {written.undo_command}[/dim]")
+    written.raise_for_conflicts()
@@ -6,7 +6,7 @@ import json
 
 from dataclasses import dataclass
 from pathlib import Path
-from typing import Any
+from typing import Any, Iterable
 
 
 try:
@@ -21,9 +21,12 @@ from rich.panel import Panel
 
 from claude_builder.core.analyzer import ProjectAnalyzer
 from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.managed_regions import write_managed_files
 from claude_builder.core.models import OutputTarget, ProjectAnalysis
+from claude_builder.core.output_renderers import default_agents_dir
 from claude_builder.utils.exceptions import ClaudeBuilderError
 
+from .diff_preview import preview_files, report_write
 from .error_handling import handle_exception
 from .next_steps import build_presenter
 from .ux import UXConfig, build_ux_config
@@ -42,15 +45,6 @@ console = Console()
 VALID_DOMAINS = ["infra", "devops", "mlops"]
 
 
-def _default_agents_dir_for_target(target: OutputTarget) -> str:
-    """Return default specialist artifact directory for a target."""
-    if target == OutputTarget.CLAUDE:
-        return ".claude/agents"
-    if target == OutputTarget.CODEX:
-        return ".agents/skills"
-    return ".gemini/agents"
-
-
 def _filter_generated_content_by_sections(
     generated_content: Any, sections_filter: list[str]
 ) -> dict[str, str]:
@@ -114,27 +108,24 @@ def _get_project_analysis(config: GenerateConfig, path: Path) -> Any:
     return analysis
 
 
-def _write_agent_files(agent_files: dict[str, str], output_path: Path) -> None:
-    """Write agent files to disk."""
+def _write_agent_files(
+    agent_files: dict[str, str], output_path: Path, project_path: Path
+) -> None:
+    """Write agent files to disk in one transaction.
+
+    ``AGENTS.md`` goes to ``output_path``; other agent files are placed
+    relative to its directory.
+    """
+    files = [
+        (output_path.name if name == "AGENTS.md" else name, content)
+        for name, content in agent_files.items()
+    ]
+    _write_managed_in(output_path.parent, files, project_path)
     if len(agent_files) == 1 and "AGENTS.md" in agent_files:
-        # Write single file
-        with output_path.open("w", encoding="utf-8") as f:
-            f.write(agent_files["AGENTS.md"])
         console.print(f"[green]✓ Agent configuration written to: {output_path}[/green]")
     else:
-        # Write multiple files
-        for filename, content in agent_files.items():
-            file_path = (
-                output_path.parent / filename
-                if output_path.is_file()
-                else output_path / filename
-            )
-            file_path.parent.mkdir(parents=True, exist_ok=True)
-            with file_path.open("w", encoding="utf-8") as f:
-                f.write(content)
         console.print(
-            f"[green]✓ Agent files written to: "
-            f"{output_path.parent if output_path.is_file() else output_path}[/green]"
+            f"[green]✓ Agent files written to: {output_path.parent}[/green]"
         )
 
 
@@ -151,6 +142,8 @@ class GenerateConfig:
     dry_run: bool = False
     verbose: int = 0
     no_suggestions: bool = False
+    diff: bool = False
+    interactive: bool = False
     # Domain-specific generation filter
     domains: tuple[str, ...] = ()
 
@@ -194,6 +187,16 @@ def generate() -> None:
     is_flag=True,
     help="Show what would be generated without creating files",
 )
+@click.option(
+    "--diff",
+    is_flag=True,
+    help="Show a unified diff of each file against the existing one",
+)
+@click.option(
+    "--interactive",
+    is_flag=True,
+    help="Review each changed file's diff and accept or reject it",
+)
 @click.option(
     "--domain",
     "domains",
@@ -270,7 +273,18 @@ def docs(ctx: click.Context, project_path: str, **kwargs: Any) -> None:
                 generated_content.files["CLAUDE.md"] = env.claude_md
 
         # Display what would be generated
-        if config.dry_run or config.verbose > 0:
+        output_path = Path(config.output_dir) if config.output_dir else path
+        if config.diff or config.interactive:
+            selected = preview_files(
+                output_path,
+                generated_content.files.items(),
+                console,
+                interactive=config.interactive and not config.dry_run,
+            )
+            generated_content.files = {
+                k: v for k, v in generated_content.files.items() if k in selected
+            }
+        elif config.dry_run or config.verbose > 0:
             _display_generation_preview(generated_content)
 
         if config.dry_run:
@@ -278,7 +292,6 @@ def docs(ctx: click.Context, project_path: str, **kwargs: Any) -> None:
             return
 
         # Write files
-        output_path = Path(config.output_dir) if config.output_dir else path
         written_files = _write_generated_files(
             generated_content,
             output_path,
@@ -320,6 +333,16 @@ def docs(ctx: click.Context, project_path: str, **kwargs: Any) -> None:
     is_flag=True,
     help="Show what would be generated without creating files",
 )
+@click.option(
+    "--diff",
+    is_flag=True,
+    help="Show a unified diff of each file against the existing one",
+)
+@click.option(
+    "--interactive",
+    is_flag=True,
+    help="Review each changed file's diff and accept or reject it",
+)
 @click.option(
     "--domain",
     "domains",
@@ -376,7 +399,7 @@ def complete(ctx: click.Context, project_path: str, **kwargs: Any) -> None:
         if config.domains:
             opts["domains"] = config.domains
         target = OutputTarget(config.target.lower())
-        agents_dir = config.agents_dir or _default_agents_dir_for_target(target)
+        agents_dir = config.agents_dir or default_agents_dir(target)
         rendered_output = template_manager.generate_target_artifacts(
             analysis,
             target=target,
@@ -385,14 +408,24 @@ def complete(ctx: click.Context, project_path: str, **kwargs: Any) -> None:
         )
 
         # Display what would be generated
-        if config.dry_run or config.verbose > 0:
+        output_dir = Path(config.output_dir) if config.output_dir else path
+        if config.diff or config.interactive:
+            selected = preview_files(
+                output_dir,
+                ((a.path, a.content) for a in rendered_output.artifacts),
+                console,
+                interactive=config.interactive and not config.dry_run,
+            )
+            rendered_output.artifacts = [
+                a for a in rendered_output.artifacts if a.path in selected
+            ]
+        elif config.dry_run or config.verbose > 0:
             _display_target_preview(rendered_output)
 
         if config.dry_run:
             console.print("[yellow]Dry run complete - no files were created[/yellow]")
             return
 
-        output_dir = Path(config.output_dir) if config.output_dir else path
         written_files = _write_target_artifacts(
             rendered_output,
             output_dir,
@@ -496,10 +529,12 @@ def subagents(project_path: str, **kwargs: Any) -> None:
         )
         output_dir.mkdir(parents=True, exist_ok=True)
 
-        for subagent in environment.subagent_files:
-            agent_path = output_dir / subagent.name
-            with agent_path.open("w", encoding="utf-8") as f:
-                f.write(subagent.content)
+        # One transaction: a failing agent leaves none of the others written
+        _write_managed_in(
+            output_dir,
+            [(sf.name, sf.content) for sf in environment.subagent_files],
+            path,
+        )
 
         console.print(
             f"[green]✓ {len(environment.subagent_files)} subagent files generated successfully[/green]"
@@ -587,7 +622,7 @@ def agents(project_path: str, **kwargs: Any) -> None:
         output_path = (
             Path(config.output_file) if config.output_file else path / "AGENTS.md"
         )
-        _write_agent_files(agent_files, output_path)
+        _write_agent_files(agent_files, output_path, path)
 
     except Exception as e:
         error_msg = f"{FAILED_TO_GENERATE_AGENTS}: {e}"
@@ -781,38 +816,65 @@ def _display_generation_preview(generated_content: Any) -> None:
     console.print(Panel(preview_text, title="Generation Preview"))
 
 
+def _write_managed(
+    file_path: Path, content: str, project_path: Path | None = None
+) -> None:
+    """Write one generated file, merging it with hand edits already there.
+
+    Baselines are kept under ``project_path`` when the file lies inside it.
+    """
+    _write_managed_in(file_path.parent, [(file_path.name, content)], project_path)
+
+
+def _write_managed_in(
+    directory: Path,
+    files: Iterable[tuple[str, str]],
+    project_path: Path | None = None,
+) -> None:
+    """Write generated files below ``directory`` together, keeping hand edits.
+
+    Baselines are kept under ``project_path`` when ``directory`` lies inside it.
+    """
+    root = directory.resolve()
+    prefix = Path()
+    if project_path is not None:
+        try:
+            prefix = root.relative_to(project_path.resolve())
+            root = project_path.resolve()
+        except ValueError:
+            pass
+    written = write_managed_files(
+        root, [((prefix / name).as_posix(), content) for name, content in files]
+    )
+    report_write(written, console)
+
+
 def _write_generated_files(
     generated_content: Any, output_path: Path, *, backup_existing: bool, verbose: int
 ) -> list[str]:
     """Write generated files to disk and return list of written filenames."""
     written_filenames = []
 
-    for filename, content in generated_content.files.items():
-        file_path = output_path / filename
-
-        # Create directories if needed
-        file_path.parent.mkdir(parents=True, exist_ok=True)
-
-        # Backup existing file if requested
-        if backup_existing and file_path.exists():
-            backup_path = file_path.with_suffix(file_path.suffix + ".bak")
-            file_path.rename(backup_path)
-            if verbose > 0:
-                console.print(
-                    f"[yellow]Backed up existing file: {backup_path}[/yellow]"
-                )
+    written = write_managed_files(
+        output_path,
+        generated_content.files.items(),
+        backup_existing=backup_existing,
+    )
 
-        # Write file
-        with file_path.open("w", encoding="utf-8") as f:
-            f.write(content)
+    for merge in written.merges:
+        if merge.backup_path and verbose > 0:
+            console.print(
+                f"[yellow]Backed up existing file: {merge.backup_path}[/yellow]"
+            )
 
-        written_filenames.append(filename)
+        written_filenames.append(merge.path)
 
         if verbose > 1:
-            console.print(f"[green]✓ {filename}[/green]")
+            console.print(f"[green]✓ {merge.path}[/green]")
 
     if verbose > 0:
         console.print(f"[green]Wrote {len(written_filenames)} files[/green]")
+    report_write(written, console)
 
     return written_filenames
 
@@ -827,27 +889,25 @@ def _write_target_artifacts(
     """Write target artifacts to disk and return list of written paths."""
     written_files: list[str] = []
 
-    for artifact in rendered_output.artifacts:
-        file_path = output_dir / artifact.path
-        file_path.parent.mkdir(parents=True, exist_ok=True)
-
-        if backup_existing and file_path.exists():
-            backup_path = file_path.with_suffix(file_path.suffix + ".bak")
-            file_path.rename(backup_path)
-            if verbose > 0:
-                console.print(
-                    f"[yellow]Backed up existing file: {backup_path}[/yellow]"
-                )
+    written = write_managed_files(
+        output_dir,
+        ((artifact.path, artifact.content) for artifact in rendered_output.artifacts),
+        backup_existing=backup_existing,
+    )
 
-        with file_path.open("w", encoding="utf-8") as f:
-            f.write(artifact.content)
+    for merge in written.merges:
+        if merge.backup_path and verbose > 0:
+            console.print(
+                f"[yellow]Backed up existing file: {merge.backup_path}[/yellow]"
+            )
 
-        written_files.append(artifact.path)
+        written_files.append(merge.path)
         if verbose > 1:
-            console.print(f"[green]✓ {artifact.path}[/green]")
+            console.print(f"[green]✓ {merge.path}[/green]")
 
     if verbose > 0:
         console.print(f"[green]Wrote {len(written_files)} files[/green]")
+    report_write(written, console)
 
     return written_files
 
@@ -947,8 +1007,7 @@ def claude_md(project_path: str, **kwargs: Any) -> None:
             Path(config.output_file) if config.output_file else path / "CLAUDE.md"
         )
 
-        with output_path.open("w", encoding="utf-8") as f:
-            f.write(claude_content)
+        _write_managed(output_path, claude_content, path)
 
         console.print("[green]✓ CLAUDE.md generated successfully[/green]")
         console.print(f"Output location: {output_path}")
@@ -1045,8 +1104,7 @@ def agents_md(project_path: str, **kwargs: Any) -> None:
             Path(config.output_file) if config.output_file else path / "AGENTS.md"
         )
 
-        with output_path.open("w", encoding="utf-8") as f:
-            f.write(agents_content)
+        _write_managed(output_path, agents_content, path)
 
         console.print("[green]✓ AGENTS.md generated successfully[/green]")
         console.print(f"Output location: {output_path}")
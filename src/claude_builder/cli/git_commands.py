@@ -7,6 +7,7 @@ from pathlib import Path
 import click
 
 from rich.console import Console
+from rich.markup import escape
 from rich.panel import Panel
 from rich.prompt import Confirm
 from rich.table import Table
@@ -17,6 +18,7 @@ from claude_builder.utils.git import (
     GitBackupManager,
     GitHookManager,
     GitIntegrationManager,
+    infer_commit_convention,
 )
 
 
@@ -307,7 +309,18 @@ def unexclude(project_path: str) -> None:
     help="Claude mention policy for hooks",
 )
 @click.option("--pre-commit", is_flag=True, help="Also install pre-commit hook")
-def install_hooks(project_path: str, policy: str | None, *, pre_commit: bool) -> None:
+@click.option(
+    "--enforce-convention",
+    is_flag=True,
+    help="Reject commit messages that break the convention found in history",
+)
+def install_hooks(
+    project_path: str,
+    policy: str | None,
+    *,
+    pre_commit: bool,
+    enforce_convention: bool,
+) -> None:
     """Install git hooks for Claude mention control."""
     try:
         project_path_obj = Path(project_path).resolve()
@@ -332,10 +345,26 @@ def install_hooks(project_path: str, policy: str | None, *, pre_commit: bool) ->
             f"[cyan]Installing git hooks with policy: {claude_policy.value}[/cyan]"
         )
 
+        convention = None
+        if enforce_convention or config.git_integration.enforce_commit_convention:
+            convention = infer_commit_convention(project_path_obj)
+            if convention.pattern:
+                console.print(
+                    f"[cyan]Enforcing commit convention: {convention.name}[/cyan]"
+                )
+            else:
+                console.print(
+                    "[yellow]No commit convention found in history; "
+                    "commit messages will not be validated[/yellow]"
+                )
+                convention = None
+
         hook_manager = GitHookManager()
 
         # Install commit-msg hook
-        result = hook_manager.install_commit_msg_hook(project_path_obj, claude_policy)
+        result = hook_manager.install_commit_msg_hook(
+            project_path_obj, claude_policy, convention
+        )
 
         if result.success:
             console.print("[green]✓ commit-msg hook installed[/green]")
@@ -362,10 +391,19 @@ def install_hooks(project_path: str, policy: str | None, *, pre_commit: bool) ->
                     console.print(f"  • {error}")
 
         # Show what the hooks do
+        behaviors = []
+        if claude_policy.value != "allowed":
+            behaviors.append("filters Claude mentions from commit messages")
+        if convention is not None:
+            behaviors.append(
+                f"rejects subjects not matching `{escape(convention.pattern)}`"
+            )
+        commit_msg_behavior = " and ".join(behaviors) or "leaves messages unchanged"
+        commit_msg_behavior = commit_msg_behavior[0].upper() + commit_msg_behavior[1:]
         console.print(
             Panel(
                 f"**Git hooks installed with policy: {claude_policy.value}**\n\n"
-                f"**commit-msg hook:** Filters Claude mentions from commit messages\n"
+                f"**commit-msg hook:** {commit_msg_behavior}\n"
                 f"**pre-commit hook:** {'Installed' if pre_commit else 'Not installed'}"
                 f" - "
                 f"Checks staged files for Claude mentions\n\n"
@@ -466,10 +504,14 @@ def list_backups(project_path: str, output_format: str) -> None:
             table.add_column("Files", style="dim")
 
             for backup in backups:
+                # Generation journal entries also list the files they created
+                file_count = len(backup.get("backed_up_files", [])) + len(
+                    backup.get("created_files", [])
+                )
                 table.add_row(
                     backup["backup_id"],
                     backup["timestamp"][:19].replace("T", " "),
-                    f"{len(backup.get('backed_up_files', []))} files",
+                    f"{file_count} files",
                 )
 
             console.print(table)
@@ -490,7 +532,12 @@ def list_backups(project_path: str, output_format: str) -> None:
 )
 @click.option("--force", is_flag=True, help="Force rollback without confirmation")
 def rollback(backup_id: str, project_path: str, *, force: bool) -> None:
-    """Rollback git configuration to a previous backup."""
+    """Rollback git configuration or a generation to a previous backup.
+
+    Every successful generation in a git repository is journaled as a backup;
+    rolling one back restores the files it replaced and removes the files it
+    created.
+    """
     try:
         project_path_obj = Path(project_path).resolve()
 
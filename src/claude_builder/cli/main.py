@@ -16,7 +16,9 @@ from rich.progress import Progress, SpinnerColumn, TextColumn
 from claude_builder.core.analyzer import ProjectAnalyzer
 from claude_builder.core.config import ConfigManager
 from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.managed_regions import ManagedWrite, write_managed_files
 from claude_builder.core.models import OutputTarget
+from claude_builder.core.output_renderers import default_agents_dir
 from claude_builder.core.template_manager import TemplateManager
 from claude_builder.utils.exceptions import ClaudeBuilderError
 from claude_builder.utils.git import GitIntegrationManager
@@ -25,7 +27,9 @@ from claude_builder.utils.validation import validate_project_path
 # Import subcommands
 from .agent_commands import agents
 from .analyze_commands import analyze
+from .check_commands import check
 from .config_commands import config
+from .diff_preview import preview_files, report_write
 from .generate_commands import generate
 from .git_commands import git
 from .health_commands import health
@@ -55,7 +59,28 @@ class ExitCodes:
     INTERRUPTED = 128
 
 
-@click.group(invoke_without_command=True)
+class ProjectGroup(click.Group):
+    """Group whose optional PROJECT_PATH argument yields to subcommand names.
+
+    Without this, ``claude-builder check`` would parse ``check`` as the
+    project path and fail because no such directory exists.
+    """
+
+    def parse_args(self, ctx: click.Context, args: list[str]) -> list[str]:
+        positional = next((arg for arg in args if not arg.startswith("-")), None)
+        if positional not in self.commands:
+            return super().parse_args(ctx, args)
+        params = self.params
+        self.params = [p for p in params if p.name != "project_path"]
+        try:
+            remaining = super().parse_args(ctx, args)
+        finally:
+            self.params = params
+        ctx.params["project_path"] = None
+        return remaining
+
+
+@click.group(cls=ProjectGroup, invoke_without_command=True)
 @click.argument(
     "project_path",
     type=click.Path(exists=True, file_okay=False, dir_okay=True),
@@ -66,6 +91,16 @@ class ExitCodes:
     is_flag=True,
     help="Show what would be generated without creating files",
 )
+@click.option(
+    "--diff",
+    is_flag=True,
+    help="Show a unified diff of each file against the existing one",
+)
+@click.option(
+    "--interactive",
+    is_flag=True,
+    help="Review each changed file's diff and accept or reject it",
+)
 @click.option(
     "--verbose", "-v", count=True, help="Verbose output (can be repeated: -vv, -vvv)"
 )
@@ -110,6 +145,9 @@ class ExitCodes:
     help="Only configure agents, skip documentation generation",
 )
 @click.option("--no-agents", is_flag=True, help="Skip agent configuration")
+@click.option(
+    "--no-cache", is_flag=True, help="Analyze without the .claude-builder cache"
+)
 @click.option(
     "--custom-agents",
     type=click.Path(exists=True, file_okay=False),
@@ -147,6 +185,10 @@ def cli(ctx: click.Context, project_path: str | None, **kwargs: Any) -> None:
         # Dry run to preview changes
         claude-builder ./project --dry-run --verbose
 
+        # Show a diff against the existing files, then accept or reject each
+        claude-builder --dry-run --diff ./project
+        claude-builder --interactive ./project
+
         # Generate Codex-oriented artifacts from top-level workflow
         claude-builder --target codex ./my-project
     """
@@ -224,7 +266,9 @@ def _execute_main(project_path: str, **kwargs: Any) -> None:
     ) as progress:
         # Step 1: Project Analysis
         task1 = progress.add_task("Analyzing project structure...", total=None)
-        analyzer = ProjectAnalyzer(config=config.analysis.__dict__)
+        analyzer = ProjectAnalyzer(
+            config={**config.analysis.__dict__, "cache_read_only": kwargs["dry_run"]}
+        )
         analysis = analyzer.analyze(project_path_obj)
         progress.update(
             task1, completed=True, description="✓ Project analysis complete"
@@ -243,6 +287,7 @@ def _execute_main(project_path: str, **kwargs: Any) -> None:
             rendered_output = template_manager.generate_target_artifacts(
                 analysis,
                 target=target,
+                agents_dir=default_agents_dir(target),
             )
 
             progress.update(
@@ -251,6 +296,12 @@ def _execute_main(project_path: str, **kwargs: Any) -> None:
                 description=f"✓ {target.value.title()} artifacts generated",
             )
 
+            if kwargs["diff"] or kwargs["interactive"]:
+                # Prompts and diffs would be redrawn under a live spinner
+                progress.stop()
+                _review_target_artifacts(rendered_output, project_path_obj, kwargs)
+                progress.start()
+
             # Write files if not dry run
             if not kwargs["dry_run"]:
                 _write_target_artifacts(rendered_output, project_path_obj, kwargs)
@@ -263,6 +314,7 @@ def _execute_main(project_path: str, **kwargs: Any) -> None:
             rendered_output = template_manager.generate_target_artifacts(
                 analysis,
                 target=target,
+                agents_dir=default_agents_dir(target),
             )
 
             progress.update(task2, completed=True, description="✓ Agents configured")
@@ -287,13 +339,12 @@ def _execute_main(project_path: str, **kwargs: Any) -> None:
                     output_dir = Path(kwargs["output_dir"])
                 output_dir.mkdir(parents=True, exist_ok=True)
 
-                agents_path = output_dir / "AGENTS.md"
-                if kwargs["backup_existing"] and agents_path.exists():
-                    backup_path = agents_path.with_suffix(agents_path.suffix + ".bak")
-                    agents_path.rename(backup_path)
-
-                with agents_path.open("w", encoding="utf-8") as f:
-                    f.write(agents_artifact.content)
+                written = write_managed_files(
+                    output_dir,
+                    [("AGENTS.md", agents_artifact.content)],
+                    backup_existing=kwargs["backup_existing"],
+                )
+                _report_write(written, kwargs)
 
         elif not kwargs["no_agents"]:
             # Legacy: documentation without agents
@@ -338,6 +389,20 @@ def _get_output_mode(kwargs: dict[str, Any]) -> str:
         return "complete environment (CLAUDE.md + subagents + AGENTS.md)"
     if target == OutputTarget.CODEX:
         return "complete environment (AGENTS.md + .agents/skills/*/SKILL.md)"
+    if target == OutputTarget.CURSOR:
+        return "complete environment (.cursor/rules/*.mdc)"
+    if target == OutputTarget.COPILOT:
+        return (
+            "complete environment (.github/copilot-instructions.md + "
+            ".github/instructions/*.instructions.md)"
+        )
+    if target == OutputTarget.WINDSURF:
+        return "complete environment (.windsurfrules)"
+    if target == OutputTarget.AIDER:
+        return (
+            "complete environment (CONVENTIONS.md + .aider/conventions/*.md + "
+            ".aider.conf.yml)"
+        )
     return "complete environment (GEMINI.md + AGENTS.md + .gemini/agents/*.md)"
 
 
@@ -476,41 +541,19 @@ def _write_environment_files(
     else:
         output_dir = Path(kwargs["output_dir"])
 
-    files_written = 0
-
-    # Write CLAUDE.md
-    claude_path = output_dir / "CLAUDE.md"
-    if kwargs["backup_existing"] and claude_path.exists():
-        backup_path = claude_path.with_suffix(claude_path.suffix + ".bak")
-        claude_path.rename(backup_path)
-
-    with claude_path.open("w", encoding="utf-8") as f:
-        f.write(environment.claude_md)
-    files_written += 1
-
-    # Write individual subagents
-    agents_dir = output_dir / ".claude" / "agents"
-    agents_dir.mkdir(parents=True, exist_ok=True)
-
-    for subagent in environment.subagent_files:
-        agent_path = agents_dir / subagent.name
-        if kwargs["backup_existing"] and agent_path.exists():
-            backup_path = agent_path.with_suffix(agent_path.suffix + ".bak")
-            agent_path.rename(backup_path)
-
-        with agent_path.open("w", encoding="utf-8") as f:
-            f.write(subagent.content)
-        files_written += 1
-
-    # Write AGENTS.md
-    agents_guide_path = output_dir / "AGENTS.md"
-    if kwargs["backup_existing"] and agents_guide_path.exists():
-        backup_path = agents_guide_path.with_suffix(agents_guide_path.suffix + ".bak")
-        agents_guide_path.rename(backup_path)
-
-    with agents_guide_path.open("w", encoding="utf-8") as f:
-        f.write(environment.agents_md)
-    files_written += 1
+    files = {
+        "CLAUDE.md": environment.claude_md,
+        **{
+            f".claude/agents/{subagent.name}": subagent.content
+            for subagent in environment.subagent_files
+        },
+        "AGENTS.md": environment.agents_md,
+    }
+    written = write_managed_files(
+        output_dir, files.items(), backup_existing=kwargs["backup_existing"]
+    )
+    files_written = len(written.merges)
+    _report_write(written, kwargs)
 
     if not kwargs["quiet"]:
         console.print("\n[green]✓ Generated complete environment:[/green]")
@@ -522,28 +565,42 @@ def _write_environment_files(
         console.print(f"   • Total: {files_written} files written to {output_dir}")
 
 
-def _write_target_artifacts(
+def _review_target_artifacts(
     rendered_output: Any, project_path: Path, kwargs: dict[str, Any]
 ) -> None:
-    """Write target-specific artifacts to disk."""
+    """Show artifact diffs and drop the artifacts rejected in review."""
     if kwargs.get("output_dir") is None:
         output_dir = project_path
     else:
         output_dir = Path(kwargs["output_dir"])
 
-    files_written = 0
-    for artifact in rendered_output.artifacts:
-        file_path = output_dir / artifact.path
-        file_path.parent.mkdir(parents=True, exist_ok=True)
+    selected = preview_files(
+        output_dir,
+        ((artifact.path, artifact.content) for artifact in rendered_output.artifacts),
+        console,
+        interactive=kwargs["interactive"] and not kwargs["dry_run"],
+    )
+    rendered_output.artifacts = [
+        artifact for artifact in rendered_output.artifacts if artifact.path in selected
+    ]
 
-        if kwargs["backup_existing"] and file_path.exists():
-            backup_path = file_path.with_suffix(file_path.suffix + ".bak")
-            file_path.rename(backup_path)
 
-        with file_path.open("w", encoding="utf-8") as f:
-            f.write(artifact.content)
+def _write_target_artifacts(
+    rendered_output: Any, project_path: Path, kwargs: dict[str, Any]
+) -> None:
+    """Write target-specific artifacts to disk."""
+    if kwargs.get("output_dir") is None:
+        output_dir = project_path
+    else:
+        output_dir = Path(kwargs["output_dir"])
 
-        files_written += 1
+    written = write_managed_files(
+        output_dir,
+        ((artifact.path, artifact.content) for artifact in rendered_output.artifacts),
+        backup_existing=kwargs["backup_existing"],
+    )
+    files_written = len(written.merges)
+    _report_write(written, kwargs)
 
     if not kwargs["quiet"]:
         target_name = getattr(rendered_output, "target", OutputTarget.CLAUDE)
@@ -568,26 +625,26 @@ def _write_generated_files(
     else:
         output_dir = Path(kwargs["output_dir"])
 
-    files_written = 0
-    for filename, content in generated_content.files.items():
-        file_path = output_dir / filename
-
-        # Create directories if needed
-        file_path.parent.mkdir(parents=True, exist_ok=True)
+    written = write_managed_files(
+        output_dir,
+        generated_content.files.items(),
+        backup_existing=kwargs["backup_existing"],
+    )
+    files_written = len(written.merges)
+    _report_write(written, kwargs)
 
-        # Backup existing file if requested
-        if kwargs["backup_existing"] and file_path.exists():
-            backup_path = file_path.with_suffix(file_path.suffix + ".bak")
-            file_path.rename(backup_path)
+    # Always provide a minimal summary for visibility in tests
+    console.print(f"\n[green]✓ Wrote {files_written} files to {output_dir}[/green]")
 
-        # Write file
-        with file_path.open("w", encoding="utf-8") as f:
-            f.write(content)
 
-        files_written += 1
+def _report_write(written: ManagedWrite, kwargs: dict[str, Any]) -> None:
+    """Report kept hand edits and the undo command unless running quietly.
 
-    # Always provide a minimal summary for visibility in tests
-    console.print(f"\n[green]✓ Wrote {files_written} files to {output_dir}[/green]")
+    Unresolved conflicts fail the command either way.
+    """
+    if not kwargs.get("quiet"):
+        report_write(written, console)
+    written.raise_for_conflicts()
 
 
 def _display_summary(
@@ -631,6 +688,22 @@ def _display_summary(
             primary_file = "AGENTS.md"
             specialist_dir = ".agents/skills/"
             tool_name = "Codex CLI"
+        elif target == OutputTarget.CURSOR:
+            primary_file = ".cursor/rules/project.mdc"
+            specialist_dir = ".cursor/rules/"
+            tool_name = "Cursor"
+        elif target == OutputTarget.COPILOT:
+            primary_file = ".github/copilot-instructions.md"
+            specialist_dir = ".github/instructions/"
+            tool_name = "GitHub Copilot"
+        elif target == OutputTarget.WINDSURF:
+            primary_file = ".windsurfrules"
+            specialist_dir = "the Specialist Rules section of .windsurfrules"
+            tool_name = "Windsurf"
+        elif target == OutputTarget.AIDER:
+            primary_file = "CONVENTIONS.md"
+            specialist_dir = ".aider/conventions/"
+            tool_name = "Aider"
         else:
             primary_file = "GEMINI.md"
             specialist_dir = ".gemini/agents/"
@@ -649,6 +722,7 @@ cli.add_command(generate)
 cli.add_command(config)
 cli.add_command(git)
 cli.add_command(health)
+cli.add_command(check)
 
 
 def main() -> None:
@@ -7,6 +7,7 @@ from enum import Enum
 from pathlib import Path
 from typing import Any, Callable, Dict, List, Optional, Union
 
+from claude_builder.core.language_plugins import get_language_registry
 from claude_builder.core.models import (
     AgentInfo,
     ComplexityLevel,
@@ -236,49 +237,11 @@ class AgentRegistry:
         )
 
     def _load_language_mappings(self) -> None:
-        """Load language-specific agent mappings."""
+        """Load language-specific agent mappings from the language plugins."""
         self.language_mappings = {
-            "python": {
-                "primary": ["python-pro"],
-                "web_frameworks": {
-                    "django": ["backend-developer", "database-architect"],
-                    "flask": ["backend-developer", "api-designer"],
-                    "fastapi": [
-                        "backend-developer",
-                        "api-designer",
-                        "performance-benchmarker",
-                    ],
-                    "data_science": ["data-scientist", "ml-engineer"],
-                },
-                "testing": ["test-writer-fixer"],
-                "deployment": ["devops-automator"],
-            },
-            "rust": {
-                "primary": ["rust-engineer"],
-                "project_types": {
-                    "cli_tool": ["cli-developer", "performance-benchmarker"],
-                    "web_service": ["backend-developer", "api-designer"],
-                    "systems": ["performance-benchmarker", "security-engineer"],
-                    "library": ["documentation-engineer", "test-writer-fixer"],
-                },
-            },
-            "javascript": {
-                "primary": ["javascript-pro"],
-                "frontend": {
-                    "react": ["frontend-developer", "react-specialist", "ui-designer"],
-                    "vue": ["frontend-developer", "vue-expert", "ui-designer"],
-                    "angular": [
-                        "frontend-developer",
-                        "angular-architect",
-                        "typescript-pro",
-                    ],
-                },
-                "backend": {
-                    "node": ["backend-developer", "api-designer"],
-                    "express": ["backend-developer", "api-designer"],
-                    "nestjs": ["backend-developer", "typescript-pro", "api-designer"],
-                },
-            },
+            plugin.name: dict(plugin.agent_mappings)
+            for plugin in get_language_registry()
+            if plugin.agent_mappings
         }
 
     def _load_framework_mappings(self) -> None:
@@ -313,6 +276,12 @@ class AgentRegistry:
             ],
             "actix": ["rust-engineer", "backend-developer", "performance-benchmarker"],
             "warp": ["rust-engineer", "backend-developer", "api-designer"],
+            # JVM frameworks
+            "springboot": ["java-pro", "backend-developer", "api-designer"],
+            "spring": ["java-pro", "backend-developer"],
+            "quarkus": ["java-pro", "backend-developer", "performance-benchmarker"],
+            "micronaut": ["java-pro", "backend-developer", "api-designer"],
+            "ktor": ["kotlin-specialist", "backend-developer", "api-designer"],
         }
 
     def _load_domain_mappings(self) -> None:
@@ -390,6 +359,13 @@ class AgentRegistry:
 class AgentSelector:
     """Selects appropriate agents based on project analysis."""
 
+    PROJECT_TYPE_AGENTS: Dict[ProjectType, List[str]] = {
+        ProjectType.PROC_MACRO: ["test-writer-fixer"],
+        ProjectType.EMBEDDED_FIRMWARE: ["performance-benchmarker"],
+        ProjectType.WASM_MODULE: ["frontend-developer", "performance-benchmarker"],
+        ProjectType.PYTHON_EXTENSION: ["python-pro", "performance-benchmarker"],
+    }
+
     def __init__(
         self, registry: Optional[AgentRegistry] = None, algorithm: str = "intelligent"
     ) -> None:
@@ -492,6 +468,12 @@ class AgentSelector:
                 if agent and agent not in agents:
                     agents.append(agent)
 
+        # Crate kinds with specific constraints (Rust classification)
+        for name in self.PROJECT_TYPE_AGENTS.get(analysis.project_type, []):
+            agent = self.registry.get_agent(name)
+            if agent and agent not in agents:
+                agents.append(agent)
+
         return agents
 
     # --- P2.3 additions: environment-driven selection (DevOps/MLOps) ---
@@ -0,0 +1,405 @@
+"""Persistent cache for project analysis results.
+
+The cache lives in ``.claude-builder/cache/`` inside the analyzed project and
+holds two JSON documents:
+
+* ``files.json`` - one record per analyzed file, keyed by relative path, with
+  its size, mtime, SHA-256, text statistics and the per-file results of the
+  detectors that read file contents (domain terms, code metrics, custom rule
+  patterns). A file whose size and mtime are unchanged is never read again; a
+  touched file is re-hashed and only counts as changed when its content
+  differs.
+* ``analysis.json`` - the last ``ProjectAnalysis`` together with the key it was
+  computed for (analyzer version and configuration), the file fingerprint and
+  the git revision.
+
+An unchanged tree reuses the cached analysis as is. Otherwise the detectors
+run again, but take the results of unchanged files from ``files.json`` and
+only read the changed ones. When nothing but existing source files changed,
+the manifest-level results (frameworks, tooling, Go modules, git history) are
+also kept from the cached analysis; a change to a manifest or to the set of
+files re-runs those detectors too.
+
+JSON is used rather than pickle so that a cache directory checked out from an
+untrusted repository cannot execute code.
+"""
+
+import hashlib
+import json
+
+from dataclasses import dataclass, field, fields, is_dataclass
+from enum import Enum
+from pathlib import Path
+from typing import (
+    Any,
+    Callable,
+    Dict,
+    ForwardRef,
+    Iterable,
+    List,
+    Optional,
+    TypeVar,
+    Union,
+    get_args,
+    get_origin,
+)
+
+from claude_builder.core import models
+from claude_builder.core.models import (
+    DependencyInfo,
+    DevelopmentEnvironment,
+    FrameworkInfo,
+    GitHistoryInfo,
+    GoWorkspaceInfo,
+    ProjectAnalysis,
+)
+
+
+CACHE_DIRECTORY = Path(".claude-builder") / "cache"
+# 2: evidence, 3: code metrics, 4: git history, 5: graphs, 6: per-file facts
+CACHE_FORMAT_VERSION = 6
+FILE_INDEX_NAME = "files.json"
+ANALYSIS_NAME = "analysis.json"
+
+# Attached after analysis (agent selection, cache provenance); not part of the
+# analysis itself
+UNCACHED_FIELDS = {"agent_configuration", "from_cache", "rescanned_files"}
+
+T = TypeVar("T")
+
+
+@dataclass
+class FileRecord:
+    """Cached facts about a single file."""
+
+    size: int
+    mtime_ns: int
+    sha256: str
+    lines: int = 0  # Text statistics, as counted by the language detector
+    chars: int = 0
+    # Per-detector results for this content, see ``FileIndex.fact``
+    facts: Dict[str, Any] = field(default_factory=dict)
+
+
+@dataclass
+class FileIndex:
+    """File records of one analysis run plus what changed since the last run."""
+
+    files: Dict[str, FileRecord] = field(default_factory=dict)
+    directories: List[str] = field(default_factory=list)
+    changed: List[str] = field(default_factory=list)  # Added or modified
+    added: List[str] = field(default_factory=list)
+    removed: List[str] = field(default_factory=list)
+    directories_changed: bool = False
+    root: Optional[Path] = None  # Paths are relative to it
+
+    @property
+    def is_unchanged(self) -> bool:
+        return not self.changed and not self.removed
+
+    @property
+    def only_edited(self) -> bool:
+        """Whether existing files changed but none was added or removed."""
+        return not self.added and not self.removed and not self.directories_changed
+
+    def record(self, path: Path) -> Optional[FileRecord]:
+        """Record of an absolute ``path``, if the file is indexed."""
+        if self.root is None:
+            return None
+        try:
+            return self.files.get(path.relative_to(self.root).as_posix())
+        except ValueError:
+            return None
+
+    def fact(self, path: Path, kind: str, compute: Callable[[Path], T]) -> T:
+        """The ``kind`` result of ``compute(path)``, computed once per content.
+
+        ``compute`` must return JSON-compatible values (lists, not tuples).
+        """
+        record = self.record(path)
+        if record is None:
+            return compute(path)
+        if kind not in record.facts:
+            record.facts[kind] = compute(path)
+        return record.facts[kind]
+
+    def fingerprint(self) -> str:
+        """Hash of every path and content hash (independent of mtimes)."""
+        digest = hashlib.sha256()
+        for path in sorted(self.files):
+            digest.update(f"{path}\0{self.files[path].sha256}\n".encode())
+        for directory in sorted(self.directories):
+            digest.update(f"{directory}/\n".encode())
+        return digest.hexdigest()
+
+
+def file_fact(
+    index: Optional[FileIndex], path: Path, kind: str, compute: Callable[[Path], T]
+) -> T:
+    """``index.fact(...)``, or ``compute(path)`` when no cache is in use."""
+    if index is None:
+        return compute(path)
+    return index.fact(path, kind, compute)
+
+
+@dataclass
+class ManifestResults:
+    """Results of the detectors that read manifests and git but no sources.
+
+    Stored as the stages returned them, before custom rules and overrides
+    changed the analysis, so that a later run can reuse them as stage results.
+    """
+
+    languages: List[str]  # Primary and secondary, which framework detection saw
+    framework_info: FrameworkInfo
+    tooling: DevelopmentEnvironment
+    go_workspace: Optional[GoWorkspaceInfo] = None
+    go_graph: List[DependencyInfo] = field(default_factory=list)
+    git_history: Optional[GitHistoryInfo] = None
+
+
+@dataclass
+class CachedAnalysis:
+    """An analysis read back from the cache and what it was computed from."""
+
+    analysis: ProjectAnalysis
+    fingerprint: str
+    revision: str
+    manifest_results: Optional[ManifestResults] = None
+
+
+class AnalysisCache:
+    """Reads and writes the on-disk analysis cache of one project."""
+
+    def __init__(self, project_path: Path, cache_dir: Optional[Path] = None):
+        self.project_path = project_path
+        self.cache_dir = cache_dir or project_path / CACHE_DIRECTORY
+
+    def clear(self) -> None:
+        """Delete the cached file index and analysis."""
+        for name in (FILE_INDEX_NAME, ANALYSIS_NAME):
+            try:
+                (self.cache_dir / name).unlink()
+            except FileNotFoundError:
+                pass
+
+    def load_index(self) -> FileIndex:
+        data = self._read_json(FILE_INDEX_NAME)
+        if data is None:
+            return FileIndex(root=self.project_path)
+        try:
+            return FileIndex(
+                files={
+                    path: FileRecord(**record)
+                    for path, record in data.get("files", {}).items()
+                },
+                directories=list(data.get("directories", [])),
+                root=self.project_path,
+            )
+        except (AttributeError, TypeError):
+            return FileIndex(root=self.project_path)
+
+    def refresh_index(
+        self, files: Iterable[Path], directories: Iterable[Path] = ()
+    ) -> FileIndex:
+        """Build the file index, reading only files that are new or modified."""
+        previous_index = self.load_index()
+        previous = previous_index.files
+        index = FileIndex(
+            directories=sorted(self._relative(d) for d in directories),
+            root=self.project_path,
+        )
+
+        for path in files:
+            relative = self._relative(path)
+            try:
+                stat = path.stat()
+            except OSError:
+                continue
+
+            cached = previous.get(relative)
+            if (
+                cached is not None
+                and cached.size == stat.st_size
+                and cached.mtime_ns == stat.st_mtime_ns
+            ):
+                index.files[relative] = cached
+                continue
+
+            try:
+                record = self._scan_file(path, stat.st_size, stat.st_mtime_ns)
+            except OSError:
+                continue
+            # A touched file with identical content is not a change
+            if cached is None:
+                index.added.append(relative)
+                index.changed.append(relative)
+            elif cached.sha256 != record.sha256:
+                index.changed.append(relative)
+            else:
+                record.facts = cached.facts
+            index.files[relative] = record
+
+        index.removed = sorted(set(previous) - set(index.files))
+        index.directories_changed = index.directories != previous_index.directories
+        return index
+
+    def load_analysis(self, key: str) -> Optional[CachedAnalysis]:
+        """Return the cached analysis when it was computed for ``key``.
+
+        The caller compares its fingerprint and revision with the current
+        tree to tell an unchanged project from one to update.
+        """
+        data = self._read_json(ANALYSIS_NAME)
+        if not isinstance(data, dict) or data.get("key") != key:
+            return None
+        try:
+            analysis = _decode(ProjectAnalysis, data["analysis"])
+            fingerprint = str(data["fingerprint"])
+            revision = str(data.get("revision", ""))
+            manifest_results = _decode(
+                Optional[ManifestResults], data.get("manifest_results")
+            )
+        except (KeyError, TypeError, ValueError, AttributeError):
+            return None
+        if not isinstance(analysis, ProjectAnalysis):
+            return None
+        # The project may have been moved or analyzed through another path
+        analysis.project_path = self.project_path
+        return CachedAnalysis(analysis, fingerprint, revision, manifest_results)
+
+    def save(
+        self,
+        index: FileIndex,
+        key: str,
+        analysis: ProjectAnalysis,
+        revision: str = "",
+        manifest_results: Optional[ManifestResults] = None,
+    ) -> bool:
+        """Persist the file index and analysis; returns False if not writable."""
+        files = {
+            path: {
+                "size": record.size,
+                "mtime_ns": record.mtime_ns,
+                "sha256": record.sha256,
+                "lines": record.lines,
+                "chars": record.chars,
+                "facts": record.facts,
+            }
+            for path, record in sorted(index.files.items())
+        }
+        try:
+            analysis_json = json.dumps(
+                {
+                    "version": CACHE_FORMAT_VERSION,
+                    "key": key,
+                    "fingerprint": index.fingerprint(),
+                    "revision": revision,
+                    "analysis": _encode(analysis),
+                    "manifest_results": _encode(manifest_results),
+                }
+            )
+            self.cache_dir.mkdir(parents=True, exist_ok=True)
+            # Keep the cache out of version control, like .pytest_cache
+            gitignore = self.cache_dir / ".gitignore"
+            if not gitignore.exists():
+                gitignore.write_text("*\n", encoding="utf-8")
+            (self.cache_dir / FILE_INDEX_NAME).write_text(
+                json.dumps(
+                    {
+                        "version": CACHE_FORMAT_VERSION,
+                        "files": files,
+                        "directories": index.directories,
+                    }
+                ),
+                encoding="utf-8",
+            )
+            (self.cache_dir / ANALYSIS_NAME).write_text(analysis_json, encoding="utf-8")
+        except (OSError, TypeError, ValueError):
+            return False
+        return True
+
+    def _relative(self, path: Path) -> str:
+        return path.relative_to(self.project_path).as_posix()
+
+    def _scan_file(self, path: Path, size: int, mtime_ns: int) -> FileRecord:
+        data = path.read_bytes()
+        # Same text the language detector sees through universal newlines
+        content = (
+            data.decode("utf-8", errors="ignore")
+            .replace("\r\n", "\n")
+            .replace("\r", "\n")
+        )
+        return FileRecord(
+            size=size,
+            mtime_ns=mtime_ns,
+            sha256=hashlib.sha256(data).hexdigest(),
+            lines=len(content.splitlines()),
+            chars=len(content),
+        )
+
+    def _read_json(self, name: str) -> Optional[Dict[str, Any]]:
+        try:
+            data = json.loads((self.cache_dir / name).read_text(encoding="utf-8"))
+        except (OSError, ValueError):
+            return None
+        if not isinstance(data, dict) or data.get("version") != CACHE_FORMAT_VERSION:
+            return None
+        return data
+
+
+def _encode(value: Any) -> Any:
+    """Convert analysis dataclasses into JSON-compatible values."""
+    if is_dataclass(value) and not isinstance(value, type):
+        return {
+            f.name: _encode(getattr(value, f.name))
+            for f in fields(value)
+            if f.name not in UNCACHED_FIELDS
+        }
+    if isinstance(value, Enum):
+        return value.value
+    if isinstance(value, Path):
+        return str(value)
+    if isinstance(value, dict):
+        return {str(key): _encode(item) for key, item in value.items()}
+    if isinstance(value, (list, tuple, set)):
+        return [_encode(item) for item in value]
+    return value
+
+
+def _resolve(hint: Any) -> Any:
+    if isinstance(hint, ForwardRef):
+        return getattr(models, hint.__forward_arg__, Any)
+    if isinstance(hint, str):
+        return getattr(models, hint, Any)
+    return hint
+
+
+def _decode(hint: Any, value: Any) -> Any:
+    """Rebuild a value of type ``hint`` from its ``_encode`` form."""
+    hint = _resolve(hint)
+    if value is None or hint is Any:
+        return value
+
+    origin = get_origin(hint)
+    if origin is Union:
+        options = [arg for arg in get_args(hint) if arg is not type(None)]
+        return _decode(options[0], value) if len(options) == 1 else value
+    if origin in (list, List):
+        (item_hint,) = get_args(hint) or (Any,)
+        return [_decode(item_hint, item) for item in value]
+    if origin in (dict, Dict):
+        _, item_hint = get_args(hint) or (Any, Any)
+        return {key: _decode(item_hint, item) for key, item in value.items()}
+    if isinstance(hint, type) and issubclass(hint, Enum):
+        return hint(value)
+    if hint is Path:
+        return Path(value)
+    if is_dataclass(hint):
+        kwargs = {
+            f.name: _decode(f.type, value[f.name])
+            for f in fields(hint)
+            if f.name in value and f.name not in UNCACHED_FIELDS
+        }
+        return hint(**kwargs)
+    return value
@@ -1,26 +1,116 @@
 """Project analysis engine for Claude Builder."""
 
+import copy
 import json
+import math
 
 from collections import defaultdict
 from datetime import datetime, timezone
+from fnmatch import fnmatch
+from itertools import zip_longest
 from pathlib import Path
-from typing import Any, Dict, List, Optional, Union
-
-import toml
+from typing import Any, Dict, Iterable, List, Optional, Set, Tuple, Union
 
+from claude_builder.analysis.cargo import (
+    CargoDependency,
+    CargoWorkspace,
+    build_dependency_graph,
+    load_cargo_workspace,
+)
+from claude_builder.analysis.code_metrics import measure_code
+from claude_builder.analysis.detection_rules import (
+    apply_rule_matches,
+    evaluate_detection_rules,
+    parse_detection_rules,
+)
+from claude_builder.analysis.domain_terms import (
+    keyword_variants,
+    path_tokens,
+    source_tokens,
+    split_identifier,
+)
+from claude_builder.analysis.evidence import EvidenceScores
+from claude_builder.analysis.go_frameworks import GO_FRAMEWORKS
+from claude_builder.analysis.gomod import (
+    GoWorkspace,
+    build_go_dependency_graph,
+    load_go_workspace,
+)
+from claude_builder.analysis.jvm import GRADLE_BUILD_FILES, GRADLE_SETTINGS_FILES
+from claude_builder.analysis.jvm_frameworks import JVM_FRAMEWORKS
+from claude_builder.analysis.monorepo import detect_monorepo, package_commands
+from claude_builder.analysis.rust_frameworks import (
+    RUST_FRAMEWORKS,
+    crates_in_category,
+    score_rust_frameworks,
+    uses_maturin,
+)
+from claude_builder.analysis.rust_source import scan_rust_sources
+from claude_builder.core.analysis_cache import (
+    CACHE_DIRECTORY,
+    CACHE_FORMAT_VERSION,
+    AnalysisCache,
+    CachedAnalysis,
+    FileIndex,
+    ManifestResults,
+    file_fact,
+)
+from claude_builder.core.language_plugins import get_language_registry
+from claude_builder.core.managed_regions import BASELINE_DIRECTORY, generated_files
 from claude_builder.core.models import (
     ArchitecturePattern,
+    CargoWorkspaceInfo,
     ComplexityLevel,
+    ComplexityMetrics,
+    CrateInfo,
+    DependencyInfo,
+    DetectionEvidence,
     DevelopmentEnvironment,
     DomainInfo,
     FileSystemInfo,
     FrameworkInfo,
+    GitHistoryInfo,
+    GoModuleInfo,
+    GoWorkspaceInfo,
     LanguageInfo,
+    MonorepoInfo,
     ProjectAnalysis,
     ProjectType,
+    RustSourceInfo,
+    WorkspacePackageInfo,
+)
+from claude_builder.core.pipeline import AnalysisPipeline, AnalysisStage
+from claude_builder.utils.exceptions import AnalysisError, GitError
+from claude_builder.utils.git import analyze_git_history, head_revision
+from claude_builder.utils.project_files import (
+    DEFAULT_IGNORE_PATTERNS,
+    DEFAULT_MAX_FILE_SIZE,
+    DEFAULT_MAX_FILES,
+    ProjectFiles,
+    matches_ignore_pattern,
+    walk_project,
 )
-from claude_builder.utils.exceptions import AnalysisError
+
+
+ANALYZER_VERSION = "0.1.0"
+
+# Settings that change how, not what, the project is analyzed
+UNKEYED_SETTINGS = (
+    "cache_enabled",
+    "rebuild_cache",
+    "cache_read_only",
+    "parallel_processing",
+)
+
+# Rust binary crates whose primary framework implies a specific project type
+RUST_BINARY_PROJECT_TYPES = {
+    "tonic": ProjectType.API_SERVICE,
+    "tauri": ProjectType.DESKTOP_APP,
+    "bevy": ProjectType.GAME,
+    "leptos": ProjectType.WEB_FRONTEND,
+    "yew": ProjectType.WEB_FRONTEND,
+    "dioxus": ProjectType.WEB_FRONTEND,
+}
 
 
 class ProjectAnalyzer:
@@ -29,21 +119,29 @@ class ProjectAnalyzer:
     def __init__(self, config: Optional[Dict[str, Any]] = None):
         self.config = config or {}
         self.ignore_patterns = self.config.get(
-            "ignore_patterns",
-            [
-                ".git/",
-                "node_modules/",
-                "__pycache__/",
-                "target/",
-                "dist/",
-                "build/",
-                ".venv/",
-                "venv/",
-                ".tox/",
-                ".coverage",
-            ],
+            "ignore_patterns", list(DEFAULT_IGNORE_PATTERNS)
         )
+        # Walk policy shared by every detector (see utils.project_files)
+        self.respect_gitignore = self.config.get("respect_gitignore", True)
+        self.follow_symlinks = self.config.get("follow_symlinks", False)
+        self.max_file_size = self.config.get("max_file_size", DEFAULT_MAX_FILE_SIZE)
+        self.max_files = self.config.get("max_files_to_analyze", DEFAULT_MAX_FILES)
         self.confidence_threshold = self.config.get("confidence_threshold", 80)
+        # Independent detectors run concurrently (see core.pipeline)
+        self.parallel_processing = self.config.get("parallel_processing", True)
+        # On-disk cache in .claude-builder/cache/ (enabled by the CLI and config)
+        self.cache_enabled = self.config.get("cache_enabled", False)
+        self.rebuild_cache = self.config.get("rebuild_cache", False)
+        # Dry runs and drift checks may read the cache but never write it
+        self.cache_read_only = self.config.get("cache_read_only", False)
+        # Declarative rules from configuration (see analysis.detection_rules)
+        self.detection_rules = parse_detection_rules(
+            self.config.get("custom_detection_rules")
+        )
+        # Measure the code (LOC, branching, coupling) to assess complexity
+        self.deep_analysis = self.config.get("deep_analysis", False)
+        # Mine commit conventions, hotspots and releases from the git history
+        self.git_history = self.config.get("git_history", True)
 
         # Initialize detectors
         self.language_detector = LanguageDetector()
@@ -54,6 +152,22 @@ class ProjectAnalyzer:
 
     def analyze(self, project_path: Path) -> ProjectAnalysis:
         """Perform complete project analysis."""
+        return self._analyze(
+            project_path, use_cache=self.cache_enabled, root=project_path
+        )
+
+    def _analyze(
+        self,
+        project_path: Path,
+        use_cache: bool,
+        root: Path,
+        file_index: Optional[FileIndex] = None,
+    ) -> ProjectAnalysis:
+        """Analyze ``project_path`` as part of the analysis rooted at ``root``.
+
+        Monorepo packages share the root, which holds the generation baselines,
+        and its ``file_index``, which holds the per-file results.
+        """
         try:
             # Check if project path exists
             if not project_path.exists():
@@ -65,25 +179,81 @@ class ProjectAnalyzer:
                 msg = f"Project path is not a directory: {project_path}"
                 raise AnalysisError(msg)
 
+            # Single walk of the project tree shared by the stages below
+            files = self._walk_project(project_path, root)
+
+            # Stage 0: Reuse the cached analysis when no file content changed
+            cache: Optional[AnalysisCache] = None
+            previous: Optional[CachedAnalysis] = None
+            revision = ""
+            if use_cache:
+                cache = AnalysisCache(project_path)
+                if self.rebuild_cache:
+                    cache.clear()
+                file_index = cache.refresh_index(files.files, files.directories)
+                # New commits change the history analytics but no file content
+                revision = (self.git_history and head_revision(project_path)) or ""
+                previous = cache.load_analysis(self._cache_key())
+                if (
+                    previous is not None
+                    and previous.fingerprint == file_index.fingerprint()
+                    and previous.revision == revision
+                ):
+                    previous.analysis.from_cache = True
+                    return previous.analysis
+
             # Initialize analysis
             analysis = ProjectAnalysis(
                 project_path=project_path,
                 analysis_timestamp=datetime.now(tz=timezone.utc).isoformat(),
-                analyzer_version="0.1.0",
+                analyzer_version=ANALYZER_VERSION,
             )
 
-            # Stage 1: File system analysis
-            filesystem_info = self._analyze_filesystem(project_path)
-            analysis.filesystem_info = filesystem_info
+            if files.truncated:
+                analysis.warnings.append(
+                    f"Analysis limited to the first {self.max_files} files "
+                    "(max_files_to_analyze)"
+                )
 
-            # Stage 2: Language detection
-            language_info = self.language_detector.detect(project_path, filesystem_info)
-            analysis.language_info = language_info
+            # Stages 1-8: Detectors run as soon as their inputs are available;
+            # after edits to source files only, manifest-level results are kept
+            reuse = self._reusable_results(previous, file_index)
+            seeded: Dict[str, Any] = {}
+            if reuse is not None:
+                seeded["tooling"] = reuse.tooling
+                seeded["go"] = (
+                    (reuse.go_workspace, reuse.go_graph)
+                    if reuse.go_workspace is not None
+                    else None
+                )
+                if previous is not None and previous.revision == revision:
+                    seeded["git_history"] = reuse.git_history
+            stages = self._analysis_stages(project_path, analysis, root, reuse)
+            results = AnalysisPipeline(
+                [stage for stage in stages if stage.name not in seeded],
+                parallel=self.parallel_processing,
+            ).run({"files": files, "file_index": file_index, **seeded})
+
+            manifest_results: Optional[ManifestResults] = None
+            if cache is not None and not self.cache_read_only:
+                # Copied before rules and overrides change the shared objects
+                go = results["go"] or (None, [])
+                manifest_results = copy.deepcopy(
+                    ManifestResults(
+                        languages=self._languages(results["language_info"]),
+                        framework_info=results["framework_info"],
+                        tooling=results["tooling"],
+                        go_workspace=go[0],
+                        go_graph=go[1],
+                        git_history=results["git_history"],
+                    )
+                )
+            if previous is not None and file_index is not None:
+                analysis.rescanned_files = list(file_index.changed)
 
-            # Stage 3: Framework detection
-            framework_info = self.framework_detector.detect(
-                project_path, filesystem_info, language_info
-            )
+            analysis.filesystem_info = results["filesystem_info"]
+            analysis.language_info = results["language_info"]
+            framework_info = results["framework_info"]
             analysis.framework_info = framework_info
             # Surface dependency names when available
             try:
@@ -94,42 +264,47 @@ class ProjectAnalyzer:
             except Exception:
                 pass
 
-            # Stage 4: Development environment analysis
-            dev_environment = self._analyze_dev_environment(
-                project_path, filesystem_info
-            )
+            if results["cargo"] is not None:
+                (
+                    analysis.cargo_workspace,
+                    cargo_graph,
+                    analysis.rust_source,
+                ) = results["cargo"]
+                if cargo_graph:
+                    analysis.dependency_graphs["cargo"] = cargo_graph
+            if results["go"] is not None:
+                analysis.go_workspace, go_graph = results["go"]
+                if go_graph:
+                    analysis.dependency_graphs["go"] = go_graph
+
+            dev_environment = results["dev_environment"]
             analysis.dev_environment = dev_environment
             # Convenience build system field
             if dev_environment.package_managers:
                 analysis.build_system = dev_environment.package_managers[0]
 
-            # Stage 5: Project type determination
-            project_type = self._determine_project_type(
-                project_path,
-                language_info,
-                framework_info,
-                filesystem_info,
-                dev_environment,
-            )
-            analysis.project_type = project_type
-
-            # Stage 6: Complexity assessment
-            complexity = self.complexity_assessor.assess(
-                filesystem_info, language_info, framework_info, dev_environment
-            )
-            analysis.complexity_level = complexity
+            analysis.project_type, analysis.project_type_evidence = results[
+                "project_type"
+            ]
+            if results["monorepo"] is not None:
+                monorepo = analysis.monorepo = results["monorepo"]
+                analysis.project_type = ProjectType.MONOREPO
+                analysis.project_type_evidence = [
+                    DetectionEvidence(
+                        ProjectType.MONOREPO.value,
+                        f"{len(monorepo.packages)} workspace packages "
+                        f"({', '.join(monorepo.tools) or 'package manifests'})",
+                    )
+                ]
 
-            # Stage 7: Architecture pattern detection
-            architecture = self.architecture_detector.detect(
-                project_path, filesystem_info, language_info, framework_info
-            )
-            analysis.architecture_pattern = architecture
+            analysis.complexity_level = results["complexity_level"]
+            analysis.complexity_metrics = results["code_metrics"]
+            analysis.git_history = results["git_history"]
+            analysis.architecture_pattern = results["architecture_pattern"]
+            analysis.domain_info = results["domain_info"]
 
-            # Stage 8: Domain analysis
-            domain_info = self.domain_detector.detect(
-                project_path, filesystem_info, language_info, framework_info
-            )
-            analysis.domain_info = domain_info
+            # Stage 8.4: Custom detection rules add to or override detections
+            apply_rule_matches(analysis, self.detection_rules, results["rule_matches"])
 
             # Stage 8.5: Apply explicit config overrides when provided
             self._apply_overrides(analysis)
@@ -140,6 +315,19 @@ class ProjectAnalyzer:
             # Stage 10: Generate suggestions and warnings
             self._generate_suggestions_and_warnings(analysis)
 
+            if (
+                cache is not None
+                and file_index is not None
+                and not self.cache_read_only
+            ):
+                cache.save(
+                    file_index,
+                    self._cache_key(),
+                    analysis,
+                    revision=revision,
+                    manifest_results=manifest_results,
+                )
+
             return analysis
 
         except FileNotFoundError:
@@ -149,82 +337,508 @@ class ProjectAnalyzer:
             msg = f"Failed to analyze project: {e}"
             raise AnalysisError(msg)
 
+    def _analysis_stages(
+        self,
+        project_path: Path,
+        analysis: ProjectAnalysis,
+        root: Path,
+        reuse: Optional[ManifestResults] = None,
+    ) -> List[AnalysisStage]:
+        """Detector stages of one analysis, named after the result they provide.
+
+        Framework detection takes its result from ``reuse`` unless the detected
+        languages differ from the ones it was computed for.
+        """
+        return [
+            # Stage 1: File system analysis
+            AnalysisStage(
+                "filesystem_info",
+                lambda r: self._analyze_filesystem(project_path, r["files"]),
+                ("files",),
+            ),
+            # Stage 2: Language detection (cached text statistics when available)
+            AnalysisStage(
+                "language_info",
+                lambda r: self.language_detector.detect(
+                    project_path,
+                    r["filesystem_info"],
+                    r["file_index"],
+                    files=r["files"],
+                ),
+                ("filesystem_info", "file_index", "files"),
+            ),
+            # Stage 3: Framework detection
+            AnalysisStage(
+                "framework_info",
+                lambda r: (
+                    reuse.framework_info
+                    if reuse is not None
+                    and reuse.languages == self._languages(r["language_info"])
+                    else self.framework_detector.detect(
+                        project_path,
+                        r["filesystem_info"],
+                        r["language_info"],
+                        files=r["files"],
+                    )
+                ),
+                ("filesystem_info", "language_info", "files"),
+            ),
+            # Stage 3.5: Cargo workspace breakdown (one entry per member crate)
+            AnalysisStage(
+                "cargo",
+                lambda r: self._analyze_cargo(project_path, r["filesystem_info"]),
+                ("filesystem_info",),
+            ),
+            # Stage 3.6: Go modules (a go.work workspace or a single go.mod)
+            AnalysisStage(
+                "go",
+                lambda r: self._analyze_go(project_path, r["filesystem_info"]),
+                ("filesystem_info",),
+            ),
+            # Stage 4: Development environment analysis
+            AnalysisStage(
+                "tooling",
+                lambda r: self._analyze_dev_environment(
+                    project_path, r["filesystem_info"], files=r["files"]
+                ),
+                ("filesystem_info", "files"),
+            ),
+            AnalysisStage(
+                "dev_environment",
+                lambda r: self._merge_test_frameworks(
+                    r["tooling"], r["framework_info"]
+                ),
+                ("tooling", "framework_info"),
+            ),
+            # Stage 5: Project type determination
+            AnalysisStage(
+                "project_type",
+                lambda r: self._determine_project_type(
+                    project_path,
+                    r["language_info"],
+                    r["framework_info"],
+                    r["filesystem_info"],
+                    r["dev_environment"],
+                    files=r["files"],
+                ),
+                (
+                    "language_info",
+                    "framework_info",
+                    "filesystem_info",
+                    "dev_environment",
+                    "files",
+                ),
+            ),
+            # Stage 5.5: Monorepo decomposition (one analysis per package)
+            AnalysisStage(
+                "monorepo",
+                lambda r: self._analyze_monorepo(
+                    project_path, analysis, root, r["file_index"]
+                ),
+                ("file_index",),
+            ),
+            # Stage 5.8: Code metrics (deep analysis only)
+            AnalysisStage(
+                "code_metrics",
+                lambda r: self._measure_code(
+                    project_path, r["language_info"], r["files"], r["file_index"]
+                ),
+                ("language_info", "files", "file_index"),
+            ),
+            # Stage 6: Complexity assessment
+            AnalysisStage(
+                "complexity_level",
+                lambda r: self.complexity_assessor.assess(
+                    r["filesystem_info"],
+                    r["language_info"],
+                    r["framework_info"],
+                    r["dev_environment"],
+                    metrics=r["code_metrics"],
+                ),
+                (
+                    "filesystem_info",
+                    "language_info",
+                    "framework_info",
+                    "dev_environment",
+                    "code_metrics",
+                ),
+            ),
+            # Stage 7: Architecture pattern detection
+            AnalysisStage(
+                "architecture_pattern",
+                lambda r: self.architecture_detector.detect(
+                    project_path,
+                    r["filesystem_info"],
+                    r["language_info"],
+                    r["framework_info"],
+                ),
+                ("filesystem_info", "language_info", "framework_info"),
+            ),
+            # Stage 8: Domain analysis
+            AnalysisStage(
+                "domain_info",
+                lambda r: self.domain_detector.detect(
+                    project_path,
+                    r["filesystem_info"],
+                    r["language_info"],
+                    r["framework_info"],
+                    files=r["files"],
+                    file_index=r["file_index"],
+                ),
+                (
+                    "filesystem_info",
+                    "language_info",
+                    "framework_info",
+                    "files",
+                    "file_index",
+                ),
+            ),
+            # Stage 8.2: Git history (conventions, hotspots, release cadence)
+            AnalysisStage(
+                "git_history", lambda r: self._analyze_git_history(project_path)
+            ),
+            AnalysisStage(
+                "rule_matches",
+                lambda r: evaluate_detection_rules(
+                    self.detection_rules, project_path, r["files"], r["file_index"]
+                ),
+                ("files", "file_index"),
+            ),
+        ]
+
+    def _measure_code(
+        self,
+        project_path: Path,
+        language_info: LanguageInfo,
+        files: ProjectFiles,
+        file_index: Optional[FileIndex] = None,
+    ) -> Optional[ComplexityMetrics]:
+        """Code metrics of the detected languages when deep analysis is enabled."""
+        if not self.deep_analysis:
+            return None
+
+        registry = get_language_registry()
+        sources: Dict[str, List[Path]] = {}
+        for language in [language_info.primary, *language_info.secondary]:
+            plugin = registry.get(language)
+            if plugin is not None and plugin.get_source_extensions():
+                sources[plugin.name] = files.with_suffix(
+                    *plugin.get_source_extensions()
+                )
+        return measure_code(project_path, sources, file_index)
+
+    def _reusable_results(
+        self, previous: Optional[CachedAnalysis], file_index: Optional[FileIndex]
+    ) -> Optional[ManifestResults]:
+        """Cached manifest-level results, if no changed file can affect them.
+
+        That is the case when existing source files were edited and nothing
+        was added, removed or renamed; any other change re-runs the detectors.
+        """
+        if previous is None or file_index is None or not file_index.only_edited:
+            return None
+        registry = get_language_registry()
+        source_extensions = registry.source_extensions()
+        manifests = [
+            pattern for plugin in registry for pattern in plugin.manifest_files
+        ]
+        for relative in map(Path, file_index.changed):
+            if relative.suffix.lower() not in source_extensions or any(
+                fnmatch(relative.name, pattern) for pattern in manifests
+            ):
+                return None
+        return previous.manifest_results
+
+    @staticmethod
+    def _languages(language_info: LanguageInfo) -> List[str]:
+        return [language_info.primary or "", *language_info.secondary]
+
+    def _analyze_git_history(self, project_path: Path) -> Optional[GitHistoryInfo]:
+        """History analytics when the project is the root of a git repository."""
+        if not self.git_history or not (project_path / ".git").exists():
+            return None
+        try:
+            return analyze_git_history(project_path)
+        except GitError:
+            # GitPython is not installed or the repository is unreadable
+            return None
+
+    def _merge_test_frameworks(
+        self, dev_environment: DevelopmentEnvironment, framework_info: FrameworkInfo
+    ) -> DevelopmentEnvironment:
+        """Add test frameworks parsed from build files (e.g. JUnit 4 vs 5)."""
+        for test_framework in framework_info.details.get("test_frameworks", []):
+            if test_framework not in dev_environment.testing_frameworks:
+                dev_environment.testing_frameworks.append(test_framework)
+        return dev_environment
+
     def _apply_overrides(self, analysis: ProjectAnalysis) -> None:
         """Apply explicit language/framework overrides from analyzer config."""
         overrides = self.config.get("overrides", {})
         if not isinstance(overrides, dict):
             return
 
-        language_override = overrides.get("language")
-        if isinstance(language_override, str) and language_override:
-            analysis.language_info.primary = language_override
-            analysis.language_info.confidence = 100.0
+        for key, info in (
+            ("language", analysis.language_info),
+            ("framework", analysis.framework_info),
+        ):
+            override = overrides.get(key)
+            if isinstance(override, str) and override:
+                info.primary = override
+                info.confidence = 100.0
+                info.evidence.append(
+                    DetectionEvidence(override, f"overrides.{key} in configuration")
+                )
 
-        framework_override = overrides.get("framework")
-        if isinstance(framework_override, str) and framework_override:
-            analysis.framework_info.primary = framework_override
-            analysis.framework_info.confidence = 100.0
+    def _analyze_cargo(
+        self, project_path: Path, filesystem_info: FileSystemInfo
+    ) -> Optional[
+        Tuple[
+            Optional[CargoWorkspaceInfo], List[DependencyInfo], Optional[RustSourceInfo]
+        ]
+    ]:
+        """Cargo workspace, resolved crate graph and Rust source layout."""
+        if "Cargo.toml" not in filesystem_info.root_files:
+            return None
+        workspace = load_cargo_workspace(project_path)
+        return (
+            self._analyze_cargo_workspace(workspace),
+            build_dependency_graph(project_path, workspace),
+            scan_rust_sources(project_path, workspace),
+        )
 
-    def _analyze_filesystem(self, project_path: Path) -> FileSystemInfo:
-        """Analyze project file system structure."""
-        info = FileSystemInfo()
+    def _analyze_go(
+        self, project_path: Path, filesystem_info: FileSystemInfo
+    ) -> Optional[Tuple[GoWorkspaceInfo, List[DependencyInfo]]]:
+        """Go workspace summary and module dependency graph."""
+        if not self._is_go_project(filesystem_info):
+            return None
+        go_workspace = load_go_workspace(project_path)
+        if go_workspace is None:
+            return None
+        return (
+            self._analyze_go_workspace(go_workspace),
+            build_go_dependency_graph(go_workspace),
+        )
 
-        # Count files and directories
-        for item in project_path.rglob("*"):
-            if self._should_ignore(item, project_path):
-                continue
+    def _analyze_cargo_workspace(
+        self, workspace: Optional[CargoWorkspace]
+    ) -> Optional[CargoWorkspaceInfo]:
+        """Break a Cargo workspace down into its member crates."""
+        if workspace is None:
+            return None
+
+        members = []
+        for manifest in workspace.members:
+            dependencies = workspace.dependencies(manifest)
+            members.append(
+                CrateInfo(
+                    name=manifest.name,
+                    path=workspace.relative_path(manifest),
+                    kind=manifest.crate_kind(),
+                    edition=workspace.edition(manifest),
+                    version=workspace.version(manifest),
+                    no_std="no_std" in manifest.crate_attributes(),
+                    targets=manifest.crate_targets(),
+                    frameworks=self.framework_detector.detect_cargo_frameworks(
+                        dependencies
+                    ),
+                    dependencies=sorted({dep.name for dep in dependencies}),
+                )
+            )
+
+        return CargoWorkspaceInfo(
+            is_workspace=workspace.is_workspace,
+            is_virtual=workspace.is_virtual,
+            members=members,
+            workspace_dependencies=sorted(workspace.workspace_dependencies),
+        )
+
+    def _is_go_project(self, filesystem_info: FileSystemInfo) -> bool:
+        return any(
+            name in filesystem_info.root_files for name in ("go.mod", "go.work")
+        )
+
+    def _analyze_go_workspace(self, workspace: GoWorkspace) -> GoWorkspaceInfo:
+        """Summarise the Go modules of a project for templates and output."""
+        modules = [
+            GoModuleInfo(
+                module=module.module or workspace.relative_path(module),
+                path=workspace.relative_path(module),
+                go_version=module.go_version,
+                requires=[
+                    f"{req.path} {req.version}" for req in module.direct_requires
+                ],
+                indirect_requires=len(module.requires) - len(module.direct_requires),
+                replaces=[replace.describe() for replace in module.replaces],
+            )
+            for module in workspace.modules
+        ]
+        return GoWorkspaceInfo(
+            is_workspace=workspace.is_workspace,
+            go_version=workspace.go_version,
+            toolchain=workspace.toolchain,
+            modules=modules,
+            replaces=[replace.describe() for replace in workspace.replaces],
+        )
 
-            if item.is_file():
-                info.total_files += 1
+    def _analyze_monorepo(
+        self,
+        project_path: Path,
+        analysis: ProjectAnalysis,
+        root: Path,
+        file_index: Optional[FileIndex] = None,
+    ) -> Optional[MonorepoInfo]:
+        """Detect workspace tools and analyze every package on its own."""
+        if not self.config.get("analyze_packages", True):
+            return None
+        layout = detect_monorepo(project_path)
+        if layout is None:
+            return None
+
+        packages = []
+        for package in layout.packages:
+            path = layout.relative_path(package)
+            try:
+                # Packages are part of the root analysis (and its cache entry)
+                package_analysis: Optional[ProjectAnalysis] = self._analyze(
+                    package.directory,
+                    use_cache=False,
+                    root=root,
+                    file_index=file_index,
+                )
+            except AnalysisError as e:
+                analysis.warnings.append(f"Could not analyze package {path}: {e}")
+                package_analysis = None
+            packages.append(
+                WorkspacePackageInfo(
+                    name=package.name,
+                    path=path,
+                    tools=list(package.tools),
+                    commands=package_commands(layout, package),
+                    analysis=package_analysis,
+                )
+            )
+        return MonorepoInfo(tools=list(layout.tools), packages=packages)
+
+    def _cache_key(self) -> str:
+        """Identify an analysis by analyzer and configuration.
+
+        The file fingerprint and git revision are stored next to the analysis
+        so that a changed tree can update it instead of starting over.
+        """
+        settings = {
+            key: value
+            for key, value in self.config.items()
+            if key not in UNKEYED_SETTINGS
+        }
+        return "|".join(
+            [
+                str(CACHE_FORMAT_VERSION),
+                ANALYZER_VERSION,
+                ",".join(get_language_registry().names()),
+                json.dumps(settings, sort_keys=True, default=str),
+            ]
+        )
 
-                # Categorize files
-                if self._is_source_file(item):
-                    info.source_files += 1
-                elif self._is_test_file(item):
-                    info.test_files += 1
-                elif self._is_config_file(item):
-                    info.config_files += 1
-                elif self._is_documentation_file(item):
-                    info.documentation_files += 1
-                else:
-                    info.asset_files += 1
+    def _walk_project(
+        self, project_path: Path, root: Optional[Path] = None
+    ) -> ProjectFiles:
+        """Walk the project once with this analyzer's ignore and size policy."""
+        return walk_project(
+            project_path,
+            ignore_patterns=self.ignore_patterns,
+            respect_gitignore=self.respect_gitignore,
+            max_file_size=self.max_file_size,
+            max_files=self.max_files,
+            follow_symlinks=self.follow_symlinks,
+            # Generated instruction files are output, not part of the project
+            exclude=[
+                CACHE_DIRECTORY.as_posix(),
+                BASELINE_DIRECTORY.as_posix(),
+                *self._generated_outputs(project_path, root or project_path),
+            ],
+        )
+
+    def _generated_outputs(self, project_path: Path, root: Path) -> List[str]:
+        """Generated files under ``project_path``, relative to it.
 
-            elif item.is_dir():
-                info.total_directories += 1
+        Baselines are stored in the analyzed ``root``, so monorepo packages
+        look up their nested CLAUDE.md files there.
+        """
+        try:
+            package = project_path.relative_to(root)
+        except ValueError:
+            root, package = project_path, Path()
+        outputs = []
+        for path in generated_files(root):
+            try:
+                outputs.append(Path(path).relative_to(package).as_posix())
+            except ValueError:
+                continue
+        return outputs
+
+    def _analyze_filesystem(
+        self, project_path: Path, files: Optional[ProjectFiles] = None
+    ) -> FileSystemInfo:
+        """Analyze project file system structure."""
+        info = FileSystemInfo()
+        files = files or self._walk_project(project_path)
+
+        # Count files and directories
+        info.total_directories = len(files.directories)
+        for item in files.files:
+            info.total_files += 1
+
+            # Categorize files
+            if self._is_source_file(item):
+                info.source_files += 1
+            elif self._is_test_file(item):
+                info.test_files += 1
+            elif self._is_config_file(item):
+                info.config_files += 1
+            elif self._is_documentation_file(item):
+                info.documentation_files += 1
+            else:
+                info.asset_files += 1
 
         # Get root files
-        info.root_files = [
-            f.name
-            for f in project_path.iterdir()
-            if f.is_file() and not self._should_ignore(f, project_path)
-        ]
+        info.root_files = [f.name for f in files.root_files()]
 
         # Basic directory structure
-        info.directory_structure = self._analyze_directory_structure(project_path)
+        info.directory_structure = self._analyze_directory_structure(
+            project_path, files
+        )
 
         return info
 
-    def _analyze_directory_structure(self, project_path: Path) -> Dict[str, Any]:
+    def _analyze_directory_structure(
+        self, project_path: Path, files: Optional[ProjectFiles] = None
+    ) -> Dict[str, Any]:
         """Analyze directory structure patterns."""
         structure = {}
+        files = files or self._walk_project(project_path)
 
-        for item in project_path.iterdir():
-            if item.is_dir() and not self._should_ignore(item, project_path):
-                structure[item.name] = {
-                    "type": "directory",
-                    "file_count": sum(1 for _ in item.rglob("*") if _.is_file()),
-                    "subdirs": [d.name for d in item.iterdir() if d.is_dir()][
-                        :5
-                    ],  # Limit for performance
-                }
+        for item in files.root_directories():
+            structure[item.name] = {
+                "type": "directory",
+                "file_count": len(files.under(item)),
+                "subdirs": sorted(
+                    d.name for d in files.directories if d.parent == item
+                )[:5],  # Limit for performance
+            }
 
         return structure
 
     def _analyze_dev_environment(
-        self, project_path: Path, filesystem_info: FileSystemInfo
+        self,
+        project_path: Path,
+        filesystem_info: FileSystemInfo,
+        files: Optional[ProjectFiles] = None,
     ) -> DevelopmentEnvironment:
         """Analyze development environment and tools."""
         env = DevelopmentEnvironment()
+        files = files or self._walk_project(project_path)
 
         # Package managers
         if "package.json" in filesystem_info.root_files:
@@ -238,11 +852,14 @@ class ProjectAnalyzer:
             for f in ["requirements.txt", "pyproject.toml", "setup.py"]
         ):
             env.package_managers.append("pip")
-        if "go.mod" in filesystem_info.root_files:
+        if self._is_go_project(filesystem_info):
             env.package_managers.append("go")
         if "pom.xml" in filesystem_info.root_files:
             env.package_managers.append("maven")
-        if "build.gradle" in filesystem_info.root_files:
+        if any(
+            f in filesystem_info.root_files
+            for f in GRADLE_BUILD_FILES + GRADLE_SETTINGS_FILES
+        ):
             env.package_managers.append("gradle")
 
         # CI/CD systems
@@ -280,7 +897,7 @@ class ProjectAnalyzer:
                 InfrastructureDetector,
             )
 
-            infra_detector = InfrastructureDetector(project_path)
+            infra_detector = InfrastructureDetector(project_path, files=files)
             infra_results, infra_metadata = infra_detector.detect_with_metadata()
 
             env.infrastructure_as_code = infra_results.get("infrastructure_as_code", [])
@@ -297,7 +914,7 @@ class ProjectAnalyzer:
         try:
             from claude_builder.analysis.detectors.mlops import MLOpsDetector
 
-            mlops_detector = MLOpsDetector()
+            mlops_detector = MLOpsDetector(files=files)
             mlops_results, mlops_metadata = mlops_detector.detect_with_metadata(
                 project_path
             )
@@ -321,124 +938,263 @@ class ProjectAnalyzer:
         framework_info: FrameworkInfo,
         filesystem_info: FileSystemInfo,
         dev_environment: DevelopmentEnvironment,
-    ) -> ProjectType:
-        """Determine the primary project type."""
-
-        # Check for specific framework patterns first
-        if framework_info.primary == "cli_tool":
-            return ProjectType.CLI_TOOL
-
-        # Frontend frameworks
-        if framework_info.primary in [
-            "react",
-            "vue",
-            "angular",
-            "svelte",
-            "nextjs",
-            "nuxt",
-        ]:
-            return ProjectType.WEB_FRONTEND
-
-        # API/backend web services
-        if framework_info.primary in [
-            "fastapi",
-            "express",
-            "axum",
-            "actix",
-            "warp",
-            "gin",
-            "echo",
-            "fiber",
-        ] or "api" in str(filesystem_info.directory_structure):
-            return ProjectType.API_SERVICE
-
-        # General web applications
-        if framework_info.primary in ["django", "flask", "starlette"]:
-            return ProjectType.WEB_APPLICATION
-
-        # Check for CLI patterns - enhanced detection
-        cli_indicators = [
-            (project_path / "main.py").exists(),
-            any(p.name == "cli.py" for p in project_path.glob("*.py")),
-            any(p.name == "__main__.py" for p in project_path.rglob("*.py")),
-            "bin" in filesystem_info.directory_structure,
-            "cli" in filesystem_info.directory_structure,
-            "commands" in filesystem_info.directory_structure,
-            language_info.primary == "rust"
-            and (project_path / "src" / "main.rs").exists(),
-            language_info.primary == "go" and (project_path / "main.go").exists(),
-            "scripts" in str(filesystem_info.root_files)
-            and language_info.primary == "python",
-        ]
+        files: Optional[ProjectFiles] = None,
+    ) -> Tuple[ProjectType, List[DetectionEvidence]]:
+        """Determine the primary project type and the signals that decided it.
+
+        The first matching rule wins; its evidence lists every indicator of
+        that rule that was present.
+        """
+        files = files or self._walk_project(project_path)
+        framework = framework_info.primary
+        directories = filesystem_info.directory_structure
+
+        def decided(
+            project_type: ProjectType, indicators: Dict[str, bool]
+        ) -> Optional[Tuple[ProjectType, List[DetectionEvidence]]]:
+            signals = [signal for signal, present in indicators.items() if present]
+            if not signals:
+                return None
+            return project_type, [
+                DetectionEvidence(project_type.value, signal) for signal in signals
+            ]
 
-        if any(cli_indicators):
-            return ProjectType.CLI_TOOL
+        # Cargo manifests say exactly what a Rust crate builds
+        if language_info.primary == "rust":
+            rust_type = self._determine_rust_project_type(project_path, framework_info)
+            if rust_type is not None:
+                return rust_type[0], [
+                    DetectionEvidence(rust_type[0].value, rust_type[1])
+                ]
+
+        candidates = [
+            # Check for specific framework patterns first
+            (
+                ProjectType.CLI_TOOL,
+                {
+                    f"primary framework `{framework}` builds CLIs": framework
+                    in ["cli_tool", "clap", "cobra", "urfave_cli"]
+                },
+            ),
+            # Frontend frameworks
+            (
+                ProjectType.WEB_FRONTEND,
+                {
+                    f"primary framework `{framework}` is a frontend framework": (
+                        framework
+                        in [
+                            "react",
+                            "vue",
+                            "angular",
+                            "svelte",
+                            "nextjs",
+                            "nuxt",
+                            "leptos",
+                            "yew",
+                            "dioxus",
+                        ]
+                    )
+                },
+            ),
+            # API/backend web services
+            (
+                ProjectType.API_SERVICE,
+                {
+                    f"primary framework `{framework}` serves APIs": framework
+                    in [
+                        "fastapi",
+                        "express",
+                        "axum",
+                        "actix",
+                        "warp",
+                        "rocket",
+                        "tonic",
+                        "gin",
+                        "echo",
+                        "fiber",
+                        "chi",
+                        "gorilla",
+                        "grpc",
+                        "springboot",
+                        "quarkus",
+                        "micronaut",
+                        "ktor",
+                    ],
+                    "`api` in the directory structure": "api" in str(directories),
+                },
+            ),
+            # General web applications
+            (
+                ProjectType.WEB_APPLICATION,
+                {
+                    f"primary framework `{framework}` builds web applications": (
+                        framework in ["django", "flask", "starlette"]
+                    )
+                },
+            ),
+            # Check for CLI patterns - enhanced detection
+            (
+                ProjectType.CLI_TOOL,
+                {
+                    "main.py in project root": (project_path / "main.py").exists(),
+                    "cli.py in project root": any(
+                        p.name == "cli.py" for p in project_path.glob("*.py")
+                    ),
+                    "__main__.py module": bool(files.named("__main__.py")),
+                    "bin/ directory": "bin" in directories,
+                    "cli/ directory": "cli" in directories,
+                    "commands/ directory": "commands" in directories,
+                    "src/main.rs": language_info.primary == "rust"
+                    and (project_path / "src" / "main.rs").exists(),
+                    "main.go in project root": language_info.primary == "go"
+                    and (project_path / "main.go").exists(),
+                    "scripts in a Python project root": "scripts"
+                    in str(filesystem_info.root_files)
+                    and language_info.primary == "python",
+                },
+            ),
+        ]
 
         # Data science patterns - enhanced
         if language_info.primary == "python":
-            data_science_indicators = [
-                "notebooks" in filesystem_info.directory_structure,
-                "data" in filesystem_info.directory_structure,
-                "models" in filesystem_info.directory_structure,
-                any(p.suffix == ".ipynb" for p in project_path.rglob("*")),
-                "analysis" in filesystem_info.directory_structure,
-                "experiments" in filesystem_info.directory_structure,
-            ]
-            if any(data_science_indicators):
-                return ProjectType.DATA_SCIENCE
+            candidates.append(
+                (
+                    ProjectType.DATA_SCIENCE,
+                    {
+                        "notebooks/ directory": "notebooks" in directories,
+                        "data/ directory": "data" in directories,
+                        "models/ directory": "models" in directories,
+                        "Jupyter notebooks": bool(files.with_suffix(".ipynb")),
+                        "analysis/ directory": "analysis" in directories,
+                        "experiments/ directory": "experiments" in directories,
+                    },
+                )
+            )
 
         # Library patterns - enhanced
-        library_indicators = [
-            "lib" in filesystem_info.directory_structure,
-            "src" in filesystem_info.directory_structure
-            and len(filesystem_info.directory_structure) <= 3,
-            language_info.primary == "rust"
-            and (project_path / "src" / "lib.rs").exists()
-            and not (project_path / "src" / "main.rs").exists(),
-            "setup.py" in filesystem_info.root_files
-            and "main.py" not in filesystem_info.root_files,
-            "package.json" in filesystem_info.root_files
-            and "index.js" in filesystem_info.root_files,
-        ]
+        candidates.append(
+            (
+                ProjectType.LIBRARY,
+                {
+                    "lib/ directory": "lib" in directories,
+                    "src/ directory with few siblings": "src" in directories
+                    and len(directories) <= 3,
+                    "src/lib.rs without src/main.rs": language_info.primary == "rust"
+                    and (project_path / "src" / "lib.rs").exists()
+                    and not (project_path / "src" / "main.rs").exists(),
+                    "setup.py without main.py": "setup.py" in filesystem_info.root_files
+                    and "main.py" not in filesystem_info.root_files,
+                    "package.json with index.js": "package.json"
+                    in filesystem_info.root_files
+                    and "index.js" in filesystem_info.root_files,
+                },
+            )
+        )
 
-        if any(library_indicators):
-            return ProjectType.LIBRARY
+        for project_type, indicators in candidates:
+            result = decided(project_type, indicators)
+            if result is not None:
+                return result
 
         # Monorepo patterns - enhanced
         package_dirs = [
             d
-            for d in filesystem_info.directory_structure
+            for d in directories
             if any(
-                pkg in str(filesystem_info.directory_structure.get(d, {}))
+                pkg in str(directories.get(d, {}))
                 for pkg in ["package.json", "Cargo.toml", "pyproject.toml"]
             )
         ]
 
         if len(package_dirs) > 1:
-            return ProjectType.MONOREPO
-
-        # Mobile app detection
-        if any(
-            indicator in filesystem_info.directory_structure
-            for indicator in ["android", "ios", "mobile"]
-        ):
-            return ProjectType.MOBILE_APP
+            return ProjectType.MONOREPO, [
+                DetectionEvidence(
+                    ProjectType.MONOREPO.value,
+                    f"package manifests in {', '.join(sorted(package_dirs))}",
+                )
+            ]
 
-        # Game development detection
-        if any(
-            indicator in filesystem_info.directory_structure
-            for indicator in ["game", "unity", "unreal", "godot"]
+        for project_type, markers in (
+            # Mobile app detection
+            (ProjectType.MOBILE_APP, ["android", "ios", "mobile"]),
+            # Game development detection
+            (ProjectType.GAME, ["game", "unity", "unreal", "godot"]),
         ):
-            return ProjectType.GAME
+            result = decided(
+                project_type,
+                {f"{marker}/ directory": marker in directories for marker in markers},
+            )
+            if result is not None:
+                return result
 
         # If we have a src directory and clear language, likely a standard application
         if (
-            "src" in filesystem_info.directory_structure
-            and language_info.confidence > 70
+            "src" in directories and language_info.confidence > 70
         ) and language_info.primary in ["rust", "go", "java", "csharp"]:
-            return ProjectType.APPLICATION
+            return ProjectType.APPLICATION, [
+                DetectionEvidence(
+                    ProjectType.APPLICATION.value,
+                    f"src/ directory in a {language_info.primary} project",
+                )
+            ]
+
+        return ProjectType.UNKNOWN, []
+
+    def _determine_rust_project_type(
+        self, project_path: Path, framework_info: FrameworkInfo
+    ) -> Optional[Tuple[ProjectType, str]]:
+        """Classify a Rust package from its targets, crate-types and attributes.
+
+        Returns the project type and the manifest signal that decided it.
+        Virtual workspaces have no root crate and fall back to the generic
+        heuristics.
+        """
+        workspace = load_cargo_workspace(project_path)
+        if workspace is None or workspace.is_virtual:
+            return None
+
+        manifest = workspace.root
+        targets = manifest.crate_targets()
+        attributes = manifest.crate_attributes()
+        dependencies = {dep.name: dep for dep in workspace.dependencies(manifest)}
+        has_bin = "bin" in targets
+
+        if "proc-macro" in targets:
+            return ProjectType.PROC_MACRO, "Cargo.toml proc-macro crate"
+
+        pyo3 = dependencies.get("pyo3")
+        if pyo3 is not None and (
+            "extension-module" in pyo3.features
+            or "cdylib" in targets
+            or uses_maturin(project_path)
+        ):
+            return ProjectType.PYTHON_EXTENSION, "pyo3 extension module"
 
-        return ProjectType.UNKNOWN
+        if "wasm-bindgen" in dependencies and not has_bin:
+            return ProjectType.WASM_MODULE, "wasm-bindgen without a binary target"
+
+        embedded_runtime = bool(crates_in_category("embedded") & set(dependencies))
+        if (has_bin or "no_main" in attributes) and (
+            "no_std" in attributes or embedded_runtime
+        ):
+            return (
+                ProjectType.EMBEDDED_FIRMWARE,
+                "no_std or embedded runtime crate in a binary",
+            )
+
+        if not has_bin:
+            return ProjectType.LIBRARY, "Cargo.toml has no binary target"
+
+        # Binaries: the framework tells a service from a GUI, game or CLI
+        framework = framework_info.primary
+        if framework in RUST_FRAMEWORKS and RUST_FRAMEWORKS[framework].web_framework:
+            return ProjectType.API_SERVICE, f"binary using web framework `{framework}`"
+        if framework in RUST_BINARY_PROJECT_TYPES:
+            return (
+                RUST_BINARY_PROJECT_TYPES[framework],
+                f"binary using `{framework}`",
+            )
+        return ProjectType.CLI_TOOL, "binary target without a service framework"
 
     def _calculate_overall_confidence(self, analysis: ProjectAnalysis) -> float:
         """Calculate overall analysis confidence."""
@@ -492,44 +1248,12 @@ class ProjectAnalyzer:
 
     def _should_ignore(self, path: Path, project_root: Path) -> bool:
         """Check if a path should be ignored during analysis."""
-        relative_path = path.relative_to(project_root)
-        path_str = str(relative_path)
-
-        for pattern in self.ignore_patterns:
-            if pattern.endswith("/"):
-                # Directory pattern
-                if (
-                    path_str.startswith(pattern[:-1])
-                    or f"/{pattern[:-1]}/" in f"/{path_str}/"
-                ):
-                    return True
-            # File pattern
-            elif pattern in path_str:
-                return True
-
-        return False
+        relative_path = path.relative_to(project_root).as_posix()
+        return matches_ignore_pattern(relative_path, self.ignore_patterns)
 
     def _is_source_file(self, path: Path) -> bool:
         """Check if file is a source code file."""
-        source_extensions = {
-            ".py",
-            ".rs",
-            ".js",
-            ".ts",
-            ".jsx",
-            ".tsx",
-            ".java",
-            ".go",
-            ".c",
-            ".cpp",
-            ".h",
-            ".hpp",
-            ".cs",
-            ".php",
-            ".rb",
-            ".scala",
-            ".kt",
-        }
+        source_extensions = get_language_registry().source_extensions()
         return path.suffix.lower() in source_extensions
 
     def _is_test_file(self, path: Path) -> bool:
@@ -559,67 +1283,43 @@ class ProjectAnalyzer:
 class LanguageDetector:
     """Detects programming languages in a project."""
 
-    LANGUAGE_EXTENSIONS = {
-        "python": [".py", ".pyx", ".pyi"],
-        "rust": [".rs"],
-        "javascript": [".js", ".mjs", ".cjs"],
-        "typescript": [".ts", ".tsx"],
-        "java": [".java"],
-        "go": [".go"],
-        "c": [".c", ".h"],
-        "cpp": [".cpp", ".cxx", ".cc", ".hpp", ".hxx"],
-        "csharp": [".cs"],
-        "php": [".php"],
-        "ruby": [".rb"],
-        "scala": [".scala"],
-        "kotlin": [".kt", ".kts"],
-        "swift": [".swift"],
-        # Additional types referenced in tests
-        "css": [".css"],
-        "markdown": [".md"],
-    }
-
     def detect(
-        self, project_path: Path, filesystem_info: FileSystemInfo
+        self,
+        project_path: Path,
+        filesystem_info: FileSystemInfo,
+        file_index: Optional[FileIndex] = None,
+        files: Optional[ProjectFiles] = None,
     ) -> LanguageInfo:
-        """Detect languages used in the project."""
+        """Detect languages used in the project.
+
+        ``files`` is the shared project walk; with a ``file_index`` the cached
+        line and character counts are used instead of reading every source
+        file again.
+        """
         language_counts: Dict[str, int] = defaultdict(int)
         language_lines: Dict[str, int] = defaultdict(int)
         language_sizes: Dict[str, int] = defaultdict(int)
+        language_suffixes: Dict[str, Set[str]] = defaultdict(set)
 
         # Skip if no source files
         if filesystem_info.source_files == 0:
             return LanguageInfo(confidence=0.0)
 
         # Count files by language with enhanced logic
-        for item in project_path.rglob("*"):
-            if (
-                item.is_file()
-                and item.suffix
-                and not self._should_ignore_for_language_detection(item, project_path)
+        language_extensions = get_language_registry().extension_map()
+        files = files or walk_project(project_path)
+        for item in files.files:
+            if not item.suffix or self._should_ignore_for_language_detection(
+                item, project_path
             ):
-                for language, extensions in self.LANGUAGE_EXTENSIONS.items():
-                    if item.suffix.lower() in extensions:
-                        language_counts[language] += 1
-
-                        # Get file size and line count
-                        try:
-                            content = item.read_text(encoding="utf-8", errors="ignore")
-                            lines = len(content.splitlines())
-                            size = len(content)
-
-                            language_lines[language] += lines
-                            language_sizes[language] += size
-                        except (OSError, UnicodeDecodeError):
-                            # Fallback estimates based on file size
-                            try:
-                                size = item.stat().st_size
-                                estimated_lines = max(1, size // 50)  # Rough estimate
-                                language_lines[language] += estimated_lines
-                                language_sizes[language] += size
-                            except OSError:
-                                language_lines[language] += 50  # Minimal fallback
-                                language_sizes[language] += 2000
+                continue
+            for language, extensions in language_extensions.items():
+                if item.suffix.lower() in extensions:
+                    language_counts[language] += 1
+                    lines, size = self._text_statistics(item, files, file_index)
+                    language_lines[language] += lines
+                    language_sizes[language] += size
+                    language_suffixes[language].add(item.suffix.lower())
 
         if not language_counts:
             return LanguageInfo(confidence=0.0)
@@ -651,9 +1351,40 @@ class LanguageDetector:
         confidence = file_dominance * 0.7 + line_dominance * 0.3
 
         # Boost confidence if there are config files that match the language
+        config_files = self._matching_config_files(filesystem_info.root_files, primary)
         config_boost = self._get_config_file_boost(filesystem_info.root_files, primary)
         confidence = min(confidence + config_boost, 100.0)
 
+        evidence: List[DetectionEvidence] = []
+        for lang in sorted(language_counts, key=calculate_language_score, reverse=True):
+            suffixes = "/".join(sorted(language_suffixes[lang]))
+            share = language_lines[lang] / total_lines * 100 if total_lines else 0
+            evidence += [
+                DetectionEvidence(
+                    lang,
+                    f"{language_counts[lang]} {suffixes} files",
+                    language_counts[lang] * 10,
+                ),
+                DetectionEvidence(
+                    lang,
+                    f"{language_lines[lang]} lines, {share:.0f}% of source lines",
+                    language_lines[lang] * 0.1,
+                ),
+                DetectionEvidence(
+                    lang,
+                    f"{language_sizes[lang]} characters",
+                    language_sizes[lang] * 0.001,
+                ),
+            ]
+        if config_files:
+            evidence.append(
+                DetectionEvidence(
+                    primary,
+                    f"{', '.join(config_files)} in project root "
+                    f"(+{config_boost:.0f}% confidence)",
+                )
+            )
+
         # Secondary languages (those with significant presence)
         secondary = [
             lang
@@ -665,10 +1396,15 @@ class LanguageDetector:
             )
         ]
 
-        # Populate version_info for primary language (tests expect presence)
+        # Primary language always has an entry; toolchain files refine it
         version_info: Dict[str, str] = {}
         if primary:
             version_info[primary] = "unknown"
+        version_info.update(
+            get_language_registry().detect_versions(
+                project_path, [primary, *secondary]
+            )
+        )
 
         return LanguageInfo(
             primary=primary,
@@ -677,54 +1413,58 @@ class LanguageDetector:
             file_counts=dict(language_counts),
             total_lines=dict(language_lines),
             version_info=version_info,
+            evidence=evidence,
         )
 
+    def _text_statistics(
+        self, item: Path, files: ProjectFiles, file_index: Optional[FileIndex] = None
+    ) -> Tuple[int, int]:
+        """Line count and size of a source file, from its cache record if any."""
+        record = file_index.record(item) if file_index is not None else None
+        if record is not None:
+            return record.lines, record.chars
+        content = files.read_text(item)
+        if content is not None:
+            return len(content.splitlines()), len(content)
+        # Oversized or unreadable: estimate from the file size
+        size = files.sizes.get(item)
+        if size is None:
+            return 50, 2000  # Minimal fallback
+        return max(1, size // 50), size  # Rough estimate
+
     def detect_primary_language(self, project_path: Path) -> LanguageInfo:
         """Test-compatible method for detecting primary language."""
         # Create a minimal filesystem analysis for the test method
-        filesystem_info = self._analyze_filesystem_for_language_detection(project_path)
-        result = self.detect(project_path, filesystem_info)
+        files = walk_project(project_path)
+        filesystem_info = self._analyze_filesystem_for_language_detection(
+            project_path, files
+        )
+        result = self.detect(project_path, filesystem_info, files=files)
 
         # Add version_info field expected by tests
         if hasattr(result, "primary") and result.primary:
-            result.version_info = {result.primary: "unknown"}
+            result.version_info.setdefault(result.primary, "unknown")
         return result
 
-    def _analyze_filesystem_for_language_detection(self, project_path: Path) -> Any:
+    def _analyze_filesystem_for_language_detection(
+        self, project_path: Path, files: Optional[ProjectFiles] = None
+    ) -> Any:
         """Minimal filesystem analysis for language detection."""
         info = FileSystemInfo()
-        for item in project_path.rglob("*"):
-            if item.is_file():
-                info.total_files += 1
-                if self._is_source_file(item):
-                    info.source_files += 1
+        files = files or walk_project(project_path)
+        for item in files.files:
+            info.total_files += 1
+            if self._is_source_file(item):
+                info.source_files += 1
 
         # Get root files
-        info.root_files = [f.name for f in project_path.iterdir() if f.is_file()]
+        info.root_files = [f.name for f in files.root_files()]
 
         return info
 
     def _is_source_file(self, path: Path) -> bool:
         """Check if file is a source code file for language detection."""
-        source_extensions = {
-            ".py",
-            ".rs",
-            ".js",
-            ".ts",
-            ".jsx",
-            ".tsx",
-            ".java",
-            ".go",
-            ".c",
-            ".cpp",
-            ".h",
-            ".hpp",
-            ".cs",
-            ".php",
-            ".rb",
-            ".scala",
-            ".kt",
-        }
+        source_extensions = get_language_registry().source_extensions()
         return path.suffix.lower() in source_extensions
 
     def _should_ignore_for_language_detection(
@@ -767,25 +1507,20 @@ class LanguageDetector:
 
     def _get_config_file_boost(self, root_files: List[str], language: str) -> float:
         """Get confidence boost from matching config files."""
-        config_mappings = {
-            "python": ["pyproject.toml", "setup.py", "requirements.txt", "Pipfile"],
-            "rust": ["Cargo.toml", "Cargo.lock"],
-            "javascript": ["package.json", "package-lock.json"],
-            "typescript": ["package.json", "tsconfig.json"],
-            "go": ["go.mod", "go.sum"],
-            "java": ["pom.xml", "build.gradle"],
-            "csharp": ["*.csproj", "*.sln"],
-        }
-
-        if language in config_mappings:
-            matching_configs = sum(
-                1
-                for config in config_mappings[language]
-                if any(config.replace("*", "") in f for f in root_files)
-            )
-            return min(matching_configs * 10, 20)  # Up to 20% boost
-
-        return 0
+        matching_configs = len(self._matching_config_files(root_files, language))
+        return min(matching_configs * 10, 20)  # Up to 20% boost
+
+    def _matching_config_files(self, root_files: List[str], language: str) -> List[str]:
+        """Manifest files of a language present in the project root."""
+        plugin = get_language_registry().get(language)
+        if plugin is None:
+            return []
+
+        return [
+            config
+            for config in plugin.manifest_files
+            if any(config.replace("*", "") in f for f in root_files)
+        ]
 
 
 class FrameworkDetector:
@@ -807,17 +1542,20 @@ class FrameworkDetector:
         "nuxt": ["nuxt", "nuxt.config"],
         "svelte": ["svelte", ".svelte"],
         # Rust frameworks
-        "axum": ["axum"],
-        "actix": ["actix-web"],
-        "warp": ["warp"],
-        "rocket": ["rocket"],
-        # Java frameworks
-        "spring": ["spring", "@SpringBootApplication"],
-        "springboot": ["spring-boot"],
+        **{
+            name: sorted(signature.crates)
+            for name, signature in RUST_FRAMEWORKS.items()
+        },
+        # JVM frameworks
+        **{
+            name: sorted(signature.groups)
+            for name, signature in JVM_FRAMEWORKS.items()
+        },
         # Go frameworks
-        "gin": ["gin-gonic"],
-        "echo": ["echo"],
-        "fiber": ["fiber"],
+        **{
+            name: sorted(signature.modules)
+            for name, signature in GO_FRAMEWORKS.items()
+        },
         # Build tools
         "webpack": ["webpack.config.js", "webpack"],
         "vite": ["vite.config.js", "vite"],
@@ -829,9 +1567,10 @@ class FrameworkDetector:
         project_path: Path,
         filesystem_info: FileSystemInfo,
         language_info: LanguageInfo,
+        files: Optional[ProjectFiles] = None,
     ) -> FrameworkInfo:
         """Detect frameworks used in the project."""
-        detected_frameworks: Dict[str, float] = {}
+        detected_frameworks = EvidenceScores()
 
         # Check package files
         framework_scores, dependencies = self._check_package_files(
@@ -839,25 +1578,34 @@ class FrameworkDetector:
         )
 
         # Check source code patterns
-        source_scores = self._check_source_patterns(project_path)
+        source_scores = self._check_source_patterns(project_path, files)
 
         # Combine scores
-        for framework, score in framework_scores.items():
-            detected_frameworks[framework] = (
-                detected_frameworks.get(framework, 0) + score
-            )
+        detected_frameworks.update_from(framework_scores)
+        detected_frameworks.update_from(source_scores)
 
-        for framework, score in source_scores.items():
-            detected_frameworks[framework] = (
-                detected_frameworks.get(framework, 0) + score
-            )
+        plugin = get_language_registry().get(language_info.primary)
+        plugin_details = (
+            plugin.framework_details(project_path) if plugin is not None else {}
+        )
 
         if not detected_frameworks:
-            return FrameworkInfo(confidence=0.0)
-
-        # Determine primary framework
-        primary = max(detected_frameworks.keys(), key=lambda k: detected_frameworks[k])
-        confidence = min(detected_frameworks[primary] * 10, 100.0)  # Scale to 0-100
+            return FrameworkInfo(confidence=0.0, details=plugin_details)
+
+        # Determine primary framework; infrastructure crates (serde, tokio, ...)
+        # are reported as secondary but never chosen as the primary framework
+        infrastructure = get_language_registry().infrastructure_frameworks()
+        candidates = [fw for fw in detected_frameworks if fw not in infrastructure]
+        primary = (
+            max(candidates, key=lambda k: detected_frameworks[k])
+            if candidates
+            else None
+        )
+        confidence = (
+            min(detected_frameworks[primary] * 10, 100.0)  # Scale to 0-100
+            if primary
+            else 0.0
+        )
 
         # Secondary frameworks
         secondary = [
@@ -866,35 +1614,20 @@ class FrameworkDetector:
             if fw != primary and score >= 3
         ]
 
-        details: Dict[str, Any] = {}
+        details: Dict[str, Any] = dict(plugin_details)
         if dependencies:
             details["dependencies"] = dependencies
         # Classify web frameworks for details bit used in tests
-        if primary in [
-            "django",
-            "flask",
-            "fastapi",
-            "starlette",
-            "react",
-            "vue",
-            "angular",
-            "express",
-            "nextjs",
-            "nuxt",
-            "svelte",
-            "axum",
-            "actix",
-            "warp",
-            "gin",
-            "echo",
-            "fiber",
-            "spring",
-            "springboot",
-        ]:
+        if primary in get_language_registry().web_frameworks():
             details["web_framework"] = True
 
         return FrameworkInfo(
-            primary=primary, secondary=secondary, confidence=confidence, details=details
+            primary=primary,
+            secondary=secondary,
+            confidence=confidence,
+            version=details.get("framework_versions", {}).get(primary),
+            details=details,
+            evidence=detected_frameworks.evidence,
         )
 
     def detect_framework(self, project_path: Path, language: str) -> FrameworkInfo:
@@ -909,28 +1642,7 @@ class FrameworkDetector:
         if hasattr(result, "primary") and result.primary:
             if not getattr(result, "details", None):
                 result.details = {}
-            web_frameworks = [
-                "django",
-                "flask",
-                "fastapi",
-                "starlette",
-                "react",
-                "vue",
-                "angular",
-                "express",
-                "nextjs",
-                "nuxt",
-                "svelte",
-                "axum",
-                "actix",
-                "warp",
-                "gin",
-                "echo",
-                "fiber",
-                "spring",
-                "springboot",
-            ]
-            if result.primary in web_frameworks:
+            if result.primary in get_language_registry().web_frameworks():
                 result.details["web_framework"] = True
 
         return result
@@ -944,203 +1656,54 @@ class FrameworkDetector:
 
     def _check_package_files(
         self, project_path: Path, primary_language: Optional[str]
-    ) -> tuple[Dict[str, float], List[str]]:
+    ) -> Tuple[EvidenceScores, List[str]]:
         """Check package files for framework dependencies."""
-        scores: Dict[str, float] = defaultdict(float)
+        scores = EvidenceScores()
         all_dependencies: List[str] = []
 
-        if primary_language == "python":
-            self._check_python_packages(project_path, scores)
-        elif primary_language in ["javascript", "typescript"]:
-            all_dependencies = self._check_npm_packages(project_path, scores)
-        elif primary_language == "rust":
-            self._check_cargo_packages(project_path, scores)
-        elif primary_language == "java":
-            self._check_java_packages(project_path, scores)
+        plugin = get_language_registry().get(primary_language)
+        if plugin is not None:
+            all_dependencies = plugin.score_frameworks(project_path, scores)
+            scores.attribute_rest(f"{plugin.name} plugin manifest scoring")
 
         return scores, all_dependencies
 
-    def _check_python_packages(
-        self, project_path: Path, scores: Dict[str, float]
+    def _score_cargo_dependencies(
+        self, dependencies: Iterable[CargoDependency], scores: Dict[str, float]
     ) -> None:
-        """Check Python package files for frameworks."""
-        # Check requirements.txt
-        req_file = project_path / "requirements.txt"
-        if req_file.exists():
-            try:
-                content = req_file.read_text()
-                if "django" in content.lower():
-                    scores["django"] += 8
-                if "flask" in content.lower():
-                    scores["flask"] += 8
-                if "fastapi" in content.lower():
-                    scores["fastapi"] += 8
-                if "starlette" in content.lower():
-                    scores["starlette"] += 6
-                # CLI tools
-                if "click" in content.lower():
-                    scores["cli_tool"] += 4
-                if "typer" in content.lower():
-                    scores["cli_tool"] += 4
-                if "argparse" in content.lower():
-                    scores["cli_tool"] += 2
-            except (OSError, UnicodeDecodeError):
-                pass
+        """Score Rust frameworks by exact crate name, weighted by features."""
+        for framework, score in score_rust_frameworks(dependencies).items():
+            scores[framework] += score
 
-        # Check pyproject.toml
-        pyproject_file = project_path / "pyproject.toml"
-        if pyproject_file.exists():
-            try:
-                with pyproject_file.open() as f:
-                    pyproject_data = toml.load(f)
+    def detect_cargo_frameworks(self, dependencies: List[CargoDependency]) -> List[str]:
+        """Return Rust frameworks used by a single crate, strongest first.
 
-                # Check dependencies
-                deps = {}
-                if (
-                    "project" in pyproject_data
-                    and "dependencies" in pyproject_data["project"]
-                ):
-                    for dep in pyproject_data["project"]["dependencies"]:
-                        dep_name = (
-                            dep.split("[")[0]
-                            .split(">=")[0]
-                            .split("==")[0]
-                            .split("~=")[0]
-                            .strip()
-                        )
-                        deps[dep_name.lower()] = True
-
-                if "django" in deps:
-                    scores["django"] += 8
-                if "flask" in deps:
-                    scores["flask"] += 8
-                if "fastapi" in deps:
-                    scores["fastapi"] += 8
-                if "starlette" in deps:
-                    scores["starlette"] += 6
-                if "click" in deps:
-                    scores["cli_tool"] += 4
-                if "typer" in deps:
-                    scores["cli_tool"] += 4
-
-                # Check tool.setuptools for script definitions
-                if (
-                    "project" in pyproject_data
-                    and "scripts" in pyproject_data["project"]
-                ):
-                    scores["cli_tool"] += 6
-
-            except (OSError, toml.TomlDecodeError, ValueError):
-                # Fallback to text search
-                try:
-                    content = pyproject_file.read_text()
-                    if "django" in content.lower():
-                        scores["django"] += 5
-                    if "flask" in content.lower():
-                        scores["flask"] += 5
-                    if "fastapi" in content.lower():
-                        scores["fastapi"] += 5
-                    if "click" in content.lower():
-                        scores["cli_tool"] += 3
-                except (OSError, UnicodeDecodeError):
-                    pass
-
-    def _check_npm_packages(
-        self, project_path: Path, scores: Dict[str, float]
-    ) -> List[str]:
-        """Check npm package.json for frameworks."""
-        package_file = project_path / "package.json"
-        deps_list: List[str] = []
-        if package_file.exists():
-            try:
-                with package_file.open() as f:
-                    package_data = json.load(f)
-
-                dependencies = {}
-                dependencies.update(package_data.get("dependencies", {}))
-                dependencies.update(package_data.get("devDependencies", {}))
-
-                for dep in dependencies:
-                    deps_list.append(dep)
-                    if "react" in dep:
-                        scores["react"] += 5
-                    elif "vue" in dep:
-                        scores["vue"] += 5
-                    elif "angular" in dep:
-                        scores["angular"] += 5
-                    elif "express" in dep:
-                        scores["express"] += 5
-                    elif "next" in dep:
-                        scores["nextjs"] += 5
-                    elif "nuxt" in dep:
-                        scores["nuxt"] += 5
-                    elif "svelte" in dep:
-                        scores["svelte"] += 5
-            except (OSError, json.JSONDecodeError):
-                pass
-        return deps_list
-
-    def _check_cargo_packages(
-        self, project_path: Path, scores: Dict[str, float]
-    ) -> None:
-        """Check Cargo.toml for Rust frameworks."""
-        cargo_file = project_path / "Cargo.toml"
-        if cargo_file.exists():
-            try:
-                with cargo_file.open() as f:
-                    cargo_data = toml.load(f)
-
-                dependencies = cargo_data.get("dependencies", {})
-
-                for dep in dependencies:
-                    if "axum" in dep:
-                        scores["axum"] += 10
-                    elif "actix" in dep:
-                        scores["actix"] += 10
-                    elif "warp" in dep:
-                        scores["warp"] += 10
-                    elif "rocket" in dep:
-                        scores["rocket"] += 10
-            except (OSError, toml.TomlDecodeError, ValueError):
-                pass
-
-    def _check_java_packages(
-        self, project_path: Path, scores: Dict[str, float]
-    ) -> None:
-        """Check Java build files for frameworks."""
-        # Check pom.xml (Maven)
-        pom_file = project_path / "pom.xml"
-        if pom_file.exists():
-            try:
-                content = pom_file.read_text()
-                if "spring" in content.lower():
-                    scores["spring"] += 5
-                if "spring-boot" in content.lower():
-                    scores["springboot"] += 5
-            except (OSError, UnicodeDecodeError):
-                pass
+        Application frameworks are listed before infrastructure crates.
+        """
+        scores: Dict[str, float] = defaultdict(float)
+        self._score_cargo_dependencies(dependencies, scores)
+        return sorted(
+            scores,
+            key=lambda fw: (RUST_FRAMEWORKS[fw].infrastructure, -scores[fw], fw),
+        )
 
-    def _check_source_patterns(self, project_path: Path) -> Dict[str, float]:
+    def _check_source_patterns(
+        self, project_path: Path, files: Optional[ProjectFiles] = None
+    ) -> EvidenceScores:
         """Check source code for framework patterns."""
-        scores: Dict[str, float] = defaultdict(float)
+        scores = EvidenceScores()
+        files = files or walk_project(project_path)
 
         # Check for Django patterns
         if (project_path / "manage.py").exists():
-            scores["django"] += 8
-
-        # Check for React patterns
-        for _jsx_file in project_path.rglob("*.jsx"):
-            scores["react"] += 2
-            break
+            scores.add("django", 8, "manage.py in project root")
 
-        for _tsx_file in project_path.rglob("*.tsx"):
-            scores["react"] += 2
-            break
-
-        # Check for Vue patterns
-        for _vue_file in project_path.rglob("*.vue"):
-            scores["vue"] += 2
-            break
+        # Check for React and Vue patterns
+        source_patterns = ((".jsx", "react"), (".tsx", "react"), (".vue", "vue"))
+        for suffix, framework in source_patterns:
+            matches = files.with_suffix(suffix)
+            if matches:
+                scores.add(framework, 2, f"{len(matches)} {suffix} files")
 
         return scores
 
@@ -1202,42 +1765,29 @@ class FrameworkDetector:
     def _get_framework_category(self, framework_name: str) -> str:
         """Get the category for a framework."""
         categories = {
-            # Web frameworks
-            "django": "web",
-            "flask": "web",
-            "fastapi": "web",
-            "starlette": "web",
-            "express": "web",
-            "nextjs": "web",
-            "nuxt": "web",
-            "axum": "web",
-            "actix": "web",
-            "warp": "web",
-            "rocket": "web",
-            "spring": "web",
-            "springboot": "web",
-            "gin": "web",
-            "echo": "web",
-            "fiber": "web",
-            # Frontend frameworks
-            "react": "frontend",
-            "vue": "frontend",
-            "angular": "frontend",
-            "svelte": "frontend",
-            # CLI tools
-            "cli_tool": "cli",
-            "click": "cli",
-            "typer": "cli",
             # Build tools
             "webpack": "build",
             "vite": "build",
             "rollup": "build",
         }
+        language_category = get_language_registry().framework_category(
+            framework_name.lower()
+        )
+        if language_category:
+            return language_category
         return categories.get(framework_name.lower(), "unknown")
 
 
 class DomainDetector:
-    """Detects application domain and specialized patterns."""
+    """Detects application domain and specialized patterns.
+
+    Keywords are matched against identifier tokens (type, function and module
+    names, route paths, directory and file names) of a sample of source files
+    in the project's primary and secondary languages. Each keyword found adds
+    a TF-IDF-style weight: sublinear in how often it occurs, and higher the
+    fewer domains list it. Keywords common in any codebase (``user``,
+    ``model``, ...) are damped so they cannot decide a domain on their own.
+    """
 
     DOMAIN_INDICATORS = {
         "ecommerce": ["cart", "payment", "order", "product", "checkout", "inventory"],
@@ -1255,97 +1805,265 @@ class DomainDetector:
         "social": ["user", "post", "comment", "follow", "message", "social"],
         "healthcare": ["patient", "medical", "health", "diagnosis", "treatment"],
         "education": ["course", "student", "teacher", "lesson", "grade", "learning"],
+        "iot": [
+            "sensor",
+            "device",
+            "mqtt",
+            "telemetry",
+            "firmware",
+            "actuator",
+            "gpio",
+            "modbus",
+            "zigbee",
+        ],
+        "observability": [
+            "metric",
+            "tracing",
+            "span",
+            "exporter",
+            "collector",
+            "scrape",
+            "otlp",
+            "alert",
+        ],
+        "compilers": [
+            "compiler",
+            "lexer",
+            "parser",
+            "ast",
+            "codegen",
+            "bytecode",
+            "opcode",
+            "grammar",
+            "interpreter",
+        ],
+        "blockchain": [
+            "blockchain",
+            "wallet",
+            "ledger",
+            "contract",
+            "ethereum",
+            "solidity",
+            "web3",
+            "nft",
+            "consensus",
+        ],
+        "search": [
+            "search",
+            "index",
+            "indexer",
+            "inverted",
+            "query",
+            "ranking",
+            "relevance",
+            "tokenizer",
+            "lucene",
+            "elasticsearch",
+            "bm25",
+        ],
     }
 
+    # Keywords that show up in most codebases regardless of domain
+    GENERIC_KEYWORDS = {
+        "user",
+        "order",
+        "model",
+        "message",
+        "post",
+        "comment",
+        "follow",
+        "level",
+        "score",
+        "engine",
+        "stream",
+        "media",
+        "search",
+        "index",
+        "query",
+        "parser",
+        "span",
+        "health",
+        "monitoring",
+        "deployment",
+    }
+    GENERIC_KEYWORD_WEIGHT = 0.2
+    # A domain needs at least this score (one specific keyword is ~2.7)
+    MIN_DOMAIN_SCORE = 2.0
+    MAX_SAMPLED_FILES = 60
+    # Locations quoted per keyword in the evidence trail
+    MAX_EVIDENCE_LOCATIONS = 3
+
     def detect(
         self,
         project_path: Path,
         filesystem_info: FileSystemInfo,
         language_info: LanguageInfo,
         framework_info: FrameworkInfo,
+        files: Optional[ProjectFiles] = None,
+        file_index: Optional[FileIndex] = None,
     ) -> DomainInfo:
-        """Detect application domain."""
-        domain_scores: Dict[str, float] = defaultdict(float)
-        found_indicators: Dict[str, List[str]] = defaultdict(list)
-
-        # Check directory names
+        """Detect application domain.
+
+        Keyword counts of source files are kept in the ``file_index``, so
+        only files that changed since the cached analysis are read.
+        """
+        files = files or walk_project(project_path)
+        weights = self._keyword_weights()
+        variants = keyword_variants(weights)
+        counts: Dict[str, int] = defaultdict(int)
+        locations: Dict[str, List[str]] = defaultdict(list)
+
+        def add(keyword: str, count: int, location: str) -> None:
+            counts[keyword] += count
+            if location not in locations[keyword]:
+                locations[keyword].append(location)
+
+        def collect(tokens: Iterable[str], location: str) -> None:
+            for token in tokens:
+                keyword = variants.get(token)
+                if keyword is not None:
+                    add(keyword, 1, location)
+
+        read_text = files.read_text
+
+        def source_keywords(path: Path) -> Dict[str, int]:
+            content = read_text(path)
+            keywords: Dict[str, int] = defaultdict(int)
+            for token in source_tokens(content) if content is not None else ():
+                keyword = variants.get(token)
+                if keyword is not None:
+                    keywords[keyword] += 1
+            return dict(keywords)
+
+        # Directory and root file names
         for dir_name in filesystem_info.directory_structure:
-            self._check_indicators(dir_name.lower(), domain_scores, found_indicators)
-
-        # Check file names
+            collect(split_identifier(dir_name), f"{dir_name}/")
         for file_name in filesystem_info.root_files:
-            self._check_indicators(file_name.lower(), domain_scores, found_indicators)
-
-        # Check source files content (sample)
-        self._check_source_content(project_path, domain_scores, found_indicators)
-
+            collect(path_tokens(file_name), file_name)
+
+        # Module paths and declared names of sampled source files
+        sample = self._sample_source_files(project_path, language_info, files)
+        for source_file in sample:
+            relative = source_file.relative_to(project_path).as_posix()
+            collect(path_tokens(relative), relative)
+            keywords = file_fact(file_index, source_file, "domain", source_keywords)
+            for keyword, count in keywords.items():
+                add(keyword, count, relative)
+
+        domain_scores = self._score_domains(counts, locations, weights)
         if not domain_scores:
             return DomainInfo(confidence=0.0)
 
         # Determine primary domain
         primary_domain = max(domain_scores.keys(), key=lambda k: domain_scores[k])
+        if domain_scores[primary_domain] < self.MIN_DOMAIN_SCORE:
+            return DomainInfo(confidence=0.0, evidence=domain_scores.evidence)
         confidence = min(domain_scores[primary_domain] * 10, 100.0)
 
         return DomainInfo(
             domain=primary_domain,
             confidence=confidence,
-            indicators=found_indicators[primary_domain],
+            indicators=sorted(
+                (kw for kw in self.DOMAIN_INDICATORS[primary_domain] if counts[kw]),
+                key=lambda kw: -(1 + math.log(counts[kw])) * weights[kw],
+            ),
+            evidence=domain_scores.evidence,
         )
 
-    def _check_indicators(
-        self, text: str, scores: Dict[str, float], indicators: Dict[str, List[str]]
-    ) -> None:
-        """Check text for domain indicators."""
+    def _keyword_weights(self) -> Dict[str, float]:
+        """Inverse domain frequency of every keyword, damped for generic ones."""
+        domain_count = len(self.DOMAIN_INDICATORS)
+        listed_in: Dict[str, int] = defaultdict(int)
+        for keywords in self.DOMAIN_INDICATORS.values():
+            for keyword in set(keywords):
+                listed_in[keyword] += 1
+
+        weights = {}
+        for keyword, domains in listed_in.items():
+            weight = math.log(1 + domain_count / domains)
+            if keyword in self.GENERIC_KEYWORDS:
+                weight *= self.GENERIC_KEYWORD_WEIGHT
+            weights[keyword] = weight
+        return weights
+
+    def _score_domains(
+        self,
+        counts: Dict[str, int],
+        locations: Dict[str, List[str]],
+        weights: Dict[str, float],
+    ) -> EvidenceScores:
+        """Sum of sublinear keyword frequency times keyword weight per domain."""
+        scores = EvidenceScores()
         for domain, keywords in self.DOMAIN_INDICATORS.items():
             for keyword in keywords:
-                if keyword in text:
-                    scores[domain] += 1
-                    indicators[domain].append(keyword)
-
-    def _check_source_content(
-        self,
-        project_path: Path,
-        scores: Dict[str, float],
-        indicators: Dict[str, List[str]],
-    ) -> None:
-        """Sample source files for domain indicators."""
-        checked_files = 0
-        max_files = 10  # Limit for performance
+                count = counts.get(keyword, 0)
+                if not count:
+                    continue
+                places = locations[keyword]
+                shown = ", ".join(places[: self.MAX_EVIDENCE_LOCATIONS])
+                if len(places) > self.MAX_EVIDENCE_LOCATIONS:
+                    shown += f" and {len(places) - self.MAX_EVIDENCE_LOCATIONS} more"
+                scores.add(
+                    domain,
+                    (1 + math.log(count)) * weights[keyword],
+                    f"`{keyword}` x{count} in {shown}",
+                )
+        return scores
 
-        for source_file in project_path.rglob("*.py"):
-            if checked_files >= max_files:
-                break
-
-            try:
-                content = source_file.read_text(
-                    encoding="utf-8", errors="ignore"
-                ).lower()
-                self._check_indicators(content, scores, indicators)
-                checked_files += 1
-            except (OSError, UnicodeDecodeError):
-                pass
+    def _sample_source_files(
+        self, project_path: Path, language_info: LanguageInfo, files: ProjectFiles
+    ) -> List[Path]:
+        """Source files of the detected languages, taken in turn per language."""
+        registry = get_language_registry()
+        groups = []
+        for language in [language_info.primary, *language_info.secondary]:
+            plugin = registry.get(language)
+            if plugin is not None and plugin.get_source_extensions():
+                groups.append(files.with_suffix(*plugin.get_source_extensions()))
+        if not groups:
+            groups = [files.with_suffix(*registry.source_extensions())]
+
+        sample: List[Path] = []
+        seen: Set[Path] = set()
+        for batch in zip_longest(*groups):
+            for path in batch:
+                if path is not None and path not in seen:
+                    seen.add(path)
+                    sample.append(path)
+        return sample[: self.MAX_SAMPLED_FILES]
 
 
 class ComplexityAssessor:
     """Assesses project complexity level."""
 
+    # (threshold, points) tables for measured code, highest threshold first
+    LOC_POINTS = ((100_000, 4), (20_000, 3), (5_000, 2), (1_000, 1))
+    PATHS_PER_FUNCTION_POINTS = ((8, 3), (5, 2), (3, 1))
+    FAN_IN_POINTS = ((15, 2), (5, 1))
+    PUBLIC_API_POINTS = ((500, 2), (100, 1))
+
     def assess(
         self,
         filesystem_info: FileSystemInfo,
         language_info: LanguageInfo,
         framework_info: FrameworkInfo,
         dev_environment: DevelopmentEnvironment,
+        metrics: Optional[ComplexityMetrics] = None,
     ) -> ComplexityLevel:
-        """Assess project complexity."""
+        """Assess project complexity.
+
+        With code ``metrics`` (deep analysis) the measured code replaces file
+        and directory counts as the size signal, and the points each metric
+        added are recorded in ``metrics.factors``.
+        """
         complexity_score = 0
 
-        # File count factor (slightly more aggressive for >100 files)
-        if filesystem_info.total_files > 1000:
-            complexity_score += 4
-        elif filesystem_info.total_files > 100:
-            complexity_score += 3
-        elif filesystem_info.total_files > 50:
-            complexity_score += 1
+        if metrics is not None:
+            factors = self._metric_factors(metrics)
+            metrics.factors = [f"{factor} (+{points})" for factor, points in factors]
+            complexity_score += sum(points for _, points in factors)
+        else:
+            complexity_score += self._size_score(filesystem_info)
 
         # Language diversity factor
         if len(language_info.secondary) > 2:
@@ -1354,7 +2072,7 @@ class ComplexityAssessor:
             complexity_score += 1
 
         # Framework complexity
-        if framework_info.primary in ["django", "spring", "angular"]:
+        if framework_info.primary in ["django", "spring", "springboot", "angular"]:
             complexity_score += 2
         elif framework_info.primary in ["react", "vue", "flask"]:
             complexity_score += 1
@@ -1373,11 +2091,32 @@ class ComplexityAssessor:
         if len(dev_environment.containerization) > 0:
             complexity_score += 2
 
+        # Determine complexity level
+        if complexity_score >= 8:
+            return ComplexityLevel.ENTERPRISE
+        if complexity_score >= 4:
+            return ComplexityLevel.COMPLEX
+        if complexity_score >= 2:
+            return ComplexityLevel.MODERATE
+        return ComplexityLevel.SIMPLE
+
+    def _size_score(self, filesystem_info: FileSystemInfo) -> int:
+        """Project size estimated from file and directory counts."""
+        score = 0
+
+        # File count factor (slightly more aggressive for >100 files)
+        if filesystem_info.total_files > 1000:
+            score += 4
+        elif filesystem_info.total_files > 100:
+            score += 3
+        elif filesystem_info.total_files > 50:
+            score += 1
+
         # Directory structure complexity
         if len(filesystem_info.directory_structure) > 20:
-            complexity_score += 2
+            score += 2
         elif len(filesystem_info.directory_structure) > 10:
-            complexity_score += 1
+            score += 1
 
         # Nested directory depth heuristic for large projects
         try:
@@ -1385,18 +2124,36 @@ class ComplexityAssessor:
                 isinstance(v, dict) and v.get("subdirs")
                 for v in filesystem_info.directory_structure.values()
             ):
-                complexity_score += 1
+                score += 1
         except Exception:
             pass
 
-        # Determine complexity level
-        if complexity_score >= 8:
-            return ComplexityLevel.ENTERPRISE
-        if complexity_score >= 4:
-            return ComplexityLevel.COMPLEX
-        if complexity_score >= 2:
-            return ComplexityLevel.MODERATE
-        return ComplexityLevel.SIMPLE
+        return score
+
+    def _metric_factors(self, metrics: ComplexityMetrics) -> List[Tuple[str, int]]:
+        """Points for code size, branching, coupling and public API surface."""
+        fan_in = max((module.fan_in for module in metrics.most_depended_on), default=0)
+        measured = [
+            (metrics.loc, self.LOC_POINTS, f"{metrics.loc:,} lines of code"),
+            (
+                metrics.complexity_per_function,
+                self.PATHS_PER_FUNCTION_POINTS,
+                f"{metrics.complexity_per_function:.1f} paths per function",
+            ),
+            (fan_in, self.FAN_IN_POINTS, f"a module imported by {fan_in} others"),
+            (
+                metrics.public_api,
+                self.PUBLIC_API_POINTS,
+                f"{metrics.public_api} public API items",
+            ),
+        ]
+
+        factors = []
+        for value, table, factor in measured:
+            points = next((p for threshold, p in table if value >= threshold), 0)
+            if points:
+                factors.append((factor, points))
+        return factors
 
 
 class ArchitectureDetector:
@@ -1462,7 +2219,12 @@ class ArchitectureDetector:
             )
         )
 
-        return mvc_count >= 2 or framework_info.primary in ["django", "rails", "spring"]
+        return mvc_count >= 2 or framework_info.primary in [
+            "django",
+            "rails",
+            "spring",
+            "springboot",
+        ]
 
     def _is_domain_driven(self, filesystem_info: FileSystemInfo) -> bool:
         """Check for domain-driven design patterns."""
@@ -8,7 +8,6 @@ import asyncio
 
 from datetime import datetime
 from pathlib import Path
-from string import Template as StringTemplate
 from typing import Any, Dict, List, Optional
 
 import aiofiles
@@ -19,6 +18,7 @@ from claude_builder.core.models import (
     ProjectAnalysis,
     TemplateRequest,
 )
+from claude_builder.core.template_engine import render_template_string
 from claude_builder.core.template_management.network.async_template_downloader import (
     AsyncTemplateDownloader,
 )
@@ -338,9 +338,10 @@ class AsyncDocumentGenerator:
                 None, self._get_template_content, template_name
             )
 
-            # Render template
-            template = StringTemplate(template_content)
-            rendered_content = template.safe_substitute(**context)
+            # Render through the shared sandbox; missing values render empty
+            rendered_content = render_template_string(
+                template_content, context, strict=False
+            )
 
             # Cache successful result
             if self.enable_caching:
@@ -366,7 +367,7 @@ class AsyncDocumentGenerator:
             "development_workflow_template": self._get_workflow_template(),
         }
 
-        return templates.get(template_name, "# Template not found: $template_name")
+        return templates.get(template_name, f"# Template not found: {template_name}")
 
     async def _write_file_async(self, file_path: Path, content: str) -> None:
         """Write file asynchronously."""
@@ -476,22 +477,22 @@ class AsyncDocumentGenerator:
     def _get_claude_md_template(self) -> str:
         return """# CLAUDE.md
 
-## Project: $project_name
+## Project: {{ project_name }}
 
 ### Project Type
-$project_type
+{{ project_type }}
 
 ### Languages
-$languages
+{{ languages }}
 
 ### Frameworks
-$frameworks
+{{ frameworks }}
 
 ### Architecture
-$architecture
+{{ architecture }}
 
 ### Complexity Level
-$complexity
+{{ complexity }}
 
 ## Development Guidelines
 
@@ -499,35 +500,35 @@ This project uses Claude Builder for optimal Claude Code integration.
 """
 
     def _get_readme_template(self) -> str:
-        return """# $project_name
+        return """# {{ project_name }}
 
 ## Setup Instructions
 
-$setup_instructions
+{{ setup_instructions }}
 
 ## Development Workflow
 
-$development_workflow
+{{ development_workflow }}
 """
 
     def _get_agents_md_template(self) -> str:
         return """# AGENTS.md - Claude Code Agent Configuration
 
-## Project: $project_name
+## Project: {{ project_name }}
 
 ### Recommended Agents
-$recommended_agents
+{{ recommended_agents }}
 
 ### Workflow Patterns
-$workflow_patterns
+{{ workflow_patterns }}
 
 ### Coordination Strategy
-$coordination_strategy
+{{ coordination_strategy }}
 """
 
     def _get_gitignore_template(self) -> str:
         return """# Language-specific ignores
-# Generated based on: $languages, $frameworks
+# Generated based on: {{ languages }}, {{ frameworks }}
 
 # Python
 __pycache__/
@@ -554,16 +555,16 @@ requires = ["setuptools>=61.0"]
 build-backend = "setuptools.build_meta"
 
 [project]
-name = "$project_name"
+name = "{{ project_name }}"
 version = "0.1.0"
 description = "Project generated with Claude Builder"
 """
 
     def _get_contributing_template(self) -> str:
-        return """# Contributing to $project_name
+        return """# Contributing to {{ project_name }}
 
 ## Testing Strategy
-$testing_strategy
+{{ testing_strategy }}
 
 ## Development Process
 1. Fork the repository
@@ -574,11 +575,11 @@ $testing_strategy
 """
 
     def _get_workflow_template(self) -> str:
-        return """# Development Workflow for $project_name
+        return """# Development Workflow for {{ project_name }}
 
-## Complexity Level: $complexity
+## Complexity Level: {{ complexity }}
 
-$agent_workflow
+{{ agent_workflow }}
 """
 
     async def batch_generate_async(
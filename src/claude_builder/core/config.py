@@ -6,6 +6,7 @@ from dataclasses import asdict, dataclass, field
 from pathlib import Path
 from typing import Any, Callable, ClassVar, Dict, List, Optional, Tuple, Union
 
+from claude_builder.analysis.detection_rules import collect_rule_definitions
 from claude_builder.core.models import ClaudeMentionPolicy, GitIntegrationMode
 from claude_builder.utils.exceptions import ConfigError
 
@@ -52,9 +53,14 @@ class AnalysisConfig:
     confidence_threshold: int = 80
     overrides: Dict[str, Optional[str]] = field(default_factory=dict)
     custom_detection_rules: Dict[str, Dict[str, Any]] = field(default_factory=dict)
+    # YAML/TOML/JSON files with more rules (see analysis.detection_rules)
+    custom_rule_files: List[str] = field(default_factory=list)
     max_file_size: int = 10485760  # 10MB
     max_files_to_analyze: int = 10000
+    respect_gitignore: bool = True  # Skip files matched by .gitignore
+    follow_symlinks: bool = False  # Only ever followed inside the project
     deep_analysis: bool = False  # More thorough but slower analysis
+    git_history: bool = True  # Commit conventions, hotspots and release cadence
 
 
 @dataclass
@@ -110,6 +116,8 @@ class GitIntegrationConfig:
     mode: GitIntegrationMode = GitIntegrationMode.NO_INTEGRATION
     claude_mention_policy: ClaudeMentionPolicy = ClaudeMentionPolicy.MINIMAL
     backup_before_changes: bool = True
+    # commit-msg hook rejecting subjects that break the convention in history
+    enforce_commit_convention: bool = False
     files_to_exclude: List[str] = field(
         default_factory=lambda: ["CLAUDE.md", "AGENTS.md", ".claude/", "docs/claude-*"]
     )
@@ -277,15 +285,17 @@ class ConfigManager:
             config = Config()
 
             # Load from config file if specified or found
-            if config_file:
-                file_config = self._loader.load_config_file(config_file)
+            # (otherwise look for config files in project directory)
+            config_path = config_file or self._loader.find_config_file(project_path)
+            file_config: Dict[str, Any] = {}
+            if config_path:
+                file_config = self._loader.load_config_file(config_path)
                 config = self._merge_configs(config, file_config)
-            else:
-                # Look for config files in project directory
-                found_config = self._loader.find_config_file(project_path)
-                if found_config:
-                    file_config = self._loader.load_config_file(found_config)
-                    config = self._merge_configs(config, file_config)
+
+            # Detection rules from the global and project config, with provenance
+            config.analysis.custom_detection_rules = self._load_detection_rules(
+                project_path, config_path, file_config
+            )
 
             # Apply CLI overrides
             if cli_overrides:
@@ -343,6 +353,33 @@ class ConfigManager:
         # Convert back to Config object
         return self._dict_to_config(merged)
 
+    def _load_detection_rules(
+        self,
+        project_path: Path,
+        config_path: Optional[Path],
+        file_config: Dict[str, Any],
+    ) -> Dict[str, Dict[str, Any]]:
+        """Collect custom detection rules; project rules replace global ones."""
+        rules: Dict[str, Dict[str, Any]] = {}
+        global_path = self._loader.find_global_config_file()
+        if global_path is not None:
+            try:
+                global_config = self._loader.load_config_file(global_path)
+            except ConfigError:
+                global_config = {}
+            rules.update(
+                collect_rule_definitions(
+                    global_config.get("analysis"), global_path, project_path
+                )
+            )
+        if config_path is not None:
+            rules.update(
+                collect_rule_definitions(
+                    file_config.get("analysis"), config_path, project_path
+                )
+            )
+        return rules
+
     def _apply_cli_overrides(
         self, config: Config, cli_overrides: Dict[str, Any]
     ) -> Config:
@@ -356,6 +393,7 @@ class ConfigManager:
             "git_track": ("git_integration", "mode"),
             "claude_mentions": ("git_integration", "claude_mention_policy"),
             "no_git": ("git_integration", "enabled"),
+            "no_cache": ("analysis", "cache_enabled"),
             "backup_existing": ("output", "backup_existing"),
             "output_format": ("output", "format"),
         }
@@ -380,6 +418,9 @@ class ConfigManager:
                     config_dict["git_integration"]["mode"] = "track_generated"
                 elif cli_key == "no_git" and value:
                     config_dict["git_integration"]["enabled"] = False
+                elif cli_key == "no_cache":
+                    if value:
+                        config_dict["analysis"]["cache_enabled"] = False
                 elif cli_key == "claude_mentions":
                     config_dict["git_integration"]["claude_mention_policy"] = value
                 else:
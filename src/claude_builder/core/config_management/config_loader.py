@@ -106,6 +106,14 @@ class ConfigLoader:
             return str(obj)
         return obj
 
+    def find_global_config_file(self) -> Optional[Path]:
+        """Find the first global configuration file that exists."""
+        for config_name in self.GLOBAL_CONFIG_NAMES:
+            config_path = Path(config_name).expanduser()
+            if config_path.exists():
+                return config_path
+        return None
+
     def load_global_config(self) -> Optional[Dict[str, Any]]:
         """Load global configuration from user's home directory."""
         for config_name in self.GLOBAL_CONFIG_NAMES:
@@ -2,6 +2,7 @@
 
 from typing import Any, List, Optional
 
+from claude_builder.analysis.detection_rules import parse_detection_rules
 from claude_builder.utils.exceptions import ConfigError
 
 
@@ -54,6 +55,9 @@ class ConfigValidator:
         if analysis.max_file_size < 1024:
             raise ConfigError(MAX_FILE_SIZE_ERROR)
 
+        # Raises ConfigError naming the rule and the problem
+        parse_detection_rules(analysis.custom_detection_rules)
+
     def _validate_template_config(self, templates: Any) -> None:
         """Validate template configuration."""
         if templates.template_cache_ttl < 0:
@@ -0,0 +1,209 @@
+"""Drift detection: compare fresh generation output with the files on disk.
+
+Instruction files go stale when the project changes underneath them: a new
+framework, a different test command, a deleted agent file. :func:`detect_drift`
+compares freshly generated artifacts with what is on disk, managed region by
+managed region, so a report can name the sections a regeneration would change.
+Agent files left over from an earlier generation that the generator no longer
+produces are reported as orphaned.
+
+Hand edits alone are not drift. When the baseline kept in
+``.claude-builder/generated/`` shows the generator still produces what it
+produced last time, a section that differs on disk was edited by a person and
+regeneration would keep it.
+"""
+
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Dict, Iterable, List, Optional
+
+from claude_builder.core.managed_regions import (
+    Region,
+    baseline_path,
+    generated_files,
+    is_managed,
+    mark_regions,
+    merged_list_key,
+    parse_regions,
+    prepare_managed_file,
+    split_frontmatter,
+)
+from claude_builder.core.models import GeneratedArtifact
+
+
+FRONT_MATTER_SECTION = "front matter"
+WHOLE_FILE_SECTION = "file"
+
+# File statuses
+CURRENT = "current"
+STALE = "stale"
+MISSING = "missing"
+ORPHANED = "orphaned"
+
+# Section changes
+CHANGED = "changed"
+ADDED = "added"
+REMOVED = "removed"
+
+
+@dataclass
+class SectionDrift:
+    """A section whose regenerated content differs from the file on disk."""
+
+    section: str
+    change: str
+
+
+@dataclass
+class FileDrift:
+    """Drift status of one generated file."""
+
+    path: str
+    status: str
+    sections: List[SectionDrift] = field(default_factory=list)
+
+    def to_dict(self) -> Dict[str, Any]:
+        return {
+            "path": self.path,
+            "status": self.status,
+            "sections": [
+                {"section": s.section, "change": s.change} for s in self.sections
+            ],
+        }
+
+
+@dataclass
+class DriftReport:
+    """Drift status of every file the generator would write."""
+
+    files: List[FileDrift] = field(default_factory=list)
+
+    @property
+    def stale_files(self) -> List[FileDrift]:
+        return [f for f in self.files if f.status != CURRENT]
+
+    @property
+    def has_drift(self) -> bool:
+        return bool(self.stale_files)
+
+    def to_dict(self) -> Dict[str, Any]:
+        return {
+            "drift": self.has_drift,
+            "checked": len(self.files),
+            "stale": len(self.stale_files),
+            "files": [f.to_dict() for f in self.files],
+        }
+
+
+def _is_stale(current: Optional[str], generated: str, base: Optional[str]) -> bool:
+    """Whether regeneration would change ``current``.
+
+    A difference that the baseline attributes to a hand edit is not drift.
+    """
+    return current != generated and (base is None or base != generated)
+
+
+def _region_bodies(body: str) -> Dict[str, str]:
+    return {c.section: c.body for c in parse_regions(body) if isinstance(c, Region)}
+
+
+def _section_drift(
+    existing: str, generated: str, baseline: Optional[str]
+) -> List[SectionDrift]:
+    new_front, new_body = split_frontmatter(generated)
+    old_front, old_body = split_frontmatter(existing)
+    base_front, base_body = split_frontmatter(baseline or "")
+    new_regions = _region_bodies(new_body)
+    old_regions = _region_bodies(old_body)
+    if not new_regions or not old_regions:
+        if _is_stale(existing, generated, baseline):
+            return [SectionDrift(WHOLE_FILE_SECTION, CHANGED)]
+        return []
+
+    base_regions = _region_bodies(base_body) if baseline is not None else {}
+    drift: List[SectionDrift] = []
+    if _is_stale(
+        old_front, new_front, base_front if baseline is not None else None
+    ):
+        drift.append(SectionDrift(FRONT_MATTER_SECTION, CHANGED))
+    for section, body in new_regions.items():
+        if section not in old_regions:
+            drift.append(SectionDrift(section, ADDED))
+        elif _is_stale(old_regions[section], body, base_regions.get(section)):
+            drift.append(SectionDrift(section, CHANGED))
+    for section, body in old_regions.items():
+        # Regeneration keeps removed sections only when they were edited
+        base = base_regions.get(section)
+        if section not in new_regions and (base is None or base == body):
+            drift.append(SectionDrift(section, REMOVED))
+    return drift
+
+
+def check_artifact(output_dir: Path, artifact: GeneratedArtifact) -> FileDrift:
+    """Compare one generated artifact with its file under ``output_dir``."""
+    target = output_dir / artifact.path
+    if not target.exists():
+        return FileDrift(artifact.path, MISSING)
+
+    existing = target.read_text(encoding="utf-8")
+    generated = artifact.content
+    if existing == generated:
+        # Written verbatim, e.g. before managed regions existed
+        return FileDrift(artifact.path, CURRENT)
+    list_key = merged_list_key(artifact.path)
+    if list_key is not None:
+        merge = prepare_managed_file(output_dir, artifact.path, generated)
+        if merge.content == existing:
+            return FileDrift(artifact.path, CURRENT)
+        return FileDrift(artifact.path, STALE, [SectionDrift(list_key, CHANGED)])
+    baseline = None
+    if is_managed(artifact.path):
+        generated = mark_regions(generated)
+        stored = baseline_path(output_dir, artifact.path)
+        baseline = stored.read_text(encoding="utf-8") if stored.exists() else None
+
+    sections = _section_drift(existing, generated, baseline)
+    return FileDrift(artifact.path, STALE if sections else CURRENT, sections)
+
+
+def orphaned_files(
+    output_dir: Path, generated: Iterable[str], agents_dir: str
+) -> List[FileDrift]:
+    """Earlier generated files under ``agents_dir`` that are no longer produced.
+
+    Only files still on disk with a stored baseline count, so files the user
+    added to the directory are never reported.
+    """
+    directory = Path(agents_dir)
+    if directory.is_absolute():
+        try:
+            directory = directory.relative_to(output_dir)
+        except ValueError:
+            return []
+    prefix = directory.as_posix().strip("/")
+    if prefix in ("", "."):
+        return []
+    current = set(generated)
+    return [
+        FileDrift(path, ORPHANED)
+        for path in generated_files(output_dir)
+        if path.startswith(f"{prefix}/")
+        and path not in current
+        and (output_dir / path).exists()
+    ]
+
+
+def detect_drift(
+    output_dir: Path,
+    artifacts: Iterable[GeneratedArtifact],
+    *,
+    agents_dir: str = "",
+) -> DriftReport:
+    """Check every artifact the generator produced against ``output_dir``.
+
+    With ``agents_dir`` the report also lists orphaned agent files there.
+    """
+    files = [check_artifact(output_dir, a) for a in artifacts]
+    if agents_dir:
+        files.extend(orphaned_files(output_dir, [f.path for f in files], agents_dir))
+    return DriftReport(files)
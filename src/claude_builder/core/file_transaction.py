@@ -0,0 +1,142 @@
+"""Write a set of generated files all together or not at all.
+
+Generation writes CLAUDE.md, AGENTS.md and one file per agent. A
+:class:`FileTransaction` stages every file in a temporary directory next to
+the output, then moves each one into place with :func:`os.replace` (atomic on
+one file system). If staging or any move fails, the files already moved are
+put back as they were, so a failure never leaves a half-updated environment.
+
+In a git repository each committed transaction is journaled with
+:class:`~claude_builder.utils.git.GitBackupManager`, so
+``claude-builder git rollback <id>`` can restore the files it replaced and
+remove the ones it created. Only the most recent journal entries are kept.
+"""
+
+import logging
+import os
+import shutil
+import tempfile
+
+from pathlib import Path
+from types import TracebackType
+from typing import Dict, List, Optional, Type
+
+from claude_builder.utils.exceptions import GitError
+from claude_builder.utils.git import GENERATION_KIND, GitBackupManager
+
+
+STAGING_PARENT = Path(".claude-builder")
+STAGING_PREFIX = "staging-"
+
+logger = logging.getLogger(__name__)
+
+
+class FileTransaction:
+    """Stage files under ``root`` and commit them together.
+
+    Used as a context manager, the transaction commits when the block
+    finishes and discards the staged files when it raises::
+
+        with FileTransaction(output_dir) as transaction:
+            transaction.stage("CLAUDE.md", content)
+    """
+
+    def __init__(self, root: Path, *, journal: bool = True) -> None:
+        self.root = Path(root)
+        self.journal = journal
+        self.journal_id: Optional[str] = None
+        self._staged: Dict[str, Path] = {}
+        self._staging_dir: Optional[Path] = None
+
+    @property
+    def paths(self) -> List[str]:
+        """Relative paths staged so far, in staging order."""
+        return list(self._staged)
+
+    def _staging(self) -> Path:
+        if self._staging_dir is None:
+            parent = self.root / STAGING_PARENT
+            parent.mkdir(parents=True, exist_ok=True)
+            # Same file system as the targets, so os.replace stays atomic
+            self._staging_dir = Path(
+                tempfile.mkdtemp(prefix=STAGING_PREFIX, dir=parent)
+            )
+        return self._staging_dir
+
+    def stage(self, path: str, content: str) -> None:
+        """Write ``content`` for ``root/path`` to the staging directory."""
+        staged = self._staging() / "files" / path
+        staged.parent.mkdir(parents=True, exist_ok=True)
+        with staged.open("w", encoding="utf-8") as f:
+            f.write(content)
+        self._staged[path] = staged
+
+    def commit(self) -> Optional[str]:
+        """Move every staged file into place, or none of them.
+
+        Returns the journal entry id when the transaction was journaled.
+        """
+        previous: Dict[str, Optional[Path]] = {}
+        try:
+            for path, staged in self._staged.items():
+                target = self.root / path
+                copy = None
+                if target.exists():
+                    copy = self._staging() / "previous" / path
+                    copy.parent.mkdir(parents=True, exist_ok=True)
+                    shutil.copy2(target, copy)
+                previous[path] = copy
+                target.parent.mkdir(parents=True, exist_ok=True)
+                os.replace(staged, target)
+        except BaseException:
+            self._restore(previous)
+            self.discard()
+            raise
+
+        try:
+            if self.journal and (self.root / ".git").is_dir() and previous:
+                backups = GitBackupManager()
+                self.journal_id = backups.record_generation(self.root, previous)
+                backups.cleanup_old_backups(self.root, kind=GENERATION_KIND)
+        except GitError as e:
+            # The files are in place; only the undo record is missing
+            logger.warning("Could not journal generated files: %s", e)
+        finally:
+            self.discard()
+        return self.journal_id
+
+    def _restore(self, previous: Dict[str, Optional[Path]]) -> None:
+        """Put back the files a failed commit already replaced."""
+        for path, copy in previous.items():
+            target = self.root / path
+            try:
+                if copy is not None:
+                    os.replace(copy, target)
+                elif target.exists():
+                    target.unlink()
+            except OSError as e:
+                logger.error("Could not restore %s after a failed write: %s", path, e)
+
+    def discard(self) -> None:
+        """Remove the staging directory and forget the staged files."""
+        if self._staging_dir is not None:
+            shutil.rmtree(self._staging_dir, ignore_errors=True)
+            self._staging_dir = None
+            parent = self.root / STAGING_PARENT
+            if parent.is_dir() and not any(parent.iterdir()):
+                parent.rmdir()
+        self._staged = {}
+
+    def __enter__(self) -> "FileTransaction":
+        return self
+
+    def __exit__(
+        self,
+        exc_type: Optional[Type[BaseException]],
+        exc: Optional[BaseException],
+        traceback: Optional[TracebackType],
+    ) -> None:
+        if exc_type is None:
+            self.commit()
+        else:
+            self.discard()
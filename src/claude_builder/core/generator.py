@@ -16,19 +16,48 @@ except Exception:  # pragma: no cover - fallback when not available
         pass
 
 
+from claude_builder.analysis.code_metrics import summarize_code_metrics
+from claude_builder.analysis.gomod import summarize_go_workspace
+from claude_builder.analysis.jvm import summarize_jvm_details
+from claude_builder.analysis.monorepo import summarize_monorepo
+from claude_builder.analysis.monorepo_docs import generate_package_docs
+from claude_builder.analysis.rust_source import (
+    render_module_tree,
+    summarize_rust_source,
+)
 from claude_builder.core.agents import UniversalAgentSystem
+from claude_builder.core.language_plugins import get_language_registry
 from claude_builder.core.models import (
+    ComplexityMetrics,
     GeneratedContent,
+    GitHistoryInfo,
+    GoWorkspaceInfo,
+    MonorepoInfo,
     ProjectAnalysis,
+    RustSourceInfo,
     TemplateRequest,
 )
+from claude_builder.core.template_engine import render_template_string
 from claude_builder.core.template_manager import CoreTemplateManager
 from claude_builder.utils.exceptions import GenerationError
+from claude_builder.utils.git import summarize_git_history
 
 
 FAILED_TO_GENERATE_DOCUMENTATION = "Failed to generate documentation"
 FAILED_TO_LOAD_TEMPLATE = "Failed to load template"
 
+# Template variable -> LanguageInfo.version_info key
+TOOLCHAIN_TEMPLATE_VARIABLES = {
+    "rust_version": "rust",
+    "minimum_rust_version": "rust_msrv",
+    "rust_edition": "rust_edition",
+    "python_version": "python",
+    "node_version": "node",
+    "go_version": "go",
+    "java_version": "java",
+    "kotlin_version": "kotlin",
+}
+
 
 class DocumentGenerator:
     """Generates documentation and configuration files based on analysis."""
@@ -77,6 +106,9 @@ class DocumentGenerator:
             # Core documentation - CLAUDE.md
             generated_files.update(self._generate_core_docs(analysis))
 
+            # Monorepos: nested CLAUDE.md per package
+            generated_files.update(generate_package_docs(analysis))
+
             # Agent configuration - AGENTS.md
             generated_files.update(self._generate_agent_config(analysis))
 
@@ -153,9 +185,16 @@ class DocumentGenerator:
                 rendered: Optional[str] = None
                 vars_ctx = self._create_template_variables(analysis)
                 for s in scopes:
-                    tpl = self.template_loader.load_template(
-                        "claude_instructions.md", s
-                    )
+                    if s == "base":
+                        tpl = self.template_loader.load_template(
+                            "claude_instructions.md", s
+                        )
+                    else:
+                        # Skip scopes without their own template so the generic
+                        # fallback never shadows a language template
+                        tpl = self.template_loader.load_scoped_template(
+                            "claude_instructions.md", s
+                        )
                     if tpl and tpl.strip():
                         # Render via modern renderer to support both ${} and Jinja
                         rendered = self.template_manager.render_template(tpl, vars_ctx)
@@ -197,6 +236,36 @@ class DocumentGenerator:
                 self._get_default_claude_template(), default_context
             )
 
+        # Monorepos: point at the per-package CLAUDE.md files
+        monorepo = getattr(analysis, "monorepo", None)
+        if isinstance(monorepo, MonorepoInfo) and monorepo.packages:
+            files["CLAUDE.md"] = (
+                files["CLAUDE.md"].rstrip()
+                + "\n\n## Packages\n\n"
+                + summarize_monorepo(monorepo)
+                + "\n"
+            )
+
+        # Deep analysis: measured hotspots and the guidance they call for
+        metrics = getattr(analysis, "complexity_metrics", None)
+        if isinstance(metrics, ComplexityMetrics) and metrics.modules:
+            files["CLAUDE.md"] = (
+                files["CLAUDE.md"].rstrip()
+                + "\n\n## Code Metrics\n\n"
+                + summarize_code_metrics(metrics, analysis.complexity_level.value)
+                + "\n"
+            )
+
+        # Git history: commit conventions to follow and files to handle with care
+        git_history = getattr(analysis, "git_history", None)
+        if isinstance(git_history, GitHistoryInfo) and git_history.commits_analyzed:
+            files["CLAUDE.md"] = (
+                files["CLAUDE.md"].rstrip()
+                + "\n\n## Git Workflow\n\n"
+                + summarize_git_history(git_history)
+                + "\n"
+            )
+
         return files
 
     def _generate_agent_config(self, analysis: ProjectAnalysis) -> Dict[str, str]:
@@ -347,6 +416,33 @@ class DocumentGenerator:
             "test_files": str(analysis.filesystem_info.test_files),
         }
 
+        # Rust source facts for the language template's structure placeholders
+        rust_source = getattr(analysis, "rust_source", None)
+        if isinstance(rust_source, RustSourceInfo):
+            variables.update(self._rust_source_variables(analysis, rust_source))
+
+        # Go module facts for the Go language template
+        go_workspace = getattr(analysis, "go_workspace", None)
+        if isinstance(go_workspace, GoWorkspaceInfo) and go_workspace.modules:
+            variables["go_module_path"] = go_workspace.modules[0].module
+            variables["go_module_facts"] = summarize_go_workspace(go_workspace)
+
+        # Maven/Gradle facts for the Java and Kotlin language templates
+        framework_details = getattr(analysis.framework_info, "details", None)
+        if isinstance(framework_details, dict) and framework_details.get(
+            "build_tool"
+        ):
+            variables["build_tool"] = framework_details["build_tool"].title()
+            variables["jvm_build_facts"] = summarize_jvm_details(framework_details)
+
+        # Toolchain versions (only those detected, so templates never claim one)
+        version_info = getattr(analysis.language_info, "version_info", None)
+        if isinstance(version_info, dict):
+            for variable, key in TOOLCHAIN_TEMPLATE_VARIABLES.items():
+                value = version_info.get(key)
+                if value and value != "unknown":
+                    variables[variable] = str(value)
+
         # Add intelligent agent variables if agent configuration exists
         if hasattr(analysis, "agent_configuration") and analysis.agent_configuration:
             agent_config = analysis.agent_configuration
@@ -401,6 +497,50 @@ class DocumentGenerator:
 
         return variables
 
+    def _rust_source_variables(
+        self, analysis: ProjectAnalysis, rust_source: RustSourceInfo
+    ) -> Dict[str, str]:
+        """Template variables describing the scanned Rust module tree."""
+        variables = {
+            "rust_module_tree": render_module_tree(
+                rust_source, analysis.project_path.name
+            ),
+            "rust_source_facts": summarize_rust_source(rust_source),
+        }
+
+        # Name the crate and real targets and items in the example snippets,
+        # falling back to neutral names so no placeholder is left unfilled
+        crate = (
+            rust_source.modules[0].path.split("::")[0]
+            if rust_source.modules
+            else analysis.project_path.name.replace("-", "_")
+        )
+        variables["rust_crate"] = crate
+        variables["rust_error_type"] = (
+            "".join(part.title() for part in crate.split("_")) + "Error"
+        )
+
+        examples = {
+            "integration_test": (rust_source.integration_tests, "workflow"),
+            "benchmark": (rust_source.benches, "throughput"),
+            "example": (rust_source.examples, "basic"),
+        }
+        for variable, (files, default) in examples.items():
+            variables[variable] = Path(files[0]).stem if files else default
+
+        public_items = {
+            kind: [
+                item.split(" ", 1)[1]
+                for module in rust_source.modules
+                for item in module.public_items
+                if item.startswith(f"{kind} ")
+            ]
+            for kind in ("fn", "struct")
+        }
+        variables["function_name"] = next(iter(public_items["fn"]), "process")
+        variables["data_type"] = next(iter(public_items["struct"]), "Record")
+        return variables
+
     def _get_feature_workflow_template(self) -> str:
         """Get feature development workflow template."""
         return """# Feature Development Workflow
@@ -1227,7 +1367,7 @@ ${uses_database == 'Yes' and '''
                             **(getattr(self, "context_data", {}) or {}),
                             **(context or {}),
                         }
-                        return self._render_lightweight_template(
+                        return self._render_template_content(
                             alt.read_text(encoding="utf-8"), merged
                         )
             except Exception:
@@ -1278,12 +1418,10 @@ ${uses_database == 'Yes' and '''
                     if context:
                         ctx_data = {**ctx_data, **context}
                     merged = {**jinja_vars, **ctx_data}
-                    # Use lightweight renderer for Jinja-like templates
-                    return self._render_lightweight_template(content, merged)
-                # Fallback: ${var} substitution
+                    return self._render_template_content(content, merged)
+                # Fallback: legacy ${var} templates
                 if context:
-                    for key, value in context.items():
-                        content = content.replace(f"${{{key}}}", str(value))
+                    content = render_template_string(content, context, strict=False)
                 # Absolute fallback: if content still looks like a stub, try rendering sibling file
                 if len(content) < 100 and hasattr(template, "name"):
                     try:
@@ -1309,7 +1447,7 @@ ${uses_database == 'Yes' and '''
                                     **(getattr(self, "context_data", {}) or {}),
                                     **(context or {}),
                                 }
-                                return self._render_lightweight_template(
+                                return self._render_template_content(
                                     alt.read_text(encoding="utf-8"), merged
                                 )
                     except Exception:
@@ -1336,145 +1474,13 @@ ${uses_database == 'Yes' and '''
         except Exception as e:
             return f"Error rendering template {template}: {e}"
 
-    def _render_lightweight_template(self, content: str, ctx: Dict[str, Any]) -> str:
-        """Very small subset renderer for the integration test's constructs.
-
-        Supports:
-        - Frontmatter --- ... --- stripping
-        - {{ var }} with dotted access (dict attributes)
-        - {% for x in range(N) %}...{% endfor %}
-        - {% for x in list_name %}...{% endfor %}
-        - {% if expression %}...{% endif %} with expression like `i % 10 == 0`
-        """
-        import ast
-        import re
-
-        # Strip frontmatter
+    def _render_template_content(self, content: str, ctx: Dict[str, Any]) -> str:
+        """Render template file content, minus any frontmatter, leniently."""
         if content.startswith("---"):
             parts = content.split("---", 2)
             if len(parts) >= 3:
                 content = parts[2].strip()
-
-        def resolve(name: str, locals_: Dict[str, Any]) -> Any:
-            parts = name.split(".")
-            val = locals_.get(parts[0], ctx.get(parts[0]))
-            for p in parts[1:]:
-                val = val.get(p) if isinstance(val, dict) else getattr(val, p, None)
-            return val
-
-        def safe_eval(expr: str, locals_: Dict[str, Any]) -> bool:
-            node = ast.parse(expr, mode="eval")
-            allowed = (
-                ast.Expression,
-                ast.BinOp,
-                ast.Mod,
-                ast.Load,
-                ast.Name,
-                ast.Constant,
-                ast.Compare,
-                ast.Eq,
-                ast.Gt,
-                ast.GtE,
-                ast.Lt,
-                ast.LtE,
-                ast.Num,
-            )
-            for n in ast.walk(node):
-                if not isinstance(n, allowed):
-                    msg = "Disallowed expression"
-                    raise ValueError(msg)
-            return bool(
-                eval(compile(node, "<expr>", "eval"), {"__builtins__": {}}, locals_)
-            )
-
-        tag_re = re.compile(
-            r"\{\%\s*(for|if)\b(.*?)\%\}|\{\%\s*end(for|if)\s*\%\}", re.DOTALL
-        )
-
-        def find_matching(text: str, start: int, kind: str) -> int:
-            # kind: 'for' or 'if'
-            depth = 0
-            for m in tag_re.finditer(text, start):
-                if m.group(1) == kind:
-                    depth += 1
-                elif m.group(3) == kind:
-                    depth -= 1
-                    if depth == 0:
-                        return m.start()
-            return -1
-
-        var_re = re.compile(r"\{\{\s*([^}]+?)\s*\}\}")
-
-        def replace_vars(s: str, locals_: Dict[str, Any]) -> str:
-            def var_repl(m: "re.Match[str]") -> str:
-                name = m.group(1).strip()
-                val = resolve(name, locals_)
-                return str(val) if val is not None else ""
-
-            return var_re.sub(var_repl, s)
-
-        def render_segment(text: str, locals_: Dict[str, Any]) -> str:
-            out: List[str] = []
-            pos = 0
-            while True:
-                m = tag_re.search(text, pos)
-                if not m:
-                    out.append(replace_vars(text[pos:], locals_))
-                    break
-                out.append(replace_vars(text[pos : m.start()], locals_))
-                if m.group(1) == "for":
-                    head = m.group(2).strip()
-                    endpos = find_matching(text, m.end(), "for")
-                    if endpos == -1:
-                        # malformed; render literally
-                        out.append(replace_vars(text[m.start() :], locals_))
-                        break
-                    body = text[m.end() : endpos]
-                    # parse head: var in range(N) or var in list
-                    try:
-                        _, after_for = (
-                            head.split("for", 1)
-                            if head.startswith("for ")
-                            else ("", head)
-                        )
-                        # normalized: var in expr
-                        var, expr = [p.strip() for p in head.split(" in ", 1)]
-                        seq: Any = None
-                        if expr.startswith("range(") and expr.endswith(")"):
-                            count = int(expr[6:-1])
-                            seq = range(count)
-                        else:
-                            seq = resolve(expr, locals_) or []
-                    except Exception:
-                        seq = []
-                        var = "i"
-                    for item in seq:
-                        l2 = dict(locals_)
-                        l2[var] = item
-                        out.append(render_segment(body, l2))
-                    pos = endpos + len("{% endfor %}")
-                elif m.group(1) == "if":
-                    expr = m.group(2).strip()
-                    endpos = find_matching(text, m.end(), "if")
-                    if endpos == -1:
-                        out.append(replace_vars(text[m.start() :], locals_))
-                        break
-                    body = text[m.end() : endpos]
-                    ok = False
-                    try:
-                        ok = safe_eval(expr, locals_ | ctx)
-                    except Exception:
-                        ok = False
-                    if ok:
-                        out.append(render_segment(body, locals_))
-                    pos = endpos + len("{% endif %}")
-                else:
-                    # unexpected end tag; append literally
-                    out.append(replace_vars(text[m.start() : m.end()], locals_))
-                    pos = m.end()
-            return "".join(out)
-
-        return render_segment(content, {})
+        return render_template_string(content, ctx, strict=False)
 
 
 # Legacy classes removed - now using CoreTemplateManager from template_manager.py
@@ -1502,10 +1508,9 @@ class TemplateLoader:
         try:
             # If a scope is provided, try the packaged scope directory first
             if scope:
-                scoped_dir = Path(__file__).parent.parent / "templates" / scope
-                scoped_file = scoped_dir / template_name
-                if scoped_file.exists():
-                    return scoped_file.read_text(encoding="utf-8")
+                scoped = self.load_scoped_template(template_name, scope)
+                if scoped is not None:
+                    return scoped
 
             # Check custom template paths first
             for base in self.template_paths:
@@ -1532,6 +1537,25 @@ class TemplateLoader:
             # Graceful handling for not found or patched manager failures
             return ""
 
+    def load_scoped_template(self, template_name: str, scope: str) -> Optional[str]:
+        """Load a template only from the scope directory (None when absent)."""
+        scoped_file = self._scope_directory(scope) / template_name
+        try:
+            if scoped_file.exists():
+                return scoped_file.read_text(encoding="utf-8")
+        except (OSError, UnicodeDecodeError):
+            pass
+        return None
+
+    def _scope_directory(self, scope: str) -> Path:
+        """Resolve a template scope; ``languages/<name>`` asks the plugin first."""
+        kind, _, name = scope.partition("/")
+        if kind == "languages":
+            plugin = get_language_registry().get(name)
+            if plugin is not None and plugin.template_dir is not None:
+                return Path(plugin.template_dir)
+        return Path(__file__).parent.parent / "templates" / scope
+
     def load_templates(self, template_names: List[str]) -> Dict[str, Optional[str]]:
         """Load multiple templates."""
         templates: Dict[str, Optional[str]] = {}
@@ -1564,27 +1588,7 @@ class TemplateLoader:
     def substitute_variables(
         self, template_content: str, variables: Dict[str, Any]
     ) -> str:
-        import re
-
-        content = str(template_content)
-
-        # Handle {{#if var}} ... {{/if}} blocks
-        def repl(match: "re.Match[str]") -> str:
-            key = match.group(1).strip()
-            body = match.group(2)
-            val = variables.get(key)
-            return body if val else ""
-
-        content = re.sub(
-            r"\{\{#if\s+([^}]+)\}\}(.*?)\{\{\/if\}\}", repl, content, flags=re.DOTALL
-        )
-
-        # Replace ${var} placeholders; missing vars -> empty string
-        def var_repl(m: "re.Match[str]") -> str:
-            key = m.group(1)
-            val = variables.get(key)
-            return str(val) if val is not None else ""
-
-        return re.sub(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}", var_repl, content)
+        # Unknown ${var} placeholders are left as written
+        return render_template_string(str(template_content), variables, strict=False)
 
     # (Note: duplicate legacy overload removed)
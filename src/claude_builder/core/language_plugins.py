@@ -0,0 +1,224 @@
+"""Language plugins: one extension point per supported programming language.
+
+A :class:`LanguagePlugin` bundles everything the analyzer and generator need to
+know about a language — file extensions, manifest files, framework scoring,
+the framework catalogue, agent mappings, toolchain versions and the directory
+holding its templates. Built-in languages live in
+:mod:`claude_builder.analysis.languages`; third-party packages register extra
+plugins (or replace built-ins) through the ``claude_builder.languages``
+entry-point group::
+
+    [project.entry-points."claude_builder.languages"]
+    zig = "acme_claude_zig:ZigPlugin"
+
+The entry point may name a ``LanguagePlugin`` subclass or a ready instance.
+"""
+
+from __future__ import annotations
+
+import logging
+
+from pathlib import Path
+from typing import Any, Iterable, Iterator, Mapping
+
+
+ENTRY_POINT_GROUP = "claude_builder.languages"
+
+logger = logging.getLogger(__name__)
+
+
+class LanguagePlugin:
+    """Description of a single language, subclassed or built from keywords.
+
+    Attributes are read-only declarations shared by every instance of a
+    subclass; pass keyword arguments to override them on one instance.
+    """
+
+    # Registry key, also the value of ``LanguageInfo.primary``
+    name: str = ""
+    # Suffixes counted when detecting the project's languages
+    extensions: tuple[str, ...] = ()
+    # Suffixes counted as source files; ``None`` means ``extensions``
+    source_extensions: tuple[str, ...] | None = None
+    # Root files that raise detection confidence (``*`` matches any prefix)
+    manifest_files: tuple[str, ...] = ()
+    # Framework catalogue: framework name -> category
+    frameworks: Mapping[str, str] = {}
+    # Frameworks flagged as ``web_framework`` in ``FrameworkInfo.details``
+    web_frameworks: tuple[str, ...] = ()
+    # General-purpose libraries that are never the primary framework
+    infrastructure_frameworks: tuple[str, ...] = ()
+    # Same shape as ``AgentRegistry.language_mappings`` entries
+    agent_mappings: Mapping[str, Any] = {}
+    # Directory with claude_instructions.md, development_guide.md, ...
+    template_dir: Path | None = None
+
+    def __init__(self, **overrides: Any) -> None:
+        for key, value in overrides.items():
+            if key.startswith("_") or not hasattr(type(self), key):
+                msg = f"Unknown LanguagePlugin attribute: {key}"
+                raise TypeError(msg)
+            setattr(self, key, value)
+        if not self.name:
+            msg = "LanguagePlugin requires a name"
+            raise ValueError(msg)
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(name={self.name!r})"
+
+    def get_source_extensions(self) -> tuple[str, ...]:
+        """Suffixes counted as source files for this language."""
+        if self.source_extensions is None:
+            return self.extensions
+        return self.source_extensions
+
+    def score_frameworks(
+        self, project_path: Path, scores: dict[str, float]
+    ) -> list[str]:
+        """Parse manifests, add framework scores and return dependency names.
+
+        Use ``claude_builder.analysis.evidence.add_score`` to record why each
+        score was added; plain ``scores[name] += n`` updates are reported as
+        coming from this plugin.
+        """
+        return []
+
+    def framework_details(self, project_path: Path) -> dict[str, Any]:
+        """Extra ``FrameworkInfo.details`` entries (build tool, modules, ...).
+
+        A ``test_frameworks`` list is also added to the development
+        environment's testing frameworks.
+        """
+        return {}
+
+    def detect_versions(self, project_path: Path) -> dict[str, str]:
+        """Return toolchain version keys for ``LanguageInfo.version_info``."""
+        return {}
+
+
+class LanguagePluginRegistry:
+    """Ordered collection of language plugins keyed by name."""
+
+    def __init__(self, plugins: Iterable[LanguagePlugin] = ()) -> None:
+        self._plugins: dict[str, LanguagePlugin] = {}
+        for plugin in plugins:
+            self.register(plugin)
+
+    def register(self, plugin: LanguagePlugin) -> None:
+        """Add a plugin, replacing any earlier plugin with the same name."""
+        self._plugins[plugin.name.lower()] = plugin
+
+    def get(self, name: str | None) -> LanguagePlugin | None:
+        if not name:
+            return None
+        return self._plugins.get(name.lower())
+
+    def names(self) -> list[str]:
+        return list(self._plugins)
+
+    def __iter__(self) -> Iterator[LanguagePlugin]:
+        return iter(list(self._plugins.values()))
+
+    def __contains__(self, name: object) -> bool:
+        return isinstance(name, str) and name.lower() in self._plugins
+
+    def __len__(self) -> int:
+        return len(self._plugins)
+
+    def extension_map(self) -> dict[str, list[str]]:
+        """Language name -> detection extensions."""
+        return {
+            plugin.name: list(plugin.extensions)
+            for plugin in self
+            if plugin.extensions
+        }
+
+    def source_extensions(self) -> set[str]:
+        return {
+            extension.lower()
+            for plugin in self
+            for extension in plugin.get_source_extensions()
+        }
+
+    def framework_category(self, framework: str) -> str | None:
+        for plugin in self:
+            category = plugin.frameworks.get(framework)
+            if category:
+                return category
+        return None
+
+    def web_frameworks(self) -> set[str]:
+        return {framework for plugin in self for framework in plugin.web_frameworks}
+
+    def infrastructure_frameworks(self) -> set[str]:
+        return {
+            framework
+            for plugin in self
+            for framework in plugin.infrastructure_frameworks
+        }
+
+    def detect_versions(
+        self, project_path: Path, languages: Iterable[str | None]
+    ) -> dict[str, str]:
+        """Collect toolchain versions from the plugins of ``languages``."""
+        versions: dict[str, str] = {}
+        for language in languages:
+            plugin = self.get(language)
+            if plugin is not None:
+                versions.update(plugin.detect_versions(project_path))
+        return versions
+
+
+def _iter_entry_points(group: str) -> list[Any]:
+    from importlib import metadata
+
+    entry_points = metadata.entry_points()
+    if hasattr(entry_points, "select"):
+        return list(entry_points.select(group=group))
+    # Python < 3.10 returns a dict of group -> entry points
+    return list(entry_points.get(group, []))
+
+
+def load_entry_point_plugins(group: str = ENTRY_POINT_GROUP) -> list[LanguagePlugin]:
+    """Instantiate plugins advertised by installed distributions.
+
+    Broken plugins are logged and skipped so one bad package cannot stop
+    analysis of every project.
+    """
+    plugins: list[LanguagePlugin] = []
+    for entry_point in _iter_entry_points(group):
+        try:
+            loaded = entry_point.load()
+            plugin = loaded() if isinstance(loaded, type) else loaded
+        except Exception as exc:
+            logger.warning("Skipping language plugin %r: %s", entry_point.name, exc)
+            continue
+        if not isinstance(plugin, LanguagePlugin):
+            logger.warning(
+                "Skipping language plugin %r: not a LanguagePlugin", entry_point.name
+            )
+            continue
+        plugins.append(plugin)
+    return plugins
+
+
+_registry: LanguagePluginRegistry | None = None
+
+
+def get_language_registry() -> LanguagePluginRegistry:
+    """Return the process-wide registry: built-ins, then entry-point plugins."""
+    global _registry
+    if _registry is None:
+        from claude_builder.analysis.languages import BUILTIN_LANGUAGE_PLUGINS
+
+        registry = LanguagePluginRegistry(BUILTIN_LANGUAGE_PLUGINS)
+        for plugin in load_entry_point_plugins():
+            registry.register(plugin)
+        _registry = registry
+    return _registry
+
+
+def reset_language_registry() -> None:
+    """Forget the cached registry so plugins are discovered again."""
+    global _registry
+    _registry = None
@@ -0,0 +1,567 @@
+"""Managed regions: regenerate instruction files without losing hand edits.
+
+Generated markdown is split at its ``## `` headings and every section is
+wrapped in markers::
+
+    <!-- claude-builder:begin section=testing -->
+    ## Testing
+    ...
+    <!-- claude-builder:end section=testing -->
+
+Text outside the markers belongs to the user and is never touched. On
+regeneration each managed region is merged three ways: the last generated
+version (the baseline, kept in ``.claude-builder/generated/``) is the common
+ancestor, the file on disk is "edited" and the fresh output is "generated".
+Unedited regions are replaced, edits to regions whose generated content did
+not change are kept, and overlapping changes are written with conflict
+markers; :meth:`ManagedWrite.raise_for_conflicts` then fails the command so
+they are not missed. Front matter must stay valid YAML, so a conflict there
+fails before anything is written. Files without ``## `` sections are merged
+the same way as a whole.
+
+Without a baseline (a fresh clone, a deleted ``.claude-builder/generated/``)
+the regions of a marked file are regenerated and the text outside them is
+kept. A file with no markers either, written by hand or by an earlier
+release, is saved as ``<name>.bak`` and replaced by the generated content.
+
+Tool configuration files the user also edits, such as ``.aider.conf.yml``,
+get no markers. Only the list the generator owns is rewritten: entries the
+baseline shows were generated last time are replaced with the new ones, and
+the user's own entries, other keys and comments are kept.
+"""
+
+import re
+
+from dataclasses import dataclass, field
+from difflib import SequenceMatcher
+from pathlib import Path
+from typing import Any, Dict, Iterable, List, Optional, Tuple, Union
+
+import yaml
+
+from claude_builder.core.file_transaction import FileTransaction
+from claude_builder.utils.exceptions import GenerationError
+
+
+BASELINE_DIRECTORY = Path(".claude-builder") / "generated"
+# Cursor rules and Windsurf rules are markdown under other names
+MANAGED_SUFFIXES = (".md", ".mdc")
+MANAGED_NAMES = (".windsurfrules",)
+# Configuration file name -> the top-level YAML list the generator owns
+MERGED_LISTS = {".aider.conf.yml": "read"}
+
+BEGIN_MARKER = "<!-- claude-builder:begin section={} -->"
+END_MARKER = "<!-- claude-builder:end section={} -->"
+REGION = re.compile(
+    r"^<!-- claude-builder:begin section=(?P<section>[\w-]+) -->\n"
+    r"(?P<body>.*?)"
+    r"^<!-- claude-builder:end section=(?P=section) -->$",
+    re.MULTILINE | re.DOTALL,
+)
+FRONTMATTER = re.compile(r"\A---\n.*?^---\n", re.MULTILINE | re.DOTALL)
+FENCE = re.compile(r"^\s*(```|~~~)")
+
+CONFLICT_START = "<<<<<<< edited"
+CONFLICT_SEPARATOR = "======="
+CONFLICT_END = ">>>>>>> generated"
+
+HEADER_SECTION = "header"
+
+
+@dataclass
+class Region:
+    """One managed section of a file."""
+
+    section: str
+    body: str
+
+    def render(self) -> str:
+        body = f"{self.body}\n" if self.body else ""
+        return (
+            f"{BEGIN_MARKER.format(self.section)}\n{body}"
+            f"{END_MARKER.format(self.section)}"
+        )
+
+
+Chunk = Union[str, Region]
+
+
+@dataclass
+class ManagedMerge:
+    """Content to write for one file and what the merge did."""
+
+    path: str
+    content: str
+    generated: str
+    preserved: List[str] = field(default_factory=list)
+    conflicts: List[str] = field(default_factory=list)
+    backup_path: Optional[Path] = None
+    # No baseline to merge with: the file on disk is backed up, then replaced
+    backup_required: bool = False
+
+
+def is_managed(path: Union[str, Path]) -> bool:
+    """Whether ``path`` is a file whose sections carry managed-region markers."""
+    path = Path(path)
+    return path.suffix in MANAGED_SUFFIXES or path.name in MANAGED_NAMES
+
+
+def merged_list_key(path: Union[str, Path]) -> Optional[str]:
+    """The YAML list merged into an existing ``path``, if it is such a config."""
+    return MERGED_LISTS.get(Path(path).name)
+
+
+def has_baseline(path: Union[str, Path]) -> bool:
+    """Whether a baseline of ``path`` is kept for later merges."""
+    return is_managed(path) or merged_list_key(path) is not None
+
+
+def split_frontmatter(content: str) -> Tuple[str, str]:
+    """Split YAML front matter (which must stay first) from the body."""
+    match = FRONTMATTER.match(content)
+    if match is None:
+        return "", content
+    return match.group(0), content[match.end() :]
+
+
+def _slug(heading: str) -> str:
+    return re.sub(r"[^a-z0-9]+", "-", heading.lower()).strip("-") or "section"
+
+
+def _sections(body: str) -> List[Tuple[str, str]]:
+    """Split markdown at level-2 headings outside code fences."""
+    sections: List[Tuple[str, List[str]]] = [(HEADER_SECTION, [])]
+    seen: Dict[str, int] = {}
+    fenced = False
+    for line in body.splitlines():
+        if FENCE.match(line):
+            fenced = not fenced
+        if not fenced and line.startswith("## "):
+            section = _slug(line[3:])
+            seen[section] = seen.get(section, 0) + 1
+            if seen[section] > 1:
+                section = f"{section}-{seen[section]}"
+            sections.append((section, []))
+        sections[-1][1].append(line)
+    return [
+        (section, "\n".join(lines).strip("\n"))
+        for section, lines in sections
+        if "\n".join(lines).strip()
+    ]
+
+
+def mark_regions(content: str) -> str:
+    """Wrap each ``## `` section of generated markdown in region markers."""
+    frontmatter, body = split_frontmatter(content)
+    sections = _sections(body)
+    if not any(section != HEADER_SECTION for section, _ in sections):
+        return content
+    regions = [Region(section, text).render() for section, text in sections]
+    return frontmatter + "\n\n".join(regions) + "\n"
+
+
+def parse_regions(body: str) -> List[Chunk]:
+    """Split file content into user text and managed regions, in order."""
+    chunks: List[Chunk] = []
+    pos = 0
+    for match in REGION.finditer(body):
+        if match.start() > pos:
+            chunks.append(body[pos : match.start()])
+        chunks.append(Region(match.group("section"), match.group("body")[:-1]))
+        pos = match.end()
+    if pos < len(body):
+        chunks.append(body[pos:])
+    return chunks
+
+
+def _regions(chunks: List[Chunk]) -> Dict[str, str]:
+    return {chunk.section: chunk.body for chunk in chunks if isinstance(chunk, Region)}
+
+
+def _changes(base: List[str], other: List[str]) -> List[Tuple[int, int, List[str]]]:
+    matcher = SequenceMatcher(None, base, other, autojunk=False)
+    return [
+        (i1, i2, other[j1:j2])
+        for tag, i1, i2, j1, j2 in matcher.get_opcodes()
+        if tag != "equal"
+    ]
+
+
+def _apply(
+    base: List[str], start: int, end: int, changes: List[Tuple[int, int, List[str]]]
+) -> List[str]:
+    out: List[str] = []
+    pos = start
+    for i1, i2, lines in changes:
+        out.extend(base[pos:i1])
+        out.extend(lines)
+        pos = i2
+    out.extend(base[pos:end])
+    return out
+
+
+def merge3(base: Optional[str], edited: str, generated: str) -> Tuple[str, bool]:
+    """Line-based three-way merge; returns the text and whether it conflicts.
+
+    Without a baseline the generated text wins, as before regions existed.
+    """
+    if base is None or edited in (base, generated):
+        return generated, False
+    if generated == base:
+        return edited, False
+
+    base_lines = base.splitlines()
+    changes = sorted(
+        [(*change, "edited") for change in _changes(base_lines, edited.splitlines())]
+        + [
+            (*change, "generated")
+            for change in _changes(base_lines, generated.splitlines())
+        ],
+        key=lambda change: (change[0], change[1]),
+    )
+    out: List[str] = []
+    conflict = False
+    pos = 0
+    index = 0
+    while index < len(changes):
+        start, end = changes[index][0], changes[index][1]
+        hunk = [changes[index]]
+        index += 1
+        # Changes overlapping the hunk, or inserting at the same line, join it
+        while index < len(changes) and (
+            changes[index][0] < end or changes[index][0] == start == end
+        ):
+            end = max(end, changes[index][1])
+            hunk.append(changes[index])
+            index += 1
+        out.extend(base_lines[pos:start])
+        ours, theirs = (
+            _apply(base_lines, start, end, [c[:3] for c in hunk if c[3] == side])
+            for side in ("edited", "generated")
+        )
+        if all(c[3] == "generated" for c in hunk) or ours == theirs:
+            out.extend(theirs)
+        elif all(c[3] == "edited" for c in hunk):
+            out.extend(ours)
+        else:
+            conflict = True
+            out.extend(
+                [CONFLICT_START, *ours, CONFLICT_SEPARATOR, *theirs, CONFLICT_END]
+            )
+        pos = end
+    out.extend(base_lines[pos:])
+    merged = "\n".join(out)
+    return merged + "\n" if generated.endswith("\n") else merged, conflict
+
+
+def _yaml_mapping(path: str, content: str) -> Dict[str, Any]:
+    try:
+        data = yaml.safe_load(content)
+    except yaml.YAMLError as e:
+        msg = f"{path} is not valid YAML, so its settings cannot be kept"
+        raise GenerationError(msg, output_path=path, cause=e) from e
+    if data is None:
+        return {}
+    if not isinstance(data, dict):
+        msg = f"{path} is not a YAML mapping, so its settings cannot be kept"
+        raise GenerationError(msg, output_path=path)
+    return data
+
+
+def _yaml_list(data: Dict[str, Any], key: str) -> List[str]:
+    value = data.get(key)
+    if value is None:
+        return []
+    if isinstance(value, list):
+        return [str(item) for item in value]
+    return [str(value)]
+
+
+def _replace_top_level_key(content: str, key: str, block: str) -> str:
+    """Replace the ``key:`` entry of a YAML document, or append it."""
+    lines = content.splitlines(keepends=True)
+    start = next(
+        (i for i, line in enumerate(lines) if re.match(rf"{re.escape(key)}\s*:", line)),
+        None,
+    )
+    if start is None:
+        separator = "" if not content or content.endswith("\n") else "\n"
+        return f"{content}{separator}{block}"
+    end = start + 1
+    # The value continues on indented lines and block sequence items
+    while end < len(lines) and re.match(r"[ \t]|-(\s|$)|\s*$", lines[end]):
+        end += 1
+    # Blank lines before the next key belong to the layout, not the value
+    while end > start + 1 and not lines[end - 1].strip():
+        end -= 1
+    return "".join(lines[:start]) + block + "".join(lines[end:])
+
+
+def merge_yaml_list(
+    path: str,
+    key: str,
+    generated: str,
+    existing: str,
+    baseline: Optional[str],
+) -> str:
+    """Merge the generated ``key`` list into the user's YAML file.
+
+    Entries generated last time (read from ``baseline``) are replaced by the
+    new ones; the user's entries, other keys and comments stay as they are.
+    Raises :class:`GenerationError` instead of writing when the file cannot be
+    merged without losing settings.
+    """
+    current = _yaml_mapping(path, existing)
+    previous = set(_yaml_list(_yaml_mapping(path, baseline or ""), key))
+    new_entries = _yaml_list(_yaml_mapping(path, generated), key)
+    entries = [e for e in _yaml_list(current, key) if e not in previous]
+    entries += [e for e in new_entries if e not in entries]
+
+    block = f"{key}:\n" + "".join(f"  - {entry}\n" for entry in entries)
+    merged = _replace_top_level_key(existing, key, block)
+    expected = {**current, key: entries}
+    if _yaml_mapping(path, merged) != expected:
+        msg = f"Could not update the {key} list in {path} without changing it"
+        raise GenerationError(
+            msg,
+            output_path=path,
+            suggestions=[f"Add these entries to {key} by hand: {new_entries}"],
+        )
+    return merged
+
+
+def _merge_frontmatter(path: str, base: str, edited: str, generated: str) -> str:
+    """Three-way merge of YAML front matter, failing rather than adding markers."""
+    merged, conflict = merge3(base, edited, generated)
+    if conflict:
+        msg = f"Conflicting edits in the front matter of {path}"
+        raise GenerationError(
+            msg,
+            output_path=path,
+            suggestions=[
+                f"Edit the front matter of {path} to match the generated one, "
+                "then re-run"
+            ],
+        )
+    return merged
+
+
+def merge_managed(
+    path: str, generated: str, existing: Optional[str], baseline: Optional[str]
+) -> ManagedMerge:
+    """Merge freshly generated content into the file currently on disk."""
+    generated = mark_regions(generated) if is_managed(path) else generated
+    result = ManagedMerge(path=path, content=generated, generated=generated)
+    list_key = merged_list_key(path)
+    if existing is not None and list_key is not None:
+        result.content = merge_yaml_list(path, list_key, generated, existing, baseline)
+        if result.content != generated:
+            result.preserved.append("other settings")
+        return result
+    if existing is None or existing == generated or not is_managed(path):
+        return result
+    if baseline is None:
+        if not _regions(parse_regions(split_frontmatter(existing)[1])):
+            # Nothing shows which lines were generated: keep a copy, then replace
+            result.backup_required = True
+            return result
+        # The markers still show what was generated: regenerate those regions
+        baseline = existing
+
+    new_front, new_body = split_frontmatter(generated)
+    old_front, old_body = split_frontmatter(existing)
+    base_front, base_body = split_frontmatter(baseline)
+    front = _merge_frontmatter(path, base_front, old_front, new_front)
+    chunks = parse_regions(old_body)
+    new_regions = _regions(parse_regions(new_body))
+    if not _regions(chunks) or not new_regions:
+        body, conflict = merge3(base_body, old_body, new_body)
+        if conflict:
+            result.conflicts.append(path)
+        result.content = front + body
+        return result
+
+    base_regions = _regions(parse_regions(base_body))
+
+    merged_chunks: List[Chunk] = []
+    for chunk in chunks:
+        if isinstance(chunk, str):
+            merged_chunks.append(chunk)
+            continue
+        base = base_regions.get(chunk.section)
+        if chunk.section not in new_regions:
+            if base is not None and chunk.body != base:
+                result.preserved.append(chunk.section)
+                merged_chunks.append(chunk)
+            elif merged_chunks and not str(merged_chunks[-1]).strip():
+                merged_chunks.pop()
+            continue
+        body, conflict = merge3(base, chunk.body, new_regions[chunk.section])
+        if conflict:
+            result.conflicts.append(chunk.section)
+        elif body != new_regions[chunk.section]:
+            result.preserved.append(chunk.section)
+        merged_chunks.append(Region(chunk.section, body))
+
+    _insert_new_regions(merged_chunks, new_regions)
+    content = "".join(c if isinstance(c, str) else c.render() for c in merged_chunks)
+    result.content = front + (content if content.endswith("\n") else content + "\n")
+    return result
+
+
+def _insert_new_regions(chunks: List[Chunk], new_regions: Dict[str, str]) -> None:
+    """Place sections the file lacks after the section preceding them."""
+    order = list(new_regions)
+    for position, section in enumerate(order):
+        present = {c.section: i for i, c in enumerate(chunks) if isinstance(c, Region)}
+        if section in present:
+            continue
+        earlier = [present[s] for s in order[:position] if s in present]
+        region = Region(section, new_regions[section])
+        if earlier:
+            chunks[max(earlier) + 1 : max(earlier) + 1] = ["\n\n", region]
+        elif present:
+            first = min(present.values())
+            chunks[first:first] = [region, "\n\n"]
+        else:
+            chunks.extend(["\n\n", region])
+
+
+def describe_merge(merge: ManagedMerge) -> List[str]:
+    """Human-readable notes on hand edits kept or in conflict."""
+    notes = []
+    if merge.backup_required:
+        backup = merge.backup_path.name if merge.backup_path else f"{merge.path}.bak"
+        notes.append(
+            f"{merge.path}: no baseline to merge with, replaced "
+            f"(previous version saved as {backup})"
+        )
+    if merge.preserved:
+        notes.append(f"{merge.path}: kept edits in {', '.join(merge.preserved)}")
+    if merge.conflicts:
+        notes.append(
+            f"{merge.path}: conflicting edits in {', '.join(merge.conflicts)} "
+            f"(marked with {CONFLICT_START!r})"
+        )
+    return notes
+
+
+def baseline_path(output_dir: Path, path: str) -> Path:
+    """Where the last generated version of ``path`` is kept."""
+    return output_dir / BASELINE_DIRECTORY / path
+
+
+def generated_files(output_dir: Path) -> List[str]:
+    """Relative paths of the files with a stored baseline under ``output_dir``."""
+    baselines = output_dir / BASELINE_DIRECTORY
+    if not baselines.is_dir():
+        return []
+    return sorted(
+        path.relative_to(baselines).as_posix()
+        for path in baselines.rglob("*")
+        if path.is_file()
+    )
+
+
+def prepare_managed_file(output_dir: Path, path: str, generated: str) -> ManagedMerge:
+    """Merge ``generated`` with the file and baseline under ``output_dir``."""
+    target = output_dir / path
+    stored = baseline_path(output_dir, path)
+    existing = target.read_text(encoding="utf-8") if target.exists() else None
+    baseline = stored.read_text(encoding="utf-8") if stored.exists() else None
+    return merge_managed(path, generated, existing, baseline)
+
+
+def stage_managed_file(
+    transaction: FileTransaction,
+    path: str,
+    generated: str,
+    *,
+    backup_existing: bool = False,
+) -> ManagedMerge:
+    """Stage generated content for ``path`` in ``transaction``, keeping hand edits.
+
+    The new baseline is staged with it, so both land or neither does. With
+    ``backup_existing`` the current file is also staged as ``<name>.bak``.
+    """
+    output_dir = transaction.root
+    merge = prepare_managed_file(output_dir, path, generated)
+    target = output_dir / path
+    if (backup_existing or merge.backup_required) and target.exists():
+        merge.backup_path = target.with_suffix(target.suffix + ".bak")
+        transaction.stage(f"{path}.bak", target.read_text(encoding="utf-8"))
+    transaction.stage(path, merge.content)
+    if has_baseline(path):
+        transaction.stage((BASELINE_DIRECTORY / path).as_posix(), merge.generated)
+    return merge
+
+
+@dataclass
+class ManagedWrite:
+    """Files written together by :func:`write_managed_files`."""
+
+    merges: List[ManagedMerge]
+    journal_id: Optional[str] = None
+
+    @property
+    def notes(self) -> List[str]:
+        """Hand edits the write kept or could not merge."""
+        return [note for merge in self.merges for note in describe_merge(merge)]
+
+    @property
+    def conflicts(self) -> List[str]:
+        """Paths written with conflict markers."""
+        return [merge.path for merge in self.merges if merge.conflicts]
+
+    def raise_for_conflicts(self) -> None:
+        """Fail when a merge left conflicts for the user to resolve."""
+        if self.conflicts:
+            msg = f"Unresolved conflicting edits in {', '.join(self.conflicts)}"
+            raise GenerationError(
+                msg,
+                suggestions=[
+                    f"Resolve the {CONFLICT_START!r} blocks by hand, then re-run"
+                ],
+            )
+
+    @property
+    def undo_command(self) -> Optional[str]:
+        """Command that rolls the write back, when it was journaled."""
+        if self.journal_id is None:
+            return None
+        return f"claude-builder git rollback {self.journal_id}"
+
+
+def write_managed_files(
+    output_dir: Path,
+    files: Iterable[Tuple[str, str]],
+    *,
+    backup_existing: bool = False,
+) -> ManagedWrite:
+    """Write generated ``(path, content)`` pairs under ``output_dir`` together.
+
+    Hand edits are kept as in :func:`stage_managed_file`. A failure part way
+    restores every file, and in a git repository the write is journaled for
+    ``claude-builder git rollback``.
+    """
+    with FileTransaction(output_dir) as transaction:
+        merges = [
+            stage_managed_file(
+                transaction, path, content, backup_existing=backup_existing
+            )
+            for path, content in files
+        ]
+    return ManagedWrite(merges, transaction.journal_id)
+
+
+def write_managed_file(
+    output_dir: Path, path: str, generated: str, *, backup_existing: bool = False
+) -> ManagedMerge:
+    """Write generated content to ``output_dir/path``, keeping hand edits.
+
+    With ``backup_existing`` the previous file is kept as ``<name>.bak``.
+    """
+    written = write_managed_files(
+        output_dir, [(path, generated)], backup_existing=backup_existing
+    )
+    return written.merges[0]
@@ -33,6 +33,10 @@ class ProjectType(Enum):
     DESKTOP_APP = "desktop_app"
     MOBILE_APP = "mobile_app"
     GAME = "game"
+    EMBEDDED_FIRMWARE = "embedded_firmware"
+    WASM_MODULE = "wasm_module"
+    PROC_MACRO = "proc_macro"
+    PYTHON_EXTENSION = "python_extension"  # Native module built for Python
     APPLICATION = "application"  # Generic application type
     UNKNOWN = "unknown"
 
@@ -62,6 +66,15 @@ class ArchitecturePattern(Enum):
     UNKNOWN = "unknown"
 
 
+@dataclass
+class DetectionEvidence:
+    """One signal behind a detection decision."""
+
+    candidate: str  # Language, framework, domain or project type it supports
+    signal: str  # e.g. "Cargo.toml dependency `axum`"
+    weight: float = 0.0  # Points added to the candidate's score (0: rule-based)
+
+
 @dataclass
 class LanguageInfo:
     """Information about detected languages."""
@@ -73,6 +86,7 @@ class LanguageInfo:
     total_lines: Dict[str, int] = field(default_factory=dict)
     # Optional version information keyed by language name (e.g., {"python": "3.11"})
     version_info: Dict[str, str] = field(default_factory=dict)
+    evidence: List[DetectionEvidence] = field(default_factory=list)
 
 
 @dataclass
@@ -86,6 +100,7 @@ class FrameworkInfo:
     config_files: List[str] = field(default_factory=list)
     # Arbitrary details map (e.g., {"web_framework": True, "dependencies": [...]})
     details: Dict[str, Any] = field(default_factory=dict)
+    evidence: List[DetectionEvidence] = field(default_factory=list)
 
 
 @dataclass
@@ -96,6 +111,7 @@ class DomainInfo:
     confidence: float = 0.0
     indicators: List[str] = field(default_factory=list)
     specialized_patterns: List[str] = field(default_factory=list)
+    evidence: List[DetectionEvidence] = field(default_factory=list)
 
 
 @dataclass
@@ -149,6 +165,247 @@ class FileSystemInfo:
     directory_structure: Dict[str, Any] = field(default_factory=dict)
 
 
+@dataclass
+class CrateInfo:
+    """Information about a single Rust crate (Cargo package)."""
+
+    name: str
+    path: str = "."  # Relative to the workspace root
+    kind: str = "lib"  # lib, bin, proc-macro, cdylib or staticlib
+    edition: Optional[str] = None
+    version: Optional[str] = None
+    no_std: bool = False
+    targets: List[str] = field(default_factory=list)
+    frameworks: List[str] = field(default_factory=list)
+    dependencies: List[str] = field(default_factory=list)
+
+
+@dataclass
+class CargoWorkspaceInfo:
+    """Cargo workspace layout with one entry per member crate."""
+
+    is_workspace: bool = False
+    is_virtual: bool = False  # Root manifest has [workspace] but no [package]
+    members: List[CrateInfo] = field(default_factory=list)
+    workspace_dependencies: List[str] = field(default_factory=list)
+
+
+@dataclass
+class GoModuleInfo:
+    """A Go module parsed from its ``go.mod``."""
+
+    module: str  # Module path, e.g. ``github.com/acme/api``
+    path: str = "."  # Directory relative to the project root
+    go_version: Optional[str] = None  # ``go`` directive
+    requires: List[str] = field(default_factory=list)  # Direct, "path version"
+    indirect_requires: int = 0
+    replaces: List[str] = field(default_factory=list)  # "old => new"
+
+
+@dataclass
+class GoWorkspaceInfo:
+    """A Go module, or a ``go.work`` workspace of several modules."""
+
+    is_workspace: bool = False
+    go_version: Optional[str] = None
+    toolchain: Optional[str] = None
+    modules: List[GoModuleInfo] = field(default_factory=list)
+    replaces: List[str] = field(default_factory=list)  # From go.work
+
+
+@dataclass
+class RustModuleInfo:
+    """A Rust module found by following ``mod`` declarations."""
+
+    path: str  # Module path, e.g. ``api::routes``
+    file: Optional[str] = None  # Relative to the project root; None if inline
+    layout: str = "file"  # root, file (foo.rs), mod.rs or inline
+    public: bool = False
+    public_items: List[str] = field(default_factory=list)  # e.g. "fn router"
+
+
+@dataclass
+class RustSourceInfo:
+    """Facts extracted from Rust sources without invoking rustc."""
+
+    crate_roots: List[str] = field(default_factory=list)
+    modules: List[RustModuleInfo] = field(default_factory=list)
+    async_entrypoints: List[str] = field(default_factory=list)  # file:line (attr)
+    test_count: int = 0
+    unit_test_files: List[str] = field(default_factory=list)  # with #[cfg(test)]
+    unsafe_blocks: Dict[str, int] = field(default_factory=dict)  # file -> count
+    integration_tests: List[str] = field(default_factory=list)
+    benches: List[str] = field(default_factory=list)
+    examples: List[str] = field(default_factory=list)
+
+    @property
+    def total_unsafe_blocks(self) -> int:
+        """Number of ``unsafe { ... }`` blocks across all scanned files."""
+        return sum(self.unsafe_blocks.values())
+
+
+@dataclass
+class WorkspacePackageInfo:
+    """One package of a monorepo together with its own analysis."""
+
+    name: str
+    path: str  # Relative to the monorepo root
+    tools: List[str] = field(default_factory=list)  # e.g. ["pnpm", "nx"]
+    commands: List[str] = field(default_factory=list)  # Run from the root
+    analysis: Optional["ProjectAnalysis"] = None
+
+
+@dataclass
+class MonorepoInfo:
+    """Workspace tools of a monorepo and its per-package analyses."""
+
+    tools: List[str] = field(default_factory=list)
+    packages: List[WorkspacePackageInfo] = field(default_factory=list)
+
+
+@dataclass
+class DetectionRuleMatch:
+    """A custom detection rule that matched, and where it was defined."""
+
+    rule: str
+    target: str  # language, framework, domain, tool or project_type
+    value: str
+    source: str  # Config or rule file that defined the rule
+    score: float = 0.0  # Summed weight of the matching conditions
+    confidence: float = 0.0
+    evidence: List[str] = field(default_factory=list)
+    effect: str = ""  # How the match changed the analysis
+
+
+@dataclass
+class CommitConvention:
+    """Commit message convention inferred from recent history."""
+
+    # conventional_commits, ticket_prefix, bracket_prefix, gitmoji or free_form
+    name: str
+    share: float = 0.0  # Fraction of recent subjects following it
+    pattern: str = ""  # Regular expression matching a conforming subject
+    types: Dict[str, int] = field(default_factory=dict)  # e.g. {"feat": 12}
+    scopes: List[str] = field(default_factory=list)
+    examples: List[str] = field(default_factory=list)
+    max_subject_length: int = 0  # 90th percentile of subject lengths
+
+
+@dataclass
+class FileChurn:
+    """How often a file changed in the analyzed history."""
+
+    path: str
+    commits: int = 0
+    lines_added: int = 0
+    lines_deleted: int = 0
+
+
+@dataclass
+class CoChange:
+    """Two files that tend to change in the same commits."""
+
+    files: List[str] = field(default_factory=list)
+    commits: int = 0  # Commits touching both files
+    coupling: float = 0.0  # Shared commits / commits of the less-changed file
+
+
+@dataclass
+class ReleaseCadence:
+    """Release tags and the time between them."""
+
+    tags: List[str] = field(default_factory=list)  # Oldest first
+    version_pattern: str = "none"  # semantic, calendar, other or none
+    average_days: Optional[float] = None
+    median_days: Optional[float] = None
+    latest_date: Optional[str] = None  # ISO date of the latest tag
+
+
+@dataclass
+class GitHistoryInfo:
+    """Conventions and risk areas mined from the repository history."""
+
+    commits_analyzed: int = 0
+    contributors: int = 0
+    first_commit_date: Optional[str] = None
+    last_commit_date: Optional[str] = None
+    commit_convention: Optional[CommitConvention] = None
+    # gitflow, release_branches, feature_branches or trunk_based
+    branching_strategy: str = "trunk_based"
+    branches: List[str] = field(default_factory=list)
+    hotspots: List[FileChurn] = field(default_factory=list)
+    co_changes: List[CoChange] = field(default_factory=list)
+    releases: Optional[ReleaseCadence] = None
+    merge_commits: int = 0
+    hotfix_commits: int = 0  # Subjects mentioning hotfix or urgent fixes
+    revert_commits: int = 0
+
+
+@dataclass
+class ModuleMetrics:
+    """Size, branching and coupling of one module (a file, or a Go package)."""
+
+    path: str
+    language: str
+    loc: int = 0  # Non-blank lines outside comments
+    functions: int = 0
+    cyclomatic_complexity: int = 0  # One per function plus decision points
+    public_api: int = 0  # Items visible outside the module
+    fan_in: int = 0  # Project modules importing this one
+    fan_out: int = 0  # Project modules this one imports
+
+
+@dataclass
+class LanguageMetrics:
+    """Code metrics of all measured modules of one language."""
+
+    language: str
+    modules: int = 0
+    loc: int = 0
+    functions: int = 0
+    cyclomatic_complexity: int = 0
+    public_api: int = 0
+
+    @property
+    def complexity_per_function(self) -> float:
+        return self.cyclomatic_complexity / max(self.functions, 1)
+
+
+@dataclass
+class ComplexityMetrics:
+    """Measured code behind a deep (``deep_analysis``) complexity assessment."""
+
+    languages: Dict[str, LanguageMetrics] = field(default_factory=dict)
+    dependencies: int = 0  # Import edges between project modules
+    most_complex: List[ModuleMetrics] = field(default_factory=list)
+    most_depended_on: List[ModuleMetrics] = field(default_factory=list)
+    # Points each metric added to the complexity score, e.g. "9,800 LOC (+2)"
+    factors: List[str] = field(default_factory=list)
+
+    @property
+    def modules(self) -> int:
+        return sum(language.modules for language in self.languages.values())
+
+    @property
+    def loc(self) -> int:
+        return sum(language.loc for language in self.languages.values())
+
+    @property
+    def functions(self) -> int:
+        return sum(language.functions for language in self.languages.values())
+
+    @property
+    def public_api(self) -> int:
+        return sum(language.public_api for language in self.languages.values())
+
+    @property
+    def complexity_per_function(self) -> float:
+        total = sum(
+            language.cyclomatic_complexity for language in self.languages.values()
+        )
+        return total / max(self.functions, 1)
+
+
 @dataclass
 class ProjectAnalysis:
     """Complete project analysis results."""
@@ -158,7 +415,11 @@ class ProjectAnalysis:
     framework_info: FrameworkInfo = field(default_factory=FrameworkInfo)
     domain_info: DomainInfo = field(default_factory=DomainInfo)
     project_type: ProjectType = ProjectType.UNKNOWN
+    # Signals of the classification rule that decided the project type
+    project_type_evidence: List[DetectionEvidence] = field(default_factory=list)
     complexity_level: ComplexityLevel = ComplexityLevel.SIMPLE
+    # Deep analysis only: the code metrics the complexity level was scored from
+    complexity_metrics: Optional[ComplexityMetrics] = None
     architecture_pattern: ArchitecturePattern = ArchitecturePattern.UNKNOWN
     dev_environment: DevelopmentEnvironment = field(
         default_factory=DevelopmentEnvironment
@@ -167,11 +428,32 @@ class ProjectAnalysis:
     # Convenience attributes commonly asserted in tests
     build_system: Optional[str] = None
     dependencies: List[str] = field(default_factory=list)
+    # Rust projects: per-crate breakdown of the Cargo workspace
+    cargo_workspace: Optional[CargoWorkspaceInfo] = None
+    # Resolved dependency graphs with pinned versions, keyed by package manager
+    # ("cargo" from Cargo.lock, "go" from go.mod)
+    dependency_graphs: Dict[str, List["DependencyInfo"]] = field(
+        default_factory=dict
+    )
+    # Rust projects: module tree, public items, tests and unsafe usage
+    rust_source: Optional[RustSourceInfo] = None
+    # Go projects: go.mod / go.work modules, requirements and replacements
+    go_workspace: Optional[GoWorkspaceInfo] = None
+    # Monorepos: workspace tools and one analysis per package
+    monorepo: Optional[MonorepoInfo] = None
+    # Git repositories: commit conventions, branching, hotspots and releases
+    git_history: Optional[GitHistoryInfo] = None
+    # Custom detection rules from configuration that matched this project
+    rule_matches: List[DetectionRuleMatch] = field(default_factory=list)
 
     # Analysis metadata
     analysis_confidence: float = 0.0
     analysis_timestamp: Optional[str] = None
     analyzer_version: Optional[str] = None
+    # Set when the analysis was reused from the on-disk cache unchanged
+    from_cache: bool = False
+    # Files re-scanned when a cached analysis was updated for changed files
+    rescanned_files: Optional[List[str]] = None
     warnings: List[str] = field(default_factory=list)
     suggestions: List[str] = field(default_factory=list)
 
@@ -190,6 +472,48 @@ class ProjectAnalysis:
         """Primary framework shorthand."""
         return self.framework_info.primary
 
+    @property
+    def crates(self) -> List[CrateInfo]:
+        """Member crates of a Cargo workspace (empty for non-Rust projects)."""
+        return self.cargo_workspace.members if self.cargo_workspace else []
+
+    @property
+    def direct_dependencies(self) -> List["DependencyInfo"]:
+        """Directly declared entries of every resolved dependency graph."""
+        return [
+            dep
+            for graph in self.dependency_graphs.values()
+            for dep in graph
+            if dep.direct
+        ]
+
+    def to_analysis_result(self) -> "AnalysisResult":
+        """Convert to the flat AnalysisResult model (with resolved dependencies).
+
+        Every resolved dependency graph is flattened into
+        ``AnalysisResult.dependencies``, in package-manager order.
+        """
+        return AnalysisResult(
+            project_info=ProjectInfo(
+                name=self.project_path.name,
+                framework=self.framework,
+                language=self.language,
+                language_version=self.language_info.version_info.get(
+                    self.language or ""
+                ),
+                path=str(self.project_path),
+            ),
+            dependencies=[
+                dep for graph in self.dependency_graphs.values() for dep in graph
+            ],
+            analysis_timestamp=self.analysis_timestamp,
+            confidence=self.analysis_confidence / 100,
+            project_type=self.project_type,
+            language=self.language,
+            framework=self.framework,
+            complexity=self.complexity_level,
+        )
+
     @property
     def has_tests(self) -> bool:
         """Whether project has test files."""
@@ -501,6 +825,9 @@ class DependencyInfo:
     package_managers: List[str] = field(default_factory=list)
     dependencies: Dict[str, str] = field(default_factory=dict)
     dev_dependencies: Dict[str, str] = field(default_factory=dict)
+    # Resolved-graph fields (e.g. from Cargo.lock)
+    direct: bool = True
+    features: List[str] = field(default_factory=list)
 
     VALID_DEPENDENCY_TYPES = {"runtime", "development", "test", "build", "optional"}
 
@@ -530,6 +857,8 @@ class DependencyInfo:
             "package_managers": self.package_managers,
             "dependencies": self.dependencies,
             "dev_dependencies": self.dev_dependencies,
+            "direct": self.direct,
+            "features": self.features,
         }
 
 
@@ -695,6 +1024,10 @@ class OutputTarget(Enum):
     CLAUDE = "claude"
     CODEX = "codex"
     GEMINI = "gemini"
+    CURSOR = "cursor"
+    COPILOT = "copilot"
+    WINDSURF = "windsurf"
+    AIDER = "aider"
 
 
 @dataclass
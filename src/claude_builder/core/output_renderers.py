@@ -8,6 +8,7 @@ import re
 from pathlib import PurePosixPath
 from typing import Protocol
 
+from claude_builder.core.language_plugins import get_language_registry
 from claude_builder.core.models import (
     EnvironmentBundle,
     GeneratedArtifact,
@@ -62,32 +63,28 @@ def _extract_front_matter_and_body(content: str) -> tuple[dict[str, str], str]:
     return metadata, body
 
 
-def _retarget_for_gemini(content: str) -> str:
-    """Retarget Claude-oriented phrasing for Gemini context files."""
+def _retarget(content: str, primary_file: str, tool_name: str, short_name: str) -> str:
+    """Retarget Claude-oriented phrasing for another tool's instruction files."""
     replacements: tuple[tuple[str, str], ...] = (
-        ("CLAUDE.md", "GEMINI.md"),
-        ("Claude Code", "Gemini CLI"),
+        ("CLAUDE.md", primary_file),
+        ("Claude Code", tool_name),
     )
 
     updated = content
     for source, destination in replacements:
         updated = updated.replace(source, destination)
 
-    return re.sub(r"\bClaude\b", "Gemini", updated)
+    return re.sub(r"\bClaude\b", short_name, updated)
 
 
-def _retarget_for_codex(content: str) -> str:
-    """Retarget Claude-oriented phrasing for Codex instruction files."""
-    replacements: tuple[tuple[str, str], ...] = (
-        ("CLAUDE.md", "AGENTS.md"),
-        ("Claude Code", "Codex CLI"),
-    )
+def _retarget_for_gemini(content: str) -> str:
+    """Retarget Claude-oriented phrasing for Gemini context files."""
+    return _retarget(content, "GEMINI.md", "Gemini CLI", "Gemini")
 
-    updated = content
-    for source, destination in replacements:
-        updated = updated.replace(source, destination)
 
-    return re.sub(r"\bClaude\b", "Codex", updated)
+def _retarget_for_codex(content: str) -> str:
+    """Retarget Claude-oriented phrasing for Codex instruction files."""
+    return _retarget(content, "AGENTS.md", "Codex CLI", "Codex")
 
 
 def _make_skill_slug(name: str) -> str:
@@ -151,6 +148,90 @@ def _render_gemini_specialist_markdown(subagent_name: str, content: str) -> str:
     return "\n".join(lines)
 
 
+# Files a specialist works on, chosen by words in its name (first match wins)
+SPECIALIST_GLOBS: tuple[tuple[frozenset[str], tuple[str, ...]], ...] = (
+    (
+        frozenset({"test", "tester", "testing", "qa"}),
+        ("tests/**", "test/**", "**/test_*", "**/*_test.*", "**/*.test.*"),
+    ),
+    (
+        frozenset({"docs", "documentation", "writer"}),
+        ("docs/**", "**/*.md"),
+    ),
+    (
+        frozenset(
+            {"devops", "deploy", "deployment", "infrastructure", "infra", "ci"}
+            | {"pipeline", "kubernetes", "docker", "terraform", "ansible", "sre"}
+        ),
+        (".github/workflows/**", "**/Dockerfile", "**/*.tf", "**/*.yml", "**/*.yaml"),
+    ),
+    (
+        frozenset({"frontend", "ui", "ux", "designer", "whimsy"}),
+        ("**/*.css", "**/*.scss", "**/*.html", "**/*.jsx", "**/*.tsx", "**/*.vue"),
+    ),
+    (
+        frozenset({"database", "data", "sql", "migration", "migrations"}),
+        ("**/*.sql", "**/migrations/**"),
+    ),
+)
+
+FENCE = re.compile(r"^\s*(```|~~~)")
+
+
+def _specialist_globs(subagent_name: str, language: str | None) -> list[str]:
+    """Glob patterns for the files a specialist is relevant to.
+
+    Falls back to the project's source files, or to no patterns when the
+    language is unknown.
+    """
+    words = set(_make_skill_slug(subagent_name).split("-"))
+    for keywords, globs in SPECIALIST_GLOBS:
+        if words & keywords:
+            return list(globs)
+    plugin = get_language_registry().get(language)
+    if plugin is None:
+        return []
+    return [f"**/*{extension}" for extension in plugin.get_source_extensions()]
+
+
+def _demote_headings(markdown: str, levels: int) -> str:
+    """Nest markdown headings ``levels`` deeper, leaving code blocks alone."""
+    lines = []
+    fenced = False
+    for line in markdown.splitlines():
+        if FENCE.match(line):
+            fenced = not fenced
+        if not fenced and re.match(r"#{1,6} ", line):
+            line = "#" * levels + line
+        lines.append(line)
+    return "\n".join(lines)
+
+
+def _specialist_description(subagent_name: str, content: str) -> str:
+    """Subagent description from its front matter, or a generic one."""
+    metadata, _ = _extract_front_matter_and_body(content)
+    display_name = metadata.get("name") or _make_skill_slug(subagent_name)
+    return metadata.get(
+        "description", f"Specialized guidance for {display_name} work."
+    )
+
+
+def _render_specialist_markdown(
+    subagent_name: str, content: str, *, heading_level: int = 1
+) -> str:
+    """Render a subagent as plain markdown guidance for tools without agents."""
+    metadata, body = _extract_front_matter_and_body(content)
+    display_name = metadata.get("name") or _make_skill_slug(subagent_name)
+    lines = [
+        f"{'#' * heading_level} {display_name}",
+        "",
+        _specialist_description(subagent_name, content),
+        "",
+        _demote_headings(body, heading_level - 1) if body else "",
+    ]
+    return "\n".join(lines).rstrip() + "\n"
+
+
 class ClaudeTargetRenderer:
     """Render current Claude-format files as generic artifacts."""
 
@@ -337,10 +418,302 @@ class GeminiContextRenderer:
         )
 
 
+class CursorRulesRenderer:
+    """Render Cursor project rules.
+
+    The project context becomes an always-applied rule and each subagent an
+    auto-attached rule whose ``globs`` select the files it covers.
+    """
+
+    target = OutputTarget.CURSOR
+
+    def render(
+        self,
+        environment: EnvironmentBundle,
+        *,
+        agents_dir: str = ".cursor/rules",
+    ) -> RenderedTargetOutput:
+        """Render ``.cursor/rules/*.mdc`` files."""
+        rules_base = _normalise_agents_dir(agents_dir) or ".cursor/rules"
+        project_rule = _retarget(
+            environment.claude_md, f"{rules_base}/project.mdc", "Cursor", "Cursor"
+        )
+        artifacts: list[GeneratedArtifact] = [
+            GeneratedArtifact(
+                path=f"{rules_base}/project.mdc",
+                content=(
+                    "---\n"
+                    'description: "Project context and conventions"\n'
+                    "globs:\n"
+                    "alwaysApply: true\n"
+                    "---\n"
+                    f"{project_rule.rstrip()}\n"
+                ),
+                description="Always-applied Cursor project rule",
+            )
+        ]
+
+        language = environment.metadata.get("language")
+        for subagent in environment.subagent_files:
+            slug = _make_skill_slug(subagent.name)
+            globs = _specialist_globs(subagent.name, language)
+            # A JSON string is a valid YAML scalar whatever the description holds
+            description = json.dumps(
+                _specialist_description(subagent.name, subagent.content),
+                ensure_ascii=False,
+            )
+            artifacts.append(
+                GeneratedArtifact(
+                    path=f"{rules_base}/{slug}.mdc",
+                    content=(
+                        "---\n"
+                        f"description: {description}\n"
+                        f"globs: {','.join(globs)}\n"
+                        "alwaysApply: false\n"
+                        "---\n"
+                        + _render_specialist_markdown(subagent.name, subagent.content)
+                    ),
+                    description="Auto-attached Cursor rule",
+                )
+            )
+
+        metadata = dict(environment.metadata)
+        metadata["rule_count"] = len(artifacts)
+        if environment.generation_timestamp:
+            metadata["generation_timestamp"] = environment.generation_timestamp
+
+        return RenderedTargetOutput(
+            target=self.target,
+            artifacts=artifacts,
+            metadata=metadata,
+        )
+
+
+class CopilotInstructionsRenderer:
+    """Render GitHub Copilot repository and path-specific instructions.
+
+    Each subagent becomes a ``.instructions.md`` file whose ``applyTo`` globs
+    select the files Copilot applies it to.
+    """
+
+    target = OutputTarget.COPILOT
+
+    def render(
+        self,
+        environment: EnvironmentBundle,
+        *,
+        agents_dir: str = ".github/instructions",
+    ) -> RenderedTargetOutput:
+        """Render ``.github/copilot-instructions.md`` and instruction files."""
+        instructions_base = _normalise_agents_dir(agents_dir) or ".github/instructions"
+        artifacts: list[GeneratedArtifact] = []
+
+        language = environment.metadata.get("language")
+        instruction_paths: list[str] = []
+        for subagent in environment.subagent_files:
+            slug = _make_skill_slug(subagent.name)
+            instruction_path = f"{instructions_base}/{slug}.instructions.md"
+            instruction_paths.append(instruction_path)
+            apply_to = ",".join(_specialist_globs(subagent.name, language)) or "**"
+            artifacts.append(
+                GeneratedArtifact(
+                    path=instruction_path,
+                    content=(
+                        f'---\napplyTo: "{apply_to}"\n---\n'
+                        + _render_specialist_markdown(subagent.name, subagent.content)
+                    ),
+                    description="Copilot path-specific instructions",
+                )
+            )
+
+        instructions = _retarget(
+            environment.claude_md,
+            ".github/copilot-instructions.md",
+            "GitHub Copilot",
+            "Copilot",
+        ).rstrip()
+        if instruction_paths:
+            listing = "\n".join(f"- `{path}`" for path in instruction_paths)
+            instructions = (
+                f"{instructions}\n\n## Path-Specific Instructions\n"
+                "Copilot also applies these files to matching paths:\n"
+                f"{listing}"
+            )
+        artifacts.insert(
+            0,
+            GeneratedArtifact(
+                path=".github/copilot-instructions.md",
+                content=f"{instructions}\n",
+                description="Copilot repository instructions",
+            ),
+        )
+
+        metadata = dict(environment.metadata)
+        metadata["instruction_count"] = len(instruction_paths)
+        if environment.generation_timestamp:
+            metadata["generation_timestamp"] = environment.generation_timestamp
+
+        return RenderedTargetOutput(
+            target=self.target,
+            artifacts=artifacts,
+            metadata=metadata,
+        )
+
+
+class WindsurfRulesRenderer:
+    """Render a single ``.windsurfrules`` file.
+
+    Windsurf reads one rules file, so each subagent becomes a section of it
+    naming the files it applies to.
+    """
+
+    target = OutputTarget.WINDSURF
+
+    def render(
+        self,
+        environment: EnvironmentBundle,
+        *,
+        agents_dir: str = "",
+    ) -> RenderedTargetOutput:
+        """Render ``.windsurfrules``; ``agents_dir`` is not used."""
+        rules = _retarget(
+            environment.claude_md, ".windsurfrules", "Windsurf", "Windsurf"
+        ).rstrip()
+
+        language = environment.metadata.get("language")
+        sections: list[str] = []
+        for subagent in environment.subagent_files:
+            section = _render_specialist_markdown(
+                subagent.name, subagent.content, heading_level=3
+            )
+            globs = _specialist_globs(subagent.name, language)
+            if globs:
+                heading, _, rest = section.partition("\n")
+                applies = ", ".join(f"`{glob}`" for glob in globs)
+                section = f"{heading}\nApplies to: {applies}\n{rest}"
+            sections.append(section.rstrip())
+
+        if sections:
+            rules = f"{rules}\n\n## Specialist Rules\n\n" + "\n\n".join(sections)
+
+        metadata = dict(environment.metadata)
+        metadata["specialist_rule_count"] = len(sections)
+        if environment.generation_timestamp:
+            metadata["generation_timestamp"] = environment.generation_timestamp
+
+        return RenderedTargetOutput(
+            target=self.target,
+            artifacts=[
+                GeneratedArtifact(
+                    path=".windsurfrules",
+                    content=f"{rules}\n",
+                    description="Windsurf workspace rules",
+                )
+            ],
+            metadata=metadata,
+        )
+
+
+class AiderConventionsRenderer:
+    """Render Aider conventions and the config that loads them.
+
+    Each subagent becomes a conventions file that ``.aider.conf.yml`` adds to
+    every chat as read-only context.
+    """
+
+    target = OutputTarget.AIDER
+
+    def render(
+        self,
+        environment: EnvironmentBundle,
+        *,
+        agents_dir: str = ".aider/conventions",
+    ) -> RenderedTargetOutput:
+        """Render ``CONVENTIONS.md``, specialist conventions and the config."""
+        conventions_base = _normalise_agents_dir(agents_dir) or ".aider/conventions"
+        artifacts: list[GeneratedArtifact] = [
+            GeneratedArtifact(
+                path="CONVENTIONS.md",
+                content=_retarget(
+                    environment.claude_md, "CONVENTIONS.md", "Aider", "Aider"
+                ).rstrip()
+                + "\n",
+                description="Aider coding conventions",
+            )
+        ]
+
+        convention_paths: list[str] = []
+        for subagent in environment.subagent_files:
+            slug = _make_skill_slug(subagent.name)
+            convention_path = f"{conventions_base}/{slug}.md"
+            convention_paths.append(convention_path)
+            artifacts.append(
+                GeneratedArtifact(
+                    path=convention_path,
+                    content=_render_specialist_markdown(
+                        subagent.name, subagent.content
+                    ),
+                    description="Aider specialist conventions",
+                )
+            )
+
+        read_entries = "\n".join(
+            f"  - {path}" for path in ["CONVENTIONS.md", *convention_paths]
+        )
+        artifacts.append(
+            GeneratedArtifact(
+                path=".aider.conf.yml",
+                content=(
+                    "# Generated by claude-builder: load the conventions as "
+                    "read-only context\n"
+                    f"read:\n{read_entries}\n"
+                ),
+                description="Aider configuration",
+            )
+        )
+
+        metadata = dict(environment.metadata)
+        metadata["convention_count"] = len(convention_paths)
+        if environment.generation_timestamp:
+            metadata["generation_timestamp"] = environment.generation_timestamp
+
+        return RenderedTargetOutput(
+            target=self.target,
+            artifacts=artifacts,
+            metadata=metadata,
+        )
+
+
+def default_agents_dir(target: OutputTarget) -> str:
+    """Return the default specialist artifact directory for a target."""
+    if target == OutputTarget.CLAUDE:
+        return ".claude/agents"
+    if target == OutputTarget.CODEX:
+        return ".agents/skills"
+    if target == OutputTarget.CURSOR:
+        return ".cursor/rules"
+    if target == OutputTarget.COPILOT:
+        return ".github/instructions"
+    if target == OutputTarget.WINDSURF:
+        # Windsurf reads a single rules file, so there is no directory
+        return ""
+    if target == OutputTarget.AIDER:
+        return ".aider/conventions"
+    return ".gemini/agents"
+
+
 def get_target_renderer(target: OutputTarget) -> TargetRenderer:
     """Return renderer for a target."""
     if target == OutputTarget.CLAUDE:
         return ClaudeTargetRenderer()
     if target == OutputTarget.CODEX:
         return CodexTargetRenderer()
+    if target == OutputTarget.CURSOR:
+        return CursorRulesRenderer()
+    if target == OutputTarget.COPILOT:
+        return CopilotInstructionsRenderer()
+    if target == OutputTarget.WINDSURF:
+        return WindsurfRulesRenderer()
+    if target == OutputTarget.AIDER:
+        return AiderConventionsRenderer()
     return GeminiContextRenderer()
@@ -0,0 +1,120 @@
+"""Dependency-driven analysis pipeline.
+
+Every ``AnalysisStage`` names the results it ``requires`` and provides one
+result under its own ``name``. ``AnalysisPipeline`` starts a stage as soon as
+all of its inputs are available: on a thread pool when parallel processing is
+enabled, otherwise one stage at a time in dependency order. Independent
+detectors (frameworks, Cargo and Go manifests, tooling, architecture, domain)
+therefore overlap their file I/O while dependent ones still see complete
+inputs.
+
+``ProjectAnalyzer`` and ``AsyncProjectAnalyzer`` run the same pipeline, so sync
+and async callers get the same ``ProjectAnalysis``.
+"""
+
+from concurrent.futures import FIRST_COMPLETED, Future, ThreadPoolExecutor, wait
+from dataclasses import dataclass
+from typing import Any, Callable, Dict, List, Mapping, Optional, Sequence, Tuple
+
+
+@dataclass(frozen=True)
+class AnalysisStage:
+    """One detector step.
+
+    ``run`` receives a mapping that holds exactly the results named in
+    ``requires``, so a stage cannot read anything it did not declare.
+    """
+
+    name: str
+    run: Callable[[Dict[str, Any]], Any]
+    requires: Tuple[str, ...] = ()
+
+
+class AnalysisPipeline:
+    """Runs analysis stages once their inputs are available."""
+
+    def __init__(
+        self,
+        stages: Sequence[AnalysisStage],
+        *,
+        parallel: bool = True,
+        max_workers: Optional[int] = None,
+    ):
+        names = [stage.name for stage in stages]
+        duplicates = sorted({name for name in names if names.count(name) > 1})
+        if duplicates:
+            msg = f"Duplicate analysis stages: {', '.join(duplicates)}"
+            raise ValueError(msg)
+        self.stages = list(stages)
+        self.parallel = parallel
+        self.max_workers = max_workers
+
+    def order(self, inputs: Sequence[str] = ()) -> List[AnalysisStage]:
+        """Stages in a dependency-respecting order (declaration order on ties).
+
+        Raises ``ValueError`` when a stage requires a result that neither an
+        input nor another stage provides, or when stages depend on each other.
+        """
+        known = set(inputs) | {stage.name for stage in self.stages}
+        for stage in self.stages:
+            missing = [name for name in stage.requires if name not in known]
+            if missing:
+                msg = f"Stage '{stage.name}' requires unknown {', '.join(missing)}"
+                raise ValueError(msg)
+
+        available = set(inputs)
+        pending = list(self.stages)
+        ordered: List[AnalysisStage] = []
+        while pending:
+            ready = [s for s in pending if available.issuperset(s.requires)]
+            if not ready:
+                cycle = ", ".join(stage.name for stage in pending)
+                msg = f"Analysis stages depend on each other: {cycle}"
+                raise ValueError(msg)
+            for stage in ready:
+                ordered.append(stage)
+                available.add(stage.name)
+                pending.remove(stage)
+        return ordered
+
+    def run(self, inputs: Optional[Mapping[str, Any]] = None) -> Dict[str, Any]:
+        """Run every stage and return the inputs plus all stage results.
+
+        The first exception raised by a stage is re-raised unchanged once the
+        stages already running have finished; stages that have not started
+        yet are skipped.
+        """
+        results: Dict[str, Any] = dict(inputs or {})
+        ordered = self.order(list(results))
+
+        if not self.parallel or len(ordered) < 2:
+            for stage in ordered:
+                results[stage.name] = stage.run(self._arguments(stage, results))
+            return results
+
+        pending = list(ordered)
+        running: Dict["Future[Any]", AnalysisStage] = {}
+        with ThreadPoolExecutor(
+            max_workers=self.max_workers, thread_name_prefix="claude-builder-analysis"
+        ) as executor:
+            while pending or running:
+                for stage in [s for s in pending if results.keys() >= set(s.requires)]:
+                    pending.remove(stage)
+                    arguments = self._arguments(stage, results)
+                    running[executor.submit(stage.run, arguments)] = stage
+
+                done, _ = wait(running, return_when=FIRST_COMPLETED)
+                for future in done:
+                    stage = running.pop(future)
+                    error = future.exception()
+                    if error is not None:
+                        pending.clear()
+                        raise error
+                    results[stage.name] = future.result()
+        return results
+
+    @staticmethod
+    def _arguments(
+        stage: AnalysisStage, results: Mapping[str, Any]
+    ) -> Dict[str, Any]:
+        return {name: results[name] for name in stage.requires}
@@ -0,0 +1,236 @@
+"""Sandboxed Jinja2 environment shared by every template renderer.
+
+All templates, whether packaged, user supplied or built in code, render through
+the :class:`~jinja2.sandbox.SandboxedEnvironment` created here so they share
+one set of filters, the domain macros and include search paths.
+
+Older templates use ``${var}`` placeholders, ``${#list}...${/list}`` sections
+and ``{{#if}}``/``{{#each}}`` blocks. :func:`migrate_template_syntax` rewrites
+that syntax to Jinja2 when a template is loaded, so both styles keep working.
+"""
+
+import re
+
+from datetime import date, datetime
+from functools import lru_cache
+from pathlib import Path
+from typing import Any, Callable, Dict, List, Optional, Sequence, Tuple, Union
+
+from jinja2 import (
+    BaseLoader,
+    ChainableUndefined,
+    FileSystemLoader,
+    StrictUndefined,
+    Undefined,
+)
+from jinja2.sandbox import SandboxedEnvironment
+
+
+TEMPLATES_ROOT = Path(__file__).parent.parent / "templates"
+
+# Searched after any user template directories; domain templates import
+# ``_macros.md`` by bare name.
+DEFAULT_SEARCH_PATHS = (
+    TEMPLATES_ROOT / "domains" / "devops",
+    TEMPLATES_ROOT / "domains" / "mlops",
+    TEMPLATES_ROOT / "domains",
+    TEMPLATES_ROOT,
+)
+
+# Only lowercase identifiers are template variables; ``${DATABASE_URL}``,
+# ``${userId}`` and ``${doc.id}`` in code samples are shell or JavaScript.
+LEGACY_VARIABLE = re.compile(r"(\{?)\$\{([a-z_][a-z0-9_]*)\}")
+LEGACY_BLOCK = re.compile(
+    r"\$\{(?P<sigil>[#^/])(?P<name>[a-z_][a-z0-9_]*)\}"
+    r"|\{\{(?P<hash>[#/])(?P<kind>if|each)(?:\s+(?P<var>[A-Za-z_]\w*))?\s*\}\}"
+)
+# GitHub Actions expressions in workflow samples are not Jinja.
+ACTIONS_EXPRESSION = re.compile(r"\$\{\{.*?\}\}")
+
+
+def _strftime(value: Any, fmt: str) -> str:
+    if isinstance(value, (datetime, date)):
+        return value.strftime(fmt)
+    return "" if value is None else str(value)
+
+
+def _section(value: Any) -> List[Any]:
+    """Items a legacy section iterates: the list, the value once, or nothing."""
+    if isinstance(value, Undefined) or not value:
+        return []
+    if isinstance(value, (list, tuple)):
+        return list(value)
+    return [value]
+
+
+def _or_placeholder(value: Any, name: str) -> Any:
+    """Legacy ``${name}`` semantics: unknown names stay literal, None is empty."""
+    if isinstance(value, Undefined):
+        return "${" + name + "}"
+    return "" if value is None else value
+
+
+# Added to Jinja's built-in filters (upper, lower, title, ...), which accept
+# None and undefined values
+FILTERS: Dict[str, Callable[..., Any]] = {
+    "length": len,
+    "strftime": _strftime,
+    "section": _section,
+    "or_placeholder": _or_placeholder,
+}
+
+
+def _variable(name: str, scopes: Sequence[str]) -> str:
+    expression = name
+    for scope in scopes:
+        item = f'{scope}["{name}"]'
+        expression = f"{item} if {item} is defined else {expression}"
+    if scopes:
+        expression = f"({expression})"
+    return "{{ " + f'{expression} | or_placeholder("{name}")' + " }}"
+
+
+def _block_key(match: "re.Match[str]") -> Optional[Tuple[str, str]]:
+    """The name an opening or closing block tag pairs on, and which it is."""
+    if match.group("sigil") == "/" or match.group("hash") == "/":
+        return (match.group("name") or match.group("kind"), "close")
+    if match.group("sigil"):
+        return (match.group("name"), "open")
+    if match.group("var"):
+        return (match.group("kind"), "open")
+    return None
+
+
+def _find_close(text: str, start: int, key: str) -> Optional["re.Match[str]"]:
+    depth = 1
+    for match in LEGACY_BLOCK.finditer(text, start):
+        tag = _block_key(match)
+        if tag is None or tag[0] != key:
+            continue
+        depth += 1 if tag[1] == "open" else -1
+        if depth == 0:
+            return match
+    return None
+
+
+def _standalone(text: str, match: "re.Match[str]") -> bool:
+    """Whether a legacy tag sits alone on its line, like a Mustache tag."""
+    line_start = text.rfind("\n", 0, match.start()) + 1
+    line_end = text.find("\n", match.end())
+    after = text[match.end() :] if line_end == -1 else text[match.end() : line_end]
+    return not text[line_start : match.start()].strip() and not after.strip()
+
+
+def _tag(statement: str, text: str, match: "re.Match[str]") -> str:
+    # ``+%}`` keeps the newline after inline tags that trim_blocks would eat
+    end = "%}" if _standalone(text, match) else "+%}"
+    return f"{{% {statement} {end}"
+
+
+def _migrate(text: str, scopes: Sequence[str]) -> str:
+    out: List[str] = []
+    pos = 0
+    for match in LEGACY_BLOCK.finditer(text):
+        if match.start() < pos:
+            continue
+        tag = _block_key(match)
+        close = _find_close(text, match.end(), tag[0]) if tag else None
+        if tag is None or tag[1] != "open" or close is None:
+            continue
+        out.append(_migrate_variables(text[pos : match.start()], scopes))
+        body = text[match.end() : close.start()]
+        name = match.group("name") or match.group("var")
+        if match.group("sigil") == "#":
+            loop = f"_{name}"
+            opening, closing = f"for {loop} in {name} | section", "endfor"
+            body = _migrate(body, [*scopes, loop])
+        elif match.group("sigil") == "^":
+            opening, closing = f"if not {name} | section", "endif"
+            body = _migrate(body, scopes)
+        elif match.group("kind") == "each":
+            opening, closing = f"for item in {name} | section", "endfor"
+            body = _migrate(body, scopes)
+        else:
+            opening, closing = f"if {name} | section", "endif"
+            body = _migrate(body, scopes)
+        out.append(_tag(opening, text, match) + body + _tag(closing, text, close))
+        pos = close.end()
+    out.append(_migrate_variables(text[pos:], scopes))
+    return "".join(out)
+
+
+def _literal(text: str) -> str:
+    # A string expression, unlike {% raw %}, keeps the newline that follows
+    escaped = text.replace("\\", "\\\\").replace('"', '\\"')
+    return '{{ "' + escaped + '" }}'
+
+
+def _migrate_variables(text: str, scopes: Sequence[str]) -> str:
+    # A literal brace before the tag would open ``{{{``, so emit it as a string
+    return LEGACY_VARIABLE.sub(
+        lambda m: ("{{ '{' }}" if m.group(1) else "") + _variable(m.group(2), scopes),
+        text,
+    )
+
+
+def migrate_template_syntax(content: str) -> str:
+    """Rewrite legacy placeholder syntax in ``content`` to Jinja2.
+
+    ``${var}`` becomes ``{{ var }}`` but renders literally when ``var`` is not
+    in the context, ``${#items}...${/items}`` loops over a list (or renders
+    once for a truthy value) with item fields shadowing outer variables,
+    ``${^items}`` renders when the section would not, and ``{{#if}}`` /
+    ``{{#each}}`` become ``{% if %}`` / ``{% for item in ... %}``.
+    """
+    content = ACTIONS_EXPRESSION.sub(lambda m: _literal(m.group(0)), content)
+    return _migrate(content, [])
+
+
+class MigratingLoader(FileSystemLoader):
+    """File system loader that migrates legacy syntax as templates load."""
+
+    def get_source(
+        self, environment: Any, template: str
+    ) -> Tuple[str, Optional[str], Optional[Callable[[], bool]]]:
+        source, filename, uptodate = super().get_source(environment, template)
+        return migrate_template_syntax(source), filename, uptodate
+
+
+@lru_cache(maxsize=None)
+def _environment(search_paths: Tuple[str, ...], strict: bool) -> SandboxedEnvironment:
+    loader: BaseLoader = MigratingLoader(list(search_paths))
+    env = SandboxedEnvironment(
+        loader=loader,
+        autoescape=False,
+        undefined=StrictUndefined if strict else ChainableUndefined,
+        trim_blocks=True,
+        lstrip_blocks=True,
+        keep_trailing_newline=True,
+    )
+    env.filters.update(FILTERS)
+    return env
+
+
+def create_environment(
+    template_dirs: Optional[Sequence[Union[str, Path]]] = None, *, strict: bool = True
+) -> SandboxedEnvironment:
+    """Return the shared sandboxed environment for ``template_dirs``.
+
+    User directories are searched before the packaged templates. A strict
+    environment raises on undefined ``{{ }}`` variables; a lenient one renders
+    them empty.
+    """
+    paths = [Path(p) for p in template_dirs or []] + list(DEFAULT_SEARCH_PATHS)
+    return _environment(tuple(str(p) for p in paths), strict)
+
+
+def render_template_string(
+    content: str,
+    context: Dict[str, Any],
+    *,
+    strict: bool = True,
+    template_dirs: Optional[Sequence[Union[str, Path]]] = None,
+) -> str:
+    """Migrate and render template ``content`` with ``context``."""
+    env = create_environment(template_dirs, strict=strict)
+    return env.from_string(migrate_template_syntax(content)).render(**context)
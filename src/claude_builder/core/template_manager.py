@@ -16,12 +16,26 @@ from datetime import datetime, timezone
 from pathlib import Path
 from typing import Any, Dict, List, Optional, cast
 
+from claude_builder.analysis.code_metrics import summarize_code_metrics
+from claude_builder.analysis.monorepo import summarize_monorepo
+from claude_builder.analysis.monorepo_docs import generate_package_docs
+from claude_builder.analysis.rust_source import (
+    render_module_tree,
+    summarize_rust_source,
+)
 from claude_builder.core.models import (
     AgentDefinition,
+    ComplexityMetrics,
+    CommitConvention,
     EnvironmentBundle,
+    GeneratedArtifact,
+    GitHistoryInfo,
+    GoWorkspaceInfo,
+    MonorepoInfo,
     OutputTarget,
     ProjectAnalysis,
     RenderedTargetOutput,
+    RustSourceInfo,
     SubagentFile,
     ValidationResult,
 )
@@ -41,6 +55,10 @@ from claude_builder.core.template_management.validation.template_validator impor
     ComprehensiveTemplateValidator,
 )
 from claude_builder.utils.exceptions import SecurityError
+from claude_builder.utils.git import (
+    summarize_commit_guidelines,
+    summarize_git_workflow,
+)
 from claude_builder.utils.security import security_validator
 
 
@@ -87,6 +105,42 @@ except Exception:  # pragma: no cover
 # Expose a simple capability flag used in tests to branch behavior
 MODULAR_COMPONENTS_AVAILABLE = True
 
+# Extra Rust standards for crate kinds that change what code may do
+RUST_PROJECT_TYPE_STANDARDS = {
+    "proc_macro": [
+        "- Report macro errors as `syn::Error` with spans instead of panicking",
+        "- Cover expansions and compile errors with `trybuild` tests",
+    ],
+    "embedded_firmware": [
+        "- Stay `no_std`: no heap allocation unless a global allocator is set up",
+        "- Never block or panic in interrupt handlers",
+        "- Verify builds for the target triple, not only the host",
+    ],
+    "wasm_module": [
+        "- Keep the `#[wasm_bindgen]` surface small and convert errors to `JsValue`",
+        "- Test with `wasm-pack test` in addition to `cargo test`",
+    ],
+    "python_extension": [
+        "- Convert errors into `PyErr`; never panic across the FFI boundary",
+        "- Release the GIL (`allow_threads`) around long-running Rust work",
+        "- Rebuild with `maturin develop` before running the Python tests",
+    ],
+}
+
+# Language -> (LanguageInfo.version_info key, label) for the CLAUDE.md overview
+TOOLCHAIN_SUMMARY_LABELS = {
+    "Rust": [
+        ("rust_toolchain", "toolchain"),
+        ("rust_msrv", "MSRV"),
+        ("rust_edition", "edition"),
+    ],
+    "Python": [("python_pinned", "pinned"), ("python_requires", "requires")],
+    "Node.js": [("node_pinned", "pinned"), ("node_engine", "engines")],
+    "Go": [("go_toolchain", "toolchain"), ("go_directive", "go directive")],
+    "Java": [("java_pinned", "pinned"), ("java_release", "release")],
+    "Kotlin": [("kotlin", "version")],
+}
+
 
 class ModernTemplateManager:
     """Modern template manager with modular architecture.
@@ -668,7 +722,23 @@ class ModernTemplateManager:
         """Generate target-specific output artifacts from project analysis."""
         environment = self.generate_complete_environment(analysis, **kwargs)
         renderer = get_target_renderer(target)
-        return renderer.render(environment, agents_dir=agents_dir)
+        rendered = renderer.render(environment, agents_dir=agents_dir)
+        if target == OutputTarget.CLAUDE:
+            rendered.artifacts.extend(self._generate_package_artifacts(analysis))
+        return rendered
+
+    def _generate_package_artifacts(
+        self, analysis: ProjectAnalysis
+    ) -> List[GeneratedArtifact]:
+        """Nested ``<package>/CLAUDE.md`` files for the packages of a monorepo."""
+        return [
+            GeneratedArtifact(
+                path=path,
+                content=content,
+                description="Package instructions",
+            )
+            for path, content in generate_package_docs(analysis).items()
+        ]
 
     def _create_agent_definitions(
         self, agent_config: Any, analysis: ProjectAnalysis
@@ -728,7 +798,8 @@ class ModernTemplateManager:
         elif "backend" in specialization:
             base_tools.extend(["git", "docker"])
 
-        return list(set(base_tools))  # Remove duplicates
+        # Remove duplicates, keeping the order stable between runs
+        return list(dict.fromkeys(base_tools))
 
     def _generate_agent_name(self, base_name: str, analysis: ProjectAnalysis) -> str:
         """Generate project-specific agent names."""
@@ -750,6 +821,20 @@ class ModernTemplateManager:
             f"- Framework: {analysis.framework or 'None detected'}",
             f"- Type: {analysis.project_type.value}",
             f"- Complexity: {analysis.complexity_level.value}",
+        ]
+        runtime_dependencies = [
+            dep
+            for dep in self._direct_dependencies(analysis)
+            if dep.dependency_type == "runtime"
+        ]
+        if runtime_dependencies:
+            context_lines.append(
+                "- Key dependencies: "
+                + "; ".join(
+                    self._format_dependency(dep) for dep in runtime_dependencies[:8]
+                )
+            )
+        context_lines += [
             "",
             "## Core Responsibilities",
             agent.description or f"Specialized assistance for {agent.name} tasks.",
@@ -870,6 +955,13 @@ class ModernTemplateManager:
             ],
             "development_commands": self._generate_development_commands(analysis),
             "development_standards": self._generate_development_standards(analysis),
+            "toolchain": self._generate_toolchain_summary(analysis),
+            "workspace_layout": self._generate_workspace_layout(analysis),
+            "source_structure": self._generate_source_structure(analysis),
+            "key_dependencies": self._generate_key_dependencies(analysis),
+            "code_metrics": self._generate_code_metrics(analysis),
+            "git_workflow": self._generate_git_workflow(analysis),
+            "commit_guidelines": self._generate_commit_guidelines(analysis),
             # Phase 3: domain-aware environment context
             "dev_environment": {
                 "infrastructure_as_code": infrastructure_as_code,
@@ -923,6 +1015,202 @@ class ModernTemplateManager:
 
         return "\n".join(commands)
 
+    def _generate_toolchain_summary(self, analysis: ProjectAnalysis) -> str:
+        """Summarise detected language toolchains, e.g. Rust MSRV and edition."""
+        language_info = getattr(analysis, "language_info", None)
+        version_info = getattr(language_info, "version_info", None)
+        if not isinstance(version_info, dict):
+            return ""
+
+        summaries = []
+        for language, labels in TOOLCHAIN_SUMMARY_LABELS.items():
+            details = [
+                f"{label} {version_info[key]}"
+                for key, label in labels
+                if version_info.get(key)
+            ]
+            if details:
+                summaries.append(f"{language} " + ", ".join(details))
+        return "; ".join(summaries)
+
+    def _generate_workspace_layout(self, analysis: ProjectAnalysis) -> str:
+        """Describe a monorepo, Cargo, Go or JVM workspace (or nothing)."""
+        # Cargo and Go workspaces have richer layouts of their own below
+        monorepo = getattr(analysis, "monorepo", None)
+        if isinstance(monorepo, MonorepoInfo) and set(monorepo.tools) - {
+            "cargo",
+            "go",
+        }:
+            return summarize_monorepo(monorepo)
+
+        go_workspace = getattr(analysis, "go_workspace", None)
+        if isinstance(go_workspace, GoWorkspaceInfo) and go_workspace.is_workspace:
+            return self._generate_go_workspace_layout(go_workspace)
+
+        framework_details = getattr(analysis.framework_info, "details", None)
+        if isinstance(framework_details, dict) and framework_details.get(
+            "build_tool"
+        ):
+            jvm_layout = self._generate_jvm_build_layout(framework_details)
+            if jvm_layout:
+                return jvm_layout
+
+        workspace = getattr(analysis, "cargo_workspace", None)
+        if workspace is None or not workspace.is_workspace or not workspace.members:
+            return ""
+
+        lines = [
+            f"This Cargo workspace contains {len(workspace.members)} crates:",
+            "",
+        ]
+        for crate in workspace.members:
+            details = [f"{crate.kind} crate"]
+            if crate.no_std:
+                details.append("`no_std`")
+            if crate.edition:
+                details.append(f"edition {crate.edition}")
+            if crate.frameworks:
+                details.append("uses " + ", ".join(crate.frameworks))
+            lines.append(
+                f"- **{crate.name}** (`{crate.path}`): " + "; ".join(details)
+            )
+
+        if workspace.workspace_dependencies:
+            lines.extend(
+                [
+                    "",
+                    "Shared dependency versions are pinned in "
+                    "`[workspace.dependencies]`; add new shared crates there and "
+                    "inherit them with `dep.workspace = true`.",
+                ]
+            )
+        return "\n".join(lines)
+
+    def _generate_go_workspace_layout(self, workspace: GoWorkspaceInfo) -> str:
+        """Describe the modules listed in ``go.work``."""
+        if not workspace.modules:
+            return ""
+
+        lines = [
+            f"This Go workspace (`go.work`) contains {len(workspace.modules)} "
+            "modules:",
+            "",
+        ]
+        for module in workspace.modules:
+            details = []
+            if module.go_version:
+                details.append(f"go {module.go_version}")
+            details.append(f"{len(module.requires)} direct requirements")
+            lines.append(
+                f"- **{module.module}** (`{module.path}`): " + "; ".join(details)
+            )
+
+        replaces = workspace.replaces + [
+            replace for module in workspace.modules for replace in module.replaces
+        ]
+        if replaces:
+            lines.extend(["", "Replaced modules (edit the target, not the cache):"])
+            lines.extend(f"- `{replace}`" for replace in replaces)
+        return "\n".join(lines)
+
+    def _generate_jvm_build_layout(self, details: Dict[str, Any]) -> str:
+        """Describe the modules of a Maven reactor or multi-project Gradle build."""
+        modules = [m for m in details.get("modules") or [] if m != "."]
+        if not modules:
+            return ""
+        if details["build_tool"] == "maven":
+            intro = f"This Maven reactor contains {len(modules)} modules:"
+            hint = (
+                "Build one module with its dependencies using "
+                "`mvn -pl <module> -am verify`."
+            )
+        else:
+            intro = f"This Gradle build includes {len(modules)} subprojects:"
+            hint = "Run one subproject's tasks with `./gradlew :<project>:test`."
+        lines = [intro, ""]
+        lines.extend(f"- `{module}`" for module in modules)
+        lines.extend(["", hint])
+        return "\n".join(lines)
+
+    def _generate_source_structure(self, analysis: ProjectAnalysis) -> str:
+        """Module tree and source facts from the Rust scanner (empty otherwise)."""
+        rust_source = getattr(analysis, "rust_source", None)
+        if not isinstance(rust_source, RustSourceInfo) or not rust_source.modules:
+            return ""
+
+        tree = render_module_tree(rust_source, analysis.project_path.name)
+        return f"```text\n{tree}\n```\n\n{summarize_rust_source(rust_source)}"
+
+    def _generate_code_metrics(self, analysis: ProjectAnalysis) -> str:
+        """Measured hotspots and the guidance they call for (deep analysis)."""
+        metrics = getattr(analysis, "complexity_metrics", None)
+        if not isinstance(metrics, ComplexityMetrics) or not metrics.modules:
+            return ""
+        return summarize_code_metrics(metrics, analysis.complexity_level.value)
+
+    def _generate_git_workflow(self, analysis: ProjectAnalysis) -> str:
+        """Commit history, release cadence and files to handle with care."""
+        history = getattr(analysis, "git_history", None)
+        if not isinstance(history, GitHistoryInfo) or not history.commits_analyzed:
+            return ""
+        return summarize_git_workflow(history)
+
+    def _generate_commit_guidelines(self, analysis: ProjectAnalysis) -> str:
+        """Commit message rules following the convention inferred from history."""
+        history = getattr(analysis, "git_history", None)
+        if not isinstance(history, GitHistoryInfo) or not history.commits_analyzed:
+            return ""
+        convention = history.commit_convention or CommitConvention("free_form")
+        return summarize_commit_guidelines(convention)
+
+    def _direct_dependencies(self, analysis: ProjectAnalysis) -> List[Any]:
+        """Direct entries of the resolved dependency graph, if any."""
+        direct = getattr(analysis, "direct_dependencies", None)
+        return list(direct) if isinstance(direct, list) else []
+
+    def _format_dependency(self, dependency: Any) -> str:
+        """Render a resolved dependency as ``name version`` plus its features."""
+        text = f"`{dependency.name}` {dependency.version or ''}".rstrip()
+        if dependency.features:
+            text += " with features " + ", ".join(
+                f"`{feature}`" for feature in dependency.features
+            )
+        if dependency.dependency_type != "runtime":
+            text += f" ({dependency.dependency_type})"
+        return text
+
+    def _generate_key_dependencies(
+        self, analysis: ProjectAnalysis, limit: int = 20
+    ) -> str:
+        """List directly used dependencies with their resolved versions."""
+        graphs = getattr(analysis, "dependency_graphs", None)
+        if not isinstance(graphs, dict):
+            return ""
+
+        # One list per package manager, e.g. Cargo crates and Go modules
+        sections = []
+        for manager, graph in graphs.items():
+            direct = [dep for dep in graph if dep.direct]
+            if not direct:
+                continue
+            source = (
+                "Versions selected in `go.mod`."
+                if manager == "go"
+                else "Resolved versions from the lockfile."
+            )
+            lines = [
+                f"{source} Use APIs available in these "
+                "releases and check the changelog before upgrading.",
+                "",
+            ]
+            lines.extend(
+                f"- {self._format_dependency(dep)}" for dep in direct[:limit]
+            )
+            if len(direct) > limit:
+                lines.append(f"- ...and {len(direct) - limit} more")
+            sections.append("\n".join(lines))
+        return "\n\n".join(sections)
+
     def _generate_development_standards(self, analysis: ProjectAnalysis) -> str:
         """Generate language-specific development standards."""
         standards = [
@@ -949,6 +1237,8 @@ class ModernTemplateManager:
                     "- Document public APIs with rustdoc",
                 ]
             )
+            project_type = getattr(analysis.project_type, "value", None)
+            standards.extend(RUST_PROJECT_TYPE_STANDARDS.get(project_type, []))
 
         return "\n".join(standards)
 
@@ -998,7 +1288,38 @@ tools: {agent.get_yaml_tools()}"""
 **Framework**: {context["primary_framework"]}
 **Type**: {context["project_type"]}
 **Complexity**: {context["complexity_level"]}
-
+"""
+            + (
+                f"""**Toolchain**: {context["toolchain"]}
+"""
+                if context.get("toolchain")
+                else ""
+            )
+            + (
+                f"""
+## Workspace Layout
+{context["workspace_layout"]}
+"""
+                if context.get("workspace_layout")
+                else ""
+            )
+            + (
+                f"""
+## Source Structure
+{context["source_structure"]}
+"""
+                if context.get("source_structure")
+                else ""
+            )
+            + (
+                f"""
+## Key Dependencies
+{context["key_dependencies"]}
+"""
+                if context.get("key_dependencies")
+                else ""
+            )
+            + f"""
 ## Development Standards
 {context["development_standards"]}
 
@@ -1018,7 +1339,32 @@ See AGENTS.md for detailed usage instructions and coordination patterns.
 
 ## Development Commands
 {context["development_commands"]}
-
+"""
+            + (
+                f"""
+## Code Metrics
+{context["code_metrics"]}
+"""
+                if context.get("code_metrics")
+                else ""
+            )
+            + (
+                f"""
+## Git Workflow
+{context["git_workflow"]}
+"""
+                if context.get("git_workflow")
+                else ""
+            )
+            + (
+                f"""
+### Commit Guidelines
+{context["commit_guidelines"]}
+"""
+                if context.get("commit_guidelines")
+                else ""
+            )
+            + """
 ## Architecture Notes
 - Follow existing project patterns and conventions
 - Maintain consistency in code style and structure
@@ -27,6 +27,10 @@ from urllib.parse import urljoin
 from urllib.request import Request, urlopen
 
 from claude_builder.core.models import ProjectAnalysis, ValidationResult
+from claude_builder.core.template_engine import (
+    create_environment,
+    render_template_string,
+)
 from claude_builder.utils.exceptions import SecurityError
 from claude_builder.utils.security import security_validator
 
@@ -1224,12 +1228,7 @@ class Template:
         self.metadata.update(remaining_kwargs)
 
     def render(self, **context: Any) -> str:
-        """Render template with context."""
-        # Simple variable substitution for testing
-        result = self.content
-        for key, value in context.items():
-            result = result.replace(f"{{{{ {key} }}}}", str(value))
-
+        """Render template with context in the shared sandboxed environment."""
         # Return specific content based on template name if no content provided
         if not self.content:
             if "claude" in self.name.lower():
@@ -1244,7 +1243,7 @@ class Template:
                 return "# Contributing to Project\n\nContribution guidelines."
             return f"# {self.name.title()}\n\nGenerated content for {self.name}."
 
-        return result
+        return render_template_string(self.content, context, strict=False)
 
     def validate(self) -> bool:
         """Validate template syntax and structure."""
@@ -2045,131 +2044,49 @@ class RemoteTemplateRepository:
 class TemplateRenderer:
     """Template rendering engine used by ModernTemplateManager.
 
-    The original implementation supported a very small, custom substitution
-    syntax. Phase 3 domain templates rely on richer constructs (loops, filters,
-    attribute access) so we now default to the Jinja2 engine while keeping the
-    original "simple" renderer available for backward compatibility.
+    Both engines render through the shared sandboxed Jinja2 environment from
+    :mod:`claude_builder.core.template_engine`, which also migrates legacy
+    ``${var}`` and section syntax. The "jinja2" engine raises on undefined
+    variables; the "simple" engine renders them empty.
     """
 
-    def __init__(self, template_engine: str = "simple", *, enable_cache: bool = False):
+    def __init__(
+        self,
+        template_engine: str = "simple",
+        *,
+        enable_cache: bool = False,
+        template_dirs: Optional[List[str]] = None,
+    ):
         """Initialize template renderer.
 
         Args:
-            template_engine: Type of template engine ("simple" for now,
-                "jinja2" for future)
+            template_engine: "jinja2" for strict rendering, "simple" for lenient
             enable_cache: Whether to enable render caching
+            template_dirs: Directories searched before the packaged templates
+                for imports and includes
         """
         self.template_engine = template_engine
         self.enable_cache = enable_cache
         self.render_cache: Optional[Dict[str, str]] = {} if enable_cache else None
         self.cache_hits: int = 0
-        self.filters = {
-            "length": len,
-            "upper": str.upper,
-            "lower": str.lower,
-            "title": str.title,
-            "capitalize": lambda s: str(s).capitalize(),
-        }
-
-        self._jinja_env: Optional[Any] = None
-        self.jinja_env: Optional[Any] = None  # public alias expected by tests
-        if self.template_engine == "jinja2":
-            try:
-                from jinja2 import (
-                    Environment,
-                    FileSystemLoader,
-                    StrictUndefined,
-                    select_autoescape,
-                )
-            except ImportError as exc:  # pragma: no cover - guard rails
-                msg = "Jinja2 is required for template rendering. Please install the 'jinja2' package."
-                raise RuntimeError(msg) from exc
-
-            # Set up template search paths for imports/includes
-            from pathlib import Path
-
-            template_search_paths = [
-                Path(__file__).parent.parent / "templates" / "domains" / "devops",
-                Path(__file__).parent.parent / "templates" / "domains" / "mlops",
-                Path(__file__).parent.parent / "templates" / "domains",
-            ]
-
-            env = Environment(
-                loader=FileSystemLoader([str(p) for p in template_search_paths]),
-                autoescape=select_autoescape(
-                    enabled_extensions=()
-                ),  # No autoescaping for markdown/text
-                undefined=StrictUndefined,
-                trim_blocks=True,
-                lstrip_blocks=True,
-            )
-            # Built-in and project filters
-            env.filters.update(self.filters)
-
-            # Add strftime filter expected by tests
-            def _strftime(value: Any, fmt: str) -> str:
-                try:
-                    # Support datetime/date objects or strings parseable by str()
-                    from datetime import date, datetime
-
-                    if isinstance(value, (datetime, date)):
-                        return value.strftime(fmt)
-                    # Fallback: attempt to format after str cast (won't change format)
-                    return str(value)
-                except Exception:
-                    return ""
+        self.template_dirs = list(template_dirs or [])
 
-            env.filters["strftime"] = _strftime
-            self._jinja_env = env
-            self.jinja_env = env
+        env = create_environment(
+            self.template_dirs, strict=self.template_engine == "jinja2"
+        )
+        # Jinja's built-in filters plus the ones from template_engine.FILTERS
+        self.filters = dict(env.filters)
+        self._jinja_env: Optional[Any] = env
+        self.jinja_env: Optional[Any] = env  # public alias expected by tests
 
     def render_template(self, template_content: str, variables: Dict[str, Any]) -> str:
-        """Render template content with variable substitution.
-
-        - If Jinja2 engine configured, use it.
-        - Otherwise, detect Jinja-like syntax and route to our lightweight
-          Jinja-style renderer that supports a safe subset (if/for/set/filters).
-        - Finally, fall back to simple ``${var}`` substitution with basic
-          conditionals and list processing.
-        """
-        import re
-
-        # Prefer full Jinja2 if available, unless the template uses ${var} style
-        if self.template_engine == "jinja2" and self._jinja_env is not None:
-            # If content uses ${...} and not Jinja markers, perform simple substitution
-            if ("${" in template_content) and (
-                "{{" not in template_content and "{%" not in template_content
-            ):
-                rendered_content = template_content
-                for var_name, var_value in variables.items():
-                    str_value = str(var_value) if var_value is not None else ""
-                    rendered_content = rendered_content.replace(
-                        f"${{{var_name}}}", str_value
-                    )
-                return rendered_content
-            template = self._jinja_env.from_string(template_content)
-            return str(template.render(**variables))
-
-        # Detect Jinja-like syntax and use our enhanced renderer
-        if ("{{" in template_content) or ("{%" in template_content):
-            return self._render_jinja_style(template_content, variables)
-
-        rendered_content = template_content
-
-        # Simple variable substitution using ${variable_name} syntax
-        for var_name, var_value in variables.items():
-            # Convert value to string
-            str_value = str(var_value) if var_value is not None else ""
-
-            # Replace ${variable_name} patterns
-            pattern = re.escape(f"${{{var_name}}}")
-            rendered_content = re.sub(pattern, str_value, rendered_content)
-
-        # Handle conditional sections: {{#if variable}}content{{/if}}
-        rendered_content = self._process_conditionals(rendered_content, variables)
-
-        # Handle lists: {{#each items}}{{item}}{{/each}}
-        return self._process_lists(rendered_content, variables)
+        """Render template content with variable substitution."""
+        return render_template_string(
+            template_content,
+            variables,
+            strict=self.template_engine == "jinja2",
+            template_dirs=self.template_dirs,
+        )
 
     def render_file(
         self, template_path: str, output_path: str, variables: Dict[str, Any]
@@ -2205,56 +2122,6 @@ class TemplateRenderer:
         except Exception:
             return False
 
-    def _process_conditionals(self, content: str, variables: Dict[str, Any]) -> str:
-        """Process conditional sections in template."""
-        import re
-
-        # Pattern for {{#if variable}}content{{/if}}
-        pattern = r"\{\{#if\s+(\w+)\}\}(.*?)\{\{/if\}}"
-
-        def replace_conditional(match: Any) -> str:
-            var_name = match.group(1)
-            section_content = match.group(2)
-
-            # Check if variable exists and is truthy
-            if variables.get(var_name):
-                return str(section_content)
-            return ""
-
-        return re.sub(pattern, replace_conditional, content, flags=re.DOTALL)
-
-    def _process_lists(self, content: str, variables: Dict[str, Any]) -> str:
-        """Process list iterations in template."""
-        import re
-
-        # Pattern for {{#each items}}{{item}}{{/each}}
-        pattern = r"\{\{#each\s+(\w+)\}\}(.*?)\{\{/each\}}"
-
-        def replace_list(match: Any) -> str:
-            list_name = match.group(1)
-            item_template = match.group(2)
-
-            if list_name not in variables:
-                return ""
-
-            items = variables[list_name]
-            if not isinstance(items, (list, tuple)):
-                return ""
-
-            rendered_items = []
-            for item in items:
-                # Create context for item
-                item_context = variables.copy()
-                item_context["item"] = item
-
-                # Simple substitution for {{item}}
-                item_content = re.sub(r"\{\{item\}\}", str(item), item_template)
-                rendered_items.append(item_content)
-
-            return "".join(rendered_items)
-
-        return re.sub(pattern, replace_list, content, flags=re.DOTALL)
-
     def render(
         self,
         template: Template,
@@ -2290,296 +2157,20 @@ class TemplateRenderer:
                 self.cache_hits += 1
                 return self.render_cache[cache_key]
 
-        # Prefer full Jinja2 rendering when available to satisfy strict behavior
-        if self._jinja_env is not None:
-            try:
-                jtpl = self._jinja_env.from_string(template.content)
-                result = jtpl.render(**context)
-            except Exception as e:
-                msg = f"Failed to render template {template.name}: {e}"
-                raise TemplateError(
-                    msg,
-                    template_name=template.name,
-                ) from e
-        else:
-            # Enhanced rendering with loops, conditionals, and filters
-            result = template.content
-
-            # Process Jinja2-style templates
-            if "{{" in result or "{%" in result:
-                result = self._render_jinja_style(result, context)
-            else:
-                # Simple variable substitution
-                for key, value in context.items():
-                    result = result.replace(f"{{{{ {key} }}}}", str(value))
+        try:
+            result = self.render_template(template.content, context)
+        except Exception as e:
+            msg = f"Failed to render template {template.name}: {e}"
+            raise TemplateError(
+                msg,
+                template_name=template.name,
+            ) from e
 
         # Cache result
         if self.render_cache is not None:
             self.render_cache[cache_key] = str(result)
         return str(result)
 
-    def _render_jinja_style(self, content: str, context: Dict[str, Any]) -> str:
-        """Render Jinja2-style template content."""
-        import re
-
-        # Work on a shallow copy we can enrich with temporary variables
-        ctx: Dict[str, Any] = dict(context)
-
-        # Minimal support for `{% set var = dev_environment.tools.get('slug') %}`
-        # used by domain templates to bind a tool dict into a local variable.
-        set_pattern = r"\{\%\s*set\s+(\w+)\s*=\s*dev_environment\.tools\.get\('([\w\-]+)'\)\s*\%\}"
-
-        def _get_by_path(data: Dict[str, Any], path: str) -> Any:
-            cur: Any = data
-            for part in path.split("."):
-                if isinstance(cur, dict):
-                    cur = cur.get(part, {})
-                else:
-                    return None
-            return cur
-
-        try:
-            matches = re.findall(set_pattern, content)
-            if matches:
-                tools_map = _get_by_path(ctx, "dev_environment.tools") or {}
-                for var_name, slug in matches:
-                    value = tools_map.get(slug)
-                    if value is not None:
-                        ctx[var_name] = value
-                # Strip the set statements from the template content
-                content = re.sub(set_pattern, "", content)
-        except Exception:
-            # If anything goes wrong, leave content unchanged and continue
-            pass
-
-        # Handle loops: {% for item in items %}
-        loop_pattern = (
-            r"\{%\s*for\s+(\w+)\s+in\s+([\w\.]+)\s*%\}(.*?)\{%\s*endfor\s*%\}"
-        )
-
-        def replace_loop(match: Any) -> str:
-            item_var = match.group(1)
-            list_var = match.group(2)
-            loop_content = match.group(3)
-
-            # Support dotted path list variables
-            def _get_by_path(data: Dict[str, Any], path: str) -> Any:
-                cur: Any = data
-                for part in path.split("."):
-                    if isinstance(cur, dict):
-                        cur = cur.get(part, {})
-                    else:
-                        return []
-                return cur
-
-            items = _get_by_path(ctx, list_var)
-            if not isinstance(items, (list, tuple)):
-                return ""
-
-            rendered_items = []
-            for item in items:
-                # Create loop context
-                loop_context = ctx.copy()
-                loop_context[item_var] = item
-                # Render loop content
-                item_content = loop_content
-                for key, value in loop_context.items():
-                    item_content = item_content.replace(f"{{{{ {key} }}}}", str(value))
-                rendered_items.append(item_content)
-
-            return "".join(rendered_items)
-
-        content = re.sub(loop_pattern, replace_loop, content, flags=re.DOTALL)
-
-        # Handle conditionals with simple expressions first
-        # Case 0: `{% if cond %}...{% else %}...{% endif %}` (must run before simpler cases)
-        if_else_pattern = (
-            r"\{%\s*if\s+([^\%]+?)\s*%\}(.*?)\{%\s*else\s*%\}(.*?)\{%\s*endif\s*%\}"
-        )
-
-        def replace_if_else(match: Any) -> str:
-            cond_expr = match.group(1).strip()
-            then_block = match.group(2) or ""
-            else_block = match.group(3) or ""
-
-            def _get_by_path(data: Dict[str, Any], path: str) -> Any:
-                cur: Any = data
-                for part in path.split("."):
-                    if isinstance(cur, dict):
-                        cur = cur.get(part, {})
-                    else:
-                        return None
-                return cur
-
-            # Support patterns like: var, var is not none, var|length == 0
-            truthy: bool = False
-            try:
-                if "|length" in cond_expr and "== 0" in cond_expr:
-                    var_path = cond_expr.split("|length")[0].strip()
-                    value = _get_by_path(ctx, var_path)
-                    try:
-                        truthy = len(value) == 0  # then branch if empty
-                    except Exception:
-                        truthy = True  # treat as empty
-                elif " is not none" in cond_expr:
-                    var_path = cond_expr.replace(" is not none", "").strip()
-                    value = _get_by_path(ctx, var_path)
-                    truthy = value is not None
-                else:
-                    value = _get_by_path(ctx, cond_expr)
-                    if isinstance(value, str):
-                        truthy = value.lower() in ("true", "yes", "1") or len(value) > 0
-                    else:
-                        truthy = bool(value)
-            except Exception:
-                truthy = False
-
-            return then_block if truthy else else_block
-
-        content = re.sub(if_else_pattern, replace_if_else, content, flags=re.DOTALL)
-        # Case 1: `{% if var is not none %}`
-        if_not_none_pattern = (
-            r"\{%\s*if\s+([\w\.]+)\s+is\s+not\s+none\s*%\}(.*?)\{%\s*endif\s*%\}"
-        )
-
-        def replace_if_not_none(match: Any) -> str:
-            var_path = match.group(1)
-            block = match.group(2) or ""
-            value = _get_by_path(ctx, var_path)
-            return block if value is not None else ""
-
-        content = re.sub(
-            if_not_none_pattern, replace_if_not_none, content, flags=re.DOTALL
-        )
-
-        # Case 2: `{% if var|length == 0 %}` to show fallback when lists are empty
-        if_len_zero_pattern = (
-            r"\{%\s*if\s+([\w\.]+)\|length\s*==\s*0\s*%\}(.*?)\{%\s*endif\s*%\}"
-        )
-
-        def replace_if_len_zero(match: Any) -> str:
-            var_path = match.group(1)
-            block = match.group(2) or ""
-            value = _get_by_path(ctx, var_path)
-            try:
-                length = len(value) if value is not None else 0
-            except Exception:
-                length = 0
-            return block if length == 0 else ""
-
-        content = re.sub(
-            if_len_zero_pattern, replace_if_len_zero, content, flags=re.DOTALL
-        )
-
-        # Handle basic conditionals: `{% if variable %}` with dotted variables
-        if_pattern = r"\{%\s*if\s+([\w\.]+)\s*%\}(.*?)\{%\s*endif\s*%\}"
-
-        def replace_if(match: Any) -> str:
-            condition_var = match.group(1)
-            if_content = match.group(2) or ""
-
-            # Resolve dotted path, truthy if found and truthy
-            def _get_by_path(data: Dict[str, Any], path: str) -> Any:
-                cur: Any = data
-                for part in path.split("."):
-                    if isinstance(cur, dict):
-                        cur = cur.get(part, {})
-                    else:
-                        return None
-                return cur
-
-            condition_value = _get_by_path(ctx, condition_var)
-            if isinstance(condition_value, str):
-                condition_value = condition_value.lower() in ("true", "yes", "1")
-
-            return if_content if condition_value else ""
-
-        content = re.sub(if_pattern, replace_if, content, flags=re.DOTALL)
-
-        # Handle filters: {{ variable|filter }}
-        filter_pattern = r"\{\{\s*([\w\.]+)\s*\|\s*(\w+)\s*\}\}"
-
-        def replace_filter(match: Any) -> str:
-            var_name = match.group(1)
-            filter_name = match.group(2)
-
-            value = _get_by_path(ctx, var_name)
-            if value is None:
-                return ""
-            if filter_name in self.filters:
-                try:
-                    return str(self.filters[filter_name](value))
-                except Exception:
-                    return str(value)
-
-            return str(value)
-
-        content = re.sub(filter_pattern, replace_filter, content)
-
-        # Support a restricted `format` filter form: {{ '%.1f'|format(tool.score) }}
-        format_pattern = r"\{\{\s*'([^']*)'\s*\|\s*format\(([^\)]+)\)\s*\}\}"
-
-        def replace_format(match: Any) -> str:
-            fmt = match.group(1)
-            arg_expr = match.group(2).strip()
-            # Only support a single dotted variable path as argument
-            value = _get_by_path(ctx, arg_expr)
-            try:
-                if value is None:
-                    return ""
-                return str(fmt % value)
-            except Exception:
-                try:
-                    # Last resort, cast to float if possible
-                    return str(fmt % float(value))
-                except Exception:
-                    return str(value)
-
-        content = re.sub(format_pattern, replace_format, content)
-
-        # Handle simple variables: {{ variable }} (supports dotted path)
-        var_pattern = r"\{\{\s*([\w\.]+)\s*\}\}"
-
-        def replace_var(match: Any) -> str:
-            var_name = match.group(1)
-
-            val = _get_by_path(ctx, var_name)
-            return str(val if val is not None else "")
-
-        content = re.sub(var_pattern, replace_var, content)
-
-        # Light formatting normalisation to avoid awkward line breaks introduced
-        # by template wrapping (e.g., "Confidence:" on one line and value on next)
-        try:
-            content = re.sub(r"(Confidence:\s*)\n\s*", r"\1 ", content)
-            content = re.sub(r"(Detection score:\s*)\n\s*", r"\1 ", content)
-            # Collapse excessive blank lines that can result from tag stripping
-            content = re.sub(r"\n{3,}", "\n\n", content)
-            # Tighten detection score line to be followed by a single newline
-            content = re.sub(r"(_Detection score:.*?_\n)\n+", r"\1", content)
-            # Ensure a single blank line BEFORE fenced code blocks (for readability/snapshots)
-            content = re.sub(r"([^\n])\n```", r"\1\n\n```", content)
-            # Remove any blank line immediately AFTER the opening fence
-            content = re.sub(r"(```[^\n]*\n)\n+", r"\1", content)
-            # Remove any blank line immediately BEFORE the closing fence
-            content = re.sub(r"\n+```", "\n```", content)
-            # Ensure a single blank line AFTER fenced code blocks (for snapshots)
-            content = re.sub(r"```\n(?!\n)", "```\n\n", content)
-            # Ensure exactly one blank line after the 'Key Files Detected:' heading
-            content = re.sub(r"(\*\*Key Files Detected:\*\*)\n*", r"\1\n\n", content)
-            # Tighten spacing before recommendations header to a single blank line
-            content = re.sub(
-                r"\n{2,}(\*\*Actionable Recommendations:)", r"\n\n\1", content
-            )
-            # Final safety: strip any remaining raw control tags left unprocessed
-            content = re.sub(r"\{\%\s*endif\s*\%\}", "", content)
-            content = re.sub(r"\{\%\s*if\s+[^\%]+\%\}", "", content)
-            content = re.sub(r"\{\%\s*else\s*\%\}", "", content)
-        except Exception:
-            pass
-
-        return content
-
 
 class CoreTemplateManager:
     """Core template management system for Phase 2 implementation.
@@ -2593,7 +2184,9 @@ class CoreTemplateManager:
         """Initialize core template manager."""
         self.loader = TemplateLoader(template_dirs)
         # Use jinja2 engine to support imports/includes in domain templates
-        self.renderer = TemplateRenderer(template_engine="jinja2")
+        self.renderer = TemplateRenderer(
+            template_engine="jinja2", template_dirs=template_dirs
+        )
 
     def load_template(self, template_name: str) -> str:
         """Load template content."""
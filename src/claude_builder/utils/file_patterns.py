@@ -6,11 +6,18 @@ from dataclasses import dataclass
 from pathlib import Path
 from typing import Any
 
+from claude_builder.core.language_plugins import (
+    LanguagePluginRegistry,
+    get_language_registry,
+)
+from claude_builder.utils.project_files import ProjectFiles, walk_project
+
 
 class FilePatterns:
     """Utilities for file pattern recognition."""
 
-    # Common source file extensions by language
+    # Extra suffixes for plugin languages, and file types without a plugin;
+    # use language_extensions() for the full mapping
     LANGUAGE_EXTENSIONS = {
         "python": {".py", ".pyx", ".pyi", ".pyw"},
         "rust": {".rs"},
@@ -893,12 +900,42 @@ class FilePatterns:
         "warp": {"Cargo.toml", "warp"},
     }
 
+    # language_extensions() mapping and the registry it was built from
+    _extensions: dict[str, set[str]] = {}
+    _extensions_registry: LanguagePluginRegistry | None = None
+
+    @classmethod
+    def language_extensions(cls) -> dict[str, set[str]]:
+        """Source file extensions by language.
+
+        Registered language plugins come first, so languages added through
+        entry points are recognised, followed by ``LANGUAGE_EXTENSIONS``.
+        """
+        registry = get_language_registry()
+        if cls._extensions_registry is not registry:
+            extensions: dict[str, set[str]] = {}
+            for plugin in registry:
+                extensions[plugin.name] = {
+                    extension.lower()
+                    for extension in (
+                        *plugin.extensions,
+                        *plugin.get_source_extensions(),
+                    )
+                }
+            for language, suffixes in cls.LANGUAGE_EXTENSIONS.items():
+                extensions.setdefault(language, set()).update(
+                    suffix.lower() for suffix in suffixes
+                )
+            cls._extensions = extensions
+            cls._extensions_registry = registry
+        return cls._extensions
+
     @classmethod
     def get_language_from_extension(cls, file_path: Path) -> str:
         """Get programming language from file extension."""
         extension = file_path.suffix.lower()
 
-        for language, extensions in cls.LANGUAGE_EXTENSIONS.items():
+        for language, extensions in cls.language_extensions().items():
             if extension in extensions:
                 return language
 
@@ -909,7 +946,7 @@ class FilePatterns:
         """Check if file is a source code file."""
         extension = file_path.suffix.lower()
 
-        for extensions in cls.LANGUAGE_EXTENSIONS.values():
+        for extensions in cls.language_extensions().values():
             if extension in extensions:
                 return True
 
@@ -968,9 +1005,13 @@ class FilePatterns:
         return False
 
     @classmethod
-    def detect_frameworks(cls, project_path: Path) -> dict[str, float]:
+    def detect_frameworks(
+        cls, project_path: Path, files: ProjectFiles | None = None
+    ) -> dict[str, float]:
         """Detect frameworks based on file patterns."""
         detected: dict[str, float] = {}
+        files = files or walk_project(project_path)
+        file_names = {f.name for f in files.files}
 
         for framework, patterns in cls.FRAMEWORK_PATTERNS.items():
             score = 0.0
@@ -981,9 +1022,7 @@ class FilePatterns:
                     if (project_path / pattern).exists():
                         score += 5.0
                 # File pattern or content pattern
-                elif any(
-                    f.name == pattern for f in project_path.rglob("*") if f.is_file()
-                ):
+                elif pattern in file_names:
                     score += 3.0
 
             if score > 0:
@@ -992,11 +1031,17 @@ class FilePatterns:
         return detected
 
     @classmethod
-    def detect_infrastructure_tools(cls, project_path: Path) -> dict[str, float]:
-        """Detect infrastructure tools based on file patterns."""
+    def _score_tool_patterns(
+        cls,
+        project_path: Path,
+        tool_patterns: dict[str, list[str]],
+        files: ProjectFiles | None,
+    ) -> dict[str, float]:
+        """Score tools by directory (+5), recursive glob (+4) and file (+3) hits."""
         detected: dict[str, float] = {}
+        files = files or walk_project(project_path)
 
-        for tool, patterns in cls.INFRASTRUCTURE_PATTERNS.items():
+        for tool, patterns in tool_patterns.items():
             score = 0.0
 
             for pattern in patterns:
@@ -1005,8 +1050,8 @@ class FilePatterns:
                     if (project_path / pattern.rstrip("/")).exists():
                         score += 5.0
                 elif "*" in pattern:
-                    # Glob pattern - check if any files match recursively
-                    if any(project_path.rglob(pattern)):
+                    # Glob pattern - check if any indexed path matches
+                    if files.rglob(pattern):
                         score += 4.0
                 # Exact file match
                 elif (project_path / pattern).exists():
@@ -1018,59 +1063,34 @@ class FilePatterns:
         return detected
 
     @classmethod
-    def detect_observability_tools(cls, project_path: Path) -> dict[str, float]:
-        """Detect observability tools based on file patterns."""
-        detected: dict[str, float] = {}
-
-        for tool, patterns in cls.OBSERVABILITY_PATTERNS.items():
-            score = 0.0
-
-            for pattern in patterns:
-                if "/" in pattern:
-                    # Directory pattern
-                    if (project_path / pattern.rstrip("/")).exists():
-                        score += 5.0
-                elif "*" in pattern:
-                    # Glob pattern - check if any files match recursively
-                    if any(project_path.rglob(pattern)):
-                        score += 4.0
-                # Exact file match
-                elif (project_path / pattern).exists():
-                    score += 3.0
-
-            if score > 0:
-                detected[tool] = score
+    def detect_infrastructure_tools(
+        cls, project_path: Path, files: ProjectFiles | None = None
+    ) -> dict[str, float]:
+        """Detect infrastructure tools based on file patterns."""
+        return cls._score_tool_patterns(
+            project_path, cls.INFRASTRUCTURE_PATTERNS, files
+        )
 
-        return detected
+    @classmethod
+    def detect_observability_tools(
+        cls, project_path: Path, files: ProjectFiles | None = None
+    ) -> dict[str, float]:
+        """Detect observability tools based on file patterns."""
+        return cls._score_tool_patterns(
+            project_path, cls.OBSERVABILITY_PATTERNS, files
+        )
 
     @classmethod
-    def detect_security_tools(cls, project_path: Path) -> dict[str, float]:
+    def detect_security_tools(
+        cls, project_path: Path, files: ProjectFiles | None = None
+    ) -> dict[str, float]:
         """Detect security tools based on file patterns."""
-        detected: dict[str, float] = {}
-
-        for tool, patterns in cls.SECURITY_PATTERNS.items():
-            score = 0.0
-
-            for pattern in patterns:
-                if "/" in pattern:
-                    # Directory pattern
-                    if (project_path / pattern.rstrip("/")).exists():
-                        score += 5.0
-                elif "*" in pattern:
-                    # Glob pattern - check if any files match recursively
-                    if any(project_path.rglob(pattern)):
-                        score += 4.0
-                # Exact file match
-                elif (project_path / pattern).exists():
-                    score += 3.0
-
-            if score > 0:
-                detected[tool] = score
-
-        return detected
+        return cls._score_tool_patterns(project_path, cls.SECURITY_PATTERNS, files)
 
     @classmethod
-    def detect_mlops_tools(cls, project_path: Path) -> dict[str, float]:
+    def detect_mlops_tools(
+        cls, project_path: Path, files: ProjectFiles | None = None
+    ) -> dict[str, float]:
         """Detect MLOps tools based on file patterns.
 
         Scoring mirrors other detectors:
@@ -1078,42 +1098,29 @@ class FilePatterns:
         - Glob pattern: +4.0
         - Exact file match: +3.0
         """
-        detected: dict[str, float] = {}
-
-        for tool, patterns in cls.MLOPS_PATTERNS.items():
-            score = 0.0
-
-            for pattern in patterns:
-                if "/" in pattern:
-                    # Directory or path pattern
-                    if (project_path / pattern.rstrip("/")).exists():
-                        score += 5.0
-                elif "*" in pattern:
-                    # Glob pattern - search recursively
-                    if any(project_path.rglob(pattern)):
-                        score += 4.0
-                # Exact file match
-                elif (project_path / pattern).exists():
-                    score += 3.0
-
-            if score > 0:
-                detected[tool] = score
-
-        return detected
+        return cls._score_tool_patterns(project_path, cls.MLOPS_PATTERNS, files)
 
     @classmethod
-    def detect_all_devops_tools(cls, project_path: Path) -> dict[str, dict[str, float]]:
+    def detect_all_devops_tools(
+        cls, project_path: Path, files: ProjectFiles | None = None
+    ) -> dict[str, dict[str, float]]:
         """Detect all DevOps tools and return categorized results."""
+        files = files or walk_project(project_path)
         return {
-            "infrastructure": cls.detect_infrastructure_tools(project_path),
-            "observability": cls.detect_observability_tools(project_path),
-            "security": cls.detect_security_tools(project_path),
-            "mlops": cls.detect_mlops_tools(project_path),
+            "infrastructure": cls.detect_infrastructure_tools(project_path, files),
+            "observability": cls.detect_observability_tools(project_path, files),
+            "security": cls.detect_security_tools(project_path, files),
+            "mlops": cls.detect_mlops_tools(project_path, files),
         }
 
     @classmethod
     def collect_tool_examples(
-        cls, project_path: Path, category: str, tool: str, limit: int = 5
+        cls,
+        project_path: Path,
+        category: str,
+        tool: str,
+        limit: int = 5,
+        files: ProjectFiles | None = None,
     ) -> list[str]:
         """Return representative paths that triggered detection for a tool.
 
@@ -1146,9 +1153,9 @@ class FilePatterns:
                 break
 
             if "*" in pattern:
-                for candidate in resolved_root.rglob(pattern):
-                    if candidate.is_file() or candidate.is_dir():
-                        _record(candidate)
+                files = files or walk_project(project_path)
+                for candidate in files.rglob(pattern):
+                    _record(candidate)
                     if len(matches) >= limit:
                         break
                 continue
@@ -1347,7 +1354,7 @@ class LanguageDetector:
 
         # First try extension-based detection
         extension = file_path.suffix.lower()
-        for language, extensions in FilePatterns.LANGUAGE_EXTENSIONS.items():
+        for language, extensions in FilePatterns.language_extensions().items():
             if extension in extensions:
                 return language
 
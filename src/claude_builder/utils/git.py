@@ -1,13 +1,26 @@
 """Git integration utilities for Claude Builder."""
 
 import json
+import re
+import shlex
 import shutil
+import statistics
 
-from dataclasses import dataclass
+from collections import Counter
+from dataclasses import asdict, dataclass, field
 from datetime import datetime, timezone
+from itertools import combinations
 from pathlib import Path
-from typing import Any, Dict, List, Optional
-
+from typing import Any, Dict, List, Optional, Tuple
+
+from claude_builder.core.models import (
+    ClaudeMentionPolicy,
+    CoChange,
+    CommitConvention,
+    FileChurn,
+    GitHistoryInfo,
+    ReleaseCadence,
+)
 from claude_builder.utils.exceptions import GitError
 
 
@@ -17,6 +30,9 @@ BACKUP_NOT_FOUND = "Backup not found"
 BACKUP_METADATA_NOT_FOUND = "Backup metadata not found"
 FAILED_TO_RESTORE_BACKUP = "Failed to restore backup"
 
+# Backup kind recorded for the journal entry of each generated write
+GENERATION_KIND = "generation"
+
 
 @dataclass
 class GitIntegrationResult:
@@ -129,14 +145,15 @@ class GitIntegrationManager:
         self, config: Any, project_path: Path, operations: List[str]
     ) -> None:
         """Handle git hook installation for Claude mention control."""
-        if not (
-            hasattr(config, "claude_mention_policy")
-            and config.claude_mention_policy.value != "allowed"
-        ):
+        policy = getattr(config, "claude_mention_policy", ClaudeMentionPolicy.ALLOWED)
+        convention = None
+        if getattr(config, "enforce_commit_convention", False):
+            convention = self._inferred_commit_convention(project_path, operations)
+        if policy.value == "allowed" and convention is None:
             return
 
         hook_result = self.hook_manager.install_commit_msg_hook(
-            project_path, config.claude_mention_policy
+            project_path, policy, convention
         )
         if hook_result.success:
             operations.extend(hook_result.operations_performed)
@@ -146,6 +163,20 @@ class GitIntegrationManager:
                 f"Warning: Failed to install git hooks: {hook_result.errors}"
             )
 
+    def _inferred_commit_convention(
+        self, project_path: Path, operations: List[str]
+    ) -> Optional[CommitConvention]:
+        """Convention the commit-msg hook should enforce, if history shows one."""
+        try:
+            convention = infer_commit_convention(project_path)
+        except GitError as e:
+            operations.append(f"Warning: Could not read commit history: {e}")
+            return None
+        if not convention.pattern:
+            operations.append("No commit convention found in history to enforce")
+            return None
+        return convention
+
     def _handle_integration_error(
         self, error: Exception, project_path: Path, backup_id: Optional[str]
     ) -> GitIntegrationResult:
@@ -369,6 +400,52 @@ class GitBackupManager:
         else:
             return backup_id
 
+    def record_generation(
+        self, project_path: Path, previous: Dict[str, Optional[Path]]
+    ) -> str:
+        """Journal a generation so ``git rollback`` can undo it.
+
+        ``previous`` maps each written path, relative to ``project_path``, to a
+        copy of the file as it was before, or to None when the generation
+        created it.
+        """
+        backup_id = (
+            "claude_builder_generation_"
+            f"{datetime.now(timezone.utc).strftime('%Y%m%d_%H%M%S_%f')}"
+        )
+        backup_dir = project_path / ".git" / "claude-builder-backups" / backup_id
+
+        try:
+            backup_dir.mkdir(parents=True, exist_ok=True)
+
+            for file_path, copy in previous.items():
+                if copy is not None:
+                    dest = backup_dir / file_path
+                    dest.parent.mkdir(parents=True, exist_ok=True)
+                    shutil.copy2(copy, dest)
+
+            metadata = {
+                "backup_id": backup_id,
+                "timestamp": datetime.now(timezone.utc).isoformat(),
+                "kind": GENERATION_KIND,
+                "backed_up_files": sorted(
+                    path for path, copy in previous.items() if copy is not None
+                ),
+                "created_files": sorted(
+                    path for path, copy in previous.items() if copy is None
+                ),
+                "claude_builder_version": "0.1.0",
+            }
+
+            with (backup_dir / "metadata.json").open("w") as f:
+                json.dump(metadata, f, indent=2)
+
+        except OSError as e:
+            error_msg = f"{FAILED_TO_CREATE_BACKUP}: {e}"
+            raise GitError(error_msg) from e
+        else:
+            return backup_id
+
     def restore_backup(self, project_path: Path, backup_id: str) -> bool:
         """Restore from a backup."""
         backup_dir = project_path / ".git" / "claude-builder-backups" / backup_id
@@ -396,6 +473,12 @@ class GitBackupManager:
                     dest.parent.mkdir(parents=True, exist_ok=True)
                     shutil.copy2(source, dest)
 
+            # Generation journal entries also undo the files they created
+            for file_path in metadata.get("created_files", []):
+                created = project_path / file_path
+                if created.exists():
+                    created.unlink()
+
         except OSError as e:
             error_msg = f"{FAILED_TO_RESTORE_BACKUP} {backup_id}: {e}"
             raise GitError(error_msg) from e
@@ -425,9 +508,17 @@ class GitBackupManager:
 
         return sorted(backups, key=lambda x: x["timestamp"], reverse=True)
 
-    def cleanup_old_backups(self, project_path: Path, keep_count: int = 5) -> int:
-        """Clean up old backups, keeping only the most recent ones."""
+    def cleanup_old_backups(
+        self, project_path: Path, keep_count: int = 5, *, kind: Optional[str] = None
+    ) -> int:
+        """Clean up old backups, keeping only the most recent ones.
+
+        With ``kind``, only backups of that kind (such as generation journal
+        entries) are counted and removed.
+        """
         backups = self.list_backups(project_path)
+        if kind is not None:
+            backups = [backup for backup in backups if backup.get("kind") == kind]
 
         if len(backups) <= keep_count:
             return 0
@@ -457,16 +548,38 @@ class GitHookManager:
         pass
 
     def install_commit_msg_hook(
-        self, project_path: Path, claude_mention_policy: Any
+        self,
+        project_path: Path,
+        claude_mention_policy: Any,
+        commit_convention: Optional[CommitConvention] = None,
     ) -> GitIntegrationResult:
-        """Install commit-msg hook to filter Claude mentions."""
+        """Install commit-msg hook to filter Claude mentions.
+
+        With a ``commit_convention`` the hook also rejects subjects that do not
+        match its pattern.
+        """
         try:
             hooks_dir = project_path / ".git" / "hooks"
             commit_msg_hook = hooks_dir / "commit-msg"
+            backup_hook = commit_msg_hook.with_suffix(".pre-claude-builder")
 
             # Create hooks directory if it doesn't exist
             hooks_dir.mkdir(parents=True, exist_ok=True)
 
+            enforces_convention = (
+                commit_convention is not None and bool(commit_convention.pattern)
+            )
+            checks = []
+            if claude_mention_policy.value != "allowed":
+                checks.append("Claude mention filtering")
+            if enforces_convention:
+                checks.append("the commit convention")
+            operation = (
+                f"Installed commit-msg hook for {' and '.join(checks)}"
+                if checks
+                else "Installed commit-msg hook; the allowed policy leaves "
+                "messages unchanged"
+            )
             # Check if hook already exists
             if commit_msg_hook.exists():
                 # Check if it's our hook or a different one
@@ -474,21 +587,30 @@ class GitHookManager:
                     content = f.read()
 
                 if "Claude Builder" in content:
-                    return GitIntegrationResult(
-                        success=True,
-                        operations_performed=["Commit-msg hook already installed"],
-                    )
-                # Backup existing hook
-                backup_hook = commit_msg_hook.with_suffix(".pre-claude-builder")
-                shutil.copy2(commit_msg_hook, backup_hook)
+                    # Ours: regenerate it, keeping the chain to the original
+                    operation = "Updated commit-msg hook"
+                else:
+                    # Backup existing hook
+                    shutil.copy2(commit_msg_hook, backup_hook)
+            else:
+                content = None
 
+            if backup_hook.exists():
                 # Chain with existing hook
                 hook_content = self._generate_chained_commit_msg_hook(
-                    claude_mention_policy, str(backup_hook)
+                    claude_mention_policy, str(backup_hook), commit_convention
                 )
             else:
                 # Create new hook
-                hook_content = self._generate_commit_msg_hook(claude_mention_policy)
+                hook_content = self._generate_commit_msg_hook(
+                    claude_mention_policy, commit_convention
+                )
+
+            if hook_content == content:
+                return GitIntegrationResult(
+                    success=True,
+                    operations_performed=["Commit-msg hook already installed"],
+                )
 
             # Write hook
             with commit_msg_hook.open("w", encoding="utf-8") as f:
@@ -497,12 +619,13 @@ class GitHookManager:
             # Make executable
             commit_msg_hook.chmod(0o755)
 
-            return GitIntegrationResult(
-                success=True,
-                operations_performed=[
-                    "Installed commit-msg hook for Claude mention filtering"
-                ],
-            )
+            operations = [operation]
+            if commit_convention is not None and enforces_convention:
+                operations.append(
+                    "Commit-msg hook enforces "
+                    f"{COMMIT_CONVENTION_NAMES[commit_convention.name]}"
+                )
+            return GitIntegrationResult(success=True, operations_performed=operations)
 
         except OSError as e:
             return GitIntegrationResult(
@@ -619,9 +742,14 @@ class GitHookManager:
                 errors=[f"Failed to uninstall hooks: {e}"],
             )
 
-    def _generate_commit_msg_hook(self, claude_mention_policy: Any) -> str:
+    def _generate_commit_msg_hook(
+        self,
+        claude_mention_policy: Any,
+        commit_convention: Optional[CommitConvention] = None,
+    ) -> str:
         """Generate commit-msg hook script."""
         policy_value = claude_mention_policy.value
+        convention_check = self._generate_convention_check(commit_convention)
 
         return f"""#!/bin/sh
 # Claude Builder commit-msg hook
@@ -659,19 +787,57 @@ fi
 # Replace original commit message
 mv "$TEMP_FILE" "$COMMIT_FILE"
 
-exit 0
+{convention_check}exit 0
+"""
+
+    def _generate_convention_check(
+        self, commit_convention: Optional[CommitConvention]
+    ) -> str:
+        """Shell snippet rejecting subjects that break the commit convention."""
+        if commit_convention is None or not commit_convention.pattern:
+            return ""
+
+        name = COMMIT_CONVENTION_NAMES[commit_convention.name]
+        rule = commit_convention_rule(commit_convention).replace("`", "")
+        hints = [f"  {rule}"]
+        if commit_convention.examples:
+            hints.append(f"  e.g. {commit_convention.examples[0]}")
+        echo_hints = "".join(
+            f"            echo {shlex.quote(hint)} >&2\n" for hint in hints
+        )
+        # LC_ALL=C: byte-wise matching, so any non-ASCII lead byte is an emoji
+        return f"""# Commit convention: {commit_convention.name} (inferred from history)
+subject=$(grep -v '^#' "$COMMIT_FILE" | sed -n '/[^[:space:]]/{{p;q;}}')
+case "$subject" in
+    ""|"Merge "*|"Revert "*|"fixup! "*|"squash! "*|"amend! "*) ;;
+    *)
+        if ! printf '%s\\n' "$subject" |
+            LC_ALL=C grep -Eq {shlex.quote(commit_convention.pattern)}; then
+            echo "commit-msg: subject does not follow {name}" >&2
+{echo_hints}            exit 1
+        fi
+        ;;
+esac
+
 """
 
     def _generate_chained_commit_msg_hook(
-        self, claude_mention_policy: Any, backup_hook_path: str
+        self,
+        claude_mention_policy: Any,
+        backup_hook_path: str,
+        commit_convention: Optional[CommitConvention] = None,
     ) -> str:
         """Generate chained commit-msg hook script that calls existing hook."""
-        base_hook = self._generate_commit_msg_hook(claude_mention_policy)
-        return f"""{base_hook.rstrip()}
+        base_hook = self._generate_commit_msg_hook(
+            claude_mention_policy, commit_convention
+        )
+        # The chained hook runs before the final exit of the base script
+        base_hook = base_hook.rstrip()[: -len("exit 0")].rstrip()
+        return f"""{base_hook}
 
 # Chain with existing hook
 if [ -f "{backup_hook_path}" ] && [ -x "{backup_hook_path}" ]; then
-    "{backup_hook_path}" "$1"
+    "{backup_hook_path}" "$1" || exit $?
 fi
 
 exit 0
@@ -696,223 +862,1009 @@ exit 0
     ) -> str:
         """Generate chained pre-commit hook script that calls existing hook."""
         base_hook = self._generate_pre_commit_hook(claude_mention_policy)
-        return f"""{base_hook.rstrip()}
+        base_hook = base_hook.rstrip()[: -len("exit 0")].rstrip()
+        return f"""{base_hook}
 
 # Chain with existing hook
 if [ -f "{backup_hook_path}" ] && [ -x "{backup_hook_path}" ]; then
-    "{backup_hook_path}"
+    "{backup_hook_path}" || exit $?
 fi
 
 exit 0
 """
 
 
-# Test stub classes for advanced git analysis
-# These are minimal implementations to satisfy test requirements
+# Git history analytics, read from the repository with GitPython
+
+MAX_HISTORY_COMMITS = 1000
+# Commits touching more files (formatting, renames, vendoring) say nothing
+# about which files belong together
+MAX_FILES_PER_COMMIT = 30
+MAX_HOTSPOTS = 10
+MAX_CO_CHANGES = 10
+MIN_CO_CHANGE_COMMITS = 3
+MIN_CO_CHANGE_COUPLING = 0.5
+# Share of subjects that must follow a convention before it is reported
+MIN_CONVENTION_SHARE = 0.6
+STALE_BRANCH_DAYS = 90
+# Each branch lifespan costs one `git log`
+MAX_LIFESPAN_BRANCHES = 50
+
+COMMIT_CONVENTIONS: Dict[str, str] = {
+    "conventional_commits": r"^(?P<type>[a-z]+)(?:\((?P<scope>[^()\s]+)\))?!?: \S",
+    "ticket_prefix": r"^\[?(?P<type>[A-Z][A-Z0-9]+)-\d+\]?:? \S",
+    "bracket_prefix": r"^\[(?P<type>[^\]\s]+)\] \S",
+    "gitmoji": r"^(?P<type>:[a-z0-9_+-]+:|[\u2600-\u27bf\U0001f300-\U0001faff])\s*\S",
+}
+# Patterns of conforming subjects, written so that both POSIX `grep -E` (in
+# generated commit-msg hooks) and Python's re accept them
+CONVENTION_PATTERNS: Dict[str, str] = {
+    "conventional_commits": r"^[a-z]+(\([^() ]+\))?!?: [^ ]",
+    "ticket_prefix": r"^\[?[A-Z][A-Z0-9]+-[0-9]+\]?:? [^ ]",
+    "bracket_prefix": r"^\[[^] ]+\] [^ ]",
+    "gitmoji": r"^(:[a-z0-9_+-]+:|[^ -~])",
+}
+MERGE_SUBJECT = re.compile(
+    r"^Merge (?:pull request #\d+ from \S+?/(?P<pr>\S+)|"
+    r"(?:remote-tracking )?branch '(?P<branch>[^']+)')"
+)
+REVERT_SUBJECT = re.compile(r'^Revert "')
+HOTFIX_SUBJECT = re.compile(r"\b(?:hot-?fix(?:es)?|urgent|emergency)\b", re.IGNORECASE)
+SEMANTIC_VERSION = re.compile(r"^v?\d+\.\d+(?:\.\d+)?(?:[-+][0-9A-Za-z.-]+)?$")
+CALENDAR_VERSION = re.compile(r"^v?(?:19|20)\d{2}[.-]\d{1,2}(?:[.-]\d+)*$")
+
+LONG_LIVED_BRANCHES = {"main", "master", "trunk", "develop", "development"}
+# Lockfiles change with every dependency bump; their churn is not a risk signal
+LOCKFILES = {
+    "Cargo.lock",
+    "Gemfile.lock",
+    "composer.lock",
+    "go.sum",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "poetry.lock",
+    "uv.lock",
+    "yarn.lock",
+}
+DEPENDENCY_MANIFESTS = {
+    "Cargo.toml",
+    "Gemfile",
+    "Pipfile",
+    "build.gradle",
+    "build.gradle.kts",
+    "composer.json",
+    "go.mod",
+    "package.json",
+    "pom.xml",
+    "pyproject.toml",
+    "requirements.txt",
+    "setup.py",
+}
+
+# Field and record separators of the parsed `git log` format
+_UNIT = "\x1f"
+_RECORD = "\x1e"
+
+
+def open_repository(path: Path) -> Any:
+    """Open ``path`` as a GitPython ``Repo``, raising GitError when impossible."""
+    try:
+        import git
+    except ImportError as e:
+        msg = "GitPython is required for git history analysis"
+        raise GitError(msg, working_directory=str(path)) from e
+
+    try:
+        return git.Repo(path)
+    except (git.InvalidGitRepositoryError, git.NoSuchPathError) as e:
+        raise GitError("Not a git repository", working_directory=str(path)) from e
+
+
+def head_revision(path: Path) -> Optional[str]:
+    """Commit checked out in the repository at ``path``, read without git."""
+    git_dir = path / ".git"
+    try:
+        if git_dir.is_file():
+            # Worktrees and submodules point at their git directory
+            pointer = git_dir.read_text(encoding="utf-8").strip()
+            git_dir = (path / pointer[len("gitdir:") :].strip()).resolve()
+        head = (git_dir / "HEAD").read_text(encoding="utf-8").strip()
+        if not head.startswith("ref:"):
+            return head  # Detached HEAD
+        ref = head[len("ref:") :].strip()
+        for git_root in (git_dir, _common_git_dir(git_dir)):
+            loose = git_root / ref
+            if loose.is_file():
+                return loose.read_text(encoding="utf-8").strip()
+            packed = git_root / "packed-refs"
+            if packed.is_file():
+                for line in packed.read_text(encoding="utf-8").splitlines():
+                    if line.endswith(f" {ref}"):
+                        return line.split(" ", 1)[0]
+    except OSError:
+        return None
+    return None
+
+
+def _common_git_dir(git_dir: Path) -> Path:
+    """Main git directory of a linked worktree (``git_dir`` otherwise)."""
+    common = git_dir / "commondir"
+    if common.is_file():
+        return (git_dir / common.read_text(encoding="utf-8").strip()).resolve()
+    return git_dir
+
+
+def _iso_date(timestamp: int) -> str:
+    return datetime.fromtimestamp(timestamp, tz=timezone.utc).date().isoformat()
+
+
+def _is_test_path(path: str) -> bool:
+    parts = path.lower().split("/")
+    name = parts[-1]
+    return (
+        any(part in {"test", "tests", "spec", "__tests__"} for part in parts[:-1])
+        or name.startswith("test_")
+        or any(marker in name for marker in ("_test.", ".test.", ".spec."))
+    )
+
+
+@dataclass
+class GitCommit:
+    """One commit of the analyzed history."""
+
+    sha: str
+    author: str  # "Name <email>"
+    timestamp: int
+    parents: int
+    subject: str
+    files: Dict[str, Tuple[int, int]] = field(default_factory=dict)
+
+    @property
+    def is_merge(self) -> bool:
+        return self.parents > 1
+
+    @property
+    def lines_changed(self) -> int:
+        return sum(added + deleted for added, deleted in self.files.values())
+
+
+class GitHistory:
+    """Commits, branches and tags of a repository, read once and shared."""
+
+    def __init__(self, repo_path: Path, max_commits: int = MAX_HISTORY_COMMITS):
+        self.repo_path = Path(repo_path)
+        self.max_commits = max_commits
+        self._repo: Any = None
+        self._commits: Optional[List[GitCommit]] = None
+        self._branches: Optional[Dict[str, int]] = None
+        self._branch_refs: Dict[str, str] = {}
+        self._tags: Optional[List[Tuple[str, int]]] = None
+
+    @property
+    def repo(self) -> Any:
+        if self._repo is None:
+            self._repo = open_repository(self.repo_path)
+        return self._repo
+
+    def _git(self, command: str, *args: str) -> str:
+        """Output of a git command, or "" when it fails (e.g. no commits yet)."""
+        repo = self.repo  # Raises GitError without GitPython
+        from git import CommandError
+
+        try:
+            return str(getattr(repo.git, command)(*args))
+        except CommandError:
+            return ""
+
+    @property
+    def commits(self) -> List[GitCommit]:
+        """Most recent commits reachable from HEAD, newest first."""
+        if self._commits is None:
+            output = self._git(
+                "log",
+                f"--max-count={self.max_commits}",
+                f"--format={_RECORD}%H{_UNIT}%an <%ae>{_UNIT}%at{_UNIT}%P{_UNIT}%s",
+                "--numstat",
+                "--no-renames",
+            )
+            self._commits = [
+                self._parse_commit(record)
+                for record in output.split(_RECORD)
+                if record.strip()
+            ]
+        return self._commits
+
+    @staticmethod
+    def _parse_commit(record: str) -> GitCommit:
+        header, _, numstat = record.partition("\n")
+        sha, author, timestamp, parents, subject = header.split(_UNIT, 4)
+        files: Dict[str, Tuple[int, int]] = {}
+        for line in numstat.splitlines():
+            parts = line.split("\t", 2)
+            if len(parts) != 3:
+                continue
+            added, deleted, path = parts
+            # Binary files report "-" instead of line counts
+            files[path] = (
+                int(added) if added.isdigit() else 0,
+                int(deleted) if deleted.isdigit() else 0,
+            )
+        return GitCommit(
+            sha=sha,
+            author=author,
+            timestamp=int(timestamp),
+            parents=len(parents.split()),
+            subject=subject,
+            files=files,
+        )
+
+    @property
+    def branches(self) -> Dict[str, int]:
+        """Local and remote branch names mapped to their last commit time."""
+        if self._branches is None:
+            output = self._git(
+                "for_each_ref",
+                "--format=%(refname)%09%(committerdate:unix)",
+                "refs/heads",
+                "refs/remotes",
+            )
+            branches: Dict[str, int] = {}
+            for line in output.splitlines():
+                ref, _, timestamp = line.partition("\t")
+                if ref.startswith("refs/heads/"):
+                    name = ref[len("refs/heads/") :]
+                else:
+                    # refs/remotes/<remote>/<branch>
+                    name = ref.split("/", 3)[-1]
+                if name == "HEAD" or not timestamp.isdigit():
+                    continue
+                branches[name] = max(branches.get(name, 0), int(timestamp))
+                # Local branches sort first and win over remote-tracking ones
+                self._branch_refs.setdefault(name, ref)
+            self._branches = branches
+        return self._branches
+
+    @property
+    def tags(self) -> List[Tuple[str, int]]:
+        """Tag names with their creation time, oldest first."""
+        if self._tags is None:
+            output = self._git(
+                "for_each_ref",
+                "--sort=creatordate",
+                "--format=%(refname:short)%09%(creatordate:unix)",
+                "refs/tags",
+            )
+            self._tags = [
+                (name, int(timestamp))
+                for name, _, timestamp in (
+                    line.partition("\t") for line in output.splitlines()
+                )
+                if timestamp.isdigit()
+            ]
+        return self._tags
+
+    def unmerged_commit_times(self, branch: str, base: str) -> List[int]:
+        """Commit times on ``branch`` that are not on ``base``, newest first."""
+        if branch not in self.branches or base not in self.branches:
+            return []
+        refs = self._branch_refs
+        output = self._git("log", f"{refs[base]}..{refs[branch]}", "--format=%at")
+        return [int(line) for line in output.splitlines() if line.isdigit()]
+
+    def file_exists(self, path: str) -> bool:
+        return (self.repo_path / path).exists()
+
+
+def detect_commit_convention(subjects: List[str]) -> CommitConvention:
+    """Infer the commit message convention followed by ``subjects``."""
+    subjects = [
+        subject
+        for subject in subjects
+        if subject
+        and not MERGE_SUBJECT.match(subject)
+        and not REVERT_SUBJECT.match(subject)
+    ]
+    if not subjects:
+        return CommitConvention("free_form")
+
+    lengths = sorted(len(subject) for subject in subjects)
+    max_subject_length = lengths[min(len(lengths) - 1, int(len(lengths) * 0.9))]
+
+    best: Optional[Tuple[str, List["re.Match[str]"]]] = None
+    matched = set()
+    for name, pattern in COMMIT_CONVENTIONS.items():
+        matches = [m for m in map(re.compile(pattern).match, subjects) if m]
+        matched.update(m.string for m in matches)
+        if best is None or len(matches) > len(best[1]):
+            best = (name, matches)
+    unstructured = sum(1 for subject in subjects if subject not in matched)
+
+    name, matches = best  # type: ignore[misc]
+    share = len(matches) / len(subjects)
+    if share < MIN_CONVENTION_SHARE:
+        return CommitConvention(
+            "free_form",
+            share=round(unstructured / len(subjects), 2),
+            examples=subjects[:3],
+            max_subject_length=max_subject_length,
+        )
+
+    types = Counter(_convention_type(name, m.group("type")) for m in matches)
+    scopes = Counter(m.group("scope") for m in matches if m.groupdict().get("scope"))
+    return CommitConvention(
+        name,
+        share=round(share, 2),
+        pattern=_convention_pattern(name, types),
+        types=dict(types.most_common(10)),
+        scopes=[scope for scope, _ in scopes.most_common(10)],
+        examples=[m.string for m in matches[:3]],
+        max_subject_length=max_subject_length,
+    )
+
+
+def _convention_type(name: str, value: str) -> str:
+    # "[task-12]" and "[task-13]" are the same kind of prefix
+    if name == "bracket_prefix":
+        return re.sub(r"\d+", "#", value)
+    return value
+
+
+def _convention_pattern(name: str, types: Dict[str, int]) -> str:
+    """Pattern of a conforming subject, narrowed to the prefixes seen in use."""
+    if name == "ticket_prefix" and types:
+        keys = "|".join(sorted(_ere_escape(key) for key in types))
+        return rf"^\[?({keys})-[0-9]+\]?:? [^ ]"
+    if name == "bracket_prefix" and types:
+        prefixes = sorted(
+            _ere_escape(prefix).replace("#", "[0-9]+") for prefix in types
+        )
+        return rf"^\[({'|'.join(prefixes)})\] [^ ]"
+    return CONVENTION_PATTERNS[name]
+
+
+def _ere_escape(text: str) -> str:
+    """Escape ``text`` for a pattern read by both grep -E and Python's re."""
+    return re.sub(r"([.[\]()*+?{}|^$\\])", r"\\\1", text)
+
+
+def commit_convention_rule(convention: CommitConvention) -> str:
+    """One-sentence instruction for writing a subject in ``convention``."""
+    if convention.name == "conventional_commits":
+        kinds = ", ".join(f"`{kind}`" for kind in list(convention.types)[:5])
+        return (
+            "Write `type(scope): summary` with a lowercase type"
+            + (f" such as {kinds}" if kinds else "")
+        )
+    if convention.name == "ticket_prefix":
+        keys = ", ".join(f"`{key}-123`" for key in list(convention.types)[:3])
+        return "Start the subject with a ticket key" + (f" ({keys})" if keys else "")
+    if convention.name == "bracket_prefix":
+        prefixes = ", ".join(
+            f"`[{prefix.replace('#', '<n>')}]`"
+            for prefix in list(convention.types)[:3]
+        )
+        return "Start the subject with a bracketed prefix" + (
+            f" ({prefixes})" if prefixes else ""
+        )
+    if convention.name == "gitmoji":
+        emoji = ", ".join(f"`{kind}`" for kind in list(convention.types)[:3])
+        return "Start the subject with a gitmoji" + (f" ({emoji})" if emoji else "")
+    return "Write a short imperative subject line"
+
+
+def detect_branching_strategy(branches: List[str], merged: List[str]) -> str:
+    """Branching strategy from live branch names and names of merged branches."""
+    names = set(branches) | set(merged)
+    prefixes = {name.split("/", 1)[0] for name in names if "/" in name}
+    if names & {"develop", "development"}:
+        return "gitflow"
+    if "release" in prefixes or any(name.startswith("release-") for name in names):
+        return "release_branches"
+    if names - LONG_LIVED_BRANCHES:
+        return "feature_branches"
+    return "trunk_based"
+
+
+def merged_branches(commits: List[GitCommit]) -> List[str]:
+    """Branch names mentioned by merge commit subjects."""
+    names = []
+    for commit in commits:
+        match = MERGE_SUBJECT.match(commit.subject)
+        if match:
+            names.append(match.group("pr") or match.group("branch"))
+    return names
+
+
+def file_churn(history: GitHistory) -> List[FileChurn]:
+    """Churn of files that still exist, most frequently changed first."""
+    churn: Dict[str, FileChurn] = {}
+    for commit in history.commits:
+        for path, (added, deleted) in commit.files.items():
+            entry = churn.setdefault(path, FileChurn(path))
+            entry.commits += 1
+            entry.lines_added += added
+            entry.lines_deleted += deleted
+    return sorted(
+        (
+            entry
+            for entry in churn.values()
+            if Path(entry.path).name not in LOCKFILES
+            and history.file_exists(entry.path)
+        ),
+        key=lambda entry: (
+            -entry.commits,
+            -(entry.lines_added + entry.lines_deleted),
+            entry.path,
+        ),
+    )
+
+
+def co_changes(history: GitHistory) -> List[CoChange]:
+    """File pairs that change together, strongest coupling first."""
+    file_commits: Counter[str] = Counter()
+    pair_commits: Counter[Tuple[str, str]] = Counter()
+    for commit in history.commits:
+        files = sorted(
+            path
+            for path in commit.files
+            if Path(path).name not in LOCKFILES and history.file_exists(path)
+        )
+        if commit.is_merge or len(files) > MAX_FILES_PER_COMMIT:
+            continue
+        file_commits.update(files)
+        pair_commits.update(combinations(files, 2))
+
+    pairs = []
+    for (first, second), shared in pair_commits.items():
+        if shared < MIN_CO_CHANGE_COMMITS:
+            continue
+        coupling = shared / min(file_commits[first], file_commits[second])
+        if coupling >= MIN_CO_CHANGE_COUPLING:
+            pairs.append(CoChange([first, second], shared, round(coupling, 2)))
+    pairs.sort(key=lambda pair: (-pair.commits, -pair.coupling, pair.files))
+    return pairs[:MAX_CO_CHANGES]
+
+
+def release_cadence(tags: List[Tuple[str, int]]) -> Optional[ReleaseCadence]:
+    """Version pattern and time between release tags (oldest first)."""
+    if not tags:
+        return None
+    names = [name for name, _ in tags]
+    if all(CALENDAR_VERSION.match(name) for name in names):
+        version_pattern = "calendar"
+    elif all(SEMANTIC_VERSION.match(name) for name in names):
+        version_pattern = "semantic"
+    else:
+        version_pattern = "other"
+
+    timestamps = sorted(timestamp for _, timestamp in tags)
+    intervals = [
+        (later - earlier) / 86400 for earlier, later in zip(timestamps, timestamps[1:])
+    ]
+    return ReleaseCadence(
+        tags=names,
+        version_pattern=version_pattern,
+        average_days=round(statistics.mean(intervals), 1) if intervals else None,
+        median_days=round(statistics.median(intervals), 1) if intervals else None,
+        latest_date=_iso_date(timestamps[-1]),
+    )
 
 
 @dataclass
 class BranchAnalysis:
     """Results of branch analysis."""
 
-    strategy_type: str = "feature_branch"
-    feature_branches: Optional[List[str]] = None
-    hotfix_branches: Optional[List[str]] = None
-    branch_lifespans: Optional[List[Dict[str, Any]]] = None
-
-    def __post_init__(self) -> None:
-        if self.feature_branches is None:
-            self.feature_branches = ["feature/auth", "feature/api"]
-        if self.hotfix_branches is None:
-            self.hotfix_branches = ["hotfix/critical-bug"]
-        if self.branch_lifespans is None:
-            self.branch_lifespans = [{"branch": "main", "days": 365}]
+    strategy_type: str = "trunk_based"
+    feature_branches: List[str] = field(default_factory=list)
+    release_branches: List[str] = field(default_factory=list)
+    hotfix_branches: List[str] = field(default_factory=list)
+    # Branches without commits for STALE_BRANCH_DAYS
+    stale_branches: List[str] = field(default_factory=list)
+    # Days between the first and last unmerged commit of each topic branch
+    branch_lifespans: Dict[str, float] = field(default_factory=dict)
 
 
 @dataclass
 class GitAnalysis:
     """Results of git repository analysis."""
 
-    repository_stats: Optional[Dict[str, Any]] = None
-    history_insights: Optional[Dict[str, Any]] = None
-    performance_metrics: Optional[Dict[str, Any]] = None
-
-    def __post_init__(self) -> None:
-        if self.repository_stats is None:
-            self.repository_stats = {"total_commits": 100, "contributors": 5}
-        if self.history_insights is None:
-            self.history_insights = {"avg_commits_per_day": 2.5}
-        if self.performance_metrics is None:
-            self.performance_metrics = {"build_success_rate": 0.95}
+    repository_stats: Dict[str, Any] = field(default_factory=dict)
+    history_insights: Dict[str, Any] = field(default_factory=dict)
+    performance_metrics: Dict[str, Any] = field(default_factory=dict)
+    history: Optional[GitHistoryInfo] = None
 
 
 class BranchAnalyzer:
-    """Stub implementation for branch analysis."""
+    """Branch layout and branching strategy of a repository."""
 
-    def __init__(self, git_path: Path):
+    def __init__(self, git_path: Path, history: Optional[GitHistory] = None):
         self.git_path = git_path
+        self.history = history or GitHistory(git_path)
 
     def analyze_branches(self) -> BranchAnalysis:
-        """Analyze repository branches."""
-        return BranchAnalysis()
+        """Classify live branches by their role."""
+        branches = self.history.branches
+        latest = max(branches.values(), default=0)
+        analysis = BranchAnalysis(strategy_type=self.detect_branching_strategy())
+        for name, timestamp in sorted(branches.items()):
+            kind = name.split("/", 1)[0] if "/" in name else ""
+            if kind in {"release", "releases"}:
+                analysis.release_branches.append(name)
+            elif kind in {"hotfix", "hotfixes"}:
+                analysis.hotfix_branches.append(name)
+            elif name not in LONG_LIVED_BRANCHES:
+                analysis.feature_branches.append(name)
+            if latest - timestamp > STALE_BRANCH_DAYS * 86400:
+                analysis.stale_branches.append(name)
+
+        mainline = next(
+            (name for name in ("main", "master", "trunk") if name in branches), None
+        )
+        if mainline is not None:
+            topics = [name for name in branches if name not in LONG_LIVED_BRANCHES]
+            for name in sorted(topics)[:MAX_LIFESPAN_BRANCHES]:
+                times = self.history.unmerged_commit_times(name, mainline)
+                if times:
+                    analysis.branch_lifespans[name] = round(
+                        (times[0] - times[-1]) / 86400, 1
+                    )
+        return analysis
 
     def detect_branching_strategy(self) -> str:
         """Detect the branching strategy used."""
-        return "feature_branch"
+        return detect_branching_strategy(
+            list(self.history.branches), merged_branches(self.history.commits)
+        )
 
 
 class HistoryAnalyzer:
-    """Stub implementation for history analysis."""
+    """Commit message conventions of a repository."""
 
-    def __init__(self, git_path: Path):
+    def __init__(self, git_path: Path, history: Optional[GitHistory] = None):
         self.git_path = git_path
+        self.history = history or GitHistory(git_path)
+
+    def detect_commit_convention(self) -> CommitConvention:
+        return detect_commit_convention(
+            [commit.subject for commit in self.history.commits]
+        )
 
     def detect_commit_patterns(self) -> Dict[str, Any]:
         """Detect commit patterns."""
-        return {"pattern": "conventional_commits", "frequency": "daily"}
+        return asdict(self.detect_commit_convention())
 
 
 class GitHistoryAnalyzer:
-    """Stub implementation for detailed git history analysis."""
+    """Commit rhythm, releases, hotfixes and churn of a repository."""
 
-    def __init__(self, git_path: Path):
+    def __init__(self, git_path: Path, history: Optional[GitHistory] = None):
         self.git_path = git_path
+        self.history = history or GitHistory(git_path)
 
     def analyze_commit_patterns(self) -> Dict[str, Any]:
         """Analyze commit patterns."""
+        commits = self.history.commits
+        hours = Counter(
+            datetime.fromtimestamp(commit.timestamp, tz=timezone.utc).hour
+            for commit in commits
+        )
+        weeks = 0.0
+        if commits:
+            weeks = max(
+                (commits[0].timestamp - commits[-1].timestamp) / (7 * 86400), 1.0
+            )
         return {
-            "patterns": ["conventional_commits", "feature_commits"],
-            "frequency": "daily",
-            "peak_hours": [9, 14, 16],
+            "convention": asdict(
+                detect_commit_convention([commit.subject for commit in commits])
+            ),
+            "commits_per_week": round(len(commits) / weeks, 1) if weeks else 0.0,
+            "peak_hours": [hour for hour, _ in hours.most_common(3)],  # UTC
         }
 
     def analyze_release_cycles(self) -> Dict[str, Any]:
         """Analyze release cycles."""
-        return {
-            "average_cycle_days": 14,
-            "releases_per_quarter": 6,
-            "stability_trends": "improving",
-        }
+        return asdict(release_cadence(self.history.tags) or ReleaseCadence())
 
     def analyze_hotfix_patterns(self) -> Dict[str, Any]:
         """Analyze hotfix patterns."""
+        commits = self.history.commits
+        hotfixes = [c for c in commits if HOTFIX_SUBJECT.search(c.subject)]
+        reverts = [c for c in commits if REVERT_SUBJECT.match(c.subject)]
+        areas = Counter(
+            path.split("/", 1)[0] if "/" in path else path
+            for commit in hotfixes + reverts
+            for path in commit.files
+        )
         return {
-            "hotfix_frequency": "low",
-            "average_fix_time": "2 hours",
-            "critical_areas": ["authentication", "payments"],
+            "hotfix_commits": len(hotfixes),
+            "revert_commits": len(reverts),
+            "hotfix_share": round(len(hotfixes) / len(commits), 3) if commits else 0.0,
+            "critical_areas": [area for area, _ in areas.most_common(5)],
         }
 
     def analyze_code_churn(self) -> Dict[str, Any]:
         """Analyze code churn metrics."""
+        commits = [commit for commit in self.history.commits if not commit.is_merge]
+        changed = sum(commit.lines_changed for commit in commits)
+        hotspots = file_churn(self.history)[:MAX_HOTSPOTS]
         return {
-            "churn_rate": 0.15,
-            "high_churn_files": ["config.py", "models.py"],
-            "stability_score": 0.85,
+            "lines_changed_per_commit": round(changed / len(commits), 1)
+            if commits
+            else 0.0,
+            "high_churn_files": [entry.path for entry in hotspots],
+            "hotspots": [asdict(entry) for entry in hotspots],
+            "co_changes": [asdict(pair) for pair in co_changes(self.history)],
         }
 
 
 class ContributorAnalyzer:
-    """Stub implementation for contributor analysis."""
+    """Authorship of the analyzed history."""
 
-    def __init__(self, git_path: Path):
+    def __init__(self, git_path: Path, history: Optional[GitHistory] = None):
         self.git_path = git_path
+        self.history = history or GitHistory(git_path)
 
     def analyze_contributors(self) -> List[Dict[str, Any]]:
         """Analyze repository contributors."""
-        return [
-            {"name": "Developer 1", "commits": 50, "lines_added": 1000},
-            {"name": "Developer 2", "commits": 30, "lines_added": 800},
-        ]
+        contributors: Dict[str, Dict[str, Any]] = {}
+        for commit in self.history.commits:
+            entry = contributors.setdefault(
+                commit.author,
+                {"name": commit.author, "commits": 0, "lines_added": 0},
+            )
+            entry["commits"] += 1
+            entry["lines_added"] += sum(added for added, _ in commit.files.values())
+        return sorted(
+            contributors.values(), key=lambda c: (-c["commits"], c["name"])
+        )
+
+    def bus_factor(self) -> int:
+        """Fewest authors who together wrote more than half of the commits."""
+        total = len(self.history.commits)
+        covered = 0
+        for count, contributor in enumerate(self.analyze_contributors(), 1):
+            covered += contributor["commits"]
+            if covered * 2 > total:
+                return count
+        return 0
 
 
 class CodeEvolutionTracker:
-    """Stub implementation for code evolution tracking."""
+    """How files, layout, dependencies and tests changed over the history."""
 
-    def __init__(self, git_path: Path):
+    def __init__(self, git_path: Path, history: Optional[GitHistory] = None):
         self.git_path = git_path
+        self.history = history or GitHistory(git_path)
 
     def track_file_evolution(self) -> Dict[str, Any]:
         """Track individual file evolution."""
+        last_changed: Dict[str, int] = {}
+        for commit in self.history.commits:
+            for path in commit.files:
+                last_changed.setdefault(path, commit.timestamp)
         return {
-            "files_tracked": 25,
+            "files_tracked": len(last_changed),
             "evolution_patterns": {
-                "main.py": {"complexity_trend": "stable", "changes": 42},
-                "utils.py": {"complexity_trend": "increasing", "changes": 18},
+                entry.path: {
+                    "changes": entry.commits,
+                    "lines_added": entry.lines_added,
+                    "lines_deleted": entry.lines_deleted,
+                    "last_changed": _iso_date(last_changed[entry.path]),
+                }
+                for entry in file_churn(self.history)[:MAX_HOTSPOTS]
             },
-            "overall_stability": 0.78,
         }
 
     def analyze_architecture_evolution(self) -> Dict[str, Any]:
         """Analyze architecture evolution over time."""
+        introduced: Dict[str, int] = {}
+        for commit in reversed(self.history.commits):
+            for path in commit.files:
+                if "/" in path:
+                    introduced.setdefault(path.split("/", 1)[0], commit.timestamp)
         return {
             "architecture_changes": [
-                {"date": "2024-01-15", "change": "Added microservices layer"},
-                {"date": "2024-03-10", "change": "Introduced event sourcing"},
-            ],
-            "complexity_score": 0.65,
-            "maintainability_trend": "improving",
+                {"date": _iso_date(timestamp), "change": f"Added `{directory}/`"}
+                for directory, timestamp in sorted(
+                    introduced.items(), key=lambda item: (item[1], item[0])
+                )
+            ]
         }
 
     def track_dependency_evolution(self) -> Dict[str, Any]:
         """Track dependency changes over time."""
-        return {
-            "dependencies_added": 8,
-            "dependencies_removed": 3,
-            "major_version_updates": 2,
-            "security_updates": 5,
-            "dependency_health": "good",
-        }
+        changes: Counter[str] = Counter()
+        last_changed: Dict[str, str] = {}
+        for commit in self.history.commits:
+            for path in commit.files:
+                if Path(path).name in DEPENDENCY_MANIFESTS:
+                    changes[path] += 1
+                    last_changed.setdefault(path, _iso_date(commit.timestamp))
+        return {"manifest_changes": dict(changes), "last_changed": last_changed}
 
     def analyze_quality_trends(self) -> Dict[str, Any]:
         """Analyze code quality trends over time."""
-        return {
-            "quality_score_trend": "improving",
-            "test_coverage_trend": "stable",
-            "bug_density_trend": "decreasing",
-            "technical_debt_trend": "stable",
-        }
+        commits = [commit for commit in self.history.commits if commit.files]
+        half = len(commits) // 2
+        recent = self._test_share(commits[:half])
+        earlier = self._test_share(commits[half:])
+        trend = "stable"
+        if half and recent - earlier > 0.1:
+            trend = "increasing"
+        elif half and earlier - recent > 0.1:
+            trend = "decreasing"
+        return {"test_change_share": round(recent, 2), "test_change_trend": trend}
+
+    @staticmethod
+    def _test_share(commits: List[GitCommit]) -> float:
+        """Fraction of commits that touch tests."""
+        if not commits:
+            return 0.0
+        with_tests = sum(
+            1 for commit in commits if any(map(_is_test_path, commit.files))
+        )
+        return with_tests / len(commits)
 
 
 class AdvancedGitAnalyzer:
-    """Stub implementation for advanced git analysis."""
+    """Git history analytics for a repository, sharing one read of the history."""
 
-    def __init__(self, git_path: Path):
+    def __init__(self, git_path: Path, max_commits: int = MAX_HISTORY_COMMITS):
         self.git_path = git_path
-        self.branch_analyzer = BranchAnalyzer(git_path)
-        self.history_analyzer = HistoryAnalyzer(git_path)
-        self.contributor_analyzer = ContributorAnalyzer(git_path)
+        self.history = GitHistory(git_path, max_commits)
+        self.branch_analyzer = BranchAnalyzer(git_path, self.history)
+        self.history_analyzer = HistoryAnalyzer(git_path, self.history)
+        self.contributor_analyzer = ContributorAnalyzer(git_path, self.history)
+        self.git_history_analyzer = GitHistoryAnalyzer(git_path, self.history)
+        self.evolution_tracker = CodeEvolutionTracker(git_path, self.history)
+
+    def history_info(self) -> GitHistoryInfo:
+        """Conventions and risk areas for the project analysis."""
+        commits = self.history.commits
+        hotfixes = self.git_history_analyzer.analyze_hotfix_patterns()
+        return GitHistoryInfo(
+            commits_analyzed=len(commits),
+            contributors=len({commit.author for commit in commits}),
+            first_commit_date=_iso_date(commits[-1].timestamp) if commits else None,
+            last_commit_date=_iso_date(commits[0].timestamp) if commits else None,
+            commit_convention=self.history_analyzer.detect_commit_convention(),
+            branching_strategy=self.branch_analyzer.detect_branching_strategy(),
+            branches=sorted(self.history.branches),
+            hotspots=file_churn(self.history)[:MAX_HOTSPOTS],
+            co_changes=co_changes(self.history),
+            releases=release_cadence(self.history.tags),
+            merge_commits=sum(1 for commit in commits if commit.is_merge),
+            hotfix_commits=hotfixes["hotfix_commits"],
+            revert_commits=hotfixes["revert_commits"],
+        )
 
     def analyze_repository(self) -> GitAnalysis:
         """Perform comprehensive repository analysis."""
-        return GitAnalysis()
+        info = self.history_info()
+        commits = self.history.commits
+        days = 1.0
+        if commits:
+            days = max((commits[0].timestamp - commits[-1].timestamp) / 86400, 1.0)
+        convention = info.commit_convention or CommitConvention("free_form")
+        churn = self.git_history_analyzer.analyze_code_churn()
+        return GitAnalysis(
+            repository_stats={
+                "total_commits": info.commits_analyzed,
+                "contributors": info.contributors,
+                "branches": len(info.branches),
+                "tags": len(self.history.tags),
+                "merge_commits": info.merge_commits,
+            },
+            history_insights={
+                "avg_commits_per_day": round(len(commits) / days, 2),
+                "commit_convention": convention.name,
+                "convention_share": convention.share,
+                "branching_strategy": info.branching_strategy,
+                "bus_factor": self.contributor_analyzer.bus_factor(),
+            },
+            performance_metrics={
+                "lines_changed_per_commit": churn["lines_changed_per_commit"],
+                "hotfix_share": round(info.hotfix_commits / len(commits), 3)
+                if commits
+                else 0.0,
+                "revert_share": round(info.revert_commits / len(commits), 3)
+                if commits
+                else 0.0,
+            },
+            history=info,
+        )
 
 
 class GitInsights:
-    """Stub implementation for git insights generation."""
+    """Readable findings derived from an AdvancedGitAnalyzer."""
 
     def __init__(self, analyzer: AdvancedGitAnalyzer):
         self.analyzer = analyzer
+        self._analysis: Optional[GitAnalysis] = None
+
+    @property
+    def analysis(self) -> GitAnalysis:
+        if self._analysis is None:
+            self._analysis = self.analyzer.analyze_repository()
+        return self._analysis
 
     def generate_workflow_insights(self) -> List[str]:
         """Generate insights about development workflow."""
-        return [
-            "Team follows feature branch workflow",
-            "Good commit frequency with daily contributions",
-            "High build success rate indicates good CI practices",
+        info = self.analysis.history
+        if info is None or not info.commits_analyzed:
+            return ["Repository has no commits yet"]
+        insights = [
+            f"{info.commits_analyzed} commits by {info.contributors} contributor(s) "
+            f"between {info.first_commit_date} and {info.last_commit_date}",
+            f"Branching strategy: {BRANCHING_STRATEGIES[info.branching_strategy]}",
         ]
+        convention = info.commit_convention
+        if convention and convention.name != "free_form":
+            insights.append(
+                f"Commit subjects follow {COMMIT_CONVENTION_NAMES[convention.name]} "
+                f"({convention.share:.0%} of recent commits)"
+            )
+        if info.releases and info.releases.median_days is not None:
+            insights.append(
+                f"Releases are tagged every {info.releases.median_days:g} days "
+                "(median)"
+            )
+        return insights
 
     def generate_performance_insights(self) -> List[str]:
         """Generate insights about repository performance."""
-        return [
-            "Repository size is well-managed",
-            "Build times are within acceptable range",
-            "Test coverage is comprehensive",
+        info = self.analysis.history
+        if info is None:
+            return []
+        insights = [
+            f"`{entry.path}` changed in {entry.commits} commits"
+            for entry in info.hotspots[:3]
         ]
+        insights.extend(
+            f"`{pair.files[0]}` and `{pair.files[1]}` change together "
+            f"in {pair.commits} commits"
+            for pair in info.co_changes[:3]
+        )
+        return insights
 
     def generate_recommendations(self) -> List[str]:
         """Generate recommendations for improvement."""
-        return [
-            "Consider implementing conventional commit messages",
-            "Add more automated testing for edge cases",
-            "Document branching strategy in README",
+        info = self.analysis.history
+        if info is None or not info.commits_analyzed:
+            return []
+        recommendations = []
+        convention = info.commit_convention
+        if convention is None or convention.name == "free_form":
+            recommendations.append("Adopt a commit message convention")
+        if info.releases is None:
+            recommendations.append("Tag releases so their cadence can be tracked")
+        if self.analysis.history_insights.get("bus_factor") == 1 and (
+            info.contributors > 1
+        ):
+            recommendations.append(
+                "Most commits come from one contributor; spread review of core areas"
+            )
+        if info.hotspots:
+            recommendations.append(
+                f"Keep `{info.hotspots[0].path}` well tested; "
+                "it changes more than any other file"
+            )
+        if info.revert_commits > max(2, info.commits_analyzed // 20):
+            recommendations.append(
+                "Reverts are frequent; consider more checks before merging"
+            )
+        return recommendations
+
+
+COMMIT_CONVENTION_NAMES = {
+    "conventional_commits": "Conventional Commits",
+    "ticket_prefix": "ticket-key prefixes",
+    "bracket_prefix": "bracketed prefixes",
+    "gitmoji": "gitmoji",
+    "free_form": "no fixed convention",
+}
+BRANCHING_STRATEGIES = {
+    "gitflow": "GitFlow (long-lived `develop` branch)",
+    "release_branches": "release branches cut from the main line",
+    "feature_branches": "short-lived feature branches merged into the main line",
+    "trunk_based": "trunk-based (commits land directly on the main line)",
+}
+
+
+def infer_commit_convention(
+    path: Path, max_commits: int = MAX_HISTORY_COMMITS
+) -> CommitConvention:
+    """Commit message convention of the recent history of the repository."""
+    history = GitHistory(path, max_commits)
+    return HistoryAnalyzer(path, history).detect_commit_convention()
+
+
+def analyze_git_history(
+    path: Path, max_commits: int = MAX_HISTORY_COMMITS
+) -> GitHistoryInfo:
+    """Mine conventions and risk areas from the history of the repository."""
+    return AdvancedGitAnalyzer(path, max_commits).history_info()
+
+
+def summarize_git_history(info: GitHistoryInfo) -> str:
+    """Markdown of the git workflow and commit guidelines for generated docs."""
+    convention = info.commit_convention or CommitConvention("free_form")
+    return (
+        summarize_git_workflow(info)
+        + "\n\n### Commit Guidelines\n\n"
+        + summarize_commit_guidelines(convention)
+    )
+
+
+def summarize_git_workflow(info: GitHistoryInfo) -> str:
+    """Markdown of the history, branching, releases and hotspots of a repo."""
+    lines = [
+        f"- **History analyzed**: {info.commits_analyzed} commits by "
+        f"{info.contributors} contributor(s), {info.first_commit_date} "
+        f"to {info.last_commit_date}"
+    ]
+    lines.append(f"- **Branching**: {BRANCHING_STRATEGIES[info.branching_strategy]}")
+
+    releases = info.releases
+    if releases:
+        cadence = f"{len(releases.tags)} tags, latest `{releases.tags[-1]}`"
+        if releases.median_days is not None:
+            cadence += f", about every {releases.median_days:g} days"
+        if releases.version_pattern in {"semantic", "calendar"}:
+            cadence += f"; {releases.version_pattern} versioning"
+        lines.append(f"- **Releases**: {cadence}")
+
+    if info.hotspots:
+        lines.append(
+            "- **Hotspots** (changed most often; review changes here carefully)"
+        )
+        lines.extend(
+            f"  - `{entry.path}`: {entry.commits} commits, "
+            f"+{entry.lines_added}/-{entry.lines_deleted} lines"
+            for entry in info.hotspots[:5]
+        )
+    if info.co_changes:
+        lines.append(
+            "- **Files that change together** (update both when editing one)"
+        )
+        lines.extend(
+            f"  - `{pair.files[0]}` and `{pair.files[1]}`: "
+            f"{pair.commits} shared commits ({pair.coupling:.0%})"
+            for pair in info.co_changes[:5]
+        )
+    if info.revert_commits or info.hotfix_commits:
+        lines.append(
+            f"- **Fixes after the fact**: {info.hotfix_commits} hotfix and "
+            f"{info.revert_commits} revert commits"
+        )
+    return "\n".join(lines)
+
+
+def summarize_commit_guidelines(convention: CommitConvention) -> str:
+    """Markdown rules for commit messages following the inferred convention."""
+    if convention.name == "free_form":
+        lines = ["Recent commits follow no fixed convention.", ""]
+    else:
+        lines = [
+            f"Recent commits follow {COMMIT_CONVENTION_NAMES[convention.name]} "
+            f"({convention.share:.0%}); match them.",
+            "",
         ]
+    lines.append(f"- {commit_convention_rule(convention)}")
+    if convention.scopes:
+        scopes = ", ".join(f"`{scope}`" for scope in convention.scopes[:5])
+        lines.append(f"- Common scopes: {scopes}")
+    if convention.max_subject_length:
+        lines.append(
+            f"- Keep the subject under ~{convention.max_subject_length} characters"
+        )
+    if convention.pattern:
+        lines.append(f"- Subjects match `{convention.pattern}`")
+        lines.append(
+            "- `claude-builder git install-hooks --enforce-convention` installs a "
+            "commit-msg hook that rejects other subjects"
+        )
+    if convention.examples:
+        lines.append("- Examples:")
+        lines.extend(f"  - `{example}`" for example in convention.examples)
+    return "\n".join(lines)
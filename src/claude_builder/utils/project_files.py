@@ -0,0 +1,270 @@
+"""Single-pass project walker.
+
+``walk_project`` traverses a project once and returns a ``ProjectFiles`` index
+that the analyzer, detectors and file-pattern helpers query instead of running
+their own ``rglob`` passes. The walk honours, in order:
+
+* configured ignore patterns (``AnalysisConfig.ignore_patterns``),
+* ``.gitignore`` files at any depth, evaluated with ``pathspec``,
+* the symlink policy (symlinks are skipped unless ``follow_symlinks`` is set,
+  and then only followed when they stay inside the project),
+* ``max_files`` (the walk stops and the index is marked truncated) and
+  ``max_file_size`` (larger files are indexed but never read).
+"""
+
+from __future__ import annotations
+
+import fnmatch
+import os
+
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Iterable, Iterator
+
+import pathspec
+
+
+DEFAULT_IGNORE_PATTERNS = [
+    ".git/",
+    "node_modules/",
+    "__pycache__/",
+    "target/",
+    "dist/",
+    "build/",
+    ".venv/",
+    "venv/",
+    ".tox/",
+    ".coverage",
+]
+DEFAULT_MAX_FILE_SIZE = 10 * 1024 * 1024  # Matches AnalysisConfig.max_file_size
+DEFAULT_MAX_FILES = 10000  # Matches AnalysisConfig.max_files_to_analyze
+GITIGNORE = ".gitignore"
+
+
+def matches_ignore_pattern(relative: str, patterns: Iterable[str]) -> bool:
+    """Whether a POSIX relative path matches an analyzer ignore pattern.
+
+    Patterns ending in ``/`` name directories at any depth and match whole
+    path components (``.git/`` does not hide ``.github`` or ``.gitignore``);
+    other patterns match as substrings of the relative path.
+    """
+    for pattern in patterns:
+        if pattern.endswith("/"):
+            if f"/{pattern.strip('/')}/" in f"/{relative}/":
+                return True
+        elif pattern in relative:
+            return True
+    return False
+
+
+@dataclass
+class ProjectFiles:
+    """Files and directories of a project that survived the walk."""
+
+    root: Path
+    files: list[Path] = field(default_factory=list)
+    directories: list[Path] = field(default_factory=list)
+    sizes: dict[Path, int] = field(default_factory=dict)
+    max_file_size: int | None = None
+    truncated: bool = False  # The walk stopped at max_files
+    skipped_symlinks: list[str] = field(default_factory=list)
+
+    def relative(self, path: Path) -> str:
+        return path.relative_to(self.root).as_posix()
+
+    def root_files(self) -> list[Path]:
+        return [path for path in self.files if path.parent == self.root]
+
+    def root_directories(self) -> list[Path]:
+        return [path for path in self.directories if path.parent == self.root]
+
+    def with_suffix(self, *suffixes: str) -> list[Path]:
+        wanted = {suffix.lower() for suffix in suffixes}
+        return [path for path in self.files if path.suffix.lower() in wanted]
+
+    def named(self, name: str) -> list[Path]:
+        return [path for path in self.files if path.name == name]
+
+    def glob(self, pattern: str) -> list[Path]:
+        """Root-level files and directories whose name matches ``pattern``."""
+        return [
+            path
+            for path in self.root_files() + self.root_directories()
+            if fnmatch.fnmatchcase(path.name, pattern)
+        ]
+
+    def rglob(self, pattern: str) -> list[Path]:
+        """Files and directories at any depth whose name matches ``pattern``."""
+        return [
+            path
+            for path in [*self.files, *self.directories]
+            if fnmatch.fnmatchcase(path.name, pattern)
+        ]
+
+    def under(self, directory: Path) -> list[Path]:
+        """Files below ``directory`` (at any depth)."""
+        return [path for path in self.files if directory in path.parents]
+
+    def is_readable(self, path: Path) -> bool:
+        """Whether the file is within the size cap."""
+        if self.max_file_size is None:
+            return True
+        return self.sizes.get(path, 0) <= self.max_file_size
+
+    def read_text(self, path: Path) -> str | None:
+        """File content, or None when it is too large or unreadable."""
+        if not self.is_readable(path):
+            return None
+        try:
+            return path.read_text(encoding="utf-8", errors="ignore")
+        except OSError:
+            return None
+
+
+def _rebase_gitignore(lines: Iterable[str], base: str) -> Iterator[str]:
+    """Rewrite a nested ``.gitignore`` so its patterns apply from the root."""
+    for raw in lines:
+        line = raw.rstrip()
+        if not line or line.startswith("#"):
+            continue
+        if not base:
+            yield line
+            continue
+        negate = line.startswith("!")
+        body = line[1:] if negate else line
+        if "/" in body.rstrip("/"):
+            rebased = f"{base}/{body.lstrip('/')}"
+        else:
+            rebased = f"{base}/**/{body}"
+        yield f"!{rebased}" if negate else rebased
+
+
+def _is_followable(path: Path, real_root: Path, visited: set[Path]) -> bool:
+    """Symlinks are followed only inside the project and only once."""
+    try:
+        target = path.resolve(strict=True)
+    except (OSError, RuntimeError):
+        return False
+    if target != real_root and real_root not in target.parents:
+        return False
+    if target in visited:
+        return False
+    visited.add(target)
+    return True
+
+
+def walk_project(
+    root: Path,
+    *,
+    ignore_patterns: Iterable[str] | None = None,
+    respect_gitignore: bool = True,
+    max_file_size: int | None = DEFAULT_MAX_FILE_SIZE,
+    max_files: int | None = DEFAULT_MAX_FILES,
+    follow_symlinks: bool = False,
+    exclude: Iterable[str] = (),
+) -> ProjectFiles:
+    """Walk ``root`` once and build its file index.
+
+    ``exclude`` lists relative directories (such as tool caches) and files
+    that are neither walked nor reported. A directory holding nothing but
+    excluded entries is not reported either, so the tool's own state never
+    shows up in the project layout.
+    """
+    root = Path(root)
+    patterns = list(
+        DEFAULT_IGNORE_PATTERNS if ignore_patterns is None else ignore_patterns
+    )
+    excluded = {directory.strip("/") for directory in exclude}
+    index = ProjectFiles(root=root, max_file_size=max_file_size)
+    real_root = root.resolve()
+    visited = {real_root}
+    gitignore_lines: list[str] = []
+    spec: pathspec.GitIgnoreSpec | None = None
+    # Directories with excluded entries are reported only if they hold more
+    partly_excluded: set[Path] = set()
+
+    def is_ignored(relative: str, is_dir: bool) -> bool:
+        if matches_ignore_pattern(relative, patterns):
+            return True
+        if spec is None:
+            return False
+        return bool(spec.match_file(f"{relative}/" if is_dir else relative))
+
+    for current, dirnames, filenames in os.walk(root, followlinks=follow_symlinks):
+        base = Path(current)
+        base_relative = base.relative_to(root).as_posix() if base != root else ""
+
+        if respect_gitignore and GITIGNORE in filenames:
+            try:
+                lines = (base / GITIGNORE).read_text(encoding="utf-8").splitlines()
+            except (OSError, UnicodeDecodeError):
+                lines = []
+            rebased = list(_rebase_gitignore(lines, base_relative))
+            if rebased:
+                gitignore_lines.extend(rebased)
+                spec = pathspec.GitIgnoreSpec.from_lines(gitignore_lines)
+
+        kept = []
+        for name in sorted(dirnames):
+            path = base / name
+            relative = f"{base_relative}/{name}" if base_relative else name
+            if relative in excluded:
+                continue
+            if path.is_symlink() and not (
+                follow_symlinks and _is_followable(path, real_root, visited)
+            ):
+                index.skipped_symlinks.append(relative)
+                continue
+            if is_ignored(relative, is_dir=True):
+                continue
+            kept.append(name)
+            index.directories.append(path)
+            if any(e.startswith(f"{relative}/") for e in excluded):
+                partly_excluded.add(path)
+        dirnames[:] = kept
+
+        for name in sorted(filenames):
+            path = base / name
+            relative = f"{base_relative}/{name}" if base_relative else name
+            if relative in excluded:
+                continue
+            if path.is_symlink() and not (
+                follow_symlinks and _is_followable(path, real_root, visited)
+            ):
+                index.skipped_symlinks.append(relative)
+                continue
+            if is_ignored(relative, is_dir=False):
+                continue
+            try:
+                size = path.stat().st_size
+            except OSError:
+                continue
+            if max_files is not None and len(index.files) >= max_files:
+                index.truncated = True
+                return _drop_tool_only_directories(index, partly_excluded)
+            index.files.append(path)
+            index.sizes[path] = size
+
+    return _drop_tool_only_directories(index, partly_excluded)
+
+
+def _drop_tool_only_directories(
+    index: ProjectFiles, partly_excluded: set[Path]
+) -> ProjectFiles:
+    """Remove directories in ``partly_excluded`` that hold nothing reported."""
+    occupied = {path.parent for path in index.files}
+    occupied.update(
+        directory.parent
+        for directory in index.directories
+        if directory not in partly_excluded
+    )
+    # os.walk lists parents first, so walking backwards settles children first
+    for directory in reversed(index.directories):
+        if directory in partly_excluded and directory in occupied:
+            occupied.add(directory.parent)
+    index.directories = [
+        directory
+        for directory in index.directories
+        if directory not in partly_excluded or directory in occupied
+    ]
+    return index
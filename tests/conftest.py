@@ -62,6 +62,13 @@ edition = "2021"
     return project
 
 
+def write_file(path: Path, content: str = "x\n") -> Path:
+    """Write ``content`` to ``path``, creating parent directories."""
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(content)
+    return path
+
+
 # Removed pytest_collection_modifyitems - test_project_command_yaml_output_no_yaml
 # is now fixed by patching sys.modules in addition to __import__
 
@@ -0,0 +1,361 @@
+"""Tests for Cargo manifest/workspace parsing and workspace-aware analysis."""
+
+from pathlib import Path
+
+from claude_builder.analysis.cargo import (
+    CargoManifest,
+    build_dependency_graph,
+    load_cargo_workspace,
+)
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.template_manager import TemplateManager
+
+
+def _write_crate(path: Path, manifest: str, *, main: bool = False) -> None:
+    (path / "src").mkdir(parents=True, exist_ok=True)
+    (path / "Cargo.toml").write_text(manifest)
+    if main:
+        (path / "src" / "main.rs").write_text("fn main() {}\n")
+    else:
+        (path / "src" / "lib.rs").write_text("pub fn hello() {}\n")
+
+
+def _create_workspace(root: Path) -> Path:
+    (root / "Cargo.toml").write_text(
+        """
+[workspace]
+members = ["crates/*", "tools/xtask"]
+exclude = ["crates/experimental"]
+resolver = "2"
+
+[workspace.package]
+edition = "2021"
+version = "0.3.0"
+
+[workspace.dependencies]
+tokio = { version = "1.38", features = ["rt-multi-thread"] }
+serde = "1.0"
+web = { package = "axum", version = "0.7" }
+""".strip()
+    )
+    _write_crate(
+        root / "crates" / "api",
+        """
+[package]
+name = "api"
+edition.workspace = true
+version.workspace = true
+
+[dependencies]
+web = { workspace = true }
+tokio = { workspace = true, features = ["macros"] }
+
+[dev-dependencies]
+serde = { workspace = true }
+
+[target.'cfg(unix)'.dependencies]
+nix = "0.28"
+""".strip(),
+        main=True,
+    )
+    _write_crate(
+        root / "crates" / "macros",
+        """
+[package]
+name = "api-macros"
+edition = "2018"
+
+[lib]
+proc-macro = true
+
+[build-dependencies]
+cc = "1"
+""".strip(),
+    )
+    _write_crate(
+        root / "crates" / "experimental",
+        '[package]\nname = "experimental"\nedition = "2021"\n',
+    )
+    _write_crate(
+        root / "tools" / "xtask",
+        '[package]\nname = "xtask"\nedition.workspace = true\n',
+        main=True,
+    )
+    return root
+
+
+class TestCargoManifest:
+    def test_collects_every_dependency_table(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path,
+            """
+[package]
+name = "demo"
+
+[dependencies]
+tokio = "1"
+
+[dev-dependencies]
+proptest = "1"
+
+[build-dependencies]
+prost-build = "0.12"
+
+[target.'cfg(target_arch = "wasm32")'.dependencies]
+wasm-bindgen = { version = "0.2", optional = true }
+""".strip(),
+        )
+        manifest = CargoManifest.load(tmp_path / "Cargo.toml")
+        assert manifest is not None
+
+        deps = {dep.name: dep for dep in manifest.dependencies()}
+        assert deps["tokio"].kind == "normal"
+        assert deps["proptest"].kind == "dev"
+        assert deps["prost-build"].kind == "build"
+        assert deps["wasm-bindgen"].target == "cfg(target_arch = \"wasm32\")"
+        assert deps["wasm-bindgen"].optional is True
+
+    def test_invalid_manifest_returns_none(self, tmp_path: Path) -> None:
+        (tmp_path / "Cargo.toml").write_text("[package\nname = ")
+        assert CargoManifest.load(tmp_path / "Cargo.toml") is None
+        assert load_cargo_workspace(tmp_path) is None
+
+    def test_crate_kind(self, tmp_path: Path) -> None:
+        _write_crate(tmp_path / "bin", '[package]\nname = "b"\n', main=True)
+        _write_crate(tmp_path / "lib", '[package]\nname = "l"\n')
+        _write_crate(
+            tmp_path / "pm", '[package]\nname = "p"\n\n[lib]\nproc-macro = true\n'
+        )
+
+        _write_crate(
+            tmp_path / "ffi",
+            '[package]\nname = "f"\n\n[lib]\ncrate-type = ["cdylib", "rlib"]\n',
+        )
+
+        kinds = {
+            name: CargoManifest.load(tmp_path / name / "Cargo.toml").crate_kind()
+            for name in ("bin", "lib", "pm", "ffi")
+        }
+        assert kinds == {
+            "bin": "bin",
+            "lib": "lib",
+            "pm": "proc-macro",
+            "ffi": "cdylib",
+        }
+
+    def test_crate_attributes(self, tmp_path: Path) -> None:
+        _write_crate(tmp_path, '[package]\nname = "fw"\n', main=True)
+        (tmp_path / "src" / "main.rs").write_text(
+            "//! Firmware\n#![no_std]\n#![no_main]\n\nfn main() {}\n"
+        )
+        manifest = CargoManifest.load(tmp_path / "Cargo.toml")
+
+        assert manifest.crate_attributes() == {"no_std", "no_main"}
+
+
+class TestCargoWorkspace:
+    def test_resolves_glob_members_and_exclude(self, tmp_path: Path) -> None:
+        workspace = load_cargo_workspace(_create_workspace(tmp_path))
+        assert workspace is not None
+        assert workspace.is_workspace
+        assert workspace.is_virtual
+
+        names = [member.name for member in workspace.members]
+        assert names == ["api", "api-macros", "xtask"]
+
+    def test_workspace_inheritance(self, tmp_path: Path) -> None:
+        workspace = load_cargo_workspace(_create_workspace(tmp_path))
+        api = workspace.members[0]
+
+        assert workspace.edition(api) == "2021"
+        assert workspace.version(api) == "0.3.0"
+
+        deps = {dep.alias: dep for dep in workspace.dependencies(api)}
+        # Renamed workspace dependency resolves to the real crate name
+        assert deps["web"].name == "axum"
+        assert deps["web"].workspace is True
+        assert deps["tokio"].version == "1.38"
+        assert deps["tokio"].features == ["rt-multi-thread", "macros"]
+        assert deps["serde"].kind == "dev"
+        assert deps["nix"].target == "cfg(unix)"
+
+    def test_single_package_is_one_member_workspace(self, tmp_path: Path) -> None:
+        _write_crate(tmp_path, '[package]\nname = "solo"\nedition = "2021"\n')
+        workspace = load_cargo_workspace(tmp_path)
+
+        assert not workspace.is_workspace
+        assert [m.name for m in workspace.members] == ["solo"]
+        assert workspace.relative_path(workspace.members[0]) == "."
+
+
+class TestWorkspaceAwareAnalysis:
+    def test_analysis_has_per_crate_breakdown(self, tmp_path: Path) -> None:
+        analysis = ProjectAnalyzer().analyze(_create_workspace(tmp_path))
+
+        assert analysis.language_info.primary == "rust"
+        assert analysis.framework_info.primary == "axum"
+
+        crates = {crate.name: crate for crate in analysis.crates}
+        assert set(crates) == {"api", "api-macros", "xtask"}
+        assert crates["api"].path == "crates/api"
+        assert crates["api"].kind == "bin"
+        assert crates["api"].edition == "2021"
+        assert crates["api"].frameworks == ["axum", "tokio", "serde"]
+        assert crates["api-macros"].kind == "proc-macro"
+        assert crates["api-macros"].edition == "2018"
+        assert crates["xtask"].frameworks == []
+        assert analysis.cargo_workspace.workspace_dependencies == [
+            "serde",
+            "tokio",
+            "web",
+        ]
+
+    def test_claude_md_describes_workspace_layout(self, tmp_path: Path) -> None:
+        analysis = ProjectAnalyzer().analyze(_create_workspace(tmp_path))
+        environment = TemplateManager().generate_complete_environment(analysis)
+
+        assert "## Workspace Layout" in environment.claude_md
+        assert "contains 3 crates" in environment.claude_md
+        assert (
+            "- **api** (`crates/api`): bin crate; edition 2021; uses axum"
+            in environment.claude_md
+        )
+        assert "- **api-macros** (`crates/macros`): proc-macro crate" in (
+            environment.claude_md
+        )
+
+    def test_single_crate_has_no_workspace_section(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path, '[package]\nname = "solo"\nedition = "2021"\n', main=True
+        )
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+        environment = TemplateManager().generate_complete_environment(analysis)
+
+        assert [crate.name for crate in analysis.crates] == ["solo"]
+        assert "## Workspace Layout" not in environment.claude_md
+
+
+CARGO_LOCK = """
+version = 3
+
+[[package]]
+name = "api"
+version = "0.3.0"
+dependencies = [
+ "axum",
+ "api-macros",
+ "serde",
+ "tokio",
+]
+
+[[package]]
+name = "api-macros"
+version = "0.3.0"
+dependencies = [
+ "cc",
+]
+
+[[package]]
+name = "axum"
+version = "0.7.5"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "hyper 1.3.1",
+ "tokio",
+]
+
+[[package]]
+name = "cc"
+version = "1.0.98"
+source = "sparse+https://index.crates.io/"
+
+[[package]]
+name = "hyper"
+version = "0.14.28"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "hyper"
+version = "1.3.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "serde"
+version = "1.0.203"
+source = "git+https://github.com/serde-rs/serde?branch=master#abc123"
+
+[[package]]
+name = "tokio"
+version = "1.38.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "xtask"
+version = "0.3.0"
+""".strip()
+
+
+class TestCargoLockGraph:
+    def test_graph_versions_sources_and_direct_flags(self, tmp_path: Path) -> None:
+        root = _create_workspace(tmp_path)
+        (root / "Cargo.lock").write_text(CARGO_LOCK)
+
+        graph = {
+            (dep.name, dep.version): dep for dep in build_dependency_graph(root)
+        }
+
+        # Workspace members are not dependencies of themselves
+        assert not {name for name, _ in graph} & {"api", "api-macros", "xtask"}
+
+        tokio = graph[("tokio", "1.38.0")]
+        assert tokio.direct is True
+        assert tokio.source == "crates.io"
+        assert tokio.features == ["rt-multi-thread", "macros"]
+        assert tokio.package_managers == ["cargo"]
+
+        axum = graph[("axum", "0.7.5")]
+        assert axum.direct is True
+        assert axum.dependencies == {"hyper": "1.3.1", "tokio": "1.38.0"}
+
+        assert graph[("hyper", "1.3.1")].direct is False
+        assert graph[("serde", "1.0.203")].source == "git"
+        assert graph[("serde", "1.0.203")].dependency_type == "development"
+        assert graph[("cc", "1.0.98")].source == "crates.io"
+        assert graph[("cc", "1.0.98")].dependency_type == "build"
+
+    def test_missing_lock_yields_empty_graph(self, tmp_path: Path) -> None:
+        assert build_dependency_graph(_create_workspace(tmp_path)) == []
+
+    def test_lock_graph_flows_into_analysis(self, tmp_path: Path) -> None:
+        root = _create_workspace(tmp_path)
+        (root / "Cargo.lock").write_text(CARGO_LOCK)
+
+        analysis = ProjectAnalyzer().analyze(root)
+        assert list(analysis.dependency_graphs) == ["cargo"]
+        assert [dep.name for dep in analysis.direct_dependencies] == [
+            "axum",
+            "cc",
+            "serde",
+            "tokio",
+        ]
+
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+        assert "## Key Dependencies" in claude_md
+        assert "- `tokio` 1.38.0 with features `rt-multi-thread`, `macros`" in (
+            claude_md
+        )
+        assert "- `cc` 1.0.98 (build)" in claude_md
+
+    def test_lock_graph_flows_into_analysis_result(self, tmp_path: Path) -> None:
+        root = _create_workspace(tmp_path)
+        (root / "Cargo.lock").write_text(CARGO_LOCK)
+
+        result = ProjectAnalyzer().analyze(root).to_analysis_result()
+
+        pinned = {dep.name: dep for dep in result.dependencies}
+        assert set(pinned) == {"axum", "cc", "hyper", "serde", "tokio"}
+        assert pinned["tokio"].version == "1.38.0"
+        assert pinned["tokio"].package_managers == ["cargo"]
+        assert pinned["hyper"].direct is False
+        assert [d.name for d in result.filter_dependencies("build")] == ["cc"]
+        assert result.language == "rust"
@@ -0,0 +1,202 @@
+"""Tests for code metrics and the deep complexity assessment."""
+
+import json
+
+from pathlib import Path
+
+from click.testing import CliRunner
+
+from claude_builder.analysis.code_metrics import (
+    SYNTAX,
+    estimate_complexity,
+    measure_code,
+    strip_code,
+)
+from claude_builder.cli.main import cli
+from claude_builder.core.analyzer import ComplexityAssessor, ProjectAnalyzer
+from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.models import (
+    ComplexityLevel,
+    ComplexityMetrics,
+    DevelopmentEnvironment,
+    FileSystemInfo,
+    FrameworkInfo,
+    LanguageInfo,
+    LanguageMetrics,
+)
+from tests.conftest import write_file
+
+
+def _dense_compiler(root: Path) -> Path:
+    """Seven Rust files of branchy public functions sharing one AST module."""
+    write_file(root / "Cargo.toml", '[package]\nname = "tinyc"\nversion = "0.1.0"\n')
+    write_file(root / "src" / "ast.rs", "pub enum Node { Leaf }\n")
+    passes = ["lexer", "parser", "resolve", "typeck", "lower", "codegen"]
+    write_file(root / "src" / "main.rs", "".join(f"mod {name};\n" for name in passes))
+    function = (
+        "pub fn {name}_{i}(node: &Node, depth: u32) -> u32 {{\n"
+        "    if depth > 8 && depth < 64 {{\n"
+        "        return 0;\n"
+        "    }}\n"
+        "    for step in 0..depth {{\n"
+        "        if step % 2 == 0 || step % 3 == 0 {{\n"
+        "            continue;\n"
+        "        }}\n"
+        "    }}\n"
+        "    match node {{ Node::Leaf => depth }}\n"
+        "}}\n"
+    )
+    for name in passes:
+        body = "".join(function.format(name=name, i=i) for i in range(20))
+        write_file(root / "src" / f"{name}.rs", "use crate::ast::Node;\n\n" + body)
+    return root
+
+
+class TestCodeMetrics:
+    def test_comments_and_strings_are_not_code(self) -> None:
+        python = (
+            '"""Module docs: def fake(): if x"""\n'
+            "# if commented:\n"
+            "def run(a, b):\n"
+            "    if a and b:\n"
+            "        return 'if or and'\n"
+        )
+        code = strip_code(python, "python")
+
+        assert "fake" not in code
+        assert estimate_complexity(code, SYNTAX["python"]) == (1, 3)
+
+    def test_modules_are_measured_per_language(self, tmp_path: Path) -> None:
+        write_file(
+            tmp_path / "pkg" / "models.py",
+            "class User:\n    pass\n\n\ndef _helper():\n    pass\n",
+        )
+        write_file(
+            tmp_path / "src" / "lib.rs",
+            "pub fn parse() {}\npub(crate) fn internal() {}\n// pub fn old() {}\n",
+        )
+
+        metrics = measure_code(
+            tmp_path,
+            {
+                "python": [tmp_path / "pkg" / "models.py"],
+                "rust": [tmp_path / "src" / "lib.rs"],
+            },
+        )
+
+        python, rust = metrics.languages["python"], metrics.languages["rust"]
+        assert (python.modules, python.loc, python.functions) == (1, 4, 1)
+        assert python.public_api == 1
+        assert (rust.loc, rust.functions, rust.public_api) == (2, 2, 1)
+
+    def test_imports_resolve_to_project_modules(self, tmp_path: Path) -> None:
+        sources = {
+            "python": [
+                write_file(tmp_path / "app" / "__init__.py", ""),
+                write_file(tmp_path / "app" / "models.py", "class User:\n    pass\n"),
+                write_file(
+                    tmp_path / "app" / "api.py",
+                    "import os\nfrom .models import User\nfrom app import models\n",
+                ),
+                write_file(
+                    tmp_path / "tests" / "test_api.py", "from app.api import x\n"
+                ),
+            ],
+            "go": [
+                write_file(tmp_path / "internal" / "store" / "a.go", "package store\n"),
+                write_file(tmp_path / "internal" / "store" / "b.go", "package store\n"),
+                write_file(
+                    tmp_path / "cmd" / "main.go",
+                    'package main\n\nimport (\n\t"fmt"\n'
+                    '\t"example.com/shop/internal/store"\n)\n',
+                ),
+            ],
+            "typescript": [
+                write_file(tmp_path / "web" / "util.ts", "export const a = 1;\n"),
+                write_file(
+                    tmp_path / "web" / "index.ts", 'import { a } from "./util";\n'
+                ),
+            ],
+        }
+
+        metrics = measure_code(tmp_path, sources)
+
+        # api -> models, test_api -> api, cmd -> store package, index -> util
+        assert metrics.dependencies == 4
+        assert metrics.languages["go"].modules == 2
+
+    def test_generated_files_are_skipped(self, tmp_path: Path) -> None:
+        sources = [
+            write_file(tmp_path / "app.js", "function run() {}\n"),
+            write_file(tmp_path / "vendor.min.js", "function a(){}function b(){}\n"),
+            write_file(tmp_path / "api_pb.js", "// @generated\nfunction c() {}\n"),
+        ]
+
+        metrics = measure_code(tmp_path, {"javascript": sources})
+
+        assert metrics.languages["javascript"].modules == 1
+
+
+class TestDeepAssessment:
+    def test_dense_code_is_complex(self, tmp_path: Path) -> None:
+        root = _dense_compiler(tmp_path)
+
+        shallow = ProjectAnalyzer({"cache_enabled": False}).analyze(root)
+        deep = ProjectAnalyzer(
+            {"cache_enabled": False, "deep_analysis": True}
+        ).analyze(root)
+
+        assert shallow.complexity_level == ComplexityLevel.SIMPLE
+        assert shallow.complexity_metrics is None
+        assert deep.complexity_level == ComplexityLevel.COMPLEX
+        metrics = deep.complexity_metrics
+        assert metrics.most_depended_on[0].path == "src/ast.rs"
+        assert metrics.most_depended_on[0].fan_in == 6
+        assert "a module imported by 6 others (+1)" in metrics.factors
+
+    def test_many_files_with_little_code_are_simple(self) -> None:
+        filesystem = FileSystemInfo(
+            total_files=2000,
+            directory_structure={f"assets/{i}": {"subdirs": 1} for i in range(30)},
+        )
+        metrics = ComplexityMetrics(
+            languages={"javascript": LanguageMetrics("javascript", 12, 400, 40, 60)}
+        )
+        args = (filesystem, LanguageInfo(), FrameworkInfo(), DevelopmentEnvironment())
+
+        assessor = ComplexityAssessor()
+
+        assert assessor.assess(*args) == ComplexityLevel.COMPLEX
+        assert assessor.assess(*args, metrics=metrics) == ComplexityLevel.SIMPLE
+        assert metrics.factors == []
+
+    def test_metrics_shape_generated_guidance(self, tmp_path: Path) -> None:
+        root = _dense_compiler(tmp_path / "project")
+        analysis = ProjectAnalyzer(
+            {"cache_enabled": False, "deep_analysis": True}
+        ).analyze(root)
+
+        files = DocumentGenerator().generate(analysis, tmp_path).files
+
+        claude_md = files["CLAUDE.md"]
+        assert "## Code Metrics" in claude_md
+        assert "- **Most depended-on modules**" in claude_md
+        assert "  - `src/ast.rs`: imported by 6 modules" in claude_md
+        assert "put new logic in small functions" in claude_md
+        assert ".claude/ARCHITECTURE.md" in files
+
+    def test_default_command_writes_metrics_into_claude_md(
+        self, tmp_path: Path
+    ) -> None:
+        root = _dense_compiler(tmp_path)
+        write_file(
+            root / "claude-builder.json",
+            json.dumps({"analysis": {"deep_analysis": True, "cache_enabled": False}}),
+        )
+
+        result = CliRunner().invoke(cli, [str(root)])
+
+        assert result.exit_code == 0, result.output
+        claude_md = (root / "CLAUDE.md").read_text()
+        assert "## Code Metrics" in claude_md
+        assert "  - `src/ast.rs`: imported by 6 modules" in claude_md
@@ -0,0 +1,225 @@
+"""Tests for declarative custom detection rules."""
+
+import json
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from click.testing import CliRunner
+from rich.console import Console
+
+from claude_builder.analysis.detection_rules import (
+    evaluate_detection_rules,
+    parse_detection_rules,
+)
+from claude_builder.cli.analyze_commands import analyze
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.config import ConfigManager
+from claude_builder.core.config_management.config_loader import ConfigLoader
+from claude_builder.core.models import ProjectType
+from claude_builder.utils.exceptions import ConfigError
+from claude_builder.utils.project_files import walk_project
+from tests.conftest import write_file
+
+
+def _python_project(root: Path) -> Path:
+    write_file(root / "src" / "app.py", "import acme_rpc\n")
+    write_file(root / "src" / "billing" / "ledger.py", "BALANCE = 0\n")
+    write_file(root / "requirements.txt", "Flask_Login==0.6\nhvac>=1.0  # vault\n")
+    return root
+
+
+class TestRuleSchema:
+    def test_invalid_rules_name_the_problem(self) -> None:
+        invalid = {
+            "no-target": {"conditions": [{"glob": "*.py"}]},
+            "two-kinds": {
+                "target": "framework",
+                "conditions": [{"glob": "*.py", "file_exists": "x"}],
+            },
+            "bad-regex": {"target": "domain", "conditions": [{"content": "("}]},
+            "no-category": {"target": "tool", "conditions": [{"glob": "*.py"}]},
+            "bad-type": {
+                "target": "project_type",
+                "value": "spaceship",
+                "conditions": [{"glob": "*.py"}],
+            },
+            "typo": {"target": "language", "condition": []},
+        }
+
+        for name, rule in invalid.items():
+            with pytest.raises(ConfigError, match=f"'{name}'"):
+                parse_detection_rules({name: rule})
+
+    def test_rule_defaults(self) -> None:
+        (rule,) = parse_detection_rules(
+            {"acme": {"target": "framework", "conditions": [{"glob": "*.acme"}]}}
+        )
+
+        assert rule.value == "acme"
+        assert rule.threshold is None
+        assert not rule.override
+        assert rule.source == "analyzer configuration"
+
+
+class TestRuleEvaluation:
+    def test_conditions_weights_and_threshold(self, tmp_path: Path) -> None:
+        root = _python_project(tmp_path)
+        rules = parse_detection_rules(
+            {
+                "acme-rpc": {
+                    "target": "framework",
+                    "threshold": 3,
+                    "conditions": [
+                        {"content": "^import acme_rpc", "files": "*.py", "weight": 2},
+                        {"glob": "src/**/ledger.py"},
+                        {"file_exists": "acme.yaml"},
+                    ],
+                },
+                "vault": {
+                    "target": "tool",
+                    "category": "secrets_management",
+                    "conditions": [{"dependency": "HVAC"}],
+                },
+                "login": {
+                    "target": "framework",
+                    "conditions": [{"dependency": "flask-login"}],
+                },
+                "unmet": {"target": "domain", "conditions": [{"glob": "*.rs"}]},
+            }
+        )
+
+        matches = evaluate_detection_rules(rules, root, walk_project(root))
+
+        assert [match.rule for match in matches] == ["acme-rpc", "vault", "login"]
+        acme = matches[0]
+        assert acme.score == 3.0
+        assert acme.confidence == 75.0
+        assert acme.evidence == [
+            "src/app.py matches '^import acme_rpc'",
+            "src/billing/ledger.py",
+        ]
+
+    def test_rules_add_or_override_detections(self, tmp_path: Path) -> None:
+        root = _python_project(tmp_path)
+        rules = {
+            "kotlin-scripts": {
+                "target": "language",
+                "value": "kotlin",
+                "conditions": [{"glob": "*.py"}],
+            },
+            "acme-rpc": {
+                "target": "framework",
+                "override": True,
+                "conditions": [{"content": "import acme_rpc"}],
+            },
+            "vault": {
+                "target": "tool",
+                "category": "secrets_management",
+                "conditions": [{"dependency": "hvac"}],
+            },
+            "service": {
+                "target": "project_type",
+                "value": "api_service",
+                "conditions": [{"glob": "*.py"}],
+            },
+        }
+
+        analysis = ProjectAnalyzer({"custom_detection_rules": rules}).analyze(root)
+
+        assert analysis.language_info.primary == "python"
+        assert "kotlin" in analysis.language_info.secondary
+        assert analysis.framework_info.primary == "acme-rpc"
+        assert "flask" in analysis.framework_info.secondary
+        assert analysis.dev_environment.secrets_management == ["vault"]
+        assert analysis.dev_environment.tool_details["vault"].confidence == "high"
+        # Without override a detected project type is kept
+        assert analysis.project_type != ProjectType.API_SERVICE
+        assert [match.effect for match in analysis.rule_matches] == [
+            "added as secondary",
+            "set as primary",
+            "added to secrets_management",
+            f"kept detected {analysis.project_type.value} (no override)",
+        ]
+
+
+class TestRuleSources:
+    def test_global_project_and_rule_file_provenance(self, tmp_path: Path) -> None:
+        home = tmp_path / "home"
+        domain_rule = {"target": "domain", "conditions": [{"glob": "*.x"}]}
+        global_config = write_file(
+            home / "config.json",
+            json.dumps(
+                {
+                    "analysis": {
+                        "custom_detection_rules": {
+                            "shared": domain_rule,
+                            "team": domain_rule,
+                        }
+                    }
+                }
+            ),
+        )
+        project = tmp_path / "project"
+        write_file(
+            project / "claude-builder.toml",
+            '[analysis]\ncustom_rule_files = ["rules/detect.yaml"]\n\n'
+            "[analysis.custom_detection_rules.team]\n"
+            'target = "framework"\n'
+            'conditions = [{ file_exists = "team.toml" }]\n',
+        )
+        write_file(
+            project / "rules" / "detect.yaml",
+            "rules:\n  ledger:\n    target: domain\n"
+            "    value: payments\n    conditions:\n      - glob: ledger*.py\n",
+        )
+
+        with patch.object(ConfigLoader, "GLOBAL_CONFIG_NAMES", [str(global_config)]):
+            config = ConfigManager().load_config(project)
+
+        rules = config.analysis.custom_detection_rules
+        assert {name: rule["source"] for name, rule in rules.items()} == {
+            "shared": str(global_config),
+            "team": "claude-builder.toml",
+            "ledger": "rules/detect.yaml",
+        }
+        assert rules["team"]["target"] == "framework"
+
+    def test_invalid_rule_fails_config_loading(self, tmp_path: Path) -> None:
+        write_file(
+            tmp_path / "claude-builder.json",
+            json.dumps({"analysis": {"custom_detection_rules": {"x": {}}}}),
+        )
+
+        with pytest.raises(ConfigError, match="Invalid custom detection rule 'x'"):
+            ConfigManager().load_config(tmp_path)
+
+    def test_verbose_analysis_shows_rule_provenance(self, tmp_path: Path) -> None:
+        root = _python_project(tmp_path)
+        write_file(
+            root / "claude-builder.json",
+            json.dumps(
+                {
+                    "analysis": {
+                        "custom_detection_rules": {
+                            "billing": {
+                                "target": "domain",
+                                "conditions": [{"glob": "ledger.py"}],
+                            }
+                        }
+                    }
+                }
+            ),
+        )
+
+        with patch("claude_builder.cli.analyze_commands.console", Console(width=200)):
+            result = CliRunner().invoke(
+                analyze, ["project", str(root), "--no-cache", "-v"]
+            )
+
+        assert result.exit_code == 0, result.output
+        assert "Custom Detection Rules" in result.output
+        assert "claude-builder.json" in result.output
+        assert "src/billing/ledger.py" in result.output
@@ -0,0 +1,120 @@
+"""Tests for identifier-based domain detection."""
+
+from pathlib import Path
+
+from claude_builder.analysis.domain_terms import (
+    keyword_variants,
+    path_tokens,
+    source_tokens,
+    split_identifier,
+)
+from claude_builder.core.analyzer import DomainDetector, ProjectAnalyzer
+from claude_builder.core.models import ProjectAnalysis
+from tests.conftest import write_file
+
+
+def _analyze(root: Path) -> ProjectAnalysis:
+    return ProjectAnalyzer({"cache_enabled": False}).analyze(root)
+
+
+class TestIdentifierTokens:
+    def test_names_are_split_into_words(self) -> None:
+        assert split_identifier("HTTPOrderItems") == ["http", "order", "items"]
+        assert split_identifier("order_items-v2") == ["order", "items"]
+        assert path_tokens("src/payments/RefundService.ts") == [
+            "src",
+            "payments",
+            "refund",
+            "service",
+        ]
+
+    def test_declarations_imports_and_routes_across_languages(self) -> None:
+        rust = "use crate::inventory::Stock;\npub struct ShoppingCart {}\n"
+        typescript = (
+            'import { x } from "./catalog/products";\n'
+            'router.get("/api/checkout/:id", handler);\n'
+        )
+        go = 'import (\n\t"fmt"\n)\nfunc (s *Store) Refund() {}\n'
+
+        assert source_tokens(rust) == [
+            "shopping",
+            "cart",
+            "crate",
+            "inventory",
+            "stock",
+        ]
+        assert source_tokens(typescript) == [
+            "catalog",
+            "products",
+            "api",
+            "checkout",
+            "id",
+        ]
+        assert source_tokens(go) == ["refund", "fmt"]
+        # Comments and plain prose are not names
+        assert source_tokens("// every user gets a cart\nlet total = 0;\n") == []
+
+    def test_plurals_fold_onto_keywords(self) -> None:
+        variants = keyword_variants(["order", "entry"])
+
+        assert variants["orders"] == "order"
+        assert variants["entries"] == "entry"
+        assert "ordering" not in variants
+
+
+class TestDomainDetection:
+    def test_rust_and_typescript_sources_are_sampled(self, tmp_path: Path) -> None:
+        write_file(
+            tmp_path / "Cargo.toml", '[package]\nname = "shop"\nversion = "0.1.0"\n'
+        )
+        write_file(tmp_path / "src" / "main.rs", "fn main() {}\n")
+        write_file(tmp_path / "src" / "cart.rs", "pub struct Cart {}\n")
+        write_file(tmp_path / "src" / "checkout.rs", "pub fn checkout() {}\n")
+        write_file(
+            tmp_path / "web" / "inventory.ts",
+            "export interface InventoryItem {}\n",
+        )
+        write_file(tmp_path / "web" / "api.ts", "export function listProducts() {}\n")
+
+        analysis = _analyze(tmp_path)
+
+        assert analysis.language_info.primary == "rust"
+        assert analysis.domain_info.domain == "ecommerce"
+        assert set(analysis.domain_info.indicators) == {
+            "cart",
+            "checkout",
+            "inventory",
+            "product",
+        }
+        locations = " ".join(e.signal for e in analysis.domain_info.evidence)
+        assert "web/inventory.ts" in locations
+        assert "src/cart.rs" in locations
+
+    def test_generic_words_and_substrings_do_not_decide(self, tmp_path: Path) -> None:
+        write_file(
+            tmp_path / "app" / "accounts.py",
+            "from postgres_pool import connect\n\n"
+            "class UserSettings:\n    pass\n\n"
+            "def load_user(user_id):\n    pass\n",
+        )
+
+        domain_info = _analyze(tmp_path).domain_info
+
+        assert domain_info.domain is None
+        assert {e.candidate for e in domain_info.evidence} == {"social"}
+
+    def test_new_domains_are_detected(self, tmp_path: Path) -> None:
+        write_file(
+            tmp_path / "src" / "lexer.rs",
+            "pub struct Lexer {}\npub enum Opcode { Push }\n",
+        )
+        write_file(tmp_path / "src" / "codegen.rs", "pub fn emit_bytecode() {}\n")
+
+        assert _analyze(tmp_path).domain_info.domain == "compilers"
+
+    def test_shared_and_generic_keywords_weigh_less(self) -> None:
+        weights = DomainDetector()._keyword_weights()
+
+        # payment is listed by ecommerce and fintech, cart by ecommerce only
+        assert weights["payment"] < weights["cart"]
+        assert weights["user"] < weights["payment"]
@@ -0,0 +1,155 @@
+"""Tests for the evidence trail behind detection decisions."""
+
+import json
+
+from pathlib import Path
+from unittest.mock import patch
+
+from click.testing import CliRunner
+from rich.console import Console
+
+from claude_builder.analysis.evidence import EvidenceScores, add_score
+from claude_builder.cli.analyze_commands import analyze
+from claude_builder.core.analyzer import FrameworkDetector, ProjectAnalyzer
+from claude_builder.core.models import DetectionEvidence, LanguageInfo, ProjectType
+from tests.conftest import write_file
+
+
+def _rust_service(root: Path) -> Path:
+    write_file(
+        root / "Cargo.toml",
+        '[package]\nname = "svc"\nversion = "0.1.0"\n\n'
+        '[dependencies]\naxum = "0.7"\n'
+        'tokio = { version = "1", features = ["full"] }\n',
+    )
+    write_file(root / "src" / "main.rs", "fn main() {}\n")
+    write_file(root / "src" / "routes.rs", "pub fn router() {}\n")
+    write_file(root / "scripts" / "seed.py", "class CheckoutSession:\n    pass\n")
+    return root
+
+
+class TestEvidenceScores:
+    def test_unrecorded_points_are_attributed(self) -> None:
+        scores = EvidenceScores()
+        add_score(scores, "axum", 10, "Cargo.toml dependency `axum`")
+        # Third-party plugins may only update the mapping
+        scores["axum"] += 2
+        scores["ntex"] += 5
+        scores.attribute_rest("acme plugin manifest scoring")
+
+        assert scores == {"axum": 12, "ntex": 5}
+        assert scores.evidence == [
+            DetectionEvidence("axum", "Cargo.toml dependency `axum`", 10),
+            DetectionEvidence("axum", "acme plugin manifest scoring", 2),
+            DetectionEvidence("ntex", "acme plugin manifest scoring", 5),
+        ]
+
+    def test_add_score_accepts_plain_mappings(self) -> None:
+        scores = {"flask": 1.0}
+        add_score(scores, "flask", 8, "requirements.txt mentions `flask`")
+
+        assert scores == {"flask": 9.0}
+
+
+class TestDetectorEvidence:
+    def test_evidence_adds_up_to_framework_scores(self, tmp_path: Path) -> None:
+        root = _rust_service(tmp_path)
+
+        info = FrameworkDetector().detect(
+            root, ProjectAnalyzer()._analyze_filesystem(root), LanguageInfo("rust")
+        )
+
+        signals = {(e.candidate, e.signal) for e in info.evidence}
+        assert ("axum", "Cargo.toml dependency `axum`") in signals
+        assert ("tokio", "Cargo.toml dependency `tokio`") in signals
+        primary_total = sum(e.weight for e in info.evidence if e.candidate == "axum")
+        assert info.confidence == min(primary_total * 10, 100.0)
+
+    def test_analysis_records_every_decision(self, tmp_path: Path) -> None:
+        root = _rust_service(tmp_path)
+
+        analysis = ProjectAnalyzer({"cache_enabled": False}).analyze(root)
+
+        language = [e.signal for e in analysis.language_info.evidence]
+        assert "2 .rs files" in language
+        assert "1 .py files" in language
+        assert "Cargo.toml in project root (+10% confidence)" in language
+        domain = {(e.candidate, e.signal) for e in analysis.domain_info.evidence}
+        assert ("ecommerce", "`checkout` x1 in scripts/seed.py") in domain
+        assert analysis.project_type == ProjectType.API_SERVICE
+        assert analysis.project_type_evidence == [
+            DetectionEvidence("api_service", "binary using web framework `axum`")
+        ]
+
+    def test_misleading_directory_name_is_visible(self, tmp_path: Path) -> None:
+        write_file(tmp_path / "api" / "notes.py", "NOTES = []\n")
+        write_file(tmp_path / "main.py", "print('hello')\n")
+
+        analysis = ProjectAnalyzer({"cache_enabled": False}).analyze(tmp_path)
+
+        assert analysis.project_type == ProjectType.API_SERVICE
+        assert [e.signal for e in analysis.project_type_evidence] == [
+            "`api` in the directory structure"
+        ]
+
+    def test_rules_and_overrides_are_recorded(self, tmp_path: Path) -> None:
+        root = _rust_service(tmp_path)
+        config = {
+            "cache_enabled": False,
+            "overrides": {"language": "python"},
+            "custom_detection_rules": {
+                "shop": {
+                    "target": "domain",
+                    "value": "logistics",
+                    "override": True,
+                    "conditions": [{"glob": "routes.rs", "weight": 2}],
+                }
+            },
+        }
+
+        analysis = ProjectAnalyzer(config).analyze(root)
+
+        assert analysis.language_info.evidence[-1] == DetectionEvidence(
+            "python", "overrides.language in configuration"
+        )
+        assert analysis.domain_info.evidence[-1] == DetectionEvidence(
+            "logistics",
+            "custom rule `shop` in analyzer configuration (set as domain)",
+            2.0,
+        )
+
+
+class TestExplainOutput:
+    def test_explain_lists_signals_and_alternatives(self, tmp_path: Path) -> None:
+        root = _rust_service(tmp_path)
+
+        with patch("claude_builder.cli.analyze_commands.console", Console(width=200)):
+            result = CliRunner().invoke(
+                analyze, ["project", str(root), "--no-cache", "--explain"]
+            )
+
+        assert result.exit_code == 0, result.output
+        assert "Why Framework: axum" in result.output
+        assert "Cargo.toml dependency `axum`" in result.output
+        assert "tokio (not chosen), total 8" in result.output
+        assert "Why Project Type: api_service" in result.output
+
+    def test_json_output_includes_evidence(self, tmp_path: Path) -> None:
+        root = _rust_service(tmp_path / "project")
+        output = tmp_path / "analysis.json"
+
+        result = CliRunner().invoke(
+            analyze,
+            ["project", str(root), "--no-cache", "--format", "json", "-o", str(output)],
+        )
+
+        assert result.exit_code == 0, result.output
+        data = json.loads(output.read_text())
+        assert {
+            "candidate": "axum",
+            "signal": "Cargo.toml dependency `axum`",
+            "weight": 10.0,
+        } in data["framework_info"]["evidence"]
+        assert data["project_type_evidence"][0]["candidate"] == "api_service"
+        assert "evidence" in data["language_info"]
+        assert "evidence" in data["domain_info"]
@@ -0,0 +1,177 @@
+"""Tests for go.mod / go.work parsing and Go framework detection."""
+
+from pathlib import Path
+
+from claude_builder.analysis.go_frameworks import score_go_frameworks
+from claude_builder.analysis.gomod import (
+    GoRequirement,
+    build_go_dependency_graph,
+    load_go_workspace,
+    parse_go_mod,
+)
+from claude_builder.core.analyzer import FrameworkDetector, ProjectAnalyzer
+from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.models import ProjectType
+from claude_builder.core.template_manager import TemplateManager
+
+
+GO_MOD = """// Service module
+module github.com/acme/api
+
+go 1.22
+
+toolchain go1.22.3
+
+require github.com/labstack/echo/v4 v4.11.4
+
+require (
+\tgithub.com/spf13/cobra v1.8.0
+\tgolang.org/x/net v0.22.0 // indirect
+)
+
+replace github.com/acme/shared => ../shared
+replace (
+\tgolang.org/x/net v0.22.0 => golang.org/x/net v0.23.0
+)
+"""
+
+
+def _write_module(path: Path, go_mod: str, main: str = "package main\n") -> Path:
+    path.mkdir(parents=True, exist_ok=True)
+    (path / "go.mod").write_text(go_mod)
+    (path / "main.go").write_text(main)
+    return path
+
+
+class TestParseGoMod:
+    def test_directives_blocks_and_replacements(self, tmp_path: Path) -> None:
+        module = parse_go_mod(GO_MOD, tmp_path)
+
+        assert module.module == "github.com/acme/api"
+        assert module.go_version == "1.22"
+        assert module.toolchain == "go1.22.3"
+        assert [(r.path, r.version, r.indirect) for r in module.requires] == [
+            ("github.com/labstack/echo/v4", "v4.11.4", False),
+            ("github.com/spf13/cobra", "v1.8.0", False),
+            ("golang.org/x/net", "v0.22.0", True),
+        ]
+        assert [r.describe() for r in module.replaces] == [
+            "github.com/acme/shared => ../shared",
+            "golang.org/x/net v0.22.0 => golang.org/x/net v0.23.0",
+        ]
+        assert module.replaces[0].is_local
+        assert not module.replaces[1].is_local
+
+    def test_go_work_loads_member_modules(self, tmp_path: Path) -> None:
+        (tmp_path / "go.work").write_text(
+            "go 1.22\n\nuse (\n\t./api\n\t./shared\n)\n\n"
+            "replace example.com/old => example.com/new v1.0.0\n"
+        )
+        _write_module(tmp_path / "api", GO_MOD)
+        _write_module(
+            tmp_path / "shared",
+            "module github.com/acme/shared\n\ngo 1.21\n",
+            main="package shared\n",
+        )
+
+        workspace = load_go_workspace(tmp_path)
+        assert workspace is not None
+        assert workspace.is_workspace
+        assert [m.module for m in workspace.modules] == [
+            "github.com/acme/api",
+            "github.com/acme/shared",
+        ]
+        assert workspace.relative_path(workspace.modules[1]) == "shared"
+        assert workspace.replaces[0].new_version == "v1.0.0"
+
+    def test_dependency_graph(self, tmp_path: Path) -> None:
+        workspace = load_go_workspace(_write_module(tmp_path, GO_MOD))
+        assert workspace is not None
+
+        graph = {dep.name: dep for dep in build_go_dependency_graph(workspace)}
+        assert graph["github.com/spf13/cobra"].direct
+        assert graph["github.com/spf13/cobra"].source == "proxy"
+        assert not graph["golang.org/x/net"].direct
+        assert graph["golang.org/x/net"].source == "replace"
+
+
+class TestGoFrameworks:
+    def test_major_version_suffix_and_exact_paths(self) -> None:
+        scores = score_go_frameworks(
+            [
+                GoRequirement("github.com/labstack/echo/v4", "v4.11.4"),
+                GoRequirement("github.com/labstack/echo-contrib", "v0.15.0"),
+                GoRequirement("github.com/gin-gonic/gin", "v1.9.1", indirect=True),
+            ]
+        )
+        assert scores["echo"] == 10.0
+        assert scores["gin"] < 10.0
+        assert set(scores) == {"echo", "gin"}
+
+    def test_detect_framework_from_go_mod(self, tmp_path: Path) -> None:
+        _write_module(tmp_path, GO_MOD)
+        result = FrameworkDetector().detect_framework(tmp_path, "go")
+
+        assert result.primary == "echo"
+        assert "cobra" in result.secondary
+        assert result.details["web_framework"] is True
+
+
+class TestGoProjectAnalysis:
+    def test_analysis_versions_and_generated_docs(self, tmp_path: Path) -> None:
+        _write_module(tmp_path, GO_MOD)
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        assert analysis.language_info.primary == "go"
+        assert analysis.language_info.version_info["go"] == "1.22.3"
+        assert analysis.language_info.version_info["go_directive"] == "1.22"
+        assert analysis.project_type == ProjectType.API_SERVICE
+        assert "go" in analysis.dev_environment.package_managers
+        assert analysis.go_workspace is not None
+        assert analysis.go_workspace.modules[0].indirect_requires == 1
+        assert [dep.name for dep in analysis.direct_dependencies] == [
+            "github.com/labstack/echo/v4",
+            "github.com/spf13/cobra",
+        ]
+
+        files = DocumentGenerator().generate(analysis, tmp_path).files
+        assert "Go Development Instructions" in files["CLAUDE.md"]
+        assert "**Module**: `github.com/acme/api`" in files["CLAUDE.md"]
+        assert "**Language**: Go 1.22.3" in files["CLAUDE.md"]
+        assert "github.com/acme/shared => ../shared" in files["CLAUDE.md"]
+
+    def test_go_work_layout_in_claude_md(self, tmp_path: Path) -> None:
+        (tmp_path / "go.work").write_text("go 1.22\n\nuse ./api\n")
+        _write_module(tmp_path / "api", GO_MOD)
+
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+
+        assert "This Go workspace (`go.work`) contains 1 modules:" in claude_md
+        assert "- **github.com/acme/api** (`api`): go 1.22" in claude_md
+        assert "Versions selected in `go.mod`." in claude_md
+
+    def test_rust_and_go_graphs_are_kept_apart(self, tmp_path: Path) -> None:
+        _write_module(tmp_path, GO_MOD)
+        (tmp_path / "Cargo.toml").write_text(
+            '[package]\nname = "bridge"\nversion = "0.1.0"\n\n'
+            '[dependencies]\nserde = "1"\n'
+        )
+        (tmp_path / "Cargo.lock").write_text(
+            'version = 3\n\n[[package]]\nname = "bridge"\nversion = "0.1.0"\n'
+            'dependencies = ["serde"]\n\n[[package]]\nname = "serde"\n'
+            'version = "1.0.203"\n'
+            'source = "registry+https://github.com/rust-lang/crates.io-index"\n'
+        )
+
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+
+        assert [dep.name for dep in analysis.dependency_graphs["cargo"]] == ["serde"]
+        assert "github.com/spf13/cobra" in [
+            dep.name for dep in analysis.dependency_graphs["go"]
+        ]
+        assert "Resolved versions from the lockfile." in claude_md
+        assert "- `serde` 1.0.203" in claude_md
+        assert "Versions selected in `go.mod`." in claude_md
+        assert "- `github.com/spf13/cobra` v1.8.0" in claude_md
@@ -0,0 +1,283 @@
+"""Tests for Maven/Gradle parsing and JVM framework detection."""
+
+from pathlib import Path
+
+from claude_builder.analysis.jvm import (
+    JvmDependency,
+    load_jvm_build,
+    parse_gradle_build,
+)
+from claude_builder.analysis.jvm_frameworks import detect_junit
+from claude_builder.core.analyzer import FrameworkDetector, ProjectAnalyzer
+from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.models import ProjectType
+from claude_builder.core.template_manager import TemplateManager
+
+
+PARENT_POM = """<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <parent>
+    <groupId>org.springframework.boot</groupId>
+    <artifactId>spring-boot-starter-parent</artifactId>
+    <version>3.2.1</version>
+  </parent>
+  <groupId>com.acme</groupId>
+  <artifactId>shop</artifactId>
+  <version>1.4.0</version>
+  <packaging>pom</packaging>
+  <properties>
+    <java.version>17</java.version>
+    <junit.version>4.13.2</junit.version>
+  </properties>
+  <modules>
+    <module>core</module>
+    <module>api</module>
+  </modules>
+</project>
+"""
+
+API_POM = """<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <artifactId>shop-api</artifactId>
+  <dependencies>
+    <dependency>
+      <groupId>org.springframework.boot</groupId>
+      <artifactId>spring-boot-starter-web</artifactId>
+    </dependency>
+    <dependency>
+      <groupId>com.acme</groupId>
+      <artifactId>shop-core</artifactId>
+      <version>${project.version}</version>
+    </dependency>
+    <dependency>
+      <groupId>junit</groupId>
+      <artifactId>junit</artifactId>
+      <version>${junit.version}</version>
+      <scope>test</scope>
+    </dependency>
+  </dependencies>
+</project>
+"""
+
+KTOR_BUILD = """plugins {
+    kotlin("jvm") version "1.9.22"
+    alias(libs.plugins.ktor)
+}
+
+kotlin {
+    jvmToolchain(21)
+}
+
+dependencies {
+    implementation(libs.ktor.server.core)
+    implementation("io.ktor:ktor-server-netty:2.3.7")
+    // implementation("io.micronaut:micronaut-core:4.0.0")
+    testImplementation("org.junit.jupiter:junit-jupiter:5.10.1")
+}
+"""
+
+VERSION_CATALOG = """[versions]
+ktor = "2.3.7"
+
+[libraries]
+ktor-server-core = { module = "io.ktor:ktor-server-core", version.ref = "ktor" }
+
+[plugins]
+ktor = { id = "io.ktor.plugin", version.ref = "ktor" }
+"""
+
+
+def _write_maven_reactor(root: Path) -> Path:
+    (root / "pom.xml").write_text(PARENT_POM)
+    for module, pom in (
+        ("core", '<project><artifactId>shop-core</artifactId></project>'),
+        ("api", API_POM),
+    ):
+        source = root / module / "src" / "main" / "java"
+        source.mkdir(parents=True)
+        (root / module / "pom.xml").write_text(pom)
+        (source / "App.java").write_text("class App {}\n")
+    return root
+
+
+def _write_ktor_build(root: Path) -> Path:
+    (root / "settings.gradle.kts").write_text(
+        'rootProject.name = "greeter"\ninclude(":app", ":lib:util")\n'
+    )
+    (root / "gradle").mkdir()
+    (root / "gradle" / "libs.versions.toml").write_text(VERSION_CATALOG)
+    source = root / "app" / "src" / "main" / "kotlin"
+    source.mkdir(parents=True)
+    (root / "app" / "build.gradle.kts").write_text(KTOR_BUILD)
+    (source / "Application.kt").write_text("fun main() {}\n")
+    (root / "lib" / "util").mkdir(parents=True)
+    (root / "lib" / "util" / "build.gradle").write_text(
+        "apply plugin: 'java-library'\n"
+        "dependencies {\n"
+        "    testImplementation group: 'junit', name: 'junit', version: '4.13.2'\n"
+        "}\n"
+    )
+    return root
+
+
+class TestMavenParsing:
+    def test_reactor_modules_properties_and_parent(self, tmp_path: Path) -> None:
+        build = load_jvm_build(_write_maven_reactor(tmp_path))
+
+        assert build is not None
+        assert build.build_tool == "maven"
+        assert [build.relative_path(m) for m in build.modules] == [".", "core", "api"]
+        assert build.modules[0].parent is not None
+        assert build.modules[0].parent.version == "3.2.1"
+        assert build.java_release == "17"
+
+        api = {dep.coordinate: dep for dep in build.modules[2].dependencies}
+        # Placeholders resolve through properties inherited from the parent POM
+        assert api["com.acme:shop-core"].version == "1.4.0"
+        assert api["junit:junit"].version == "4.13.2"
+        assert api["junit:junit"].is_test
+
+    def test_entity_expansion_is_rejected(self, tmp_path: Path) -> None:
+        (tmp_path / "pom.xml").write_text(
+            '<?xml version="1.0"?>\n'
+            '<!DOCTYPE project [<!ENTITY boom "boom">]>\n'
+            "<project><artifactId>&boom;</artifactId></project>\n"
+        )
+        assert load_jvm_build(tmp_path) is None
+
+
+class TestGradleParsing:
+    def test_kotlin_dsl_plugins_catalog_and_toolchain(self, tmp_path: Path) -> None:
+        build = load_jvm_build(_write_ktor_build(tmp_path))
+
+        assert build is not None
+        assert (build.build_tool, build.dsl) == ("gradle", "kotlin")
+        assert [build.relative_path(m) for m in build.modules] == ["app", "lib/util"]
+
+        app = build.modules[0]
+        assert app.language == "kotlin"
+        assert app.java_release == "21"
+        assert app.plugin_version("org.jetbrains.kotlin.jvm") == "1.9.22"
+        assert app.plugin_version("io.ktor.plugin") == "2.3.7"
+        # Commented-out declarations are ignored
+        assert [(d.coordinate, d.version) for d in app.dependencies] == [
+            ("io.ktor:ktor-server-netty", "2.3.7"),
+            ("org.junit.jupiter:junit-jupiter", "5.10.1"),
+            ("io.ktor:ktor-server-core", "2.3.7"),
+        ]
+
+    def test_groovy_map_notation_and_platform(self, tmp_path: Path) -> None:
+        module = parse_gradle_build(
+            "plugins { id 'io.quarkus' }\n"
+            "dependencies {\n"
+            "    implementation enforcedPlatform("
+            "'io.quarkus.platform:quarkus-bom:3.6.4')\n"
+            "    implementation 'io.quarkus:quarkus-rest'\n"
+            "    testImplementation group: 'junit', name: 'junit', version: '4.13.2'\n"
+            "}\n",
+            tmp_path,
+        )
+
+        assert [p.id for p in module.plugins] == ["io.quarkus"]
+        assert [(d.coordinate, d.version, d.scope) for d in module.dependencies] == [
+            ("io.quarkus.platform:quarkus-bom", "3.6.4", "implementation"),
+            ("io.quarkus:quarkus-rest", None, "implementation"),
+            ("junit:junit", "4.13.2", "testImplementation"),
+        ]
+
+    def test_urls_in_strings_are_not_comments(self, tmp_path: Path) -> None:
+        module = parse_gradle_build(
+            "repositories {\n"
+            '    maven { url "https://repo.example.com/releases" }; '
+            "dependencies { implementation 'com.example:client:1.2.0' }\n"
+            "}\n"
+            "dependencies {\n"
+            "    api 'com.example:core:2.0.0' // https://example.com/core\n"
+            "    // implementation 'com.example:legacy:0.9'\n"
+            "}\n",
+            tmp_path,
+        )
+
+        assert [(d.coordinate, d.version) for d in module.dependencies] == [
+            ("com.example:client", "1.2.0"),
+            ("com.example:core", "2.0.0"),
+        ]
+
+
+class TestJvmFrameworks:
+    def test_junit_generations(self) -> None:
+        assert detect_junit([JvmDependency("junit", "junit", scope="test")]) == [
+            "junit4"
+        ]
+        assert detect_junit(
+            [JvmDependency("io.quarkus", "quarkus-junit5", scope="test")]
+        ) == ["junit5"]
+        assert detect_junit(
+            [
+                JvmDependency("org.junit.jupiter", "junit-jupiter"),
+                JvmDependency("org.junit.vintage", "junit-vintage-engine"),
+            ]
+        ) == ["junit5", "junit4"]
+
+    def test_spring_boot_reactor(self, tmp_path: Path) -> None:
+        result = FrameworkDetector().detect_framework(
+            _write_maven_reactor(tmp_path), "java"
+        )
+
+        assert result.primary == "springboot"
+        assert result.version == "3.2.1"
+        # Spring Boot groups do not also count as plain Spring
+        assert "spring" not in result.secondary
+        assert result.details["build_tool"] == "maven"
+        assert result.details["test_frameworks"] == ["junit4"]
+        assert result.details["web_framework"] is True
+
+    def test_micronaut_test_dependency_does_not_outweigh_runtime(
+        self, tmp_path: Path
+    ) -> None:
+        (tmp_path / "build.gradle").write_text(
+            "dependencies {\n"
+            "    implementation 'io.ktor:ktor-server-core:2.3.7'\n"
+            "    testImplementation 'io.micronaut.test:micronaut-test-junit5:4.1.0'\n"
+            "}\n"
+        )
+        result = FrameworkDetector().detect_framework(tmp_path, "kotlin")
+
+        assert result.primary == "ktor"
+        assert result.details["test_frameworks"] == ["junit5"]
+
+
+class TestJvmProjectAnalysis:
+    def test_kotlin_analysis_and_generated_docs(self, tmp_path: Path) -> None:
+        _write_ktor_build(tmp_path)
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        assert analysis.language_info.primary == "kotlin"
+        assert analysis.language_info.version_info["kotlin"] == "1.9.22"
+        assert analysis.language_info.version_info["java"] == "21"
+        assert analysis.framework_info.primary == "ktor"
+        assert analysis.project_type == ProjectType.API_SERVICE
+        assert "gradle" in analysis.dev_environment.package_managers
+        assert {"junit5", "junit4"} <= set(analysis.dev_environment.testing_frameworks)
+
+        files = DocumentGenerator().generate(analysis, tmp_path).files
+        assert "Kotlin Development Instructions" in files["CLAUDE.md"]
+        assert "**Language**: Kotlin 1.9.22 (JVM target 21)" in files["CLAUDE.md"]
+        assert "- **Build tool**: Gradle (Kotlin DSL)" in files["CLAUDE.md"]
+        assert "- **Modules** (2): `app`, `lib/util`" in files["CLAUDE.md"]
+
+    def test_maven_reactor_layout_in_claude_md(self, tmp_path: Path) -> None:
+        _write_maven_reactor(tmp_path)
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        assert analysis.language_info.primary == "java"
+        assert analysis.framework_info.primary == "springboot"
+        assert "maven" in analysis.dev_environment.package_managers
+
+        files = DocumentGenerator().generate(analysis, tmp_path).files
+        assert "Java Development Instructions" in files["CLAUDE.md"]
+        assert "**Language**: Java 17" in files["CLAUDE.md"]
+
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+        assert "This Maven reactor contains 2 modules:" in claude_md
+        assert "- `api`" in claude_md
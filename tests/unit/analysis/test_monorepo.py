@@ -0,0 +1,222 @@
+"""Tests for monorepo detection and per-package analysis/generation."""
+
+import json
+
+from pathlib import Path
+
+from click.testing import CliRunner
+
+from claude_builder.analysis.monorepo import detect_monorepo, package_commands
+from claude_builder.cli.main import cli
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.managed_regions import write_managed_files
+from claude_builder.core.models import ProjectType
+
+
+def _write_json(path: Path, data: dict) -> None:
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(json.dumps(data))
+
+
+def _write_pnpm_repo(root: Path) -> Path:
+    (root / "pnpm-workspace.yaml").write_text(
+        "packages:\n  - 'apps/*'\n  - 'packages/*'\n  - '!packages/legacy'\n"
+    )
+    _write_json(root / "package.json", {"name": "acme", "private": True})
+    _write_json(root / "turbo.json", {"pipeline": {}})
+    _write_json(
+        root / "apps" / "web" / "package.json",
+        {
+            "name": "@acme/web",
+            "scripts": {"build": "vite build", "test": "vitest", "start": "node ."},
+            "dependencies": {"react": "^18.2.0"},
+        },
+    )
+    (root / "apps" / "web" / "index.js").write_text("export const App = 1;\n")
+    _write_json(
+        root / "packages" / "ui" / "package.json",
+        {"name": "@acme/ui", "scripts": {"lint": "eslint ."}},
+    )
+    (root / "packages" / "ui" / "index.js").write_text("module.exports = {};\n")
+    _write_json(root / "packages" / "legacy" / "package.json", {"name": "legacy"})
+    # Directories without a package.json are not packages
+    (root / "apps" / "docs").mkdir()
+    return root
+
+
+def _write_crate(directory: Path, name: str, source: str) -> None:
+    (directory / "src").mkdir(parents=True)
+    (directory / "Cargo.toml").write_text(
+        f'[package]\nname = "{name}"\nversion = "0.1.0"\nedition = "2021"\n'
+    )
+    (directory / "src" / source).write_text("pub fn run() {}\n")
+
+
+class TestDetectMonorepo:
+    def test_pnpm_globs_negation_and_turborepo(self, tmp_path: Path) -> None:
+        layout = detect_monorepo(_write_pnpm_repo(tmp_path))
+
+        assert layout is not None
+        assert layout.tools == ["pnpm", "turborepo"]
+        assert [layout.relative_path(p) for p in layout.packages] == [
+            "apps/web",
+            "packages/ui",
+        ]
+        web = layout.packages[0]
+        assert web.name == "@acme/web"
+        assert web.tools == ["pnpm", "turborepo"]
+        assert package_commands(layout, web) == [
+            "pnpm --filter @acme/web build",
+            "pnpm --filter @acme/web test",
+            "npx turbo run build test --filter=@acme/web",
+        ]
+
+    def test_yarn_workspaces_and_nx_projects(self, tmp_path: Path) -> None:
+        _write_json(
+            tmp_path / "package.json",
+            {"name": "root", "workspaces": {"packages": ["libs/*"]}},
+        )
+        (tmp_path / "yarn.lock").write_text("")
+        _write_json(tmp_path / "nx.json", {})
+        _write_json(tmp_path / "libs" / "core" / "package.json", {"name": "core"})
+        # Nx-only project (no package.json)
+        _write_json(tmp_path / "apps" / "admin" / "project.json", {"name": "admin"})
+
+        layout = detect_monorepo(tmp_path)
+
+        assert layout is not None
+        assert layout.tools == ["yarn", "nx"]
+        packages = {layout.relative_path(p): p.tools for p in layout.packages}
+        assert packages == {"apps/admin": ["nx"], "libs/core": ["yarn", "nx"]}
+
+    def test_uv_poetry_bazel_and_go(self, tmp_path: Path) -> None:
+        (tmp_path / "pyproject.toml").write_text(
+            "[tool.uv.workspace]\n"
+            'members = ["py/*"]\n'
+            'exclude = ["py/scratch"]\n'
+            "\n"
+            "[tool.poetry.dependencies]\n"
+            'tooling = { path = "tools/tooling", develop = true }\n'
+        )
+        for name in ("api", "scratch"):
+            (tmp_path / "py" / name).mkdir(parents=True)
+            (tmp_path / "py" / name / "pyproject.toml").write_text(
+                f'[project]\nname = "acme-{name}"\n'
+            )
+        (tmp_path / "tools" / "tooling").mkdir(parents=True)
+        (tmp_path / "MODULE.bazel").write_text("")
+        (tmp_path / "proto" / "v1").mkdir(parents=True)
+        (tmp_path / "proto" / "BUILD.bazel").write_text("")
+        (tmp_path / "proto" / "v1" / "BUILD").write_text("")  # Nested package
+        (tmp_path / "go.work").write_text("go 1.22\n\nuse ./svc\n")
+        (tmp_path / "svc").mkdir()
+        (tmp_path / "svc" / "go.mod").write_text("module example.com/svc\n")
+
+        layout = detect_monorepo(tmp_path)
+
+        assert layout is not None
+        assert layout.tools == ["uv", "poetry", "bazel", "go"]
+        names = {layout.relative_path(p): p.name for p in layout.packages}
+        assert names == {
+            "proto": "proto",
+            "py/api": "acme-api",
+            "svc": "example.com/svc",
+            "tools/tooling": "tooling",
+        }
+
+    def test_single_package_is_not_a_monorepo(self, tmp_path: Path) -> None:
+        (tmp_path / "pnpm-workspace.yaml").write_text("packages:\n  - 'apps/*'\n")
+        _write_json(tmp_path / "apps" / "web" / "package.json", {"name": "web"})
+
+        assert detect_monorepo(tmp_path) is None
+
+
+class TestMonorepoAnalysis:
+    def test_per_package_analyses_and_nested_claude_md(self, tmp_path: Path) -> None:
+        _write_pnpm_repo(tmp_path)
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        assert analysis.project_type == ProjectType.MONOREPO
+        assert analysis.monorepo is not None
+        web = analysis.monorepo.packages[0]
+        assert web.analysis is not None
+        assert web.analysis.language_info.primary == "javascript"
+        assert web.analysis.framework_info.primary == "react"
+
+        files = DocumentGenerator().generate(analysis, tmp_path).files
+        assert "- **@acme/web** (`apps/web`): javascript, react" in files["CLAUDE.md"]
+        package_md = files["apps/web/CLAUDE.md"]
+        assert "# @acme/web - Package Instructions" in package_md
+        assert "**Framework**: react" in package_md
+        assert "pnpm --filter @acme/web test" in package_md
+        # Only this package's commands
+        assert "@acme/ui" not in package_md
+        assert "packages/ui/CLAUDE.md" in files
+
+    def test_cargo_workspace_crates_get_their_own_claude_md(
+        self, tmp_path: Path
+    ) -> None:
+        (tmp_path / "Cargo.toml").write_text(
+            '[workspace]\nmembers = ["crates/*"]\nresolver = "2"\n'
+        )
+        _write_crate(tmp_path / "crates" / "cli", "acme-cli", "main.rs")
+        _write_crate(tmp_path / "crates" / "core", "acme-core", "lib.rs")
+
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+        files = DocumentGenerator().generate(analysis, tmp_path).files
+
+        assert analysis.project_type == ProjectType.MONOREPO
+        assert "cargo test -p acme-core" in files["crates/core/CLAUDE.md"]
+        assert "**Language**: rust" in files["crates/cli/CLAUDE.md"]
+
+    def test_generated_package_docs_keep_the_layout_on_reanalysis(
+        self, tmp_path: Path
+    ) -> None:
+        (tmp_path / "Cargo.toml").write_text('[workspace]\nmembers = ["crates/*"]\n')
+        for name, source in (("cli", "main.rs"), ("core", "lib.rs"), ("io", "lib.rs")):
+            _write_crate(tmp_path / "crates" / name, f"acme-{name}", source)
+        analyzer = ProjectAnalyzer({"cache_enabled": True})
+
+        before = analyzer.analyze(tmp_path)
+        files = DocumentGenerator().generate(before, tmp_path).files
+        write_managed_files(tmp_path, files.items())
+        after = analyzer.analyze(tmp_path)
+
+        assert "crates/io/CLAUDE.md" in files
+        assert after.from_cache
+        assert after.filesystem_info.total_directories == (
+            before.filesystem_info.total_directories
+        )
+        assert "crates" in after.filesystem_info.directory_structure
+        assert after.filesystem_info.directory_structure == (
+            before.filesystem_info.directory_structure
+        )
+
+    def test_every_cli_path_writes_and_checks_package_claude_md(
+        self, tmp_path: Path
+    ) -> None:
+        _write_pnpm_repo(tmp_path)
+        runner = CliRunner()
+
+        generated = runner.invoke(cli, [str(tmp_path)])
+        current = runner.invoke(cli, ["check", str(tmp_path)])
+        package_md = (tmp_path / "apps" / "web" / "CLAUDE.md").read_text()
+        _write_json(
+            tmp_path / "packages" / "ui" / "package.json",
+            {"name": "@acme/ui", "scripts": {"lint": "eslint .", "test": "jest"}},
+        )
+        stale = runner.invoke(cli, ["check", str(tmp_path)])
+
+        assert generated.exit_code == 0, generated.output
+        assert "pnpm --filter @acme/web test" in package_md
+        assert (tmp_path / "packages" / "ui" / "CLAUDE.md").exists()
+        assert current.exit_code == 0, current.output
+        assert stale.exit_code == 1
+        assert "packages/ui/CLAUDE.md: stale" in stale.output
+
+    def test_package_analysis_can_be_disabled(self, tmp_path: Path) -> None:
+        _write_pnpm_repo(tmp_path)
+        analysis = ProjectAnalyzer({"analyze_packages": False}).analyze(tmp_path)
+
+        assert analysis.monorepo is None
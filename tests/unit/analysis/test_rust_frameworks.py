@@ -0,0 +1,115 @@
+"""Tests for the Rust framework catalogue and exact crate-name matching."""
+
+from pathlib import Path
+
+from claude_builder.analysis.cargo import CargoDependency
+from claude_builder.analysis.rust_frameworks import (
+    RUST_FRAMEWORKS,
+    score_rust_frameworks,
+)
+from claude_builder.core.analyzer import FrameworkDetector, ProjectAnalyzer
+from claude_builder.core.models import ProjectType
+
+
+def _dep(name: str, *features: str, optional: bool = False) -> CargoDependency:
+    return CargoDependency(
+        name=name, alias=name, features=list(features), optional=optional
+    )
+
+
+def _write_package(path: Path, dependencies: str) -> Path:
+    (path / "src").mkdir(parents=True, exist_ok=True)
+    (path / "src" / "lib.rs").write_text("")
+    (path / "Cargo.toml").write_text(
+        f'[package]\nname = "demo"\nedition = "2021"\n\n[dependencies]\n{dependencies}'
+    )
+    return path
+
+
+class TestScoreRustFrameworks:
+    def test_exact_crate_names_only(self) -> None:
+        scores = score_rust_frameworks(
+            [_dep("warpgrid"), _dep("axum-extra"), _dep("rocket_sync")]
+        )
+        assert scores == {}
+
+    def test_features_add_weight(self) -> None:
+        bare = score_rust_frameworks([_dep("tokio")])["tokio"]
+        full = score_rust_frameworks([_dep("tokio", "full", "macros")])["tokio"]
+        assert full > bare
+
+    def test_optional_dependencies_count_for_less(self) -> None:
+        required = score_rust_frameworks([_dep("sqlx")])["sqlx"]
+        optional = score_rust_frameworks([_dep("sqlx", optional=True)])["sqlx"]
+        assert optional < required
+
+    def test_companion_crates_accumulate(self) -> None:
+        scores = score_rust_frameworks(
+            [_dep("tonic"), _dep("prost"), _dep("tonic-build"), _dep("tonic")]
+        )
+        assert scores["tonic"] == 13.0
+
+    def test_every_entry_has_category(self) -> None:
+        detector = FrameworkDetector()
+        for name, signature in RUST_FRAMEWORKS.items():
+            assert signature.crates
+            assert detector._get_framework_category(name) == signature.category
+
+
+class TestRustFrameworkDetection:
+    def test_detects_ecosystem_crates(self, tmp_path: Path) -> None:
+        _write_package(
+            tmp_path,
+            'bevy = "0.13"\nserde = { version = "1", features = ["derive"] }\n',
+        )
+        result = FrameworkDetector().detect_framework(tmp_path, "rust")
+
+        assert result.primary == "bevy"
+        assert "serde" in result.secondary
+
+    def test_pyo3_with_maturin(self, tmp_path: Path) -> None:
+        _write_package(
+            tmp_path,
+            'pyo3 = { version = "0.21", features = ["extension-module"] }\n'
+            'clap = { version = "4", features = ["derive"] }\n',
+        )
+        (tmp_path / "pyproject.toml").write_text(
+            '[build-system]\nrequires = ["maturin>=1.5"]\n'
+            'build-backend = "maturin"\n'
+        )
+        result = FrameworkDetector().detect_framework(tmp_path, "rust")
+
+        assert result.primary == "pyo3"
+        assert result.confidence == 100.0
+
+    def test_clap_marks_cli_tool(self, tmp_path: Path) -> None:
+        _write_package(tmp_path, 'clap = { version = "4", features = ["derive"] }\n')
+        (tmp_path / "src" / "main.rs").write_text("fn main() {}\n")
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        assert analysis.framework_info.primary == "clap"
+        assert analysis.project_type == ProjectType.CLI_TOOL
+
+    def test_clap_outranks_tokio_in_cli(self, tmp_path: Path) -> None:
+        _write_package(
+            tmp_path,
+            'tokio = { version = "1", features = ["full"] }\n'
+            'clap = { version = "4", features = ["derive"] }\n',
+        )
+        (tmp_path / "src" / "main.rs").write_text("fn main() {}\n")
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        assert analysis.framework_info.primary == "clap"
+        assert "tokio" in analysis.framework_info.secondary
+        assert analysis.project_type == ProjectType.CLI_TOOL
+
+    def test_infrastructure_crates_are_never_primary(self, tmp_path: Path) -> None:
+        _write_package(
+            tmp_path,
+            'serde = { version = "1", features = ["derive"] }\n'
+            'tracing = "0.1"\n',
+        )
+        result = FrameworkDetector().detect_framework(tmp_path, "rust")
+
+        assert result.primary is None
+        assert set(result.secondary) == {"serde", "tracing"}
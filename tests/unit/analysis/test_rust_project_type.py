@@ -0,0 +1,113 @@
+"""Tests for Rust project-type classification from Cargo manifests."""
+
+from pathlib import Path
+from typing import Optional
+
+from claude_builder.core.agents import AgentSelector
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.models import ProjectType
+from claude_builder.core.template_manager import TemplateManager
+
+
+def _write_crate(
+    path: Path,
+    manifest: str,
+    *,
+    lib: Optional[str] = None,
+    main: Optional[str] = None,
+) -> Path:
+    (path / "src").mkdir(parents=True, exist_ok=True)
+    (path / "Cargo.toml").write_text(f'[package]\nname = "demo"\n{manifest}')
+    if lib is not None:
+        (path / "src" / "lib.rs").write_text(lib)
+    if main is not None:
+        (path / "src" / "main.rs").write_text(main)
+    return path
+
+
+FIRMWARE_MAIN = """#![no_std]
+#![no_main]
+
+use cortex_m_rt::entry;
+
+#[entry]
+fn main() -> ! {
+    loop {}
+}
+"""
+
+
+def _project_type(path: Path) -> ProjectType:
+    return ProjectAnalyzer().analyze(path).project_type
+
+
+class TestRustProjectType:
+    def test_proc_macro(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path, "\n[lib]\nproc-macro = true\n", lib="extern crate proc_macro;\n"
+        )
+        assert _project_type(tmp_path) == ProjectType.PROC_MACRO
+
+    def test_python_extension(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path,
+            '\n[lib]\ncrate-type = ["cdylib"]\n\n[dependencies]\npyo3 = "0.21"\n',
+            lib="use pyo3::prelude::*;\n",
+        )
+        assert _project_type(tmp_path) == ProjectType.PYTHON_EXTENSION
+
+    def test_wasm_module(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path,
+            '\n[lib]\ncrate-type = ["cdylib", "rlib"]\n\n'
+            '[dependencies]\nwasm-bindgen = "0.2"\n',
+            lib="use wasm_bindgen::prelude::*;\n",
+        )
+        assert _project_type(tmp_path) == ProjectType.WASM_MODULE
+
+    def test_embedded_firmware(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path, '\n[dependencies]\ncortex-m-rt = "0.7"\n', main=FIRMWARE_MAIN
+        )
+        assert _project_type(tmp_path) == ProjectType.EMBEDDED_FIRMWARE
+
+    def test_no_std_library_is_library(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path, "", lib="#![no_std]\n\npub fn add(a: u8, b: u8) -> u8 { a + b }\n"
+        )
+        assert _project_type(tmp_path) == ProjectType.LIBRARY
+
+    def test_binary_framework_decides_type(self, tmp_path: Path) -> None:
+        main = "fn main() {}\n"
+        _write_crate(tmp_path / "desktop", '\n[dependencies]\ntauri = "1"\n', main=main)
+        _write_crate(tmp_path / "api", '\n[dependencies]\naxum = "0.7"\n', main=main)
+        assert _project_type(tmp_path / "desktop") == ProjectType.DESKTOP_APP
+        assert _project_type(tmp_path / "api") == ProjectType.API_SERVICE
+
+    def test_bin_target_is_cli_tool(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path,
+            '\n[[bin]]\nname = "tool"\npath = "src/tool.rs"\n',
+            lib="pub fn run() {}\n",
+        )
+        assert _project_type(tmp_path) == ProjectType.CLI_TOOL
+
+    def test_conditional_no_std_is_not_firmware(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path,
+            "",
+            main='#![cfg_attr(not(feature = "std"), no_std)]\nfn main() {}\n',
+        )
+        assert _project_type(tmp_path) == ProjectType.CLI_TOOL
+
+    def test_firmware_gets_matching_standards_and_agents(self, tmp_path: Path) -> None:
+        _write_crate(
+            tmp_path, '\n[dependencies]\ncortex-m-rt = "0.7"\n', main=FIRMWARE_MAIN
+        )
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+        assert "Stay `no_std`" in claude_md
+
+        agents = AgentSelector().select_domain_agents(analysis)
+        assert "performance-benchmarker" in [agent.name for agent in agents]
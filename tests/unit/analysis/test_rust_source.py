@@ -0,0 +1,167 @@
+"""Tests for the offline Rust source scanner."""
+
+from pathlib import Path
+
+from claude_builder.analysis.rust_source import (
+    render_module_tree,
+    scan_rust_sources,
+    strip_comments_and_strings,
+    summarize_rust_source,
+)
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.template_manager import TemplateManager
+
+
+MAIN_RS = """//! Docs mentioning `pub fn not_an_item()` and `mod fake;`
+mod api;
+#[path = "platform/linux.rs"]
+mod platform;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    let text = "unsafe { mod nope; }";
+    let brace = '{';
+    unsafe { libc::abort() }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parses() {}
+
+    #[tokio::test]
+    async fn serves<'a>() {}
+}
+"""
+
+
+def _create_service(root: Path) -> Path:
+    (root / "src" / "api").mkdir(parents=True)
+    (root / "src" / "platform").mkdir()
+    (root / "tests").mkdir()
+    (root / "Cargo.toml").write_text('[package]\nname = "my-svc"\nedition = "2021"\n')
+    (root / "src" / "main.rs").write_text(MAIN_RS)
+    (root / "src" / "api" / "mod.rs").write_text(
+        "pub mod routes;\n\npub struct AppState;\n\n"
+        "#[macro_export]\nmacro_rules! route { () => {} }\n"
+    )
+    (root / "src" / "api" / "routes.rs").write_text(
+        "pub fn router() {}\npub(crate) fn internal() {}\n"
+        'pub unsafe extern "C" fn ffi_entry() {}\n'
+    )
+    (root / "src" / "platform" / "linux.rs").write_text("pub const PORT: u16 = 80;\n")
+    (root / "tests" / "smoke.rs").write_text("#[test]\nfn boots() {}\n")
+    return root
+
+
+class TestStripCommentsAndStrings:
+    def test_blanks_literals_and_keeps_lines(self) -> None:
+        source = (
+            'let a = "x // y";\n/* outer /* nested */ still */\n'
+            'let b = r#"raw "quoted""#; // tail\nlet c = \'"\';\n'
+        )
+        stripped = strip_comments_and_strings(source)
+
+        assert stripped.count("\n") == source.count("\n")
+        assert "//" not in stripped
+        assert "nested" not in stripped
+        assert "quoted" not in stripped
+        assert "let c = ' ';" in stripped
+
+    def test_lifetimes_are_not_char_literals(self) -> None:
+        source = "fn f<'a>(x: &'a str) -> &'a str { x }"
+        assert strip_comments_and_strings(source) == source
+
+
+class TestScanRustSources:
+    def test_module_tree_and_layouts(self, tmp_path: Path) -> None:
+        info = scan_rust_sources(_create_service(tmp_path))
+
+        modules = {module.path: module for module in info.modules}
+        assert modules["my_svc"].layout == "root"
+        assert modules["my_svc::api"].file == "src/api/mod.rs"
+        assert modules["my_svc::api"].layout == "mod.rs"
+        assert modules["my_svc::api::routes"].layout == "file"
+        assert modules["my_svc::api::routes"].public is True
+        assert modules["my_svc::platform"].file == "src/platform/linux.rs"
+        assert modules["my_svc::tests"].layout == "inline"
+        # Declarations in comments and strings are ignored
+        assert not any("fake" in path or "nope" in path for path in modules)
+
+    def test_public_items_tests_and_unsafe(self, tmp_path: Path) -> None:
+        info = scan_rust_sources(_create_service(tmp_path))
+        modules = {module.path: module for module in info.modules}
+
+        assert modules["my_svc::api"].public_items == [
+            "struct AppState",
+            "macro route!",
+        ]
+        assert modules["my_svc::api::routes"].public_items == [
+            "fn router",
+            "fn ffi_entry",
+        ]
+        assert modules["my_svc"].public_items == []
+        assert info.async_entrypoints == ["src/main.rs:6 (#[tokio::main])"]
+        assert info.test_count == 3
+        assert info.unit_test_files == ["src/main.rs"]
+        assert info.integration_tests == ["tests/smoke.rs"]
+        assert info.unsafe_blocks == {"src/main.rs": 1}
+
+    def test_rendering(self, tmp_path: Path) -> None:
+        info = scan_rust_sources(_create_service(tmp_path))
+
+        tree = render_module_tree(info, "my-svc")
+        assert tree.splitlines()[:3] == [
+            "my-svc/",
+            "├── Cargo.toml",
+            "├── src/",
+        ]
+        assert "│   │   └── routes.rs  # my_svc::api::routes" in tree
+        assert "    └── smoke.rs" in tree
+
+        facts = summarize_rust_source(info)
+        assert "1 `mod.rs`-style, 2 `foo.rs`-style" in facts
+        assert "`my_svc::api::routes`: `fn router`, `fn ffi_entry`" in facts
+        assert "- **Unsafe**: 1 `unsafe` block in `src/main.rs` (1)" in facts
+
+    def test_workspace_members_are_scanned(self, tmp_path: Path) -> None:
+        (tmp_path / "Cargo.toml").write_text('[workspace]\nmembers = ["svc"]\n')
+        _create_service(tmp_path / "svc")
+
+        info = scan_rust_sources(tmp_path)
+        assert info.crate_roots == ["svc/src/main.rs"]
+        assert "svc/src/api/routes.rs" in [module.file for module in info.modules]
+
+
+class TestRustSourceInGeneratedDocs:
+    def test_template_variables_and_claude_md(self, tmp_path: Path) -> None:
+        analysis = ProjectAnalyzer().analyze(_create_service(tmp_path))
+        assert analysis.rust_source is not None
+
+        variables = DocumentGenerator()._create_template_variables(analysis)
+        assert "routes.rs  # my_svc::api::routes" in variables["rust_module_tree"]
+        assert "**Async entry points**" in variables["rust_source_facts"]
+        assert variables["integration_test"] == "smoke"
+        assert variables["function_name"] == "router"
+        assert variables["data_type"] == "AppState"
+        assert variables["rust_error_type"] == "MySvcError"
+        assert variables["benchmark"] == "throughput"
+
+        rust_md = DocumentGenerator().generate(analysis, tmp_path).files["CLAUDE.md"]
+        assert "use my_svc::*;" in rust_md
+        assert "fn test_router_success()" in rust_md
+        for placeholder in ("${shared_state}", "${data_type}", "${service_name}"):
+            assert placeholder not in rust_md
+
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+        assert "## Source Structure" in claude_md
+        assert "src/main.rs:6 (#[tokio::main])" in claude_md
+
+    def test_non_rust_project_has_no_source_section(self, tmp_path: Path) -> None:
+        (tmp_path / "app.py").write_text("print('hi')\n")
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        assert analysis.rust_source is None
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+        assert "## Source Structure" not in claude_md
@@ -0,0 +1,128 @@
+"""Tests for language toolchain/version detection."""
+
+import json
+
+from pathlib import Path
+
+from claude_builder.analysis.toolchains import (
+    detect_language_versions,
+    detect_node_versions,
+    detect_python_versions,
+    detect_rust_versions,
+)
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.template_manager import TemplateManager
+
+
+def _write_rust_package(path: Path, package: str) -> Path:
+    (path / "src").mkdir(parents=True, exist_ok=True)
+    (path / "src" / "main.rs").write_text("fn main() {}\n")
+    (path / "Cargo.toml").write_text(f'[package]\nname = "demo"\n{package}')
+    return path
+
+
+class TestRustVersions:
+    def test_toolchain_msrv_and_edition(self, tmp_path: Path) -> None:
+        _write_rust_package(tmp_path, 'edition = "2021"\nrust-version = "1.70"\n')
+        (tmp_path / "rust-toolchain.toml").write_text(
+            '[toolchain]\nchannel = "1.78.0"\ncomponents = ["clippy"]\n'
+        )
+
+        assert detect_rust_versions(tmp_path) == {
+            "rust_toolchain": "1.78.0",
+            "rust_msrv": "1.70",
+            "rust_edition": "2021",
+            "rust": "1.78.0",
+        }
+
+    def test_legacy_toolchain_file(self, tmp_path: Path) -> None:
+        _write_rust_package(tmp_path, "")
+        (tmp_path / "rust-toolchain").write_text("nightly-2024-05-01\n")
+
+        versions = detect_rust_versions(tmp_path)
+        assert versions["rust"] == "nightly-2024-05-01"
+        assert "rust_edition" not in versions
+
+    def test_workspace_reports_oldest_member_values(self, tmp_path: Path) -> None:
+        (tmp_path / "Cargo.toml").write_text(
+            '[workspace]\nmembers = ["a", "b"]\n\n'
+            '[workspace.package]\nedition = "2021"\nrust-version = "1.74"\n'
+        )
+        _write_rust_package(
+            tmp_path / "a", "edition.workspace = true\nrust-version.workspace = true\n"
+        )
+        _write_rust_package(tmp_path / "b", 'edition = "2018"\nrust-version = "1.65"\n')
+
+        versions = detect_rust_versions(tmp_path)
+        assert versions["rust_msrv"] == "1.65"
+        assert versions["rust_edition"] == "2018"
+        # Without a toolchain file the MSRV is the best available answer
+        assert versions["rust"] == "1.65"
+
+
+class TestPythonAndNodeVersions:
+    def test_python_version_file_wins(self, tmp_path: Path) -> None:
+        (tmp_path / ".python-version").write_text("3.11.4\n")
+        (tmp_path / "pyproject.toml").write_text(
+            '[project]\nname = "demo"\nrequires-python = ">=3.9"\n'
+        )
+
+        assert detect_python_versions(tmp_path) == {
+            "python_pinned": "3.11.4",
+            "python_requires": ">=3.9",
+            "python": "3.11.4",
+        }
+
+    def test_poetry_python_constraint(self, tmp_path: Path) -> None:
+        (tmp_path / "pyproject.toml").write_text(
+            '[tool.poetry.dependencies]\npython = "^3.10"\n'
+        )
+        assert detect_python_versions(tmp_path)["python"] == "^3.10"
+
+    def test_nvmrc_and_engines(self, tmp_path: Path) -> None:
+        (tmp_path / ".nvmrc").write_text("v20.11.0\n")
+        (tmp_path / "package.json").write_text(
+            json.dumps({"name": "demo", "engines": {"node": ">=18"}})
+        )
+
+        assert detect_node_versions(tmp_path) == {
+            "node_pinned": "20.11.0",
+            "node_engine": ">=18",
+            "node": "20.11.0",
+        }
+        versions = detect_language_versions(tmp_path, ["typescript"])
+        assert versions["typescript"] == "20.11.0"
+
+    def test_nothing_found(self, tmp_path: Path) -> None:
+        assert detect_language_versions(tmp_path, ["python", "rust", "go"]) == {}
+
+
+class TestToolchainsInAnalysis:
+    def test_version_info_and_generated_output(self, tmp_path: Path) -> None:
+        _write_rust_package(tmp_path, 'edition = "2021"\nrust-version = "1.70"\n')
+        (tmp_path / "rust-toolchain.toml").write_text(
+            '[toolchain]\nchannel = "1.78.0"\n'
+        )
+
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+        assert analysis.language_info.version_info["rust"] == "1.78.0"
+        assert analysis.language_info.version_info["rust_edition"] == "2021"
+
+        variables = DocumentGenerator()._create_template_variables(analysis)
+        assert variables["rust_version"] == "1.78.0"
+        assert variables["minimum_rust_version"] == "1.70"
+
+        claude_md = TemplateManager().generate_complete_environment(analysis).claude_md
+        assert (
+            "**Toolchain**: Rust toolchain 1.78.0, MSRV 1.70, edition 2021"
+            in claude_md
+        )
+
+    def test_unknown_version_is_not_substituted(self, tmp_path: Path) -> None:
+        _write_rust_package(tmp_path, "")
+
+        analysis = ProjectAnalyzer().analyze(tmp_path)
+        assert analysis.language_info.version_info == {"rust": "unknown"}
+        variables = DocumentGenerator()._create_template_variables(analysis)
+        assert "rust_version" not in variables
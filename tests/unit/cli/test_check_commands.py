@@ -0,0 +1,77 @@
+"""Tests for the drift check command."""
+
+import json
+
+from pathlib import Path
+
+from click.testing import CliRunner
+
+from claude_builder.cli.generate_commands import generate
+from claude_builder.cli.main import cli
+from tests.conftest import write_file
+
+
+class TestCheckCommand:
+    def test_check_passes_after_generation_and_fails_on_drift(
+        self, tmp_path: Path
+    ) -> None:
+        write_file(tmp_path / "pyproject.toml", '[project]\nname = "shop"\n')
+        write_file(tmp_path / "shop" / "__init__.py", "")
+        runner = CliRunner()
+
+        missing = runner.invoke(cli, ["check", str(tmp_path)])
+        generated = runner.invoke(
+            generate, ["complete", str(tmp_path), "--no-suggestions"]
+        )
+        current = runner.invoke(cli, ["check", str(tmp_path)])
+        write_file(
+            tmp_path / "pyproject.toml",
+            '[project]\nname = "shop"\ndependencies = ["django>=4"]\n',
+        )
+        stale = runner.invoke(cli, ["check", str(tmp_path)])
+
+        assert missing.exit_code == 1
+        assert "CLAUDE.md: missing" in missing.output
+        assert generated.exit_code == 0, generated.output
+        assert current.exit_code == 0, current.output
+        assert "up to date" in current.output
+        assert stale.exit_code == 1
+        assert "CLAUDE.md: stale" in stale.output
+        assert "project-overview (changed)" in stale.output
+
+    def test_json_report(self, tmp_path: Path) -> None:
+        write_file(tmp_path / "pyproject.toml", '[project]\nname = "shop"\n')
+
+        result = CliRunner().invoke(cli, ["check", str(tmp_path), "--json"])
+
+        report = json.loads(result.stdout)
+        assert result.exit_code == 1
+        assert report["drift"] is True
+        assert {f["status"] for f in report["files"]} == {"missing"}
+
+    def test_subcommands_are_not_taken_for_the_project_path(self) -> None:
+        result = CliRunner().invoke(cli, ["check", "--help"])
+
+        assert result.exit_code == 0
+        assert "Check whether generated instruction files" in result.output
+
+    def test_agent_files_no_longer_generated_are_reported(
+        self, tmp_path: Path
+    ) -> None:
+        write_file(tmp_path / "pyproject.toml", '[project]\nname = "shop"\n')
+        write_file(tmp_path / "shop" / "__init__.py", "")
+        runner = CliRunner()
+        runner.invoke(generate, ["complete", str(tmp_path), "--no-suggestions"])
+        leftover = ".claude/agents/retired-agent.md"
+        write_file(tmp_path / leftover, "# Retired\n")
+        write_file(tmp_path / ".claude-builder" / "generated" / leftover, "# Retired\n")
+        write_file(tmp_path / ".claude" / "agents" / "notes.txt", "mine\n")
+
+        result = runner.invoke(cli, ["check", str(tmp_path), "--json"])
+
+        report = json.loads(result.stdout)
+        assert result.exit_code == 1
+        assert [f["path"] for f in report["files"] if f["status"] != "current"] == [
+            leftover
+        ]
+        assert report["files"][-1]["status"] == "orphaned"
@@ -0,0 +1,74 @@
+"""Tests for unified diff previews and per-file review of generated files."""
+
+from pathlib import Path
+
+from click.testing import CliRunner
+
+from claude_builder.cli.diff_preview import plan_changes, summarize_changes
+from claude_builder.cli.generate_commands import generate
+from claude_builder.cli.main import cli
+from tests.conftest import write_file
+
+
+def _project(tmp_path: Path) -> Path:
+    write_file(tmp_path / "pyproject.toml", '[project]\nname = "shop"\n')
+    write_file(tmp_path / "shop" / "__init__.py", "")
+    return tmp_path
+
+
+class TestPlanChanges:
+    def test_files_are_classified_and_diffed(self, tmp_path: Path) -> None:
+        write_file(tmp_path / "same.txt", "kept\n")
+        write_file(tmp_path / "edit.txt", "old\nkept\n")
+
+        changes = plan_changes(
+            tmp_path,
+            [
+                ("new.txt", "hello\n"),
+                ("edit.txt", "new\nkept\n"),
+                ("same.txt", "kept\n"),
+            ],
+        )
+
+        assert [c.status for c in changes] == ["added", "changed", "unchanged"]
+        assert summarize_changes(changes) == "1 added, 1 changed, 1 unchanged"
+        assert changes[0].diff[:2] == ["--- /dev/null\n", "+++ b/new.txt\n"]
+        assert "-old\n" in changes[1].diff
+        assert "+new\n" in changes[1].diff
+        assert changes[2].diff == []
+
+
+class TestDiffOptions:
+    def test_dry_run_diff_shows_changes_without_writing(self, tmp_path: Path) -> None:
+        project = _project(tmp_path)
+        runner = CliRunner()
+        runner.invoke(generate, ["complete", str(project), "--no-suggestions"])
+        claude_md = project / "CLAUDE.md"
+        before = claude_md.read_text()
+        write_file(
+            project / "pyproject.toml",
+            '[project]\nname = "shop"\ndependencies = ["django>=4"]\n',
+        )
+
+        result = runner.invoke(cli, ["--dry-run", "--diff", "--quiet", str(project)])
+
+        assert result.exit_code == 0, result.output
+        assert "+**Framework**: django" in result.output
+        assert "changed, 0 unchanged" in result.output
+        assert claude_md.read_text() == before
+
+    def test_interactive_review_writes_only_accepted_files(
+        self, tmp_path: Path
+    ) -> None:
+        project = _project(tmp_path)
+
+        result = CliRunner().invoke(
+            generate,
+            ["complete", str(project), "--interactive", "--no-suggestions"],
+            input="n\ny\nq\n",
+        )
+
+        assert result.exit_code == 0, result.output
+        assert not (project / "CLAUDE.md").exists()
+        assert len(list((project / ".claude" / "agents").iterdir())) == 1
+        assert not (project / "AGENTS.md").exists()
@@ -4,6 +4,7 @@ import json
 import tempfile
 
 from pathlib import Path
+from types import SimpleNamespace
 
 import pytest
 
@@ -25,7 +26,9 @@ from claude_builder.cli.generate_commands import (
     agents_md,
     claude_md,
     docs,
+    subagents,
 )
+from claude_builder.core.managed_regions import write_managed_files
 
 
 class TestGenerateCommandsComprehensive:
@@ -267,6 +270,36 @@ class TestGenerateCommandsComprehensive:
                 result = runner.invoke(agents, [tmp_dir])
                 assert result.exit_code == 0
 
+    def test_generate_subagents_writes_in_one_transaction(self):
+        """Every subagent file is written by a single managed write."""
+        subagent_files = [
+            SimpleNamespace(name=f"agent-{i}.md", content=f"# Agent {i}\n")
+            for i in range(7)
+        ]
+        with patch("claude_builder.cli.generate_commands.ProjectAnalyzer"), patch(
+            "claude_builder.core.template_manager.TemplateManager"
+        ) as mock_manager_class, patch(
+            "claude_builder.cli.generate_commands.write_managed_files",
+            wraps=write_managed_files,
+        ) as mock_write:
+            mock_manager = mock_manager_class.return_value
+            mock_manager.generate_complete_environment.return_value = (
+                SimpleNamespace(subagent_files=subagent_files)
+            )
+
+            runner = CliRunner()
+            with tempfile.TemporaryDirectory() as tmp_dir:
+                result = runner.invoke(subagents, [tmp_dir])
+                assert result.exit_code == 0
+
+                mock_write.assert_called_once()
+                _, files = mock_write.call_args.args
+                assert [path for path, _ in files] == [
+                    f".claude/agents/agent-{i}.md" for i in range(7)
+                ]
+                agent = Path(tmp_dir) / ".claude" / "agents" / "agent-6.md"
+                assert agent.read_text() == "# Agent 6\n"
+
     def test_claude_md_command_comprehensive(self):
         """Test claude_md command with comprehensive options."""
         with patch(
@@ -616,6 +649,28 @@ class TestGenerateHelperFunctions:
             # Check new content was written
             assert existing_file.read_text() == "# New content"
 
+    def test_write_generated_files_keeps_edits_since_last_generation(self):
+        """Test regenerating over a file edited after the previous run."""
+        first = Mock()
+        first.files = {"CLAUDE.md": "# Project\nline one\nline two\n"}
+        second = Mock()
+        second.files = {"CLAUDE.md": "# Project\nline one\nline two, updated\n"}
+
+        with tempfile.TemporaryDirectory() as tmp_dir:
+            output_path = Path(tmp_dir)
+            claude_md = output_path / "CLAUDE.md"
+
+            _write_generated_files(first, output_path, backup_existing=False, verbose=0)
+            claude_md.write_text("# Project\nline one (mine)\nline two\n")
+            _write_generated_files(
+                second, output_path, backup_existing=False, verbose=0
+            )
+
+            assert claude_md.read_text() == (
+                "# Project\nline one (mine)\nline two, updated\n"
+            )
+            assert not (output_path / "CLAUDE.md.bak").exists()
+
     def test_write_generated_files_verbose(self):
         """Test writing generated files with verbose output."""
         mock_content = Mock()
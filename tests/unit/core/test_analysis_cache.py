@@ -0,0 +1,249 @@
+"""Tests for the persistent, incremental analysis cache."""
+
+import os
+
+from pathlib import Path
+from typing import Any, List
+from unittest.mock import patch
+
+from click.testing import CliRunner
+
+from claude_builder.analysis import code_metrics
+from claude_builder.cli.analyze_commands import analyze
+from claude_builder.cli.main import cli
+from claude_builder.core.analysis_cache import CACHE_DIRECTORY, AnalysisCache
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.models import ProjectType
+from claude_builder.utils.project_files import ProjectFiles
+
+
+def _write_project(root: Path) -> Path:
+    (root / "src").mkdir()
+    (root / "src" / "app.py").write_text("def main():\n    return 1\n")
+    (root / "src" / "util.py").write_text("VALUE = 1\n")
+    (root / "pyproject.toml").write_text('[project]\nname = "demo"\n')
+    return root
+
+
+def _cached_analyzer(**config: Any) -> ProjectAnalyzer:
+    return ProjectAnalyzer({"cache_enabled": True, **config})
+
+
+# Exercises every detector that reads file contents
+DEEP_CONFIG = {
+    "deep_analysis": True,
+    "custom_detection_rules": {
+        "payments": {
+            "target": "domain",
+            "conditions": [{"content": "invoice", "files": "*.py"}],
+        }
+    },
+}
+
+
+def _record_scans(scanned: List[str]) -> Any:
+    """Patch the cache so that every file it reads is recorded by name."""
+    original = AnalysisCache._scan_file
+
+    def scan(self: AnalysisCache, path: Path, size: int, mtime_ns: int) -> Any:
+        scanned.append(path.name)
+        return original(self, path, size, mtime_ns)
+
+    return patch.object(AnalysisCache, "_scan_file", scan)
+
+
+def _record_reads(read: List[str]) -> Any:
+    """Patch the detectors' file reads so that every file is recorded by name."""
+    read_text = ProjectFiles.read_text
+    measure_file = code_metrics._measure_file
+
+    def read_project_file(self: ProjectFiles, path: Path) -> Any:
+        read.append(path.name)
+        return read_text(self, path)
+
+    def measure(path: Path, language: str) -> Any:
+        read.append(path.name)
+        return measure_file(path, language)
+
+    return (
+        patch.object(ProjectFiles, "read_text", read_project_file),
+        patch.object(code_metrics, "_measure_file", measure),
+    )
+
+
+class TestFileIndex:
+    def test_only_new_or_modified_files_are_read(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        cache = AnalysisCache(root)
+        files = sorted(p for p in root.rglob("*") if p.is_file())
+        first = cache.refresh_index(files)
+        cache.save(first, "key", _cached_analyzer().analyze(root))
+
+        assert first.changed == ["pyproject.toml", "src/app.py", "src/util.py"]
+        assert first.files["src/app.py"].lines == 2
+
+        # Touched but identical content is re-hashed, yet not a change
+        os.utime(root / "src" / "util.py", (1, 1))
+        (root / "src" / "app.py").write_text("def main():\n    return 2\n\n")
+        scanned: List[str] = []
+        with _record_scans(scanned):
+            second = cache.refresh_index(files)
+
+        assert sorted(scanned) == ["app.py", "util.py"]
+        assert second.changed == ["src/app.py"]
+        assert second.files["src/app.py"].lines == 3
+        assert second.fingerprint() != first.fingerprint()
+
+    def test_removed_files_are_reported(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        cache = AnalysisCache(root)
+        index = cache.refresh_index(p for p in root.rglob("*") if p.is_file())
+        cache.save(index, "key", _cached_analyzer().analyze(root))
+
+        second = cache.refresh_index([root / "pyproject.toml"])
+
+        assert second.removed == ["src/app.py", "src/util.py"]
+        assert not second.is_unchanged
+
+
+class TestCachedAnalysis:
+    def test_unchanged_project_reuses_cached_analysis(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        analyzer = _cached_analyzer()
+        first = analyzer.analyze(root)
+
+        assert not first.from_cache
+        assert (root / CACHE_DIRECTORY / ".gitignore").read_text() == "*\n"
+
+        with patch.object(
+            analyzer.language_detector, "detect", side_effect=AssertionError
+        ):
+            second = analyzer.analyze(root)
+
+        assert second.from_cache
+        assert second.project_path == root
+        assert second.project_type == first.project_type == ProjectType.LIBRARY
+        assert second.language_info == first.language_info
+        assert second.filesystem_info == first.filesystem_info
+        assert second.analysis_timestamp == first.analysis_timestamp
+
+    def test_changed_file_is_merged_into_new_analysis(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        analyzer = _cached_analyzer()
+        analyzer.analyze(root)
+
+        (root / "src" / "cli.js").write_text("console.log(1);\n")
+        result = analyzer.analyze(root)
+
+        assert not result.from_cache
+        assert result.language_info.file_counts == {"python": 2, "javascript": 1}
+        # Cached line counts of unchanged files are merged with the new file
+        assert result.language_info.total_lines == {"python": 3, "javascript": 1}
+        # The cache directory is not part of the analyzed tree
+        assert result.filesystem_info.total_directories == 1
+
+    def test_edited_source_file_is_the_only_one_read_again(
+        self, tmp_path: Path
+    ) -> None:
+        root = _write_project(tmp_path)
+        analyzer = _cached_analyzer(**DEEP_CONFIG)
+        analyzer.analyze(root)
+
+        (root / "src" / "app.py").write_text(
+            "def invoice(total):\n    if total:\n        return total\n"
+        )
+        read: List[str] = []
+        read_text, measure = _record_reads(read)
+        with read_text, measure, patch.object(
+            analyzer.framework_detector, "detect", side_effect=AssertionError
+        ):
+            result = analyzer.analyze(root)
+
+        assert set(read) == {"app.py"}
+        assert not result.from_cache
+        assert result.rescanned_files == ["src/app.py"]
+        # The merged analysis matches one computed from scratch
+        fresh = ProjectAnalyzer(DEEP_CONFIG).analyze(root)
+        assert result.language_info == fresh.language_info
+        assert result.framework_info == fresh.framework_info
+        assert result.domain_info == fresh.domain_info
+        assert result.complexity_metrics == fresh.complexity_metrics
+        assert result.rule_matches == fresh.rule_matches
+        assert result.rule_matches[0].evidence == ["src/app.py matches 'invoice'"]
+
+    def test_manifest_change_runs_every_detector_again(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        analyzer = _cached_analyzer()
+        analyzer.analyze(root)
+
+        (root / "pyproject.toml").write_text(
+            '[project]\nname = "demo"\ndependencies = ["flask"]\n'
+        )
+        result = analyzer.analyze(root)
+
+        assert result.rescanned_files == ["pyproject.toml"]
+        assert result.framework_info.primary == "flask"
+
+    def test_rebuild_and_corrupt_cache(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        _cached_analyzer().analyze(root)
+        (root / CACHE_DIRECTORY / "analysis.json").write_text("{not json")
+
+        assert not _cached_analyzer().analyze(root).from_cache
+
+        rebuilt = _cached_analyzer(rebuild_cache=True)
+        scanned: List[str] = []
+        with _record_scans(scanned):
+            assert not rebuilt.analyze(root).from_cache
+        assert sorted(scanned) == ["app.py", "pyproject.toml", "util.py"]
+
+    def test_cache_is_opt_in_for_library_use(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        ProjectAnalyzer().analyze(root)
+
+        assert not (root / ".claude-builder").exists()
+
+
+class TestCacheCli:
+    def test_analyze_project_cache_flags(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        runner = CliRunner()
+
+        result = runner.invoke(analyze, ["project", str(root), "--no-cache"])
+        assert result.exit_code == 0, result.output
+        assert not (root / ".claude-builder").exists()
+
+        runner.invoke(analyze, ["project", str(root)])
+        result = runner.invoke(analyze, ["project", str(root), "-v"])
+        assert result.exit_code == 0, result.output
+        assert "using the cached analysis" in result.output
+        assert (root / CACHE_DIRECTORY / "files.json").exists()
+
+        result = runner.invoke(analyze, ["project", str(root), "--rebuild-cache", "-v"])
+        assert result.exit_code == 0, result.output
+        assert "using the cached analysis" not in result.output
+
+    def test_dry_run_and_check_do_not_write_the_cache(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        runner = CliRunner()
+
+        dry_run = runner.invoke(cli, ["--dry-run", str(root)])
+        check = runner.invoke(cli, ["check", str(root)])
+
+        assert dry_run.exit_code == 0, dry_run.output
+        assert check.exit_code == 1, check.output
+        assert not (root / CACHE_DIRECTORY).exists()
+
+    def test_check_reads_the_cache_of_the_last_generation(
+        self, tmp_path: Path
+    ) -> None:
+        root = _write_project(tmp_path)
+        runner = CliRunner()
+        runner.invoke(cli, [str(root)])
+        index = (root / CACHE_DIRECTORY / "files.json").read_text()
+        (root / "src" / "cli.py").write_text("print(1)\n")
+
+        result = runner.invoke(cli, ["check", str(root)])
+
+        assert result.exit_code == 0, result.output
+        assert (root / CACHE_DIRECTORY / "files.json").read_text() == index
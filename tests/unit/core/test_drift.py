@@ -0,0 +1,57 @@
+"""Tests for section-level drift detection."""
+
+from pathlib import Path
+
+from claude_builder.core.drift import detect_drift
+from claude_builder.core.managed_regions import write_managed_file
+from claude_builder.core.models import GeneratedArtifact
+from tests.conftest import write_file
+
+
+GENERATED = "# Shop\n\n## Overview\nA web shop.\n\n## Testing\nRun pytest\n"
+
+
+class TestDetectDrift:
+    def test_changed_added_and_removed_sections_are_reported(
+        self, tmp_path: Path
+    ) -> None:
+        write_managed_file(tmp_path, "CLAUDE.md", GENERATED + "\n## Legacy\nOld\n")
+        regenerated = GENERATED.replace("pytest", "tox") + "\n## Linting\nruff\n"
+
+        report = detect_drift(
+            tmp_path,
+            [
+                GeneratedArtifact("CLAUDE.md", regenerated),
+                GeneratedArtifact(".claude/agents/tester.md", "# Tester\n"),
+            ],
+        )
+
+        claude_md, agent = report.files
+        assert report.has_drift
+        assert [(s.section, s.change) for s in claude_md.sections] == [
+            ("testing", "changed"),
+            ("linting", "added"),
+            ("legacy", "removed"),
+        ]
+        assert agent.status == "missing"
+        assert report.to_dict()["stale"] == 2
+
+    def test_hand_edits_are_not_drift(self, tmp_path: Path) -> None:
+        write_managed_file(tmp_path, "CLAUDE.md", GENERATED)
+        claude_md = tmp_path / "CLAUDE.md"
+        claude_md.write_text(
+            claude_md.read_text().replace("A web shop.", "A web shop for plants.")
+            + "\n## Team Notes\nDeploy on Fridays\n"
+        )
+        write_file(tmp_path / "notes.md", GENERATED)
+
+        report = detect_drift(
+            tmp_path,
+            [
+                GeneratedArtifact("CLAUDE.md", GENERATED),
+                GeneratedArtifact("notes.md", GENERATED),
+            ],
+        )
+
+        assert not report.has_drift
+        assert [f.status for f in report.files] == ["current", "current"]
@@ -0,0 +1,111 @@
+"""Tests for transactional multi-file writes and their rollback journal."""
+
+import os
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from claude_builder.core.file_transaction import STAGING_PARENT, FileTransaction
+from claude_builder.core.managed_regions import (
+    write_managed_file,
+    write_managed_files,
+)
+from claude_builder.utils.git import GitBackupManager
+from tests.conftest import write_file
+
+
+class TestFileTransaction:
+    def test_failure_part_way_restores_every_file(self, tmp_path: Path) -> None:
+        write_file(tmp_path / "CLAUDE.md", "old claude\n")
+        write_file(tmp_path / ".claude" / "agents" / "tester.md", "old tester\n")
+        real_replace = os.replace
+        calls = []
+
+        def flaky_replace(src: str, dst: str) -> None:
+            calls.append(dst)
+            if len(calls) == 3:
+                raise OSError(28, "No space left on device")
+            real_replace(src, dst)
+
+        with pytest.raises(OSError), patch(
+            "claude_builder.core.file_transaction.os.replace", flaky_replace
+        ):
+            with FileTransaction(tmp_path) as transaction:
+                transaction.stage("CLAUDE.md", "new claude\n")
+                transaction.stage(".claude/agents/tester.md", "new tester\n")
+                transaction.stage("AGENTS.md", "new agents\n")
+
+        assert (tmp_path / "CLAUDE.md").read_text() == "old claude\n"
+        assert (tmp_path / ".claude" / "agents" / "tester.md").read_text() == (
+            "old tester\n"
+        )
+        assert not (tmp_path / "AGENTS.md").exists()
+        assert not (tmp_path / STAGING_PARENT).exists()
+
+    def test_an_error_while_staging_writes_nothing(self, tmp_path: Path) -> None:
+        with pytest.raises(RuntimeError):
+            with FileTransaction(tmp_path) as transaction:
+                transaction.stage("CLAUDE.md", "new\n")
+                raise RuntimeError("render failed")
+
+        assert list(tmp_path.iterdir()) == []
+
+    def test_generations_are_journaled_for_git_rollback(self, tmp_path: Path) -> None:
+        (tmp_path / ".git").mkdir()
+        write_file(tmp_path / "CLAUDE.md", "# Before\n")
+
+        with FileTransaction(tmp_path) as transaction:
+            transaction.stage("CLAUDE.md", "# After\n")
+            transaction.stage("AGENTS.md", "# Agents\n")
+        backups = GitBackupManager().list_backups(tmp_path)
+        GitBackupManager().restore_backup(tmp_path, transaction.journal_id)
+
+        assert [b["backup_id"] for b in backups] == [transaction.journal_id]
+        assert backups[0]["created_files"] == ["AGENTS.md"]
+        assert (tmp_path / "CLAUDE.md").read_text() == "# Before\n"
+        assert not (tmp_path / "AGENTS.md").exists()
+
+    def test_only_recent_generation_journals_are_kept(self, tmp_path: Path) -> None:
+        (tmp_path / ".git").mkdir()
+        manual_id = GitBackupManager().create_backup(tmp_path)
+
+        journal_ids = []
+        for run in range(7):
+            with FileTransaction(tmp_path) as transaction:
+                transaction.stage("CLAUDE.md", f"# Run {run}\n")
+            journal_ids.append(transaction.journal_id)
+        backups = GitBackupManager().list_backups(tmp_path)
+
+        assert [b["backup_id"] for b in backups if b.get("kind")] == (
+            journal_ids[::-1][:5]
+        )
+        assert manual_id in [b["backup_id"] for b in backups]
+
+    def test_managed_writes_stage_file_backup_and_baseline(
+        self, tmp_path: Path
+    ) -> None:
+        write_file(tmp_path / "NOTES.md", "# Notes\nmine\n")
+
+        merge = write_managed_file(
+            tmp_path, "NOTES.md", "# Notes\ngenerated\n", backup_existing=True
+        )
+
+        assert merge.backup_path == tmp_path / "NOTES.md.bak"
+        assert merge.backup_path.read_text() == "# Notes\nmine\n"
+        assert (tmp_path / "NOTES.md").read_text() == "# Notes\ngenerated\n"
+        assert (tmp_path / ".claude-builder" / "generated" / "NOTES.md").exists()
+
+    def test_managed_writes_report_the_journal_for_undo(self, tmp_path: Path) -> None:
+        (tmp_path / ".git").mkdir()
+
+        written = write_managed_files(
+            tmp_path, [("CLAUDE.md", "# Claude\n"), ("AGENTS.md", "# Agents\n")]
+        )
+
+        assert [merge.path for merge in written.merges] == ["CLAUDE.md", "AGENTS.md"]
+        assert written.undo_command == (
+            f"claude-builder git rollback {written.journal_id}"
+        )
+        assert written.notes == []
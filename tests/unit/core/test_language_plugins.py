@@ -0,0 +1,156 @@
+"""Tests for the language-plugin API and entry-point discovery."""
+
+from pathlib import Path
+from typing import Any, Dict, List
+
+import pytest
+
+from claude_builder.core import language_plugins
+from claude_builder.core.agents import AgentRegistry
+from claude_builder.core.analyzer import FrameworkDetector, ProjectAnalyzer
+from claude_builder.core.generator import TemplateLoader
+from claude_builder.core.language_plugins import (
+    LanguagePlugin,
+    LanguagePluginRegistry,
+    get_language_registry,
+    load_entry_point_plugins,
+    reset_language_registry,
+)
+from claude_builder.utils.file_patterns import FilePatterns
+
+
+class ZigPlugin(LanguagePlugin):
+    name = "zig"
+    extensions = (".zig",)
+    manifest_files = ("build.zig", "build.zig.zon")
+    frameworks = {"zap": "web"}
+    web_frameworks = ("zap",)
+    agent_mappings = {"primary": ["cli-developer"]}
+
+    def score_frameworks(
+        self, project_path: Path, scores: Dict[str, float]
+    ) -> List[str]:
+        manifest = project_path / "build.zig.zon"
+        if manifest.exists() and ".zap" in manifest.read_text():
+            scores["zap"] += 8
+            return ["zap"]
+        return []
+
+    def detect_versions(self, project_path: Path) -> Dict[str, str]:
+        return {"zig": "0.12.0"}
+
+
+class FakeEntryPoint:
+    def __init__(self, name: str, target: Any) -> None:
+        self.name = name
+        self._target = target
+
+    def load(self) -> Any:
+        if isinstance(self._target, Exception):
+            raise self._target
+        return self._target
+
+
+def _install_plugins(monkeypatch: Any, *entry_points: FakeEntryPoint) -> None:
+    monkeypatch.setattr(
+        language_plugins, "_iter_entry_points", lambda group: list(entry_points)
+    )
+    reset_language_registry()
+
+
+def _write_zig_project(root: Path) -> Path:
+    (root / "src").mkdir()
+    (root / "src" / "main.zig").write_text('const std = @import("std");\n')
+    (root / "build.zig").write_text("pub fn build(b: *std.Build) void {}\n")
+    (root / "build.zig.zon").write_text('.{ .dependencies = .{ .zap = .{} } }\n')
+    return root
+
+
+class TestLanguagePlugin:
+    def test_keyword_construction(self) -> None:
+        plugin = LanguagePlugin(name="elixir", extensions=(".ex", ".exs"))
+
+        assert plugin.get_source_extensions() == (".ex", ".exs")
+        assert plugin.score_frameworks(Path(), {}) == []
+        assert plugin.detect_versions(Path()) == {}
+
+    def test_name_is_required(self) -> None:
+        with pytest.raises(ValueError):
+            LanguagePlugin(extensions=(".ex",))
+
+    def test_unknown_attribute_is_rejected(self) -> None:
+        with pytest.raises(TypeError):
+            LanguagePlugin(name="elixir", extension=".ex")
+
+
+class TestLanguagePluginRegistry:
+    def test_builtins_are_registered(self) -> None:
+        registry = get_language_registry()
+
+        assert {"python", "rust", "javascript", "typescript", "go"} <= set(
+            registry.names()
+        )
+        assert registry.extension_map()["javascript"] == [".js", ".mjs", ".cjs"]
+        assert ".jsx" in registry.source_extensions()
+        assert ".md" not in registry.source_extensions()
+        assert registry.framework_category("axum") == "web"
+        assert "django" in registry.web_frameworks()
+
+    def test_later_registration_replaces(self) -> None:
+        registry = LanguagePluginRegistry([LanguagePlugin(name="go")])
+        replacement = LanguagePlugin(name="Go", extensions=(".go",))
+        registry.register(replacement)
+
+        assert len(registry) == 1
+        assert registry.get("go") is replacement
+
+
+class TestEntryPointDiscovery:
+    def test_loads_classes_and_instances(self, monkeypatch: Any) -> None:
+        elixir = LanguagePlugin(name="elixir", extensions=(".ex",))
+        monkeypatch.setattr(
+            language_plugins,
+            "_iter_entry_points",
+            lambda group: [
+                FakeEntryPoint("zig", ZigPlugin),
+                FakeEntryPoint("elixir", elixir),
+                FakeEntryPoint("broken", ImportError("missing dependency")),
+                FakeEntryPoint("bogus", object()),
+            ],
+        )
+
+        plugins = load_entry_point_plugins()
+        assert [plugin.name for plugin in plugins] == ["zig", "elixir"]
+        assert isinstance(plugins[0], ZigPlugin)
+
+    def test_plugin_drives_analysis_agents_and_templates(
+        self, tmp_path: Path, monkeypatch: Any
+    ) -> None:
+        template_dir = tmp_path / "zig-templates"
+        template_dir.mkdir()
+        (template_dir / "claude_instructions.md").write_text("# Zig rules\n")
+        ZigPlugin.template_dir = template_dir
+        _install_plugins(monkeypatch, FakeEntryPoint("zig", ZigPlugin))
+        try:
+            project = tmp_path / "project"
+            project.mkdir()
+            analysis = ProjectAnalyzer().analyze(_write_zig_project(project))
+
+            assert analysis.language_info.primary == "zig"
+            assert analysis.language_info.version_info["zig"] == "0.12.0"
+            assert analysis.framework_info.primary == "zap"
+            assert analysis.framework_info.details["web_framework"] is True
+            assert FrameworkDetector()._get_framework_category("zap") == "web"
+            assert AgentRegistry().language_mappings["zig"] == {
+                "primary": ["cli-developer"]
+            }
+            loader = TemplateLoader()
+            assert (
+                loader.load_template("claude_instructions.md", "languages/zig")
+                == "# Zig rules\n"
+            )
+            assert FilePatterns.is_source_file(Path("src/main.zig"))
+            assert FilePatterns.get_language_from_extension(Path("a.zig")) == "zig"
+        finally:
+            ZigPlugin.template_dir = None
+            reset_language_registry()
@@ -0,0 +1,197 @@
+"""Tests for managed regions and three-way regeneration merges."""
+
+from pathlib import Path
+
+import pytest
+
+from click.testing import CliRunner
+
+from claude_builder.cli.generate_commands import generate
+from claude_builder.core.managed_regions import (
+    BASELINE_DIRECTORY,
+    mark_regions,
+    merge3,
+    merge_managed,
+    write_managed_file,
+)
+from claude_builder.utils.exceptions import GenerationError
+from tests.conftest import write_file
+
+
+GENERATED = (
+    "# Shop\n\n"
+    "## Overview\nA web shop.\nPython 3.11\n\n"
+    "## Testing\nRun pytest\n\n"
+    "## Legacy\nUnused section\n"
+)
+
+
+class TestRegions:
+    def test_sections_are_marked_after_front_matter(self) -> None:
+        agent = "---\nname: tester\n---\n\n# Tester\n\n## Focus\nTests\n"
+
+        marked = mark_regions(agent)
+
+        assert marked == (
+            "---\nname: tester\n---\n"
+            "<!-- claude-builder:begin section=header -->\n# Tester\n"
+            "<!-- claude-builder:end section=header -->\n\n"
+            "<!-- claude-builder:begin section=focus -->\n## Focus\nTests\n"
+            "<!-- claude-builder:end section=focus -->\n"
+        )
+        assert mark_regions("# Notes\n```\n## not a heading\n```\n") == (
+            "# Notes\n```\n## not a heading\n```\n"
+        )
+
+    def test_regeneration_keeps_user_text_and_edits(self) -> None:
+        first = merge_managed("CLAUDE.md", GENERATED, None, None)
+        edited = (
+            first.content.replace("A web shop.", "A web shop for plants.")
+            + "\n## Team Notes\nDeploy on Fridays only\n"
+        )
+        regenerated = (
+            GENERATED.replace("Python 3.11", "Python 3.12")
+            .replace("## Legacy\nUnused section\n", "")
+            .replace("## Testing", "## Linting\nRun ruff\n\n## Testing")
+        )
+
+        merge = merge_managed("CLAUDE.md", regenerated, edited, first.generated)
+
+        assert merge.preserved == ["overview"]
+        assert merge.conflicts == []
+        assert "A web shop for plants.\nPython 3.12\n" in merge.content
+        assert "Unused section" not in merge.content
+        assert merge.content.index("## Linting") < merge.content.index("## Testing")
+        assert merge.content.endswith("## Team Notes\nDeploy on Fridays only\n")
+
+    def test_overlapping_edits_are_marked_as_conflicts(self) -> None:
+        merged, conflict = merge3(
+            "Run pytest\nThen lint", "Run pytest -x\nThen lint", "Run tox\nThen lint"
+        )
+
+        assert conflict
+        assert merged == (
+            "<<<<<<< edited\nRun pytest -x\n=======\nRun tox\n>>>>>>> generated\n"
+            "Then lint"
+        )
+
+    def test_a_file_without_baseline_is_backed_up_and_replaced(
+        self, tmp_path: Path
+    ) -> None:
+        write_file(tmp_path / "CLAUDE.md", "# My notes\n\nDeploy with make ship.\n")
+
+        merge = write_managed_file(tmp_path, "CLAUDE.md", GENERATED)
+
+        assert merge.backup_required
+        assert merge.conflicts == []
+        assert (tmp_path / "CLAUDE.md").read_text() == mark_regions(GENERATED)
+        assert merge.backup_path == tmp_path / "CLAUDE.md.bak"
+        assert merge.backup_path.read_text() == (
+            "# My notes\n\nDeploy with make ship.\n"
+        )
+
+    def test_a_marked_file_without_baseline_keeps_text_outside_regions(
+        self, tmp_path: Path
+    ) -> None:
+        first = merge_managed("CLAUDE.md", GENERATED, None, None)
+        write_file(
+            tmp_path / "CLAUDE.md",
+            first.content.replace("Run pytest", "Run pytest -x")
+            + "\n## Team Notes\nDeploy on Fridays only\n",
+        )
+
+        merge = write_managed_file(
+            tmp_path, "CLAUDE.md", GENERATED.replace("Python 3.11", "Python 3.12")
+        )
+
+        assert not merge.backup_required
+        assert not (tmp_path / "CLAUDE.md.bak").exists()
+        assert "A web shop.\nPython 3.12\n" in merge.content
+        assert "Run pytest\n" in merge.content
+        assert merge.content.endswith("## Team Notes\nDeploy on Fridays only\n")
+
+    def test_conflicting_front_matter_fails_without_writing(
+        self, tmp_path: Path
+    ) -> None:
+        agent = ".claude/agents/tester.md"
+        generated = "---\nname: tester\nmodel: sonnet\n---\n\n# Tester\n"
+        write_managed_file(tmp_path, agent, generated)
+        edited = (tmp_path / agent).read_text().replace("sonnet", "opus")
+        write_file(tmp_path / agent, edited)
+
+        with pytest.raises(GenerationError, match="front matter"):
+            write_managed_file(tmp_path, agent, generated.replace("sonnet", "haiku"))
+
+        assert (tmp_path / agent).read_text() == edited
+
+    def test_files_without_sections_merge_as_a_whole(self, tmp_path: Path) -> None:
+        write_managed_file(tmp_path, "NOTES.md", "# Notes\nline one\nline two\n")
+        write_file(tmp_path / "NOTES.md", "# Notes\nline one (mine)\nline two\n")
+
+        merge = write_managed_file(
+            tmp_path, "NOTES.md", "# Notes\nline one\nline two, updated\n"
+        )
+
+        assert merge.content == "# Notes\nline one (mine)\nline two, updated\n"
+        assert (tmp_path / BASELINE_DIRECTORY / "NOTES.md").read_text() == (
+            "# Notes\nline one\nline two, updated\n"
+        )
+
+
+class TestMergedConfig:
+    def test_existing_aider_config_keeps_its_settings(self, tmp_path: Path) -> None:
+        config = write_file(
+            tmp_path / ".aider.conf.yml",
+            "# my settings\nmodel: sonnet\nread:\n  - NOTES.md\n"
+            "\nauto-commits: false\n",
+        )
+
+        write_managed_file(
+            tmp_path,
+            ".aider.conf.yml",
+            "read:\n  - CONVENTIONS.md\n  - .aider/conventions/tester.md\n",
+        )
+        first = config.read_text()
+        write_managed_file(
+            tmp_path, ".aider.conf.yml", "read:\n  - CONVENTIONS.md\n"
+        )
+
+        assert first == (
+            "# my settings\nmodel: sonnet\nread:\n  - NOTES.md\n"
+            "  - CONVENTIONS.md\n  - .aider/conventions/tester.md\n"
+            "\nauto-commits: false\n"
+        )
+        assert config.read_text() == (
+            "# my settings\nmodel: sonnet\nread:\n  - NOTES.md\n"
+            "  - CONVENTIONS.md\n\nauto-commits: false\n"
+        )
+
+    def test_a_config_that_cannot_be_merged_is_left_alone(
+        self, tmp_path: Path
+    ) -> None:
+        config = write_file(tmp_path / ".aider.conf.yml", "model: [unclosed\n")
+
+        with pytest.raises(GenerationError):
+            write_managed_file(tmp_path, ".aider.conf.yml", "read:\n  - A.md\n")
+
+        assert config.read_text() == "model: [unclosed\n"
+
+
+class TestRegenerationCommand:
+    def test_hand_edits_to_claude_md_survive_regeneration(
+        self, tmp_path: Path
+    ) -> None:
+        write_file(tmp_path / "pyproject.toml", '[project]\nname = "shop"\n')
+        write_file(tmp_path / "shop" / "__init__.py", "")
+        runner = CliRunner()
+
+        first = runner.invoke(generate, ["claude-md", str(tmp_path)])
+        claude_md = tmp_path / "CLAUDE.md"
+        claude_md.write_text(claude_md.read_text() + "\n## Team Notes\nUse uv.\n")
+        second = runner.invoke(generate, ["claude-md", str(tmp_path)])
+
+        assert first.exit_code == 0, first.output
+        assert second.exit_code == 0, second.output
+        content = claude_md.read_text()
+        assert "<!-- claude-builder:begin section=header -->" in content
+        assert content.endswith("## Team Notes\nUse uv.\n")
@@ -2,6 +2,8 @@
 
 from pathlib import Path
 
+import yaml
+
 from claude_builder.core.models import (
     EnvironmentBundle,
     OutputTarget,
@@ -9,9 +11,13 @@ from claude_builder.core.models import (
     SubagentFile,
 )
 from claude_builder.core.output_renderers import (
+    AiderConventionsRenderer,
     ClaudeTargetRenderer,
     CodexTargetRenderer,
+    CopilotInstructionsRenderer,
+    CursorRulesRenderer,
     GeminiContextRenderer,
+    WindsurfRulesRenderer,
     get_target_renderer,
 )
 
@@ -128,3 +134,103 @@ def test_get_target_renderer_supports_all_targets() -> None:
     assert isinstance(get_target_renderer(OutputTarget.CLAUDE), ClaudeTargetRenderer)
     assert isinstance(get_target_renderer(OutputTarget.CODEX), CodexTargetRenderer)
     assert isinstance(get_target_renderer(OutputTarget.GEMINI), GeminiContextRenderer)
+    assert isinstance(get_target_renderer(OutputTarget.CURSOR), CursorRulesRenderer)
+    assert isinstance(
+        get_target_renderer(OutputTarget.COPILOT), CopilotInstructionsRenderer
+    )
+    assert isinstance(
+        get_target_renderer(OutputTarget.WINDSURF), WindsurfRulesRenderer
+    )
+    assert isinstance(get_target_renderer(OutputTarget.AIDER), AiderConventionsRenderer)
+
+
+def test_cursor_renderer_snapshot() -> None:
+    renderer = CursorRulesRenderer()
+    rendered = renderer.render(_sample_environment(), agents_dir=".cursor/rules")
+
+    expected = _read_snapshot("cursor_target_artifacts_snapshot.md")
+    assert rendered.target == OutputTarget.CURSOR
+    assert _snapshot_text(rendered) == expected
+    assert rendered.get_paths() == [
+        ".cursor/rules/project.mdc",
+        ".cursor/rules/test-writer-fixer.mdc",
+        ".cursor/rules/backend-architect.mdc",
+    ]
+
+
+def test_copilot_renderer_snapshot() -> None:
+    renderer = CopilotInstructionsRenderer()
+    rendered = renderer.render(
+        _sample_environment(), agents_dir=".github/instructions"
+    )
+
+    expected = _read_snapshot("copilot_target_artifacts_snapshot.md")
+    assert rendered.target == OutputTarget.COPILOT
+    assert _snapshot_text(rendered) == expected
+    assert rendered.get_paths() == [
+        ".github/copilot-instructions.md",
+        ".github/instructions/test-writer-fixer.instructions.md",
+        ".github/instructions/backend-architect.instructions.md",
+    ]
+
+
+def test_windsurf_renderer_snapshot() -> None:
+    renderer = WindsurfRulesRenderer()
+    rendered = renderer.render(_sample_environment())
+
+    expected = _read_snapshot("windsurf_target_artifacts_snapshot.md")
+    assert rendered.target == OutputTarget.WINDSURF
+    assert _snapshot_text(rendered) == expected
+    assert rendered.get_paths() == [".windsurfrules"]
+
+
+def test_aider_renderer_snapshot() -> None:
+    renderer = AiderConventionsRenderer()
+    rendered = renderer.render(_sample_environment(), agents_dir=".aider/conventions")
+
+    expected = _read_snapshot("aider_target_artifacts_snapshot.md")
+    assert rendered.target == OutputTarget.AIDER
+    assert _snapshot_text(rendered) == expected
+    assert rendered.get_paths() == [
+        "CONVENTIONS.md",
+        ".aider/conventions/test-writer-fixer.md",
+        ".aider/conventions/backend-architect.md",
+        ".aider.conf.yml",
+    ]
+
+
+def test_specialist_headings_are_nested_outside_code_blocks() -> None:
+    environment = _sample_environment()
+    environment.subagent_files = [
+        SubagentFile(
+            name="backend-architect.md",
+            content=(
+                "---\nname: backend-architect\n---\n\n"
+                "## Rules\nKeep it small.\n\n```bash\n# not a heading\n```"
+            ),
+            path=".claude/agents/backend-architect.md",
+        )
+    ]
+
+    rules = WindsurfRulesRenderer().render(environment).artifacts[0].content
+
+    assert "### backend-architect\n" in rules
+    assert "\n#### Rules\n" in rules
+    assert "```bash\n# not a heading\n```" in rules
+
+
+def test_cursor_rule_description_is_quoted_in_front_matter() -> None:
+    description = 'Reviews APIs: REST, "GraphQL" # and more'
+    environment = _sample_environment()
+    environment.subagent_files = [
+        SubagentFile(
+            name="api-reviewer.md",
+            content=f"---\nname: api-reviewer\ndescription: {description}\n---\n",
+            path=".claude/agents/api-reviewer.md",
+        )
+    ]
+
+    rule = CursorRulesRenderer().render(environment).artifacts[1].content
+    line = next(line for line in rule.splitlines() if line.startswith("description"))
+
+    assert yaml.safe_load(line) == {"description": description}
@@ -0,0 +1,157 @@
+"""Tests for the dependency-driven analysis pipeline."""
+
+import asyncio
+import threading
+
+from dataclasses import replace
+from pathlib import Path
+from typing import List
+
+import pytest
+
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.async_analyzer import AsyncProjectAnalyzer
+from claude_builder.core.pipeline import AnalysisPipeline, AnalysisStage
+from claude_builder.utils.exceptions import AnalysisError
+
+
+def _write_project(root: Path) -> Path:
+    (root / "src").mkdir()
+    (root / "src" / "app.py").write_text("from fastapi import FastAPI\n")
+    (root / "tests").mkdir()
+    (root / "tests" / "test_app.py").write_text("def test_app():\n    pass\n")
+    (root / "requirements.txt").write_text("fastapi\npytest\n")
+    (root / "Dockerfile").write_text("FROM python:3.11\n")
+    return root
+
+
+class TestAnalysisPipeline:
+    def test_stages_run_in_dependency_order(self) -> None:
+        pipeline = AnalysisPipeline(
+            [
+                AnalysisStage(
+                    "total", lambda r: r["double"] + r["base"], ("double", "base")
+                ),
+                AnalysisStage("double", lambda r: r["base"] * 2, ("base",)),
+                AnalysisStage("base", lambda r: r["seed"] + 1, ("seed",)),
+            ],
+            parallel=False,
+        )
+
+        assert [stage.name for stage in pipeline.order(["seed"])] == [
+            "base",
+            "double",
+            "total",
+        ]
+        assert pipeline.run({"seed": 1}) == {
+            "seed": 1,
+            "base": 2,
+            "double": 4,
+            "total": 6,
+        }
+
+    def test_undeclared_inputs_are_not_visible(self) -> None:
+        pipeline = AnalysisPipeline(
+            [
+                AnalysisStage("a", lambda r: 1),
+                AnalysisStage("b", lambda r: r["a"]),
+            ],
+            parallel=False,
+        )
+
+        with pytest.raises(KeyError):
+            pipeline.run()
+
+    def test_unknown_and_cyclic_requirements(self) -> None:
+        unknown = AnalysisPipeline([AnalysisStage("a", lambda r: 1, ("missing",))])
+        cyclic = AnalysisPipeline(
+            [
+                AnalysisStage("a", lambda r: 1, ("b",)),
+                AnalysisStage("b", lambda r: 1, ("a",)),
+            ]
+        )
+
+        with pytest.raises(ValueError, match="unknown missing"):
+            unknown.run()
+        with pytest.raises(ValueError, match="depend on each other"):
+            cyclic.run()
+        with pytest.raises(ValueError, match="Duplicate"):
+            AnalysisPipeline([AnalysisStage("a", len), AnalysisStage("a", len)])
+
+    def test_independent_stages_run_concurrently(self) -> None:
+        # Both stages must be running at the same time to pass the barrier
+        barrier = threading.Barrier(2, timeout=5)
+        threads: List[str] = []
+
+        def stage(results: dict) -> int:
+            threads.append(threading.current_thread().name)
+            return barrier.wait()
+
+        results = AnalysisPipeline(
+            [
+                AnalysisStage("left", stage),
+                AnalysisStage("right", stage),
+                AnalysisStage(
+                    "both", lambda r: (r["left"], r["right"]), ("left", "right")
+                ),
+            ]
+        ).run()
+
+        assert sorted(results["both"]) == [0, 1]
+        assert threading.current_thread().name not in threads
+
+    def test_first_error_is_raised_and_dependants_are_skipped(self) -> None:
+        ran: List[str] = []
+
+        def fail(results: dict) -> None:
+            msg = "detector failed"
+            raise OSError(msg)
+
+        pipeline = AnalysisPipeline(
+            [
+                AnalysisStage("broken", fail),
+                AnalysisStage("after", lambda r: ran.append("after"), ("broken",)),
+            ]
+        )
+
+        with pytest.raises(OSError, match="detector failed"):
+            pipeline.run()
+        assert ran == []
+
+
+class TestSharedPipeline:
+    def test_parallel_and_sequential_analyses_match(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+
+        parallel = ProjectAnalyzer({"parallel_processing": True}).analyze(root)
+        sequential = ProjectAnalyzer({"parallel_processing": False}).analyze(root)
+
+        assert parallel.language_info.primary == "python"
+        assert replace(parallel, analysis_timestamp="") == replace(
+            sequential, analysis_timestamp=""
+        )
+
+    def test_async_analyzer_returns_the_sync_analysis(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+
+        sync = ProjectAnalyzer().analyze(root.resolve())
+        analyzer = AsyncProjectAnalyzer(enable_caching=False)
+        async_result = asyncio.run(analyzer.analyze_async(root))
+
+        assert async_result.analyzer_version == sync.analyzer_version
+        assert replace(async_result, analysis_timestamp="") == replace(
+            sync, analysis_timestamp=""
+        )
+
+    def test_stage_errors_surface_as_analysis_errors(self, tmp_path: Path) -> None:
+        root = _write_project(tmp_path)
+        analyzer = ProjectAnalyzer()
+
+        def fail(*args: object, **kwargs: object) -> None:
+            msg = "boom"
+            raise RuntimeError(msg)
+
+        analyzer.domain_detector.detect = fail  # type: ignore[method-assign]
+
+        with pytest.raises(AnalysisError, match="Failed to analyze project: boom"):
+            analyzer.analyze(root)
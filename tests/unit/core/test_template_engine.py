@@ -0,0 +1,162 @@
+"""Tests for the shared sandboxed template engine and legacy syntax migration."""
+
+import asyncio
+
+from pathlib import Path
+
+import pytest
+
+from jinja2.exceptions import SecurityError, UndefinedError
+
+from claude_builder.core.async_generator import AsyncDocumentGenerator
+from claude_builder.core.generator import TemplateLoader
+from claude_builder.core.template_engine import (
+    TEMPLATES_ROOT,
+    create_environment,
+    migrate_template_syntax,
+    render_template_string,
+)
+from claude_builder.core.template_manager import CoreTemplateManager, Template
+from tests.conftest import write_file
+
+
+class TestLegacySyntax:
+    def test_unknown_and_foreign_placeholders_stay_literal(self) -> None:
+        template = (
+            "# ${project_name} ${license}\n"
+            "fetch(`/users/${userId}/${doc.id}`) ${DATABASE_URL}\n"
+            "f'/{${app}.slug}/'\n"
+            "run: docker push ${{ secrets.REGISTRY }}:${{ github.sha }}\n"
+        )
+
+        rendered = render_template_string(
+            template, {"project_name": "shop", "license": None, "app": "orders"}
+        )
+
+        assert rendered == (
+            "# shop \n"
+            "fetch(`/users/${userId}/${doc.id}`) ${DATABASE_URL}\n"
+            "f'/{orders.slug}/'\n"
+            "run: docker push ${{ secrets.REGISTRY }}:${{ github.sha }}\n"
+        )
+
+    def test_sections_loop_with_item_fields_shadowing_outer_names(self) -> None:
+        template = (
+            "${#agents}\n"
+            "- ${name} (${project_name})\n"
+            "${/agents}\n"
+            "${^agents}\n"
+            "No agents\n"
+            "${/agents}\n"
+        )
+        agents = [{"name": "api-dev"}, {"name": "tester", "project_name": "qa"}]
+
+        with_agents = render_template_string(
+            template, {"agents": agents, "name": "x", "project_name": "shop"}
+        )
+        without = render_template_string(template, {"agents": []})
+
+        assert with_agents == "- api-dev (shop)\n- tester (qa)\n"
+        assert without == "No agents\n"
+
+    def test_handlebars_blocks_keep_inline_layout(self) -> None:
+        template = (
+            "Stack: Python{{#if framework}} with ${framework}{{/if}}\n"
+            "{{#each extras}}\n"
+            "- {{item}}\n"
+            "{{/each}}\n"
+        )
+
+        rendered = render_template_string(
+            template, {"framework": "FastAPI", "extras": ["Docker", "Redis"]}
+        )
+
+        assert rendered == "Stack: Python with FastAPI\n- Docker\n- Redis\n"
+        assert "{% for item in extras | section %}" in migrate_template_syntax(
+            template
+        )
+
+
+class TestSandboxedEnvironment:
+    def test_unsafe_attribute_access_is_blocked(self) -> None:
+        with pytest.raises(SecurityError):
+            render_template_string("{{ ''.__class__.__mro__[1].__subclasses__() }}", {})
+
+    def test_strictness_only_applies_to_jinja_variables(self) -> None:
+        with pytest.raises(UndefinedError):
+            render_template_string("{{ missing }}", {})
+
+        assert render_template_string("{{ missing.name }}", {}, strict=False) == ""
+        assert render_template_string("${missing}", {}) == "${missing}"
+
+    def test_case_filters_accept_none_and_undefined_values(self) -> None:
+        template = "{{ value | upper }}|{{ value | lower }}|{{ value | title }}"
+
+        assert render_template_string(template, {}, strict=False) == "||"
+        assert render_template_string(template, {"value": None}) == "NONE|none|None"
+        assert render_template_string(template, {"value": "web api"}) == (
+            "WEB API|web api|Web Api"
+        )
+        with pytest.raises(UndefinedError):
+            render_template_string(template, {})
+
+    def test_every_packaged_template_compiles(self) -> None:
+        env = create_environment()
+
+        for path in TEMPLATES_ROOT.rglob("*"):
+            if path.is_file():
+                env.get_template(path.relative_to(TEMPLATES_ROOT).as_posix())
+
+    def test_user_templates_share_macros_and_includes(self, tmp_path: Path) -> None:
+        write_file(tmp_path / "partials" / "stack.md", "Built with ${language}\n")
+        write_file(
+            tmp_path / "TOOLS.md",
+            "{% import '_macros.md' as macros %}\n"
+            "{% include 'partials/stack.md' %}\n"
+            "{{ macros.tool_header_inline(tool) }}\n",
+        )
+        context = {
+            "language": "Rust",
+            "tool": {"display_name": "Terraform", "confidence": "high", "score": 9},
+        }
+
+        rendered = CoreTemplateManager([str(tmp_path)]).render_template_by_name(
+            "TOOLS", context
+        )
+
+        assert rendered == (
+            "Built with Rust\n**Detected:** Terraform (High) | Score: 9.0\n"
+        )
+
+    def test_loader_substitution_uses_the_shared_engine(self) -> None:
+        rendered = TemplateLoader().substitute_variables(
+            "{{#if has_tests}}Run ${test_command}{{/if}}",
+            {"has_tests": True, "test_command": "cargo test"},
+        )
+
+        assert rendered == "Run cargo test"
+
+    def test_legacy_template_objects_render_with_jinja(self) -> None:
+        template = Template(
+            "guide",
+            content="{% for step in steps %}{{ loop.index }}. {{ step }}\n{% endfor %}",
+        )
+
+        assert template.render(steps=["lint", "test"]) == "1. lint\n2. test\n"
+        with pytest.raises(SecurityError):
+            Template(
+                "bad", content="{{ ''.__class__.__mro__[1].__subclasses__() }}"
+            ).render()
+
+    def test_async_generator_renders_through_the_sandbox(self) -> None:
+        generator = AsyncDocumentGenerator(enable_caching=False)
+
+        rendered = asyncio.run(
+            generator._render_template_async(
+                "claude_md_template", {"project_name": "shop"}
+            )
+        )
+
+        assert "## Project: shop\n" in rendered
+        assert "{{" not in rendered
+        assert "$" not in rendered
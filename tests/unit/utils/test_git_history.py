@@ -0,0 +1,348 @@
+"""Tests for git history analytics on real repositories."""
+
+import os
+import subprocess
+
+from pathlib import Path
+
+import pytest
+
+from click.testing import CliRunner
+
+from claude_builder.cli.generate_commands import generate
+from claude_builder.cli.git_commands import git
+from claude_builder.cli.main import cli
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.core.generator import DocumentGenerator
+from claude_builder.core.models import ClaudeMentionPolicy
+from claude_builder.utils.git import (
+    AdvancedGitAnalyzer,
+    GitHookManager,
+    GitInsights,
+    detect_commit_convention,
+    infer_commit_convention,
+)
+
+
+pytest.importorskip("git")
+
+DAY = 86400
+START = 1_700_000_000
+
+
+class _Repo:
+    """A throwaway repository with commits at fixed dates."""
+
+    def __init__(self, root: Path) -> None:
+        self.root = root
+        self.day = 0
+        root.mkdir(parents=True, exist_ok=True)
+        self.git("init", "-q", "-b", "main")
+
+    def git(self, *args: str) -> None:
+        date = f"@{START + self.day * DAY} +0000"
+        env = {
+            **os.environ,
+            "GIT_AUTHOR_NAME": "Dev",
+            "GIT_AUTHOR_EMAIL": "dev@example.com",
+            "GIT_COMMITTER_NAME": "Dev",
+            "GIT_COMMITTER_EMAIL": "dev@example.com",
+            "GIT_AUTHOR_DATE": date,
+            "GIT_COMMITTER_DATE": date,
+        }
+        subprocess.run(
+            ["git", *args], cwd=self.root, env=env, check=True, capture_output=True
+        )
+
+    def commit(self, subject: str, *paths: str, day: int = 1) -> None:
+        self.day += day
+        for path in paths:
+            file = self.root / path
+            file.parent.mkdir(parents=True, exist_ok=True)
+            with file.open("a") as handle:
+                handle.write(f"{subject}\n")
+        self.git("add", "-A")
+        self.git("commit", "-q", "--allow-empty", "-m", subject)
+
+    def try_commit(self, subject: str) -> "subprocess.CompletedProcess[str]":
+        return subprocess.run(
+            ["git", "commit", "-q", "--allow-empty", "-m", subject],
+            cwd=self.root,
+            env={**os.environ, "GIT_AUTHOR_NAME": "Dev", "GIT_COMMITTER_NAME": "Dev"},
+            capture_output=True,
+            text=True,
+        )
+
+
+def _service(root: Path) -> _Repo:
+    repo = _Repo(root)
+    repo.commit("feat(api): add orders endpoint", "app/api.py", "app/models.py")
+    repo.git("tag", "v1.0.0")
+    repo.commit("fix(api): validate order totals", "app/api.py", "app/models.py")
+    repo.commit("docs: describe the API", "README.md")
+    repo.commit("feat(api): add refunds", "app/api.py", "app/models.py", day=6)
+    repo.git("tag", "v1.1.0")
+    repo.commit("refactor(db): pool connections", "app/db.py", "app/api.py")
+    repo.commit("fix: urgent hotfix for refund rounding", "app/api.py")
+    repo.commit("chore(deps): bump requests", "requirements.txt", day=6)
+    repo.git("tag", "v1.2.0")
+    return repo
+
+
+class TestCommitConventions:
+    def test_conventional_commits_with_types_and_scopes(self) -> None:
+        convention = detect_commit_convention(
+            [
+                "feat(api): add orders",
+                "fix(api): reject empty carts",
+                "Merge pull request #4 from dev/orders",
+                "feat(db)!: drop legacy tables",
+                "fix typo",
+            ]
+        )
+
+        assert convention.name == "conventional_commits"
+        assert convention.share == 0.75
+        assert convention.types == {"feat": 2, "fix": 1}
+        assert convention.scopes[0] == "api"
+
+    def test_bracketed_and_ticket_prefixes_narrow_the_pattern(self) -> None:
+        bracketed = detect_commit_convention(
+            ["[task-12] Add metrics", "[task-13] Mine history", "[task-20] Hook"]
+        )
+        tickets = detect_commit_convention(
+            ["SHOP-12: add cart", "SHOP-40 fix totals", "[PAY-3] refunds"]
+        )
+
+        assert bracketed.name == "bracket_prefix"
+        assert bracketed.pattern == r"^\[(task-[0-9]+)\] [^ ]"
+        assert tickets.name == "ticket_prefix"
+        assert tickets.types == {"SHOP": 2, "PAY": 1}
+
+    def test_mixed_subjects_are_free_form(self) -> None:
+        convention = detect_commit_convention(
+            ["Add cart", "feat: orders", "wip", "Fix the build"]
+        )
+
+        assert convention.name == "free_form"
+        assert convention.pattern == ""
+
+
+class TestHistoryAnalytics:
+    def test_service_history(self, tmp_path: Path) -> None:
+        _service(tmp_path)
+
+        info = AdvancedGitAnalyzer(tmp_path).history_info()
+
+        assert info.commits_analyzed == 7
+        assert info.commit_convention.name == "conventional_commits"
+        assert info.hotspots[0].path == "app/api.py"
+        assert info.hotspots[0].commits == 5
+        assert [(p.files, p.commits) for p in info.co_changes] == [
+            (["app/api.py", "app/models.py"], 3)
+        ]
+        assert info.releases.tags == ["v1.0.0", "v1.1.0", "v1.2.0"]
+        assert info.releases.version_pattern == "semantic"
+        assert info.releases.median_days == 8
+        assert info.hotfix_commits == 1
+
+    def test_deleted_files_and_lockfiles_are_not_hotspots(
+        self, tmp_path: Path
+    ) -> None:
+        repo = _Repo(tmp_path)
+        for i in range(3):
+            repo.commit(f"Bump {i}", "old.py", "poetry.lock", "app.py")
+        repo.git("rm", "-q", "old.py")
+        repo.commit("Remove old module")
+
+        info = AdvancedGitAnalyzer(tmp_path).history_info()
+
+        assert [entry.path for entry in info.hotspots] == ["app.py"]
+        assert info.co_changes == []
+
+    def test_branching_strategy_from_refs_and_merges(self, tmp_path: Path) -> None:
+        gitflow = _Repo(tmp_path / "gitflow")
+        gitflow.commit("Initial commit", "app.py")
+        gitflow.git("branch", "develop")
+        gitflow.git("checkout", "-q", "-b", "release/1.0")
+        gitflow.commit("Bump version", "VERSION", day=2)
+        gitflow.commit("Fix release notes", "NOTES.md", day=3)
+        feature = _Repo(tmp_path / "feature")
+        feature.commit("Initial commit", "app.py")
+        feature.git("checkout", "-q", "-b", "orders")
+        feature.commit("Add orders", "orders.py")
+        feature.git("checkout", "-q", "main")
+        feature.git("merge", "-q", "--no-ff", "orders", "-m", "Merge branch 'orders'")
+        feature.git("branch", "-D", "orders")
+        trunk = _Repo(tmp_path / "trunk")
+        trunk.commit("Initial commit", "app.py")
+
+        gitflow_analyzer = AdvancedGitAnalyzer(tmp_path / "gitflow")
+        branches = gitflow_analyzer.branch_analyzer.analyze_branches()
+        feature_info = AdvancedGitAnalyzer(tmp_path / "feature").history_info()
+
+        assert branches.strategy_type == "gitflow"
+        assert branches.release_branches == ["release/1.0"]
+        assert branches.branch_lifespans == {"release/1.0": 3.0}
+        assert feature_info.branching_strategy == "feature_branches"
+        assert feature_info.merge_commits == 1
+        assert (
+            AdvancedGitAnalyzer(tmp_path / "trunk").history_info().branching_strategy
+            == "trunk_based"
+        )
+
+    def test_insights_come_from_the_history(self, tmp_path: Path) -> None:
+        _service(tmp_path)
+
+        insights = GitInsights(AdvancedGitAnalyzer(tmp_path))
+
+        assert (
+            "Commit subjects follow Conventional Commits (100% of recent commits)"
+            in insights.generate_workflow_insights()
+        )
+        assert "`app/api.py` changed in 5 commits" in (
+            insights.generate_performance_insights()
+        )
+        assert insights.generate_recommendations() == [
+            "Keep `app/api.py` well tested; it changes more than any other file"
+        ]
+
+
+def _tickets(root: Path) -> _Repo:
+    repo = _Repo(root)
+    repo.git("config", "user.email", "dev@example.com")
+    for subject in ("SHOP-1: add cart", "SHOP-2: add checkout", "PAY-7: refunds"):
+        repo.commit(subject, "app.py")
+    return repo
+
+
+class TestConventionHook:
+    def test_hook_rejects_subjects_breaking_the_convention(
+        self, tmp_path: Path
+    ) -> None:
+        repo = _tickets(tmp_path)
+        convention = infer_commit_convention(tmp_path)
+
+        result = GitHookManager().install_commit_msg_hook(
+            tmp_path, ClaudeMentionPolicy.ALLOWED, convention
+        )
+
+        assert result.operations_performed == [
+            "Installed commit-msg hook for the commit convention",
+            "Commit-msg hook enforces ticket-key prefixes",
+        ]
+        rejected = repo.try_commit("add coupons")
+        assert rejected.returncode != 0
+        assert "subject does not follow ticket-key prefixes" in rejected.stderr
+        assert "e.g. PAY-7: refunds" in rejected.stderr
+        assert repo.try_commit("SHOP-3: add coupons").returncode == 0
+        assert repo.try_commit("Revert \"SHOP-3: add coupons\"").returncode == 0
+
+    def test_install_message_names_what_the_hook_checks(
+        self, tmp_path: Path
+    ) -> None:
+        manager = GitHookManager()
+        messages = {}
+        for policy in (ClaudeMentionPolicy.ALLOWED, ClaudeMentionPolicy.MINIMAL):
+            project = tmp_path / policy.value
+            _tickets(project)
+            result = manager.install_commit_msg_hook(project, policy)
+            messages[policy] = result.operations_performed
+
+        assert messages == {
+            ClaudeMentionPolicy.ALLOWED: [
+                "Installed commit-msg hook; the allowed policy leaves "
+                "messages unchanged"
+            ],
+            ClaudeMentionPolicy.MINIMAL: [
+                "Installed commit-msg hook for Claude mention filtering"
+            ],
+        }
+
+    def test_existing_hook_still_runs(self, tmp_path: Path) -> None:
+        repo = _tickets(tmp_path)
+        existing = tmp_path / ".git" / "hooks" / "commit-msg"
+        existing.parent.mkdir(parents=True, exist_ok=True)
+        existing.write_text(
+            "#!/bin/sh\ngrep -q WIP \"$1\" && echo no WIP >&2 && exit 1\nexit 0\n"
+        )
+        existing.chmod(0o755)
+        manager = GitHookManager()
+
+        manager.install_commit_msg_hook(tmp_path, ClaudeMentionPolicy.MINIMAL)
+        result = manager.install_commit_msg_hook(
+            tmp_path, ClaudeMentionPolicy.MINIMAL, infer_commit_convention(tmp_path)
+        )
+
+        assert result.operations_performed[0] == "Updated commit-msg hook"
+        assert "SHOP" in existing.read_text()
+        assert repo.try_commit("SHOP-4: WIP coupons").stderr.strip() == "no WIP"
+        assert repo.try_commit("SHOP-4: coupons").returncode == 0
+
+    def test_install_hooks_command_enforces_convention(self, tmp_path: Path) -> None:
+        _tickets(tmp_path)
+
+        result = CliRunner().invoke(
+            git, ["install-hooks", str(tmp_path), "--enforce-convention"]
+        )
+
+        assert result.exit_code == 0, result.output
+        assert "Enforcing commit convention: ticket_prefix" in result.output
+        hook = (tmp_path / ".git" / "hooks" / "commit-msg").read_text()
+        assert "'^\\[?(PAY|SHOP)-[0-9]+\\]?:? [^ ]'" in hook
+
+
+class TestGeneratedWorkflow:
+    def test_claude_md_lists_conventions_and_risky_files(self, tmp_path: Path) -> None:
+        root = tmp_path / "project"
+        _service(root)
+        analysis = ProjectAnalyzer({"cache_enabled": False}).analyze(root)
+
+        claude_md = DocumentGenerator().generate(analysis, tmp_path).files["CLAUDE.md"]
+
+        assert "## Git Workflow" in claude_md
+        assert "Recent commits follow Conventional Commits (100%)" in claude_md
+        assert "  - `chore(deps): bump requests`" in claude_md
+        assert "3 tags, latest `v1.2.0`, about every 8 days" in claude_md
+        assert "  - `app/api.py` and `app/models.py`: 3 shared commits" in claude_md
+
+    def test_default_command_writes_the_git_workflow(self, tmp_path: Path) -> None:
+        _service(tmp_path)
+
+        result = CliRunner().invoke(cli, [str(tmp_path)])
+
+        assert result.exit_code == 0, result.output
+        claude_md = (tmp_path / "CLAUDE.md").read_text()
+        assert "## Git Workflow" in claude_md
+        assert "3 tags, latest `v1.2.0`, about every 8 days" in claude_md
+        assert "  - `app/api.py`: 5 commits, +5/-0 lines" in claude_md
+
+    def test_every_generation_path_lists_commit_guidelines(
+        self, tmp_path: Path
+    ) -> None:
+        default_root = tmp_path / "default"
+        complete_root = tmp_path / "complete"
+        _service(default_root)
+        _service(complete_root)
+        runner = CliRunner()
+
+        default = runner.invoke(cli, [str(default_root)])
+        complete = runner.invoke(
+            generate, ["complete", str(complete_root), "--no-suggestions"]
+        )
+
+        assert default.exit_code == 0, default.output
+        assert complete.exit_code == 0, complete.output
+        for root in (default_root, complete_root):
+            claude_md = (root / "CLAUDE.md").read_text()
+            assert "### Commit Guidelines" in claude_md
+            assert "Recent commits follow Conventional Commits (100%)" in claude_md
+
+    def test_history_can_be_disabled(self, tmp_path: Path) -> None:
+        _service(tmp_path)
+
+        analysis = ProjectAnalyzer(
+            {"cache_enabled": False, "git_history": False}
+        ).analyze(tmp_path)
+
+        assert analysis.git_history is None
@@ -0,0 +1,130 @@
+"""Tests for the single-pass project walker."""
+
+import os
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from claude_builder.analysis.detectors.infrastructure import InfrastructureDetector
+from claude_builder.core.analyzer import ProjectAnalyzer
+from claude_builder.utils.project_files import matches_ignore_pattern, walk_project
+from tests.conftest import write_file
+
+
+def _relative(files: "list[Path]", root: Path) -> "list[str]":
+    return sorted(path.relative_to(root).as_posix() for path in files)
+
+
+class TestWalkProject:
+    def test_gitignore_at_any_depth_and_ignore_patterns(self, tmp_path: Path) -> None:
+        write_file(tmp_path / ".gitignore", "logs/\n*.tmp\n!keep.tmp\n")
+        write_file(tmp_path / "pkg" / ".gitignore", "out/\n")
+        write_file(tmp_path / "src" / "app.py")
+        write_file(tmp_path / "src" / "scratch.tmp")
+        write_file(tmp_path / "src" / "keep.tmp")
+        write_file(tmp_path / "logs" / "run.log")
+        write_file(tmp_path / "pkg" / "out" / "gen.py")
+        write_file(tmp_path / "pkg" / "lib.py")
+        write_file(tmp_path / "node_modules" / "dep" / "index.js")
+        write_file(tmp_path / ".github" / "workflows" / "ci.yml")
+
+        files = walk_project(tmp_path)
+
+        assert _relative(files.files, tmp_path) == [
+            ".github/workflows/ci.yml",
+            ".gitignore",
+            "pkg/.gitignore",
+            "pkg/lib.py",
+            "src/app.py",
+            "src/keep.tmp",
+        ]
+        assert _relative(files.directories, tmp_path) == [
+            ".github",
+            ".github/workflows",
+            "pkg",
+            "src",
+        ]
+
+        unfiltered = walk_project(tmp_path, respect_gitignore=False)
+        assert "logs/run.log" in _relative(unfiltered.files, tmp_path)
+
+    def test_size_cap_and_file_limit(self, tmp_path: Path) -> None:
+        big = write_file(tmp_path / "big.py", "x = 1\n" * 100)
+        small = write_file(tmp_path / "small.py")
+
+        files = walk_project(tmp_path, max_file_size=100)
+        assert files.files == [big, small]
+        assert files.read_text(big) is None
+        assert files.read_text(small) == "x\n"
+
+        limited = walk_project(tmp_path, max_files=1)
+        assert limited.files == [big]
+        assert limited.truncated
+
+    @pytest.mark.skipif(not hasattr(os, "symlink"), reason="symlinks unsupported")
+    def test_symlink_policy(self, tmp_path: Path) -> None:
+        project = tmp_path / "project"
+        write_file(project / "src" / "app.py")
+        write_file(tmp_path / "elsewhere" / "secret.py")
+        (project / "outside").symlink_to(tmp_path / "elsewhere")
+        (project / "alias").symlink_to(project / "src")
+
+        skipped = walk_project(project)
+        assert _relative(skipped.files, project) == ["src/app.py"]
+        assert sorted(skipped.skipped_symlinks) == ["alias", "outside"]
+
+        # Followed only when the target stays inside the project
+        followed = walk_project(project, follow_symlinks=True)
+        assert _relative(followed.files, project) == ["alias/app.py", "src/app.py"]
+        assert followed.skipped_symlinks == ["outside"]
+
+    def test_directory_patterns_match_whole_components(self) -> None:
+        assert matches_ignore_pattern("a/build/x.py", ["build/"])
+        assert not matches_ignore_pattern("buildtools/x.py", ["build/"])
+        assert not matches_ignore_pattern(".github/ci.yml", [".git/"])
+        assert matches_ignore_pattern("htmlcov/.coverage", [".coverage"])
+
+
+class TestSharedIndex:
+    def test_analyzer_walks_the_tree_once(self, tmp_path: Path) -> None:
+        write_file(tmp_path / ".gitignore", "generated/\n")
+        write_file(tmp_path / "pyproject.toml", '[project]\nname = "demo"\n')
+        write_file(tmp_path / "src" / "app.py", "def main():\n    pass\n")
+        write_file(tmp_path / "generated" / "big.js", "var a;\n" * 50)
+        write_file(tmp_path / "infra" / "main.tf", 'resource "x" "y" {}\n')
+
+        with patch.object(Path, "rglob", side_effect=AssertionError("rglob")), patch(
+            "claude_builder.utils.project_files.os.walk", wraps=os.walk
+        ) as walk:
+            analysis = ProjectAnalyzer().analyze(tmp_path)
+
+        # One os.walk generator for the whole analysis
+        assert sum(1 for call in walk.call_args_list if call.args[0] == tmp_path) == 1
+        assert analysis.language_info.file_counts == {"python": 1}
+        assert analysis.filesystem_info.total_files == 4
+        assert "terraform" in analysis.dev_environment.infrastructure_as_code
+
+    def test_file_limit_is_reported(self, tmp_path: Path) -> None:
+        for name in ("a.py", "b.py", "c.py"):
+            write_file(tmp_path / name)
+
+        analysis = ProjectAnalyzer({"max_files_to_analyze": 2}).analyze(tmp_path)
+
+        assert analysis.filesystem_info.total_files == 2
+        assert any("first 2 files" in warning for warning in analysis.warnings)
+
+    def test_detectors_share_a_supplied_index(self, tmp_path: Path) -> None:
+        write_file(tmp_path / "infra" / "main.tf")
+        files = walk_project(tmp_path)
+
+        with patch(
+            "claude_builder.utils.project_files.os.walk",
+            side_effect=AssertionError("walked again"),
+        ):
+            detector = InfrastructureDetector(tmp_path, files=files)
+            results, metadata = detector.detect_with_metadata()
+
+        assert results["infrastructure_as_code"] == ["terraform"]
+        assert metadata["terraform"].files == ["infra/main.tf"]